@@ -0,0 +1,284 @@
+//! League-wide leaders for one day's slate, from the final boxscores.
+
+use std::cmp::Ordering;
+use std::collections::HashMap;
+
+use crate::ice_time::IceTime;
+use crate::ids::{GameId, PlayerId};
+use crate::types::{Boxscore, GameMatchup, GoalieDecision, Position, TeamPlayerStats, ThreeStar};
+
+/// One skater's line in a [`DailyLeaders`] table.
+#[derive(Debug, Clone, PartialEq)]
+pub struct DailyLeaderEntry {
+    pub game_id: GameId,
+    pub player_id: PlayerId,
+    pub name: String,
+    pub team_abbrev: String,
+    pub position: Option<Position>,
+    pub goals: i32,
+    pub assists: i32,
+    pub points: i32,
+    pub sog: i32,
+    pub toi: IceTime,
+}
+
+/// One goalie's line in a [`DailyLeaders`] table.
+#[derive(Debug, Clone, PartialEq)]
+pub struct DailyGoalieEntry {
+    pub game_id: GameId,
+    pub player_id: PlayerId,
+    pub name: String,
+    pub team_abbrev: String,
+    pub saves: i32,
+    pub shots_against: i32,
+    pub goals_against: i32,
+    pub decision: Option<GoalieDecision>,
+    pub toi: IceTime,
+}
+
+impl DailyGoalieEntry {
+    /// Saves over shots against; `None` without a shot faced.
+    pub fn save_pctg(&self) -> Option<f64> {
+        (self.shots_against > 0).then(|| self.saves as f64 / self.shots_against as f64)
+    }
+}
+
+/// Every skater and goalie who played in a day's final games, ranked, with
+/// each game's official three stars.
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct DailyLeaders {
+    /// By points, then goals.
+    pub skaters: Vec<DailyLeaderEntry>,
+    /// Goalies with ice time, by saves, then save percentage.
+    pub goalies: Vec<DailyGoalieEntry>,
+    /// Official three stars of each final game, first star first. Missing
+    /// for games whose summary names none.
+    pub three_stars_by_game: HashMap<GameId, Vec<ThreeStar>>,
+    /// Games on the slate that weren't final, in the order given.
+    pub pending: Vec<GameId>,
+}
+
+impl DailyLeaders {
+    /// Leaders from a day's boxscores and, for the three stars, the same
+    /// games' landing payloads. Boxscores of games that aren't final go to
+    /// [`Self::pending`] and count for nothing.
+    pub fn from_games(boxscores: &[Boxscore], landings: &[GameMatchup]) -> Self {
+        let mut leaders = Self::default();
+        for boxscore in boxscores {
+            if !boxscore.game_state.is_final() {
+                leaders.pending.push(boxscore.id);
+                continue;
+            }
+            let stats = &boxscore.player_by_game_stats;
+            leaders.add_team(boxscore.id, &boxscore.away_team.abbrev, &stats.away_team);
+            leaders.add_team(boxscore.id, &boxscore.home_team.abbrev, &stats.home_team);
+
+            let stars = landings
+                .iter()
+                .find(|landing| landing.id == boxscore.id)
+                .and_then(|landing| landing.summary.as_ref())
+                .map(|summary| summary.three_stars.clone())
+                .unwrap_or_default();
+            if !stars.is_empty() {
+                let mut stars = stars;
+                stars.sort_by_key(|star| star.star);
+                leaders.three_stars_by_game.insert(boxscore.id, stars);
+            }
+        }
+
+        leaders.skaters.sort_by(|a, b| {
+            b.points
+                .cmp(&a.points)
+                .then(b.goals.cmp(&a.goals))
+                .then(a.player_id.cmp(&b.player_id))
+        });
+        leaders.goalies.sort_by(|a, b| {
+            b.saves
+                .cmp(&a.saves)
+                .then(
+                    b.save_pctg()
+                        .partial_cmp(&a.save_pctg())
+                        .unwrap_or(Ordering::Equal),
+                )
+                .then(a.player_id.cmp(&b.player_id))
+        });
+        leaders
+    }
+
+    fn add_team(&mut self, game_id: GameId, team_abbrev: &str, players: &TeamPlayerStats) {
+        for skater in players.forwards.iter().chain(&players.defense) {
+            self.skaters.push(DailyLeaderEntry {
+                game_id,
+                player_id: skater.player_id,
+                name: skater.name.default.clone(),
+                team_abbrev: team_abbrev.to_string(),
+                position: skater.position.clone(),
+                goals: skater.goals,
+                assists: skater.assists,
+                points: skater.points,
+                sog: skater.sog,
+                toi: skater.toi,
+            });
+        }
+        for goalie in players
+            .goalies
+            .iter()
+            .filter(|goalie| goalie.toi > IceTime::ZERO)
+        {
+            self.goalies.push(DailyGoalieEntry {
+                game_id,
+                player_id: goalie.player_id,
+                name: goalie.name.default.clone(),
+                team_abbrev: team_abbrev.to_string(),
+                saves: goalie.saves,
+                shots_against: goalie.shots_against,
+                goals_against: goalie.goals_against,
+                decision: goalie.decision.clone(),
+                toi: goalie.toi,
+            });
+        }
+    }
+
+    /// Skaters by goals, then points.
+    pub fn goal_leaders(&self) -> Vec<&DailyLeaderEntry> {
+        let mut skaters: Vec<&DailyLeaderEntry> = self.skaters.iter().collect();
+        skaters.sort_by(|a, b| b.goals.cmp(&a.goals).then(b.points.cmp(&a.points)));
+        skaters
+    }
+
+    /// Goalies who faced at least `min_shots_against` shots, by save
+    /// percentage, then saves.
+    pub fn save_pctg_leaders(&self, min_shots_against: i32) -> Vec<&DailyGoalieEntry> {
+        let mut goalies: Vec<&DailyGoalieEntry> = self
+            .goalies
+            .iter()
+            .filter(|goalie| goalie.shots_against >= min_shots_against.max(1))
+            .collect();
+        goalies.sort_by(|a, b| {
+            b.save_pctg()
+                .partial_cmp(&a.save_pctg())
+                .unwrap_or(Ordering::Equal)
+                .then(b.saves.cmp(&a.saves))
+        });
+        goalies
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const BOXSCORE_JSON: &str = include_str!("../../tests/fixtures/boxscore.json");
+
+    /// The boxscore fixture as game `game_id` in `game_state`.
+    fn boxscore(game_id: i64, game_state: &str) -> Boxscore {
+        let json = BOXSCORE_JSON.replace(
+            r#""gameState": "LIVE""#,
+            &format!(r#""gameState": "{}""#, game_state),
+        );
+        let mut boxscore: Boxscore = serde_json::from_str(&json).unwrap();
+        boxscore.id = GameId::new(game_id);
+        boxscore
+    }
+
+    fn landing_with_stars(game_id: i64, stars: &[(i32, i64)]) -> GameMatchup {
+        let stars: Vec<String> = stars
+            .iter()
+            .map(|(star, player_id)| {
+                format!(
+                    r#"{{"star": {}, "playerId": {}, "teamAbbrev": "NJD", "headshot": "",
+                        "name": {{"default": "Player"}}, "sweaterNo": 13}}"#,
+                    star, player_id
+                )
+            })
+            .collect();
+        let team = |id: i32, abbrev: &str| {
+            format!(
+                r#"{{"id": {}, "commonName": {{"default": ""}}, "abbrev": "{}",
+                    "placeName": {{"default": ""}}, "placeNameWithPreposition": {{"default": ""}},
+                    "score": 0, "sog": 0, "logo": "", "darkLogo": ""}}"#,
+                id, abbrev
+            )
+        };
+        serde_json::from_str(&format!(
+            r#"{{"id": {}, "season": 20242025, "gameType": 2, "limitedScoring": false,
+                "gameDate": "2024-10-04", "venue": {{"default": ""}},
+                "venueLocation": {{"default": ""}}, "startTimeUTC": "2024-10-04T17:00:00Z",
+                "easternUTCOffset": "-04:00", "venueUTCOffset": "+02:00",
+                "venueTimezone": "Europe/Prague", "periodDescriptor": {{}},
+                "gameState": "OFF", "gameScheduleState": "OK",
+                "awayTeam": {}, "homeTeam": {}, "shootoutInUse": true, "maxPeriods": 5,
+                "otInUse": true, "tiesInUse": false,
+                "summary": {{"scoring": [], "threeStars": [{}]}}}}"#,
+            game_id,
+            team(1, "NJD"),
+            team(7, "BUF"),
+            stars.join(",")
+        ))
+        .unwrap()
+    }
+
+    #[test]
+    fn test_daily_leaders_ranks_final_games_and_skips_live() {
+        let mut second = boxscore(2024020002, "FINAL");
+        second.player_by_game_stats.home_team.forwards[0].goals = 3;
+        second.player_by_game_stats.home_team.forwards[0].points = 3;
+        let boxscores = [
+            boxscore(2024020001, "OFF"),
+            second,
+            boxscore(2024020003, "LIVE"),
+        ];
+        let landings = [
+            landing_with_stars(2024020001, &[(2, 8481559), (1, 8479407), (3, 8480045)]),
+            landing_with_stars(2024020003, &[(1, 8479420)]),
+        ];
+
+        let leaders = DailyLeaders::from_games(&boxscores, &landings);
+
+        assert_eq!(leaders.pending, vec![GameId::new(2024020003)]);
+        assert_eq!(leaders.skaters.len(), 12);
+        let top = &leaders.skaters[0];
+        assert_eq!(
+            (top.game_id, top.name.as_str(), top.points),
+            (GameId::new(2024020002), "T. Thompson", 3)
+        );
+        assert!(leaders
+            .skaters
+            .windows(2)
+            .all(|pair| pair[0].points >= pair[1].points));
+        assert_eq!(leaders.goal_leaders()[0].goals, 3);
+
+        assert_eq!(leaders.goalies.len(), 4);
+        assert!(leaders
+            .goalies
+            .windows(2)
+            .all(|pair| pair[0].saves >= pair[1].saves));
+
+        let stars: Vec<(i32, i64)> = leaders.three_stars_by_game[&GameId::new(2024020001)]
+            .iter()
+            .map(|star| (star.star, star.player_id.as_i64()))
+            .collect();
+        assert_eq!(stars, [(1, 8479407), (2, 8481559), (3, 8480045)]);
+        assert!(!leaders
+            .three_stars_by_game
+            .contains_key(&GameId::new(2024020002)));
+        assert!(!leaders
+            .three_stars_by_game
+            .contains_key(&GameId::new(2024020003)));
+    }
+
+    #[test]
+    fn test_save_pctg_leaders_respect_minimum_shots() {
+        let mut boxscore = boxscore(2024020001, "OFF");
+        let goalie = &mut boxscore.player_by_game_stats.home_team.goalies[0];
+        goalie.saves = 4;
+        goalie.shots_against = 4;
+
+        let leaders = DailyLeaders::from_games(&[boxscore], &[]);
+
+        assert_eq!(leaders.save_pctg_leaders(1)[0].save_pctg(), Some(1.0));
+        let qualified = leaders.save_pctg_leaders(10);
+        assert_eq!(qualified.len(), 1);
+        assert_eq!(qualified[0].name, "J. Markstrom");
+    }
+}
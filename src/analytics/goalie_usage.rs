@@ -0,0 +1,392 @@
+//! Season-long goalie tandem usage: who started, how often, and how the
+//! starts were split across back-to-backs.
+
+use chrono::NaiveDate;
+use serde::{Serialize, Serializer};
+use std::collections::HashMap;
+
+use crate::ids::{GameId, PlayerId};
+use crate::types::{Boxscore, ClubGoalieStats};
+
+/// One game's starting goalie for the team being reported on.
+#[derive(Debug, Clone, PartialEq, Serialize)]
+pub struct GoalieStart {
+    pub game_id: GameId,
+    #[serde(serialize_with = "serialize_date")]
+    pub date: NaiveDate,
+    pub starter: PlayerId,
+    /// Used for goalies missing from the club stats roster.
+    pub starter_name: String,
+    pub won: bool,
+}
+
+impl GoalieStart {
+    /// The start for `team_abbrev` (any case) in a finished game's boxscore. `None`
+    /// when the game isn't final, the team didn't play in it, or no starter
+    /// can be identified.
+    ///
+    /// The starter is the goalie flagged `starter`; payloads without the
+    /// flag fall back to the goalie with the most ice time.
+    pub fn from_boxscore(team_abbrev: &str, boxscore: &Boxscore) -> Option<Self> {
+        if !boxscore.game_state.is_final() {
+            return None;
+        }
+        let (team, opponent, goalies) =
+            if boxscore.home_team.abbrev.eq_ignore_ascii_case(team_abbrev) {
+                (
+                    &boxscore.home_team,
+                    &boxscore.away_team,
+                    &boxscore.player_by_game_stats.home_team.goalies,
+                )
+            } else if boxscore.away_team.abbrev.eq_ignore_ascii_case(team_abbrev) {
+                (
+                    &boxscore.away_team,
+                    &boxscore.home_team,
+                    &boxscore.player_by_game_stats.away_team.goalies,
+                )
+            } else {
+                return None;
+            };
+
+        let starter = goalies
+            .iter()
+            .find(|goalie| goalie.starter == Some(true))
+            .or_else(|| goalies.iter().max_by_key(|goalie| goalie.toi))?;
+
+        Some(Self {
+            game_id: boxscore.id,
+            date: NaiveDate::parse_from_str(&boxscore.game_date, "%Y-%m-%d").ok()?,
+            starter: starter.player_id,
+            starter_name: starter.name.default.clone(),
+            won: team.score > opponent.score,
+        })
+    }
+}
+
+fn serialize_date<S: Serializer>(date: &NaiveDate, serializer: S) -> Result<S::Ok, S::Error> {
+    serializer.collect_str(&date.format("%Y-%m-%d"))
+}
+
+/// One goalie's line in a [`GoalieUsageReport`].
+#[derive(Debug, Clone, PartialEq, Serialize)]
+pub struct GoalieUsage {
+    pub player_id: PlayerId,
+    pub name: String,
+    pub starts: u32,
+    /// Team wins in this goalie's starts.
+    pub wins: u32,
+    /// Most consecutive team games started.
+    pub longest_start_streak: u32,
+    /// Back-to-backs in which this goalie started both nights.
+    pub back_to_back_starts: u32,
+}
+
+impl GoalieUsage {
+    fn new(player_id: PlayerId, name: String) -> Self {
+        Self {
+            player_id,
+            name,
+            starts: 0,
+            wins: 0,
+            longest_start_streak: 0,
+            back_to_back_starts: 0,
+        }
+    }
+
+    /// Share of starts the team won. `None` with no starts.
+    pub fn win_pct(&self) -> Option<f64> {
+        (self.starts > 0).then(|| f64::from(self.wins) / f64::from(self.starts))
+    }
+}
+
+/// Games on consecutive days, with each night's starter.
+#[derive(Debug, Clone, PartialEq, Serialize)]
+pub struct BackToBack {
+    pub first_game: GameId,
+    pub second_game: GameId,
+    pub first_starter: PlayerId,
+    pub second_starter: PlayerId,
+}
+
+impl BackToBack {
+    /// True when a different goalie started each night.
+    pub fn is_split(&self) -> bool {
+        self.first_starter != self.second_starter
+    }
+}
+
+/// How a team's starts were shared among its goalies over a season.
+#[derive(Debug, Clone, PartialEq, Serialize)]
+pub struct GoalieUsageReport {
+    /// Games with a known starter that went into the report.
+    pub games: u32,
+    /// Every goalie on the club stats roster plus any other starter, most
+    /// starts first.
+    pub goalies: Vec<GoalieUsage>,
+    pub back_to_backs: Vec<BackToBack>,
+    /// Finished games whose boxscore couldn't be fetched, left out of every
+    /// count above. Always empty from [`Self::from_starts`].
+    pub missing_games: Vec<GameId>,
+}
+
+impl GoalieUsageReport {
+    /// Builds the report from per-game starts (in any order) and the club
+    /// stats goalie roster, which supplies names and lists goalies who never
+    /// started.
+    pub fn from_starts(starts: &[GoalieStart], roster: &[ClubGoalieStats]) -> Self {
+        let mut starts: Vec<&GoalieStart> = starts.iter().collect();
+        starts.sort_by_key(|start| (start.date, start.game_id));
+
+        let mut usage: HashMap<PlayerId, GoalieUsage> = roster
+            .iter()
+            .map(|goalie| {
+                let name = format!("{} {}", goalie.first_name.default, goalie.last_name.default);
+                (goalie.player_id, GoalieUsage::new(goalie.player_id, name))
+            })
+            .collect();
+
+        let mut streak = 0;
+        let mut back_to_backs = Vec::new();
+        for (index, start) in starts.iter().enumerate() {
+            let previous = index.checked_sub(1).map(|i| starts[i]);
+            streak = match previous {
+                Some(previous) if previous.starter == start.starter => streak + 1,
+                _ => 1,
+            };
+
+            let entry = usage
+                .entry(start.starter)
+                .or_insert_with(|| GoalieUsage::new(start.starter, start.starter_name.clone()));
+            entry.starts += 1;
+            entry.wins += u32::from(start.won);
+            entry.longest_start_streak = entry.longest_start_streak.max(streak);
+
+            if let Some(previous) =
+                previous.filter(|p| start.date - p.date == chrono::Duration::days(1))
+            {
+                let back_to_back = BackToBack {
+                    first_game: previous.game_id,
+                    second_game: start.game_id,
+                    first_starter: previous.starter,
+                    second_starter: start.starter,
+                };
+                if !back_to_back.is_split() {
+                    entry.back_to_back_starts += 1;
+                }
+                back_to_backs.push(back_to_back);
+            }
+        }
+
+        let mut goalies: Vec<GoalieUsage> = usage.into_values().collect();
+        goalies.sort_by(|a, b| b.starts.cmp(&a.starts).then_with(|| a.name.cmp(&b.name)));
+
+        Self {
+            games: starts.len() as u32,
+            goalies,
+            back_to_backs,
+            missing_games: Vec::new(),
+        }
+    }
+
+    /// The line for one goalie.
+    pub fn goalie(&self, player_id: PlayerId) -> Option<&GoalieUsage> {
+        self.goalies
+            .iter()
+            .find(|goalie| goalie.player_id == player_id)
+    }
+
+    /// Back-to-backs where each night had a different starter.
+    pub fn split_back_to_backs(&self) -> usize {
+        self.back_to_backs
+            .iter()
+            .filter(|b2b| b2b.is_split())
+            .count()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::types::GoalieStats;
+
+    const STARTER: PlayerId = PlayerId::new(8479361);
+    const BACKUP: PlayerId = PlayerId::new(8480045);
+    const THIRD: PlayerId = PlayerId::new(8481000);
+
+    fn roster_goalie(player_id: PlayerId, first: &str, last: &str) -> ClubGoalieStats {
+        let json = format!(
+            r#"{{
+                "playerId": {}, "headshot": "",
+                "firstName": {{"default": "{}"}}, "lastName": {{"default": "{}"}},
+                "gamesPlayed": 0, "gamesStarted": 0, "wins": 0, "losses": 0,
+                "overtimeLosses": 0, "goalsAgainstAverage": 0.0, "savePercentage": 0.0,
+                "shotsAgainst": 0, "saves": 0, "goalsAgainst": 0, "shutouts": 0,
+                "goals": 0, "assists": 0, "points": 0, "penaltyMinutes": 0, "timeOnIce": 0
+            }}"#,
+            player_id, first, last
+        );
+        serde_json::from_str(&json).unwrap()
+    }
+
+    fn start(game: i64, day: u32, starter: PlayerId, won: bool) -> GoalieStart {
+        GoalieStart {
+            game_id: GameId::new(2024020000 + game),
+            date: NaiveDate::from_ymd_opt(2024, 11, day).unwrap(),
+            starter,
+            starter_name: String::new(),
+            won,
+        }
+    }
+
+    /// Ten games: a five-start streak for the starter, a split back-to-back
+    /// (Nov 8/9) and a back-to-back the starter played both nights
+    /// (Nov 15/16).
+    fn ten_game_stretch() -> Vec<GoalieStart> {
+        vec![
+            start(1, 1, STARTER, true),
+            start(2, 3, STARTER, true),
+            start(3, 5, STARTER, false),
+            start(4, 6, STARTER, true),
+            start(5, 8, STARTER, true),
+            start(6, 9, BACKUP, false),
+            start(7, 12, STARTER, true),
+            start(8, 13, BACKUP, true),
+            start(9, 15, STARTER, false),
+            start(10, 16, STARTER, true),
+        ]
+    }
+
+    fn roster() -> Vec<ClubGoalieStats> {
+        vec![
+            roster_goalie(STARTER, "Ilya", "Sorokin"),
+            roster_goalie(BACKUP, "Semyon", "Varlamov"),
+            roster_goalie(THIRD, "Marcus", "Hogberg"),
+        ]
+    }
+
+    #[test]
+    fn test_goalie_usage_starts_wins_and_streak() {
+        let report = GoalieUsageReport::from_starts(&ten_game_stretch(), &roster());
+
+        assert_eq!(report.games, 10);
+        let starter = report.goalie(STARTER).unwrap();
+        assert_eq!(starter.name, "Ilya Sorokin");
+        assert_eq!(starter.starts, 8);
+        assert_eq!(starter.wins, 6);
+        assert_eq!(starter.win_pct(), Some(0.75));
+        assert_eq!(starter.longest_start_streak, 5);
+
+        let backup = report.goalie(BACKUP).unwrap();
+        assert_eq!(backup.starts, 2);
+        assert_eq!(backup.wins, 1);
+        assert_eq!(backup.longest_start_streak, 1);
+    }
+
+    #[test]
+    fn test_goalie_usage_back_to_backs() {
+        let report = GoalieUsageReport::from_starts(&ten_game_stretch(), &roster());
+
+        // Nov 5/6, 8/9, 12/13 and 15/16.
+        assert_eq!(report.back_to_backs.len(), 4);
+        assert_eq!(report.split_back_to_backs(), 2);
+        let split = &report.back_to_backs[1];
+        assert_eq!(split.first_game, GameId::new(2024020005));
+        assert_eq!(split.second_starter, BACKUP);
+        assert!(split.is_split());
+
+        assert_eq!(report.goalie(STARTER).unwrap().back_to_back_starts, 2);
+        assert_eq!(report.goalie(BACKUP).unwrap().back_to_back_starts, 0);
+    }
+
+    #[test]
+    fn test_goalie_usage_orders_by_starts_and_keeps_unused_goalies() {
+        let report = GoalieUsageReport::from_starts(&ten_game_stretch(), &roster());
+
+        let order: Vec<PlayerId> = report.goalies.iter().map(|g| g.player_id).collect();
+        assert_eq!(order, vec![STARTER, BACKUP, THIRD]);
+        let third = report.goalie(THIRD).unwrap();
+        assert_eq!(third.starts, 0);
+        assert_eq!(third.win_pct(), None);
+    }
+
+    #[test]
+    fn test_goalie_usage_sorts_unordered_input() {
+        let mut starts = ten_game_stretch();
+        starts.reverse();
+        assert_eq!(
+            GoalieUsageReport::from_starts(&starts, &roster()),
+            GoalieUsageReport::from_starts(&ten_game_stretch(), &roster())
+        );
+    }
+
+    #[test]
+    fn test_goalie_usage_starter_missing_from_roster() {
+        let mut starts = vec![start(1, 1, THIRD, true)];
+        starts[0].starter_name = "Emergency Backup".to_string();
+        let report = GoalieUsageReport::from_starts(&starts, &[]);
+        assert_eq!(report.goalies[0].name, "Emergency Backup");
+    }
+
+    fn boxscore_goalie(player_id: i64, toi: &str, starter: Option<bool>) -> GoalieStats {
+        let mut json = serde_json::json!({
+            "playerId": player_id, "sweaterNumber": 30, "name": {"default": "G. Oalie"},
+            "position": "G", "evenStrengthShotsAgainst": "0/0", "powerPlayShotsAgainst": "0/0",
+            "shorthandedShotsAgainst": "0/0", "saveShotsAgainst": "0/0",
+            "evenStrengthGoalsAgainst": 0, "powerPlayGoalsAgainst": 0,
+            "shorthandedGoalsAgainst": 0, "goalsAgainst": 0, "toi": toi,
+            "shotsAgainst": 0, "saves": 0
+        });
+        if let Some(starter) = starter {
+            json["starter"] = starter.into();
+        }
+        serde_json::from_value(json).unwrap()
+    }
+
+    fn final_boxscore(home_goalies: Vec<GoalieStats>) -> Boxscore {
+        let mut boxscore = crate::fixtures::boxscore();
+        boxscore.id = GameId::new(2024020101);
+        boxscore.game_date = "2024-11-09".to_string();
+        boxscore.home_team.abbrev = "NYI".to_string();
+        boxscore.home_team.score = 4;
+        boxscore.away_team.abbrev = "NYR".to_string();
+        boxscore.away_team.score = 2;
+        boxscore.player_by_game_stats.home_team.goalies = home_goalies;
+        boxscore
+    }
+
+    #[test]
+    fn test_goalie_start_from_boxscore_uses_starter_flag() {
+        let boxscore = final_boxscore(vec![
+            boxscore_goalie(8480045, "45:00", Some(false)),
+            boxscore_goalie(8479361, "15:00", Some(true)),
+        ]);
+        let start = GoalieStart::from_boxscore("NYI", &boxscore).unwrap();
+        assert_eq!(start.starter, STARTER);
+        assert_eq!(start.date, NaiveDate::from_ymd_opt(2024, 11, 9).unwrap());
+        assert!(start.won);
+        assert_eq!(
+            GoalieStart::from_boxscore("nyi", &boxscore).map(|start| start.starter),
+            Some(STARTER)
+        );
+    }
+
+    #[test]
+    fn test_goalie_start_from_boxscore_falls_back_to_toi() {
+        let boxscore = final_boxscore(vec![
+            boxscore_goalie(8479361, "9:30", None),
+            boxscore_goalie(8480045, "50:30", None),
+        ]);
+        let start = GoalieStart::from_boxscore("NYI", &boxscore).unwrap();
+        assert_eq!(start.starter, BACKUP);
+    }
+
+    #[test]
+    fn test_goalie_start_from_boxscore_skips_unfinished_or_other_team() {
+        let mut boxscore = final_boxscore(vec![boxscore_goalie(8479361, "60:00", Some(true))]);
+        assert!(GoalieStart::from_boxscore("TOR", &boxscore).is_none());
+        // The away side has no goalies listed.
+        assert!(GoalieStart::from_boxscore("NYR", &boxscore).is_none());
+        boxscore.game_state = crate::types::GameState::Live;
+        assert!(GoalieStart::from_boxscore("NYI", &boxscore).is_none());
+    }
+}
@@ -0,0 +1,22 @@
+//! Derived views that combine data from more than one endpoint.
+//!
+//! Helpers here take already-fetched responses (or the few fields they need
+//! from them) and compute reports the NHL API doesn't serve directly. They
+//! make no HTTP calls; the [`Client`](crate::Client) methods that wrap them
+//! do the fetching.
+
+pub mod daily_leaders;
+pub mod goalie_usage;
+pub mod officials;
+pub mod position;
+pub mod shot_context;
+pub mod standings_update;
+pub mod three_stars;
+
+pub use daily_leaders::*;
+pub use goalie_usage::*;
+pub use officials::*;
+pub use position::*;
+pub use shot_context::*;
+pub use standings_update::*;
+pub use three_stars::*;
@@ -0,0 +1,374 @@
+//! Penalty rates by referee crew and win-loss records by head coach, over a
+//! set of finished games.
+//!
+//! Officials and coaches have no ids in the API, only display names, so
+//! both reports join on [`normalize_name`]. That absorbs case, spacing,
+//! periods and accents ("J.T. Larose", "jt larose"), but not different
+//! spellings or nicknames: "Wes McCauley" and "Wesley McCauley" count as two
+//! people.
+
+use std::collections::HashMap;
+
+use crate::ids::GameId;
+use crate::types::{Officials, PeriodType, PlayByPlay, SeriesGameInfo};
+
+/// One finished game's officials, head coaches, penalty count and result.
+#[derive(Debug, Clone, PartialEq)]
+pub struct OfficiatedGame {
+    pub game_id: GameId,
+    pub officials: Officials,
+    pub home_coach: String,
+    pub away_coach: String,
+    /// Penalties called on both teams.
+    pub penalties: u32,
+    pub home_score: i32,
+    pub away_score: i32,
+    /// How the game was decided; the loser's coach gets an overtime loss
+    /// for [`PeriodType::Overtime`] and [`PeriodType::Shootout`].
+    pub decision: PeriodType,
+}
+
+impl OfficiatedGame {
+    /// Joins a game's right-rail `gameInfo` (officials and coaches) with its
+    /// play-by-play (penalties and result). `None` when the game isn't
+    /// final or how it was decided can't be told from `gameOutcome` or the
+    /// period descriptor.
+    pub fn from_game(info: &SeriesGameInfo, play_by_play: &PlayByPlay) -> Option<Self> {
+        if !play_by_play.game_state.is_final() {
+            return None;
+        }
+        let decision = play_by_play
+            .game_outcome
+            .as_ref()
+            .and_then(|outcome| outcome.last_period_type.clone())
+            .or_else(|| play_by_play.period_descriptor.period_type.clone())?;
+        Some(Self {
+            game_id: play_by_play.id,
+            officials: info.officials.clone(),
+            home_coach: info.home_team.head_coach.default.clone(),
+            away_coach: info.away_team.head_coach.default.clone(),
+            penalties: play_by_play.penalties().len() as u32,
+            home_score: play_by_play.home_team.score,
+            away_score: play_by_play.away_team.score,
+            decision,
+        })
+    }
+}
+
+/// Lowercases `name`, strips periods and common Latin accents, and
+/// collapses whitespace, so spellings of the same name from different
+/// payloads compare equal.
+pub fn normalize_name(name: &str) -> String {
+    let folded: String = name
+        .chars()
+        .filter(|c| *c != '.')
+        .flat_map(char::to_lowercase)
+        .map(fold_accent)
+        .collect();
+    folded.split_whitespace().collect::<Vec<_>>().join(" ")
+}
+
+fn fold_accent(c: char) -> char {
+    match c {
+        'à' | 'á' | 'â' | 'ã' | 'ä' | 'å' => 'a',
+        'ç' => 'c',
+        'è' | 'é' | 'ê' | 'ë' => 'e',
+        'ì' | 'í' | 'î' | 'ï' => 'i',
+        'ñ' => 'n',
+        'ò' | 'ó' | 'ô' | 'õ' | 'ö' | 'ø' => 'o',
+        'ù' | 'ú' | 'û' | 'ü' => 'u',
+        'ý' | 'ÿ' => 'y',
+        'š' => 's',
+        'ž' => 'z',
+        'č' => 'c',
+        other => other,
+    }
+}
+
+/// One referee crew's line in an [`OfficialsReport`].
+#[derive(Debug, Clone, PartialEq)]
+pub struct CrewPenalties {
+    /// The crew's referees as first seen, sorted by normalized name.
+    pub referees: Vec<String>,
+    pub games: u32,
+    pub penalties: u32,
+}
+
+impl CrewPenalties {
+    /// Penalties per game this crew worked.
+    pub fn penalties_per_game(&self) -> f64 {
+        f64::from(self.penalties) / f64::from(self.games)
+    }
+}
+
+/// Penalty rates by referee crew, from [`officials_report`].
+#[derive(Debug, Clone, PartialEq)]
+pub struct OfficialsReport {
+    /// Games that went into the report.
+    pub games: u32,
+    /// Penalties per game across all of them. `None` with no games.
+    pub league_penalties_per_game: Option<f64>,
+    /// Most penalties per game first; ties by games worked, then names.
+    pub crews: Vec<CrewPenalties>,
+}
+
+/// Aggregates penalties per game by referee crew: the set of referees who
+/// worked a game, regardless of listing order. Games with no referees
+/// listed are left out. Linesmen don't call most penalties and aren't part
+/// of the crew.
+pub fn officials_report(games: &[OfficiatedGame]) -> OfficialsReport {
+    let mut crews: HashMap<Vec<String>, CrewPenalties> = HashMap::new();
+    let mut total_games = 0;
+    let mut total_penalties = 0;
+    for game in games {
+        if game.officials.referees.is_empty() {
+            continue;
+        }
+        let mut referees: Vec<(String, String)> = game
+            .officials
+            .referees
+            .iter()
+            .map(|referee| (normalize_name(&referee.default), referee.default.clone()))
+            .collect();
+        referees.sort();
+        let key: Vec<String> = referees.iter().map(|(key, _)| key.clone()).collect();
+        let crew = crews.entry(key).or_insert_with(|| CrewPenalties {
+            referees: referees.into_iter().map(|(_, name)| name).collect(),
+            games: 0,
+            penalties: 0,
+        });
+        crew.games += 1;
+        crew.penalties += game.penalties;
+        total_games += 1;
+        total_penalties += game.penalties;
+    }
+
+    let mut crews: Vec<CrewPenalties> = crews.into_values().collect();
+    crews.sort_by(|a, b| {
+        b.penalties_per_game()
+            .total_cmp(&a.penalties_per_game())
+            .then(b.games.cmp(&a.games))
+            .then_with(|| a.referees.cmp(&b.referees))
+    });
+    OfficialsReport {
+        games: total_games,
+        league_penalties_per_game: (total_games > 0)
+            .then(|| f64::from(total_penalties) / f64::from(total_games)),
+        crews,
+    }
+}
+
+/// A head coach's record over a set of games, from [`coach_record`].
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct CoachRecord {
+    pub wins: u32,
+    /// Losses in regulation.
+    pub losses: u32,
+    /// Losses in overtime or a shootout.
+    pub ot_losses: u32,
+}
+
+impl CoachRecord {
+    pub fn games(&self) -> u32 {
+        self.wins + self.losses + self.ot_losses
+    }
+}
+
+/// `coach_name`'s W-L-OTL over the games in which they were either team's
+/// head coach, matched by [`normalize_name`].
+pub fn coach_record(games: &[OfficiatedGame], coach_name: &str) -> CoachRecord {
+    let coach = normalize_name(coach_name);
+    let mut record = CoachRecord::default();
+    for game in games {
+        let (score, opponent_score) = if normalize_name(&game.home_coach) == coach {
+            (game.home_score, game.away_score)
+        } else if normalize_name(&game.away_coach) == coach {
+            (game.away_score, game.home_score)
+        } else {
+            continue;
+        };
+        if score > opponent_score {
+            record.wins += 1;
+        } else if game.decision == PeriodType::Regulation {
+            record.losses += 1;
+        } else {
+            record.ot_losses += 1;
+        }
+    }
+    record
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::types::LocalizedString;
+
+    fn names(names: &[&str]) -> Vec<LocalizedString> {
+        names
+            .iter()
+            .map(|name| LocalizedString::new(*name))
+            .collect()
+    }
+
+    fn game(
+        id: i64,
+        referees: &[&str],
+        penalties: u32,
+        (home_coach, home_score): (&str, i32),
+        (away_coach, away_score): (&str, i32),
+        decision: PeriodType,
+    ) -> OfficiatedGame {
+        OfficiatedGame {
+            game_id: GameId::new(id),
+            officials: Officials {
+                referees: names(referees),
+                linesmen: names(&["Linesman One", "Linesman Two"]),
+            },
+            home_coach: home_coach.to_string(),
+            away_coach: away_coach.to_string(),
+            penalties,
+            home_score,
+            away_score,
+            decision,
+        }
+    }
+
+    /// Five games, two crews: crew A (Dupont and Lemieux) works three, crew
+    /// B (Smith and Jones) two.
+    fn five_games() -> Vec<OfficiatedGame> {
+        vec![
+            game(
+                1,
+                &["Marc Dupont", "Éric Lemieux"],
+                8,
+                ("Coach Martin", 3),
+                ("Coach Brown", 2),
+                PeriodType::Regulation,
+            ),
+            game(
+                2,
+                &["Eric Lemieux", "Marc Dupont"],
+                6,
+                ("Coach Brown", 2),
+                ("Coach Martin", 3),
+                PeriodType::Overtime,
+            ),
+            game(
+                3,
+                &["Tom Smith", "Dan Jones"],
+                4,
+                ("Coach Martin", 1),
+                ("Coach Green", 4),
+                PeriodType::Regulation,
+            ),
+            game(
+                4,
+                &["Dan Jones", "Tom Smith"],
+                6,
+                ("Coach Green", 2),
+                ("Coach Martin", 1),
+                PeriodType::Shootout,
+            ),
+            game(
+                5,
+                &["marc dupont", "E. Lemieux"],
+                10,
+                ("Coach Brown", 5),
+                ("Coach Green", 0),
+                PeriodType::Regulation,
+            ),
+        ]
+    }
+
+    #[test]
+    fn test_normalize_name() {
+        assert_eq!(normalize_name("  Éric   Lemieux "), "eric lemieux");
+        assert_eq!(normalize_name("J.T. Larose"), normalize_name("jt larose"));
+        assert_ne!(
+            normalize_name("Wes McCauley"),
+            normalize_name("Wesley McCauley")
+        );
+    }
+
+    #[test]
+    fn test_officials_report_groups_crews_by_normalized_names() {
+        let report = officials_report(&five_games());
+
+        assert_eq!(report.games, 5);
+        assert_eq!(report.league_penalties_per_game, Some(34.0 / 5.0));
+        // Game 5 lists "E. Lemieux", a different spelling, so it is its own crew
+        let crews: Vec<(Vec<&str>, u32, u32)> = report
+            .crews
+            .iter()
+            .map(|crew| {
+                let referees = crew.referees.iter().map(String::as_str).collect();
+                (referees, crew.games, crew.penalties)
+            })
+            .collect();
+        assert_eq!(
+            crews,
+            vec![
+                (vec!["E. Lemieux", "marc dupont"], 1, 10),
+                (vec!["Éric Lemieux", "Marc Dupont"], 2, 14),
+                (vec!["Dan Jones", "Tom Smith"], 2, 10),
+            ]
+        );
+        assert_eq!(report.crews[1].penalties_per_game(), 7.0);
+    }
+
+    #[test]
+    fn test_officials_report_skips_games_without_referees() {
+        let mut games = five_games();
+        games[0].officials.referees.clear();
+
+        let report = officials_report(&games);
+
+        assert_eq!(report.games, 4);
+        assert!(officials_report(&[]).league_penalties_per_game.is_none());
+    }
+
+    #[test]
+    fn test_coach_record() {
+        let games = five_games();
+
+        assert_eq!(
+            coach_record(&games, "coach martin"),
+            CoachRecord {
+                wins: 2,
+                losses: 1,
+                ot_losses: 1,
+            }
+        );
+        let brown = coach_record(&games, "Coach Brown");
+        assert_eq!((brown.wins, brown.losses, brown.ot_losses), (1, 1, 1));
+        assert_eq!(coach_record(&games, "Coach Green").games(), 3);
+        assert_eq!(coach_record(&games, "Nobody").games(), 0);
+    }
+
+    #[test]
+    fn test_officiated_game_from_play_by_play() {
+        let mut payload: serde_json::Value =
+            serde_json::from_str(include_str!("../../tests/fixtures/play_by_play.json")).unwrap();
+        let undecided: PlayByPlay = serde_json::from_value(payload.clone()).unwrap();
+        payload["gameOutcome"] = serde_json::json!({"lastPeriodType": "OT"});
+        let play_by_play: PlayByPlay = serde_json::from_value(payload).unwrap();
+        let info: SeriesGameInfo = serde_json::from_str(
+            r#"{"referees": [{"default": "Ref One"}, {"default": "Ref Two"}],
+                "linesmen": [],
+                "awayTeam": {"headCoach": {"default": "Away Coach"}, "scratches": []},
+                "homeTeam": {"headCoach": {"default": "Home Coach"}, "scratches": []}}"#,
+        )
+        .unwrap();
+
+        let game = OfficiatedGame::from_game(&info, &play_by_play).unwrap();
+
+        assert_eq!(game.game_id, play_by_play.id);
+        assert_eq!(game.penalties, play_by_play.penalties().len() as u32);
+        assert_eq!((game.home_score, game.away_score), (1, 2));
+        assert_eq!(game.home_coach, "Home Coach");
+        assert_eq!(game.officials.referees.len(), 2);
+        assert_eq!(game.decision, PeriodType::Overtime);
+        // No gameOutcome and an empty period descriptor: the decision is unknown
+        assert!(OfficiatedGame::from_game(&info, &undecided).is_none());
+    }
+}
@@ -0,0 +1,135 @@
+//! Position reconciliation across endpoints.
+//!
+//! The NHL endpoints don't always agree with each other (a player listed as
+//! `C` on landing may be `RW` on the club roster), so helpers here take
+//! every claim along with where it came from and resolve them with a fixed,
+//! documented precedence. [`ClubStats::skaters_by_position`] and
+//! [`ClubStats::skaters_by_reconciled_position`] bucket skaters through it.
+//!
+//! [`ClubStats::skaters_by_position`]: crate::types::ClubStats::skaters_by_position
+//! [`ClubStats::skaters_by_reconciled_position`]: crate::types::ClubStats::skaters_by_reconciled_position
+
+use std::collections::HashMap;
+
+use crate::ids::PlayerId;
+use crate::types::{Position, RosterSpot};
+
+/// A position claim for one player, tagged with the endpoint it came from.
+///
+/// Variants are listed from most to least authoritative; see
+/// [`reconcile_position`].
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum PositionSource {
+    /// `rosterSpots[].positionCode` from play-by-play: where the player
+    /// actually lined up in a specific game.
+    GameRoster(Position),
+    /// `positionCode` from the club roster (`/roster/{team}/{season}`).
+    ClubRoster(Position),
+    /// `position` from the player landing page.
+    Landing(Position),
+}
+
+impl PositionSource {
+    /// The claimed position.
+    pub const fn position(&self) -> &Position {
+        match self {
+            PositionSource::GameRoster(position)
+            | PositionSource::ClubRoster(position)
+            | PositionSource::Landing(position) => position,
+        }
+    }
+
+    /// Lower is more authoritative.
+    const fn rank(&self) -> u8 {
+        match self {
+            PositionSource::GameRoster(_) => 0,
+            PositionSource::ClubRoster(_) => 1,
+            PositionSource::Landing(_) => 2,
+        }
+    }
+}
+
+/// Resolve conflicting position claims for one player into a single position.
+///
+/// Precedence is game roster spot > club roster > landing. When the same
+/// source appears more than once, the first claim wins. Returns `None` when
+/// `sources` is empty.
+pub fn reconcile_position(sources: &[PositionSource]) -> Option<Position> {
+    sources
+        .iter()
+        .enumerate()
+        .min_by_key(|(index, source)| (source.rank(), *index))
+        .map(|(_, source)| source.position().clone())
+}
+
+/// One [`PositionSource::GameRoster`] claim per player in a game's roster
+/// spots (e.g. `PlayByPlay::roster_spots`), keyed by player. Spots without
+/// a position are skipped.
+pub fn game_roster_claims(roster_spots: &[RosterSpot]) -> HashMap<PlayerId, Vec<PositionSource>> {
+    roster_spots
+        .iter()
+        .filter_map(|spot| {
+            let position = spot.position.clone()?;
+            Some((spot.player_id, vec![PositionSource::GameRoster(position)]))
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::types::PlayByPlay;
+
+    #[test]
+    fn test_reconcile_position_game_roster_beats_club_roster_and_landing() {
+        let sources = [
+            PositionSource::Landing(Position::Center),
+            PositionSource::ClubRoster(Position::RightWing),
+            PositionSource::GameRoster(Position::LeftWing),
+        ];
+        assert_eq!(reconcile_position(&sources), Some(Position::LeftWing));
+    }
+
+    #[test]
+    fn test_reconcile_position_club_roster_beats_landing() {
+        let sources = [
+            PositionSource::Landing(Position::Center),
+            PositionSource::ClubRoster(Position::RightWing),
+        ];
+        assert_eq!(reconcile_position(&sources), Some(Position::RightWing));
+    }
+
+    #[test]
+    fn test_reconcile_position_landing_only() {
+        let sources = [PositionSource::Landing(Position::Defense)];
+        assert_eq!(reconcile_position(&sources), Some(Position::Defense));
+    }
+
+    #[test]
+    fn test_reconcile_position_first_claim_wins_within_source() {
+        let sources = [
+            PositionSource::ClubRoster(Position::Center),
+            PositionSource::ClubRoster(Position::LeftWing),
+        ];
+        assert_eq!(reconcile_position(&sources), Some(Position::Center));
+    }
+
+    #[test]
+    fn test_reconcile_position_empty() {
+        assert_eq!(reconcile_position(&[]), None);
+    }
+
+    #[test]
+    fn test_game_roster_claims_from_play_by_play() {
+        let pbp: PlayByPlay =
+            serde_json::from_str(include_str!("../../tests/fixtures/play_by_play.json")).unwrap();
+
+        let claims = game_roster_claims(&pbp.roster_spots);
+
+        assert_eq!(claims.len(), pbp.roster_spots.len());
+        assert_eq!(
+            claims[&PlayerId::new(8480002)],
+            [PositionSource::GameRoster(Position::Center)]
+        );
+    }
+}
@@ -0,0 +1,331 @@
+//! Shot attempts with the context shot-quality models start from.
+//!
+//! This is not an expected-goals model. [`enrich_shot_attempts`] gives each
+//! attempt its normalized location, distance and angle, shot type and
+//! strength from the shooter's side, and flags rebounds and rush chances.
+//! Both flags compare game time from [`PlayByPlay::elapsed_at`] and never
+//! reach across periods.
+
+use serde::Serialize;
+use std::collections::HashMap;
+use std::fmt;
+
+use crate::ice_time::IceTime;
+use crate::ids::{PlayerId, TeamId};
+use crate::types::rink::{self, BLUE_LINE_X};
+use crate::types::{GameSituation, PeriodType, PlayByPlay, PlayEvent, PlayEventType};
+
+/// Default for [`ShotContextConfig::rebound_window_seconds`].
+pub const DEFAULT_REBOUND_WINDOW_SECONDS: u32 = 3;
+
+/// Default for [`ShotContextConfig::rush_window_seconds`].
+pub const DEFAULT_RUSH_WINDOW_SECONDS: u32 = 4;
+
+/// Time windows for [`enrich_shot_attempts`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct ShotContextConfig {
+    /// An attempt is a rebound when the same team had an attempt at most
+    /// this many seconds earlier.
+    pub rebound_window_seconds: u32,
+    /// An attempt is off the rush when the event just before it, at most
+    /// this many seconds earlier, was outside the shooter's offensive zone.
+    pub rush_window_seconds: u32,
+}
+
+impl Default for ShotContextConfig {
+    fn default() -> Self {
+        Self {
+            rebound_window_seconds: DEFAULT_REBOUND_WINDOW_SECONDS,
+            rush_window_seconds: DEFAULT_RUSH_WINDOW_SECONDS,
+        }
+    }
+}
+
+/// Skaters on the ice at a shot, from the shooting team's side.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize)]
+pub struct ShotStrength {
+    /// The shooting team's skaters, an extra attacker included.
+    pub skaters_for: u8,
+    pub skaters_against: u8,
+    /// The shooting team pulled its goalie.
+    pub goalie_pulled: bool,
+    /// The defending team's net is empty.
+    pub empty_net: bool,
+}
+
+impl ShotStrength {
+    /// Reads `situation` from the home or away team's side.
+    pub fn from_situation(situation: GameSituation, shooter_is_home: bool) -> Self {
+        if shooter_is_home {
+            Self {
+                skaters_for: situation.home_skaters,
+                skaters_against: situation.away_skaters,
+                goalie_pulled: !situation.home_goalie_in,
+                empty_net: !situation.away_goalie_in,
+            }
+        } else {
+            Self {
+                skaters_for: situation.away_skaters,
+                skaters_against: situation.home_skaters,
+                goalie_pulled: !situation.away_goalie_in,
+                empty_net: !situation.home_goalie_in,
+            }
+        }
+    }
+
+    /// Both teams have the same number of skaters.
+    pub fn is_even(&self) -> bool {
+        self.skaters_for == self.skaters_against
+    }
+
+    /// The shooting team has more skaters. A pulled goalie's extra
+    /// attacker counts.
+    pub fn is_power_play(&self) -> bool {
+        self.skaters_for > self.skaters_against
+    }
+
+    /// The shooting team has fewer skaters.
+    pub fn is_short_handed(&self) -> bool {
+        self.skaters_for < self.skaters_against
+    }
+}
+
+impl fmt::Display for ShotStrength {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}v{}", self.skaters_for, self.skaters_against)
+    }
+}
+
+/// One shot attempt with its context, from [`enrich_shot_attempts`].
+#[derive(Debug, Clone, PartialEq, Serialize)]
+pub struct ShotAttempt {
+    pub event_id: i64,
+    pub period: i32,
+    /// Game time elapsed at the attempt, across periods.
+    pub elapsed: IceTime,
+    /// Goal, shot on goal, missed or blocked shot.
+    pub event_type: PlayEventType,
+    /// The shooter's team, also for blocked shots.
+    pub team_id: TeamId,
+    pub is_home: bool,
+    /// The scorer for goals.
+    pub shooter_id: Option<PlayerId>,
+    pub goalie_in_net_id: Option<PlayerId>,
+    /// Location with the shooter attacking toward `+x` (see
+    /// [`PlayEvent::normalized_coords`]). For blocked shots this is where
+    /// the shot was blocked.
+    pub coords: Option<(f32, f32)>,
+    /// Feet from the attacked net.
+    pub distance: Option<f32>,
+    /// Degrees off the middle of the rink (see [`rink::shot_angle`]).
+    pub angle: Option<f32>,
+    /// `shotType`, e.g. `"wrist"`.
+    pub shot_type: Option<String>,
+    /// `None` when the situation code doesn't parse.
+    pub strength: Option<ShotStrength>,
+    pub is_rebound: bool,
+    pub is_rush: bool,
+}
+
+impl ShotAttempt {
+    /// Whether the attempt was a goal.
+    pub fn is_goal(&self) -> bool {
+        self.event_type == PlayEventType::Goal
+    }
+}
+
+/// Every shot attempt in the game with its context, in play order.
+/// Shootout attempts are left out.
+///
+/// Rebounds follow another attempt by the same team in the same period
+/// within `config.rebound_window_seconds`. Rush chances come at most
+/// `config.rush_window_seconds` after an event whose location, seen from
+/// the shooter's side, is short of the offensive blue line. An event with
+/// no location never starts a rush.
+pub fn enrich_shot_attempts(
+    play_by_play: &PlayByPlay,
+    config: ShotContextConfig,
+) -> Vec<ShotAttempt> {
+    let home_team_id = play_by_play.home_team.id;
+    let within = |earlier: (i32, u32), now: (i32, u32), window: u32| {
+        earlier.0 == now.0 && now.1.saturating_sub(earlier.1) <= window
+    };
+
+    let mut attempts = Vec::new();
+    let mut last_attempt: HashMap<TeamId, (i32, u32)> = HashMap::new();
+    let mut previous: Option<(&PlayEvent, (i32, u32))> = None;
+    for play in &play_by_play.plays {
+        if play.period_descriptor.period_type == Some(PeriodType::Shootout) {
+            continue;
+        }
+        let at = (
+            play.period_descriptor.number,
+            play_by_play.elapsed_at(play).as_seconds(),
+        );
+        let Some(team_id) = play_by_play.shooting_team_id(play) else {
+            previous = Some((play, at));
+            continue;
+        };
+
+        let is_home = team_id == home_team_id;
+        let is_rebound = last_attempt
+            .get(&team_id)
+            .is_some_and(|&earlier| within(earlier, at, config.rebound_window_seconds));
+        let is_rush = previous.is_some_and(|(event, earlier)| {
+            within(earlier, at, config.rush_window_seconds)
+                && event
+                    .normalized_coords(home_team_id, team_id)
+                    .is_some_and(|(x, _)| x < BLUE_LINE_X)
+        });
+        let coords = play.normalized_coords(home_team_id, team_id);
+        let details = play.details.as_ref();
+        attempts.push(ShotAttempt {
+            event_id: play.event_id,
+            period: at.0,
+            elapsed: IceTime::from_seconds(at.1),
+            event_type: play.event_type(),
+            team_id,
+            is_home,
+            shooter_id: details.and_then(|d| d.shooting_player_id.or(d.scoring_player_id)),
+            goalie_in_net_id: details.and_then(|d| d.goalie_in_net_id),
+            coords,
+            distance: coords.map(|(x, y)| rink::distance_to_net(x, y)),
+            angle: coords.map(|(x, y)| rink::shot_angle(x, y)),
+            shot_type: details.and_then(|d| d.shot_type.clone()),
+            strength: play
+                .situation()
+                .map(|situation| ShotStrength::from_situation(situation, is_home)),
+            is_rebound,
+            is_rush,
+        });
+
+        last_attempt.insert(team_id, at);
+        previous = Some((play, at));
+    }
+    attempts
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const HOME: i64 = 10;
+    const AWAY: i64 = 20;
+
+    /// A period-1-or-2 event with home defending left in period 1, so home
+    /// attacks `+x` in period 1 and `-x` in period 2.
+    fn event(
+        id: i64,
+        period: i32,
+        time: &str,
+        type_key: &str,
+        owner: i64,
+        coords: (i32, i32),
+        situation: &str,
+    ) -> PlayEvent {
+        let side = if period % 2 == 1 { "left" } else { "right" };
+        serde_json::from_value(serde_json::json!({
+            "eventId": id,
+            "periodDescriptor": {"number": period, "periodType": "REG", "maxRegulationPeriods": 3},
+            "timeInPeriod": time,
+            "timeRemaining": "00:00",
+            "situationCode": situation,
+            "homeTeamDefendingSide": side,
+            "typeCode": 0,
+            "typeDescKey": type_key,
+            "sortOrder": id,
+            "details": {
+                "xCoord": coords.0,
+                "yCoord": coords.1,
+                "eventOwnerTeamId": owner,
+                "shootingPlayerId": owner * 100,
+                "shotType": "wrist"
+            }
+        }))
+        .unwrap()
+    }
+
+    fn game(plays: Vec<PlayEvent>) -> PlayByPlay {
+        let mut pbp = crate::fixtures::play_by_play();
+        pbp.home_team.id = TeamId::new(HOME);
+        pbp.away_team.id = TeamId::new(AWAY);
+        pbp.plays = plays;
+        pbp
+    }
+
+    #[test]
+    fn test_rebound_and_rush_flags_on_power_play() {
+        // Home on a 5v4: takeaway in its own zone, rush shot, rebound two
+        // seconds later, then another shot four seconds after that.
+        let pbp = game(vec![
+            event(1, 1, "05:00", "takeaway", HOME, (-60, 5), "1451"),
+            event(2, 1, "05:03", "shot-on-goal", HOME, (70, 10), "1451"),
+            event(3, 1, "05:05", "missed-shot", HOME, (80, -5), "1451"),
+            event(4, 1, "05:09", "shot-on-goal", HOME, (75, 0), "1451"),
+            // The blocker (home) owns the event; the shooter is away.
+            event(5, 1, "05:30", "blocked-shot", HOME, (-60, 0), "1451"),
+        ]);
+
+        let attempts = pbp.shot_attempts_enriched(ShotContextConfig::default());
+        let flags: Vec<(i64, bool, bool)> = attempts
+            .iter()
+            .map(|shot| (shot.event_id, shot.is_rebound, shot.is_rush))
+            .collect();
+        assert_eq!(
+            flags,
+            vec![
+                (2, false, true),
+                (3, true, false),
+                (4, false, false),
+                (5, false, false)
+            ]
+        );
+
+        let rush = &attempts[0];
+        assert!(rush.is_home);
+        assert_eq!(rush.shooter_id, Some(PlayerId::new(HOME * 100)));
+        assert_eq!(rush.coords, Some((70.0, 10.0)));
+        assert!((rush.distance.unwrap() - 19.0_f32.hypot(10.0)).abs() < 1e-4);
+        assert_eq!(rush.shot_type.as_deref(), Some("wrist"));
+        let strength = rush.strength.unwrap();
+        assert!(strength.is_power_play());
+        assert_eq!(strength.to_string(), "5v4");
+
+        let blocked = &attempts[3];
+        assert_eq!(blocked.team_id, TeamId::new(AWAY));
+        assert!(!blocked.is_home);
+        assert_eq!(blocked.coords, Some((60.0, 0.0)));
+        assert!(blocked.strength.unwrap().is_short_handed());
+
+        let wider = ShotContextConfig {
+            rebound_window_seconds: 4,
+            ..ShotContextConfig::default()
+        };
+        assert!(pbp.shot_attempts_enriched(wider)[2].is_rebound);
+    }
+
+    #[test]
+    fn test_windows_do_not_cross_periods() {
+        let pbp = game(vec![
+            event(1, 1, "19:59", "shot-on-goal", HOME, (70, 0), "1551"),
+            event(2, 2, "00:01", "shot-on-goal", HOME, (-70, 0), "1551"),
+        ]);
+
+        let attempts = pbp.shot_attempts_enriched(ShotContextConfig::default());
+        assert_eq!(attempts[1].elapsed.as_seconds(), 1201);
+        assert_eq!(attempts[1].coords, Some((70.0, 0.0)));
+        assert!(!attempts[1].is_rebound);
+        assert!(attempts[1].strength.unwrap().is_even());
+    }
+
+    #[test]
+    fn test_strength_from_situation_with_pulled_goalie() {
+        // Away pulled its goalie: 6 away skaters against 5, home net guarded.
+        let situation = GameSituation::from_code("0651").unwrap();
+        let away = ShotStrength::from_situation(situation, false);
+        assert!(away.goalie_pulled && !away.empty_net && away.is_power_play());
+        let home = ShotStrength::from_situation(situation, true);
+        assert!(home.empty_net && home.is_short_handed());
+        assert_eq!(home.to_string(), "5v6");
+    }
+}
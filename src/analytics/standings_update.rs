@@ -0,0 +1,219 @@
+//! Applying finished games from daily scores to an already-fetched
+//! standings table, so a scores watcher doesn't have to poll standings too.
+
+use std::collections::HashSet;
+
+use crate::ids::GameId;
+use crate::types::{GameScore, PeriodType, Standing};
+
+/// Standings points for a win, and for a loss in overtime or a shootout.
+const WIN_POINTS: i32 = 2;
+const OT_LOSS_POINTS: i32 = 1;
+
+/// The result of one final game, as far as standings care.
+#[derive(Debug, Clone, PartialEq)]
+pub struct FinalResult {
+    pub game_id: GameId,
+    pub winner: String,
+    pub loser: String,
+    /// How the game was decided; the loser gets an overtime loss (and a
+    /// point) for [`PeriodType::Overtime`] and [`PeriodType::Shootout`].
+    pub decision: PeriodType,
+}
+
+impl FinalResult {
+    /// The result of a regular-season game from daily scores. `None` when
+    /// the game isn't final, isn't a regular-season game, or is missing its
+    /// scores or `gameOutcome`.
+    pub fn from_game_score(game: &GameScore) -> Option<Self> {
+        if !game.is_final() || !game.game_type.is_regular_season() {
+            return None;
+        }
+        let decision = game.game_outcome.as_ref()?.last_period_type.clone()?;
+        let winner = game.winner()?;
+        let loser = if std::ptr::eq(winner, &game.away_team) {
+            &game.home_team
+        } else {
+            &game.away_team
+        };
+        Some(Self {
+            game_id: game.id,
+            winner: winner.abbrev.clone(),
+            loser: loser.abbrev.clone(),
+            decision,
+        })
+    }
+}
+
+/// Updates `wins`, `losses`, `ot_losses` and `points` in `standings` for
+/// every game in `finals` that just went final, returning the abbreviations
+/// of the teams whose rows changed.
+///
+/// Games are matched to rows by team abbreviation. `seen` records the games
+/// already applied: ids in it are skipped, and every game applied here is
+/// added, so the same scores can be passed in on every poll. Games that
+/// aren't final regular-season games with a `gameOutcome` are left out of
+/// `seen` and picked up on a later call.
+///
+/// This is an approximation until the next real standings fetch: only the
+/// four record columns change, so row order, tiebreakers and every other
+/// field are stale.
+pub fn apply_scores_to_standings(
+    standings: &mut [Standing],
+    finals: &[GameScore],
+    seen: &mut HashSet<GameId>,
+) -> Vec<String> {
+    let mut changed = Vec::new();
+    for game in finals {
+        if seen.contains(&game.id) {
+            continue;
+        }
+        let Some(result) = FinalResult::from_game_score(game) else {
+            continue;
+        };
+        seen.insert(result.game_id);
+
+        for standing in standings.iter_mut() {
+            let abbrev = standing.team_abbrev.default.as_str();
+            if abbrev == result.winner {
+                standing.wins += 1;
+                standing.points += WIN_POINTS;
+            } else if abbrev == result.loser {
+                if result.decision.is_overtime() {
+                    standing.ot_losses += 1;
+                    standing.points += OT_LOSS_POINTS;
+                } else {
+                    standing.losses += 1;
+                }
+            } else {
+                continue;
+            }
+            if !changed.iter().any(|team| team == abbrev) {
+                changed.push(abbrev.to_string());
+            }
+        }
+    }
+    changed
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn standing(abbrev: &str, wins: i32, losses: i32, ot_losses: i32) -> Standing {
+        let json = format!(
+            r#"{{"divisionAbbrev": "A", "divisionName": "Atlantic",
+                "teamName": {{"default": "{abbrev}"}}, "teamCommonName": {{"default": "{abbrev}"}},
+                "teamAbbrev": {{"default": "{abbrev}"}}, "teamLogo": "",
+                "wins": {wins}, "losses": {losses}, "otLosses": {ot_losses},
+                "points": {}}}"#,
+            wins * 2 + ot_losses
+        );
+        serde_json::from_str(&json).unwrap()
+    }
+
+    fn final_game(id: i64, away: (&str, i32), home: (&str, i32), last_period: &str) -> GameScore {
+        let json = format!(
+            r#"{{"id": {id}, "gameType": 2, "gameState": "OFF",
+                "awayTeam": {{"id": 1, "abbrev": "{}", "placeName": null, "logo": "", "score": {}}},
+                "homeTeam": {{"id": 2, "abbrev": "{}", "placeName": null, "logo": "", "score": {}}},
+                "gameOutcome": {{"lastPeriodType": "{last_period}"}}}}"#,
+            away.0, away.1, home.0, home.1
+        );
+        serde_json::from_str(&json).unwrap()
+    }
+
+    fn row<'a>(standings: &'a [Standing], abbrev: &str) -> &'a Standing {
+        standings
+            .iter()
+            .find(|s| s.team_abbrev.default == abbrev)
+            .unwrap()
+    }
+
+    fn table() -> Vec<Standing> {
+        vec![
+            standing("BOS", 10, 5, 2),
+            standing("TOR", 9, 6, 1),
+            standing("MTL", 7, 8, 3),
+            standing("OTT", 6, 9, 0),
+        ]
+    }
+
+    #[test]
+    fn test_regulation_final() {
+        let mut standings = table();
+        let mut seen = HashSet::new();
+
+        let changed = apply_scores_to_standings(
+            &mut standings,
+            &[final_game(2024020001, ("BOS", 4), ("TOR", 1), "REG")],
+            &mut seen,
+        );
+
+        assert_eq!(changed, vec!["BOS", "TOR"]);
+        let bos = row(&standings, "BOS");
+        assert_eq!(
+            (bos.wins, bos.losses, bos.ot_losses, bos.points),
+            (11, 5, 2, 24)
+        );
+        let tor = row(&standings, "TOR");
+        assert_eq!(
+            (tor.wins, tor.losses, tor.ot_losses, tor.points),
+            (9, 7, 1, 19)
+        );
+        assert_eq!(row(&standings, "MTL").points, 17);
+    }
+
+    #[test]
+    fn test_overtime_final_gives_loser_a_point() {
+        let mut standings = table();
+        let mut seen = HashSet::new();
+
+        let changed = apply_scores_to_standings(
+            &mut standings,
+            &[final_game(2024020002, ("MTL", 2), ("OTT", 3), "OT")],
+            &mut seen,
+        );
+
+        assert_eq!(changed, vec!["MTL", "OTT"]);
+        let ott = row(&standings, "OTT");
+        assert_eq!(
+            (ott.wins, ott.losses, ott.ot_losses, ott.points),
+            (7, 9, 0, 14)
+        );
+        let mtl = row(&standings, "MTL");
+        assert_eq!(
+            (mtl.wins, mtl.losses, mtl.ot_losses, mtl.points),
+            (7, 8, 4, 18)
+        );
+    }
+
+    #[test]
+    fn test_already_applied_game_is_a_noop() {
+        let mut standings = table();
+        let mut seen = HashSet::new();
+        let finals = [final_game(2024020003, ("BOS", 1), ("MTL", 2), "SO")];
+
+        apply_scores_to_standings(&mut standings, &finals, &mut seen);
+        let after_first = standings.clone();
+        let changed = apply_scores_to_standings(&mut standings, &finals, &mut seen);
+
+        assert!(changed.is_empty());
+        assert_eq!(standings, after_first);
+        assert!(seen.contains(&GameId::new(2024020003)));
+    }
+
+    #[test]
+    fn test_unfinished_game_is_left_for_later() {
+        let mut standings = table();
+        let mut seen = HashSet::new();
+        let mut game = final_game(2024020004, ("BOS", 1), ("TOR", 0), "REG");
+        game.game_state = crate::types::GameState::Live;
+
+        let changed = apply_scores_to_standings(&mut standings, &[game], &mut seen);
+
+        assert!(changed.is_empty());
+        assert!(seen.is_empty());
+        assert_eq!(standings, table());
+    }
+}
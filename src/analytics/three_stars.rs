@@ -0,0 +1,297 @@
+//! Three stars of the game, official or derived from the boxscore when the
+//! summary doesn't carry them.
+
+use serde::Serialize;
+
+use crate::ice_time::IceTime;
+use crate::ids::PlayerId;
+use crate::types::{
+    Boxscore, BoxscoreTeam, GoalieStats, LocalizedString, Position, SkaterStats, ThreeStar,
+};
+
+/// Shots a goalie must have faced for save percentage to count in
+/// [`derive_three_stars`].
+pub const DERIVED_STAR_MIN_SHOTS_AGAINST: i32 = 20;
+
+/// A star of the game, either from the summary's `threeStars` block or
+/// ranked by [`derive_three_stars`].
+#[derive(Debug, Clone, PartialEq, Serialize)]
+pub struct DerivedStar {
+    /// 1 for the first star.
+    pub star: i32,
+    pub player_id: PlayerId,
+    pub team_abbrev: String,
+    pub name: LocalizedString,
+    /// `None` for a derived star the boxscore lists without a number.
+    pub sweater_no: Option<i32>,
+    pub position: Option<Position>,
+    /// `true` when the star came from the API, `false` when derived.
+    pub official: bool,
+}
+
+impl From<&ThreeStar> for DerivedStar {
+    fn from(star: &ThreeStar) -> Self {
+        Self {
+            star: star.star,
+            player_id: star.player_id,
+            team_abbrev: star.team_abbrev.clone(),
+            name: star.name.clone(),
+            sweater_no: Some(star.sweater_no),
+            position: star.position.clone(),
+            official: true,
+        }
+    }
+}
+
+struct Candidate<'a> {
+    team: &'a BoxscoreTeam,
+    player_id: PlayerId,
+    name: &'a LocalizedString,
+    sweater_no: Option<i32>,
+    position: Option<Position>,
+    points: i32,
+    save_pctg: Option<f64>,
+    toi: IceTime,
+}
+
+impl<'a> Candidate<'a> {
+    fn skater(team: &'a BoxscoreTeam, skater: &'a SkaterStats) -> Self {
+        Self {
+            team,
+            player_id: skater.player_id,
+            name: &skater.name,
+            sweater_no: skater.sweater_number,
+            position: skater.position.clone(),
+            points: skater.points,
+            save_pctg: None,
+            toi: skater.toi,
+        }
+    }
+
+    fn goalie(team: &'a BoxscoreTeam, goalie: &'a GoalieStats) -> Self {
+        let save_pctg = (goalie.shots_against >= DERIVED_STAR_MIN_SHOTS_AGAINST)
+            .then(|| f64::from(goalie.saves) / f64::from(goalie.shots_against));
+        Self {
+            team,
+            player_id: goalie.player_id,
+            name: &goalie.name,
+            sweater_no: goalie.sweater_number,
+            position: goalie.position.clone(),
+            points: 0,
+            save_pctg,
+            toi: goalie.toi,
+        }
+    }
+}
+
+/// Ranks three stars from a boxscore's player stats.
+///
+/// Every skater and goalie who played is ordered by, in turn:
+///
+/// 1. points (goalies count as 0);
+/// 2. save percentage, only for goalies who faced at least
+///    [`DERIVED_STAR_MIN_SHOTS_AGAINST`] shots (others rank below them);
+/// 3. time on ice;
+///
+/// with ties broken by player id so the result is stable. Returns fewer than
+/// three stars when fewer players have stats, and all entries have
+/// `official: false`.
+pub fn derive_three_stars(boxscore: &Boxscore) -> Vec<DerivedStar> {
+    let stats = &boxscore.player_by_game_stats;
+    let mut candidates: Vec<Candidate> = [
+        (&boxscore.away_team, &stats.away_team),
+        (&boxscore.home_team, &stats.home_team),
+    ]
+    .into_iter()
+    .flat_map(|(team, players)| {
+        let skaters = players
+            .forwards
+            .iter()
+            .chain(&players.defense)
+            .map(move |skater| Candidate::skater(team, skater));
+        let goalies = players
+            .goalies
+            .iter()
+            .map(move |goalie| Candidate::goalie(team, goalie));
+        skaters.chain(goalies)
+    })
+    .filter(|candidate| candidate.toi > IceTime::ZERO)
+    .collect();
+
+    candidates.sort_by(|a, b| {
+        b.points
+            .cmp(&a.points)
+            .then_with(|| match (a.save_pctg, b.save_pctg) {
+                (Some(a), Some(b)) => b.total_cmp(&a),
+                (a, b) => b.is_some().cmp(&a.is_some()),
+            })
+            .then_with(|| b.toi.cmp(&a.toi))
+            .then_with(|| a.player_id.cmp(&b.player_id))
+    });
+
+    candidates
+        .into_iter()
+        .take(3)
+        .zip(1..)
+        .map(|(candidate, star)| DerivedStar {
+            star,
+            player_id: candidate.player_id,
+            team_abbrev: candidate.team.abbrev.clone(),
+            name: candidate.name.clone(),
+            sweater_no: candidate.sweater_no,
+            position: candidate.position,
+            official: false,
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::fixtures;
+    use crate::types::SavesShots;
+
+    fn skater(id: i64, points: i32, toi: &str) -> SkaterStats {
+        SkaterStats {
+            player_id: PlayerId::new(id),
+            sweater_number: Some(10),
+            name: LocalizedString::new(format!("Skater {}", id)),
+            position: Some(Position::Center),
+            goals: points,
+            assists: 0,
+            points,
+            plus_minus: 0,
+            pim: 0,
+            hits: 0,
+            power_play_goals: 0,
+            sog: 0,
+            faceoff_winning_pctg: 0.0,
+            toi: toi.parse().unwrap(),
+            blocked_shots: 0,
+            shifts: 20,
+            giveaways: 0,
+            takeaways: 0,
+        }
+    }
+
+    fn goalie(id: i64, saves: i32, shots: i32) -> GoalieStats {
+        let saves_shots = SavesShots { saves, shots };
+        GoalieStats {
+            player_id: PlayerId::new(id),
+            sweater_number: Some(30),
+            name: LocalizedString::new(format!("Goalie {}", id)),
+            position: Some(Position::Goalie),
+            even_strength_shots_against: saves_shots,
+            power_play_shots_against: SavesShots::default(),
+            shorthanded_shots_against: SavesShots::default(),
+            save_shots_against: saves_shots,
+            save_pctg: saves_shots.save_pctg(),
+            even_strength_goals_against: shots - saves,
+            power_play_goals_against: 0,
+            shorthanded_goals_against: 0,
+            pim: None,
+            goals_against: shots - saves,
+            toi: "60:00".parse().unwrap(),
+            starter: Some(true),
+            decision: None,
+            shots_against: shots,
+            saves,
+        }
+    }
+
+    fn boxscore(
+        away: Vec<SkaterStats>,
+        home: Vec<SkaterStats>,
+        goalies: Vec<GoalieStats>,
+    ) -> Boxscore {
+        let mut boxscore = fixtures::boxscore();
+        boxscore.away_team.abbrev = "TOR".to_string();
+        boxscore.home_team.abbrev = "MTL".to_string();
+        boxscore.player_by_game_stats.away_team.forwards = away;
+        boxscore.player_by_game_stats.home_team.forwards = home;
+        boxscore.player_by_game_stats.home_team.goalies = goalies;
+        boxscore
+    }
+
+    fn star_ids(stars: &[DerivedStar]) -> Vec<i64> {
+        stars.iter().map(|s| s.player_id.as_i64()).collect()
+    }
+
+    #[test]
+    fn test_derive_three_stars_ranks_by_points() {
+        let boxscore = boxscore(
+            vec![skater(1, 1, "15:00"), skater(2, 3, "15:00")],
+            vec![skater(3, 2, "15:00"), skater(4, 0, "25:00")],
+            Vec::new(),
+        );
+        let stars = derive_three_stars(&boxscore);
+        assert_eq!(star_ids(&stars), [2, 3, 1]);
+        assert_eq!(stars.iter().map(|s| s.star).collect::<Vec<_>>(), [1, 2, 3]);
+        assert_eq!(stars[0].team_abbrev, "TOR");
+        assert_eq!(stars[1].team_abbrev, "MTL");
+        assert!(stars.iter().all(|s| !s.official));
+    }
+
+    #[test]
+    fn test_derive_three_stars_goalie_save_pctg_breaks_point_ties() {
+        let boxscore = boxscore(
+            vec![skater(1, 1, "18:00"), skater(2, 0, "22:00")],
+            vec![skater(3, 0, "24:00")],
+            vec![goalie(30, 29, 30), goalie(31, 10, 10)],
+        );
+        // 31 has a perfect night on too few shots, so only 30's save % counts.
+        let stars = derive_three_stars(&boxscore);
+        assert_eq!(star_ids(&stars), [1, 30, 31]);
+    }
+
+    #[test]
+    fn test_derive_three_stars_minimum_shots_boundary() {
+        let below = boxscore(
+            vec![skater(1, 0, "20:00")],
+            Vec::new(),
+            vec![goalie(
+                30,
+                DERIVED_STAR_MIN_SHOTS_AGAINST - 2,
+                DERIVED_STAR_MIN_SHOTS_AGAINST - 1,
+            )],
+        );
+        // Falls through to TOI: the goalie's 60:00 beats the skater's 20:00.
+        assert_eq!(star_ids(&derive_three_stars(&below)), [30, 1]);
+
+        let at = boxscore(
+            Vec::new(),
+            vec![skater(1, 0, "20:00")],
+            vec![
+                goalie(
+                    30,
+                    DERIVED_STAR_MIN_SHOTS_AGAINST - 2,
+                    DERIVED_STAR_MIN_SHOTS_AGAINST,
+                ),
+                goalie(
+                    31,
+                    DERIVED_STAR_MIN_SHOTS_AGAINST - 1,
+                    DERIVED_STAR_MIN_SHOTS_AGAINST,
+                ),
+            ],
+        );
+        assert_eq!(star_ids(&derive_three_stars(&at)), [31, 30, 1]);
+    }
+
+    #[test]
+    fn test_derive_three_stars_toi_then_player_id_break_ties() {
+        let boxscore = boxscore(
+            vec![skater(5, 1, "17:00"), skater(4, 1, "19:30")],
+            vec![skater(2, 1, "17:00"), skater(1, 1, "00:00")],
+            Vec::new(),
+        );
+        // Player 1 didn't play and is skipped.
+        assert_eq!(star_ids(&derive_three_stars(&boxscore)), [4, 2, 5]);
+    }
+
+    #[test]
+    fn test_derive_three_stars_fewer_than_three_players() {
+        let boxscore = boxscore(vec![skater(1, 0, "10:00")], Vec::new(), Vec::new());
+        assert_eq!(star_ids(&derive_three_stars(&boxscore)), [1]);
+        assert!(derive_three_stars(&fixtures::boxscore()).is_empty());
+    }
+}
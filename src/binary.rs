@@ -0,0 +1,135 @@
+//! Compact binary encoding of response types for on-disk caches and
+//! archives (the `binary-cache` feature).
+//!
+//! Values are encoded as MessagePack with named fields, which is several
+//! times faster to re-read than the equivalent JSON. A plain positional
+//! format like bincode can't be used: several types here rely on
+//! `deserialize_any` (numbers sent as strings, `GameType` codes) or skip
+//! `None` fields when serializing, and both need a self-describing format.
+//!
+//! Every blob starts with [`SCHEMA_VERSION`]. A blob written by a build
+//! with a different version decodes to `Ok(None)`, to be treated as a cache
+//! miss and refetched, rather than a misread value.
+
+use std::io::{Read, Write};
+
+use serde::de::DeserializeOwned;
+use serde::Serialize;
+use thiserror::Error;
+
+/// Version byte at the start of every encoded blob. Bump it whenever a
+/// change to a response type would make older blobs decode wrongly.
+pub const SCHEMA_VERSION: u8 = 1;
+
+/// A binary cache blob could not be written or read.
+#[derive(Error, Debug)]
+pub enum BinaryCacheError {
+    #[error("binary cache I/O failed: {0}")]
+    Io(#[from] std::io::Error),
+
+    #[error("binary cache encode failed: {0}")]
+    Encode(#[from] rmp_serde::encode::Error),
+
+    #[error("binary cache decode failed: {0}")]
+    Decode(#[from] rmp_serde::decode::Error),
+}
+
+/// Encodes `value` as a versioned binary blob.
+pub fn to_binary<T: Serialize>(value: &T) -> Result<Vec<u8>, BinaryCacheError> {
+    let mut bytes = vec![SCHEMA_VERSION];
+    rmp_serde::encode::write_named(&mut bytes, value)?;
+    Ok(bytes)
+}
+
+/// Decodes a blob written by [`to_binary`]. `Ok(None)` when it is empty or
+/// was written with a different [`SCHEMA_VERSION`].
+pub fn from_binary<T: DeserializeOwned>(bytes: &[u8]) -> Result<Option<T>, BinaryCacheError> {
+    match bytes.split_first() {
+        Some((&SCHEMA_VERSION, body)) => Ok(Some(rmp_serde::from_slice(body)?)),
+        _ => Ok(None),
+    }
+}
+
+/// Writes `value` to `writer` as a versioned binary blob.
+pub fn write_binary<T: Serialize, W: Write>(
+    value: &T,
+    mut writer: W,
+) -> Result<(), BinaryCacheError> {
+    writer.write_all(&to_binary(value)?)?;
+    Ok(())
+}
+
+/// Reads a blob written by [`write_binary`]; `Ok(None)` on a schema version
+/// mismatch, as with [`from_binary`].
+pub fn read_binary<T: DeserializeOwned, R: Read>(
+    mut reader: R,
+) -> Result<Option<T>, BinaryCacheError> {
+    let mut bytes = Vec::new();
+    reader.read_to_end(&mut bytes)?;
+    from_binary(&bytes)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::ids::GameId;
+    use crate::types::{Boxscore, PlayByPlay};
+
+    const BOXSCORE_JSON: &str = include_str!("../tests/fixtures/boxscore.json");
+    const PLAY_BY_PLAY_JSON: &str = include_str!("../tests/fixtures/play_by_play.json");
+
+    /// A regular season's worth of boxscores: 1,312 games.
+    fn season_of_boxscores() -> Vec<Boxscore> {
+        let boxscore: Boxscore = serde_json::from_str(BOXSCORE_JSON).unwrap();
+        (1..=1312)
+            .map(|game| {
+                let mut game_boxscore = boxscore.clone();
+                game_boxscore.id = GameId::new(2024020000 + game);
+                game_boxscore.away_team.score = (game % 7) as i32;
+                game_boxscore
+            })
+            .collect()
+    }
+
+    #[test]
+    fn test_binary_season_of_boxscores_round_trips() {
+        let season = season_of_boxscores();
+
+        let bytes = to_binary(&season).unwrap();
+        let decoded: Vec<Boxscore> = from_binary(&bytes).unwrap().unwrap();
+
+        assert_eq!(decoded, season);
+        assert!(bytes.len() < serde_json::to_vec(&season).unwrap().len());
+    }
+
+    #[test]
+    fn test_binary_play_by_play_round_trips_through_writer() {
+        let play_by_play: PlayByPlay = serde_json::from_str(PLAY_BY_PLAY_JSON).unwrap();
+
+        let mut file = Vec::new();
+        write_binary(&play_by_play, &mut file).unwrap();
+        let decoded: PlayByPlay = read_binary(file.as_slice()).unwrap().unwrap();
+
+        assert_eq!(decoded, play_by_play);
+    }
+
+    #[test]
+    fn test_binary_schema_version_mismatch_is_a_miss() {
+        let boxscore: Boxscore = serde_json::from_str(BOXSCORE_JSON).unwrap();
+        let mut bytes = to_binary(&boxscore).unwrap();
+        bytes[0] = SCHEMA_VERSION.wrapping_add(1);
+
+        assert!(from_binary::<Boxscore>(&bytes).unwrap().is_none());
+        assert!(from_binary::<Boxscore>(&[]).unwrap().is_none());
+    }
+
+    #[test]
+    fn test_binary_corrupt_body_is_an_error() {
+        let boxscore: Boxscore = serde_json::from_str(BOXSCORE_JSON).unwrap();
+        let bytes = to_binary(&boxscore).unwrap();
+
+        let result = from_binary::<Boxscore>(&bytes[..bytes.len() / 2]);
+
+        assert!(matches!(result, Err(BinaryCacheError::Decode(_))));
+    }
+}
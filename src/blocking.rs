@@ -0,0 +1,248 @@
+//! A synchronous client for programs without an async runtime (the
+//! `blocking` feature).
+//!
+//! [`Client`] wraps the async [`crate::Client`] and a private
+//! single-threaded tokio runtime, and blocks on each call. Methods take the
+//! same arguments and return the same types and [`NHLApiError`]s as their
+//! async counterparts; [`Client::async_client`] reaches the rest of the
+//! async API.
+//!
+//! Don't call it from inside an async runtime: blocking on a runtime from
+//! within another one panics.
+//!
+//! ```no_run
+//! # fn example() -> Result<(), nhl_api::NHLApiError> {
+//! let client = nhl_api::blocking::Client::new()?;
+//! for standing in client.current_league_standings()? {
+//!     println!("{}: {} pts", standing.team_abbrev.default, standing.points);
+//! }
+//! # Ok(())
+//! # }
+//! ```
+
+use std::future::Future;
+use std::sync::Arc;
+
+use tokio::runtime::Runtime;
+
+use crate::analytics::DailyLeaders;
+use crate::config::ClientConfig;
+use crate::date::{GameDate, Season};
+use crate::error::NHLApiError;
+use crate::ids::{GameId, PlayerId};
+use crate::types::{
+    Boxscore, ClubStats, DailySchedule, DailyScores, Franchise, GameExistence, GameMatchup,
+    GameStory, GameType, PlayByPlay, PlayerGameLog, PlayerLanding, PlayerSearchResult, Roster,
+    SeasonGameTypes, SeasonInfo, SeasonSeriesMatchup, ShiftChart, Standing, Team,
+    TeamScheduleResponse, TeamToday, WeeklyScheduleResponse,
+};
+
+/// Blocking NHL API client. Cloning is cheap; clones share the runtime and
+/// the async client's connection pool, cache and stats.
+#[derive(Debug, Clone)]
+pub struct Client {
+    inner: crate::Client,
+    runtime: Arc<Runtime>,
+}
+
+/// Generates methods that block on the async client's method of the same
+/// name.
+macro_rules! blocking_methods {
+    ($(
+        $(#[$meta:meta])*
+        fn $name:ident(&self $(, $arg:ident: $ty:ty)*) -> $ret:ty;
+    )*) => {
+        $(
+            $(#[$meta])*
+            pub fn $name(&self $(, $arg: $ty)*) -> Result<$ret, NHLApiError> {
+                self.block_on(self.inner.$name($($arg),*))
+            }
+        )*
+    };
+}
+
+impl Client {
+    /// Create a new blocking client with default configuration
+    pub fn new() -> Result<Self, NHLApiError> {
+        Self::with_config(ClientConfig::default())
+    }
+
+    /// Create a new blocking client with custom configuration
+    pub fn with_config(config: ClientConfig) -> Result<Self, NHLApiError> {
+        let runtime = tokio::runtime::Builder::new_current_thread()
+            .enable_all()
+            .build()
+            .map_err(|error| NHLApiError::Other(format!("failed to start runtime: {}", error)))?;
+        Ok(Self {
+            inner: crate::Client::with_config(config)?,
+            runtime: Arc::new(runtime),
+        })
+    }
+
+    /// The async client underneath, for methods without a blocking wrapper.
+    pub fn async_client(&self) -> &crate::Client {
+        &self.inner
+    }
+
+    /// Runs any future to completion on this client's runtime, e.g. one
+    /// from [`Self::async_client`].
+    pub fn block_on<F: Future>(&self, future: F) -> F::Output {
+        self.runtime.block_on(future)
+    }
+
+    blocking_methods! {
+        /// See [`crate::Client::teams`].
+        fn teams(&self, date: Option<GameDate>) -> Vec<Team>;
+        /// See [`crate::Client::teams_with_franchises`].
+        fn teams_with_franchises(&self, date: Option<GameDate>) -> Vec<Team>;
+        /// See [`crate::Client::current_league_standings`].
+        fn current_league_standings(&self) -> Vec<Standing>;
+        /// See [`crate::Client::league_standings_for_date`].
+        fn league_standings_for_date(&self, date: &GameDate) -> Vec<Standing>;
+        /// See [`crate::Client::league_standings_for_date_checked`].
+        fn league_standings_for_date_checked(&self, date: &GameDate) -> Vec<Standing>;
+        /// See [`crate::Client::league_standings_for_season`].
+        fn league_standings_for_season(&self, season_id: i64) -> Vec<Standing>;
+        /// See [`crate::Client::season_standing_manifest`].
+        fn season_standing_manifest(&self) -> Vec<SeasonInfo>;
+        /// See [`crate::Client::daily_schedule`].
+        fn daily_schedule(&self, date: Option<GameDate>) -> DailySchedule;
+        /// See [`crate::Client::weekly_schedule`].
+        fn weekly_schedule(&self, date: Option<GameDate>) -> WeeklyScheduleResponse;
+        /// See [`crate::Client::game_ids_for_date`].
+        fn game_ids_for_date(&self, date: GameDate) -> Vec<GameId>;
+        /// See [`crate::Client::game_ids_in_range`].
+        fn game_ids_in_range(
+            &self,
+            from: GameDate,
+            to: GameDate,
+            game_type: Option<GameType>
+        ) -> Vec<GameId>;
+        /// See [`crate::Client::team_weekly_schedule`].
+        fn team_weekly_schedule(
+            &self,
+            team_abbr: &str,
+            date: Option<GameDate>
+        ) -> TeamScheduleResponse;
+        /// See [`crate::Client::club_schedule_season`].
+        fn club_schedule_season(&self, team_abbr: &str, season: Season) -> TeamScheduleResponse;
+        /// See [`crate::Client::find_game`].
+        fn find_game(&self, date: GameDate, team_abbr: &str) -> Option<GameId>;
+        /// See [`crate::Client::find_games_between`].
+        fn find_games_between(&self, team_a: &str, team_b: &str, season: Season) -> Vec<GameId>;
+        /// See [`crate::Client::daily_scores`].
+        fn daily_scores(&self, date: Option<GameDate>) -> DailyScores;
+        /// See [`crate::Client::team_today`].
+        fn team_today(&self, team_abbr: &str, date: Option<GameDate>) -> TeamToday;
+        /// See [`crate::Client::daily_leaders`].
+        fn daily_leaders(&self, date: Option<GameDate>) -> DailyLeaders;
+        /// See [`crate::Client::boxscore`].
+        fn boxscore(&self, game_id: impl Into<GameId>) -> Boxscore;
+        /// See [`crate::Client::play_by_play`].
+        fn play_by_play(&self, game_id: impl Into<GameId>) -> PlayByPlay;
+        /// See [`crate::Client::game_exists`].
+        fn game_exists(&self, game_id: impl Into<GameId>) -> GameExistence;
+        /// See [`crate::Client::landing`].
+        fn landing(&self, game_id: impl Into<GameId>) -> GameMatchup;
+        /// See [`crate::Client::game_story`].
+        fn game_story(&self, game_id: impl Into<GameId>) -> GameStory;
+        /// See [`crate::Client::season_series`].
+        fn season_series(&self, game_id: impl Into<GameId>) -> SeasonSeriesMatchup;
+        /// See [`crate::Client::shift_chart`].
+        fn shift_chart(&self, game_id: impl Into<GameId>) -> ShiftChart;
+        /// See [`crate::Client::player_landing`].
+        fn player_landing(&self, player_id: impl Into<PlayerId>) -> PlayerLanding;
+        /// See [`crate::Client::player_game_log`].
+        fn player_game_log(
+            &self,
+            player_id: impl Into<PlayerId>,
+            season: i32,
+            game_type: GameType
+        ) -> PlayerGameLog;
+        /// See [`crate::Client::search_player`].
+        fn search_player(
+            &self,
+            query: &str,
+            limit: Option<i32>,
+            active: Option<bool>
+        ) -> Vec<PlayerSearchResult>;
+        /// See [`crate::Client::search_player_exact`].
+        fn search_player_exact(&self, name: &str) -> Vec<PlayerSearchResult>;
+        /// See [`crate::Client::roster_current`].
+        fn roster_current(&self, team_abbr: &str) -> Roster;
+        /// See [`crate::Client::roster_season`].
+        fn roster_season(&self, team_abbr: &str, season: i32) -> Roster;
+        /// See [`crate::Client::franchises`].
+        fn franchises(&self) -> Vec<Franchise>;
+        /// See [`crate::Client::franchise_for_team`].
+        fn franchise_for_team(&self, team_abbr: &str) -> Option<Franchise>;
+        /// See [`crate::Client::club_stats`].
+        fn club_stats(&self, team_abbr: &str, season: i32, game_type: GameType) -> ClubStats;
+        /// See [`crate::Client::club_stats_season`].
+        fn club_stats_season(&self, team_abbr: &str) -> Vec<SeasonGameTypes>;
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn blocking_client(server: &mockito::Server) -> Client {
+        let config = ClientConfig::default().with_api_web_base(format!("{}/web/", server.url()));
+        Client::with_config(config).unwrap()
+    }
+
+    #[test]
+    fn test_blocking_boxscore() {
+        let mut server = mockito::Server::new();
+        let mock = server
+            .mock("GET", "/web/gamecenter/2024020500/boxscore")
+            .with_status(200)
+            .with_body(include_str!("../tests/fixtures/boxscore.json"))
+            .create();
+        let expected: Boxscore =
+            serde_json::from_str(include_str!("../tests/fixtures/boxscore.json")).unwrap();
+
+        let boxscore = blocking_client(&server).boxscore(2024020500).unwrap();
+
+        mock.assert();
+        assert_eq!(boxscore, expected);
+    }
+
+    #[test]
+    fn test_blocking_standings_for_date() {
+        let mut server = mockito::Server::new();
+        let mock = server
+            .mock("GET", "/web/standings/2024-11-09")
+            .with_status(200)
+            .with_body(
+                r#"{"standings": [{"divisionAbbrev": "A", "divisionName": "Atlantic",
+                    "teamName": {"default": "Montréal Canadiens"},
+                    "teamCommonName": {"default": "Canadiens"},
+                    "teamAbbrev": {"default": "MTL"}, "teamLogo": "",
+                    "wins": 5, "losses": 8, "otLosses": 2, "points": 12}]}"#,
+            )
+            .create();
+
+        let standings = blocking_client(&server)
+            .league_standings_for_date(&GameDate::from_ymd(2024, 11, 9).unwrap())
+            .unwrap();
+
+        mock.assert();
+        assert_eq!(standings[0].team_abbrev.default, "MTL");
+        assert_eq!(standings[0].points, 12);
+    }
+
+    #[test]
+    fn test_blocking_error_is_nhl_api_error() {
+        let mut server = mockito::Server::new();
+        let _mock = server
+            .mock("GET", "/web/gamecenter/2024020500/boxscore")
+            .with_status(404)
+            .create();
+
+        let result = blocking_client(&server).boxscore(2024020500);
+
+        assert!(matches!(result, Err(NHLApiError::ResourceNotFound { .. })));
+    }
+}
@@ -0,0 +1,55 @@
+//! Bulk per-game fetches with a concurrency cap and per-game results.
+
+use std::future::Future;
+
+use crate::client::Client;
+use crate::error::NHLApiError;
+use crate::ids::GameId;
+use crate::types::{Boxscore, GameMatchup, GameStory, PlayByPlay, ShiftChart};
+
+/// In-flight requests used by [`Client::boxscores_for_date`], and a
+/// reasonable starting point for [`Client::fetch_many`].
+pub const DEFAULT_BULK_CONCURRENCY: usize = 8;
+
+mod sealed {
+    pub trait Sealed {}
+}
+
+/// Per-game responses [`Client::fetch_many`] can fetch in bulk:
+/// [`Boxscore`], [`PlayByPlay`], [`GameMatchup`] (landing), [`GameStory`] and
+/// [`ShiftChart`].
+///
+/// Sealed: each implementation defers to the matching single-game `Client`
+/// method, so caching and retries behave the same as one-at-a-time calls.
+pub trait GameResource: sealed::Sealed + Sized {
+    #[doc(hidden)]
+    fn fetch(
+        client: &Client,
+        game_id: GameId,
+    ) -> impl Future<Output = Result<Self, NHLApiError>> + Send;
+}
+
+macro_rules! game_resource {
+    ($($type:ty => $method:ident),* $(,)?) => {
+        $(
+            impl sealed::Sealed for $type {}
+
+            impl GameResource for $type {
+                fn fetch(
+                    client: &Client,
+                    game_id: GameId,
+                ) -> impl Future<Output = Result<Self, NHLApiError>> + Send {
+                    client.$method(game_id)
+                }
+            }
+        )*
+    };
+}
+
+game_resource! {
+    Boxscore => boxscore,
+    PlayByPlay => play_by_play,
+    GameMatchup => landing,
+    GameStory => game_story,
+    ShiftChart => shift_chart,
+}
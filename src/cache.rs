@@ -0,0 +1,206 @@
+use std::collections::HashMap;
+use std::sync::{Arc, Mutex};
+use std::time::Duration;
+
+use crate::instant::Instant;
+
+/// How long a fetched response may be served from the cache, decided per
+/// call once the response has been decoded.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub(crate) enum CachePolicy {
+    /// Cache for the configured TTL.
+    Ttl,
+    /// Cache until evicted: the data can no longer change (e.g. a finished
+    /// game's boxscore).
+    Forever,
+    /// Don't cache: the data is live.
+    Bypass,
+}
+
+#[derive(Debug)]
+struct CacheEntry {
+    body: Arc<str>,
+    /// Insertion order, for evicting the oldest entry.
+    sequence: u64,
+    expires_at: Option<Instant>,
+}
+
+#[derive(Debug, Default)]
+struct CacheState {
+    entries: HashMap<String, CacheEntry>,
+    next_sequence: u64,
+}
+
+impl CacheEntry {
+    fn is_expired(&self, now: Instant) -> bool {
+        self.expires_at.is_some_and(|expires_at| now >= expires_at)
+    }
+}
+
+/// In-memory response bodies keyed by URL plus query string.
+///
+/// Bounded by `max_entries`: inserting into a full cache first drops expired
+/// entries, then the oldest.
+#[derive(Debug)]
+pub(crate) struct ResponseCache {
+    ttl: Duration,
+    max_entries: usize,
+    state: Mutex<CacheState>,
+}
+
+impl ResponseCache {
+    pub(crate) fn new(ttl: Duration, max_entries: usize) -> Self {
+        Self {
+            ttl,
+            max_entries,
+            state: Mutex::new(CacheState::default()),
+        }
+    }
+
+    /// Cache key for a request: the URL followed by its query parameters in
+    /// sorted order, so parameter order doesn't split entries.
+    pub(crate) fn key(url: &str, query_params: Option<&HashMap<String, String>>) -> String {
+        let Some(params) = query_params.filter(|params| !params.is_empty()) else {
+            return url.to_string();
+        };
+        let mut pairs: Vec<_> = params.iter().collect();
+        pairs.sort();
+        let query: Vec<String> = pairs
+            .into_iter()
+            .map(|(name, value)| format!("{}={}", name, value))
+            .collect();
+        format!("{}?{}", url, query.join("&"))
+    }
+
+    /// The cached body for `key`, unless missing or expired.
+    pub(crate) fn get(&self, key: &str) -> Option<Arc<str>> {
+        let mut state = self.state.lock().unwrap_or_else(|e| e.into_inner());
+        let entries = &mut state.entries;
+        let now = Instant::now();
+        match entries.get(key) {
+            Some(entry) if entry.is_expired(now) => {
+                entries.remove(key);
+                None
+            }
+            Some(entry) => Some(Arc::clone(&entry.body)),
+            None => None,
+        }
+    }
+
+    /// Stores `body` under `key` according to `policy`.
+    pub(crate) fn insert(&self, key: String, body: Arc<str>, policy: CachePolicy) {
+        let now = Instant::now();
+        let expires_at = match policy {
+            CachePolicy::Bypass => return,
+            CachePolicy::Ttl => Some(now + self.ttl),
+            CachePolicy::Forever => None,
+        };
+        if self.max_entries == 0 {
+            return;
+        }
+
+        let mut state = self.state.lock().unwrap_or_else(|e| e.into_inner());
+        let sequence = state.next_sequence;
+        state.next_sequence += 1;
+        let entries = &mut state.entries;
+        if !entries.contains_key(&key) && entries.len() >= self.max_entries {
+            entries.retain(|_, entry| !entry.is_expired(now));
+            if entries.len() >= self.max_entries {
+                let oldest = entries
+                    .iter()
+                    .min_by_key(|(_, entry)| entry.sequence)
+                    .map(|(key, _)| key.clone());
+                if let Some(oldest) = oldest {
+                    entries.remove(&oldest);
+                }
+            }
+        }
+        entries.insert(
+            key,
+            CacheEntry {
+                body,
+                sequence,
+                expires_at,
+            },
+        );
+    }
+
+    #[cfg(test)]
+    fn len(&self) -> usize {
+        self.state.lock().unwrap().entries.len()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn body(text: &str) -> Arc<str> {
+        Arc::from(text)
+    }
+
+    #[test]
+    fn test_response_cache_key_sorts_query_params() {
+        let mut params = HashMap::new();
+        params.insert("limit".to_string(), "20".to_string());
+        params.insert("q".to_string(), "mcdavid".to_string());
+        assert_eq!(
+            ResponseCache::key("https://x/search", Some(&params)),
+            "https://x/search?limit=20&q=mcdavid"
+        );
+        assert_eq!(ResponseCache::key("https://x/a", None), "https://x/a");
+        assert_eq!(
+            ResponseCache::key("https://x/a", Some(&HashMap::new())),
+            "https://x/a"
+        );
+    }
+
+    #[test]
+    fn test_response_cache_ttl_entry_round_trip() {
+        let cache = ResponseCache::new(Duration::from_secs(60), 8);
+        cache.insert("a".to_string(), body("{}"), CachePolicy::Ttl);
+        assert_eq!(cache.get("a").as_deref(), Some("{}"));
+        assert!(cache.get("b").is_none());
+    }
+
+    #[test]
+    fn test_response_cache_bypass_is_not_stored() {
+        let cache = ResponseCache::new(Duration::from_secs(60), 8);
+        cache.insert("a".to_string(), body("{}"), CachePolicy::Bypass);
+        assert!(cache.get("a").is_none());
+    }
+
+    #[test]
+    fn test_response_cache_expired_entry_is_dropped() {
+        let cache = ResponseCache::new(Duration::ZERO, 8);
+        cache.insert("a".to_string(), body("{}"), CachePolicy::Ttl);
+        assert!(cache.get("a").is_none());
+        assert_eq!(cache.len(), 0);
+    }
+
+    #[test]
+    fn test_response_cache_forever_entry_ignores_ttl() {
+        let cache = ResponseCache::new(Duration::ZERO, 8);
+        cache.insert("a".to_string(), body("{}"), CachePolicy::Forever);
+        assert_eq!(cache.get("a").as_deref(), Some("{}"));
+    }
+
+    #[test]
+    fn test_response_cache_evicts_oldest_when_full() {
+        let cache = ResponseCache::new(Duration::from_secs(60), 2);
+        cache.insert("a".to_string(), body("1"), CachePolicy::Ttl);
+        cache.insert("b".to_string(), body("2"), CachePolicy::Forever);
+        cache.insert("c".to_string(), body("3"), CachePolicy::Ttl);
+        assert_eq!(cache.len(), 2);
+        assert!(cache.get("a").is_none());
+        assert!(cache.get("b").is_some());
+        assert!(cache.get("c").is_some());
+    }
+
+    #[test]
+    fn test_response_cache_zero_capacity_stores_nothing() {
+        let cache = ResponseCache::new(Duration::from_secs(60), 0);
+        cache.insert("a".to_string(), body("{}"), CachePolicy::Forever);
+        assert!(cache.get("a").is_none());
+    }
+}
@@ -0,0 +1,120 @@
+//! A [`Client`] pinned to a moment, for reconstructing what the composite
+//! helpers would have returned then.
+
+use chrono::{DateTime, Utc};
+
+use crate::client::Client;
+use crate::date::GameDate;
+use crate::error::NHLApiError;
+use crate::types::{DailySchedule, DailyScores, Standing, TeamScheduleResponse, TeamToday};
+
+impl Client {
+    /// This client as of `as_of`: the date-defaulting and composite calls
+    /// of the returned [`ClientAt`] use `as_of` instead of the current time
+    ///
+    /// # Example
+    /// ```no_run
+    /// # use nhl_api::Client;
+    /// # use chrono::{TimeZone, Utc};
+    /// # async fn example() -> Result<(), nhl_api::NHLApiError> {
+    /// let client = Client::new()?;
+    /// let as_of = Utc.with_ymd_and_hms(2024, 11, 9, 18, 0, 0).unwrap();
+    /// println!("{}", client.at(as_of).team_today("MTL").await?.headline());
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn at(&self, as_of: DateTime<Utc>) -> ClientAt<'_> {
+        ClientAt {
+            client: self,
+            as_of,
+        }
+    }
+}
+
+/// A borrowed [`Client`] with "now" pinned to a fixed instant. From
+/// [`Client::at`].
+///
+/// Dates resolve to the UTC date of `as_of`, like [`GameDate::today`] does
+/// for the current time. The API only serves each game's state now, so
+/// games are rewound to what could be known at `as_of`:
+///
+/// - a game that starts later is reported unstarted
+///   ([`GameState::Future`](crate::GameState::Future));
+/// - a game that had started is reported live
+///   ([`GameState::Live`](crate::GameState::Live)), even if it had already
+///   ended, because the API doesn't say when a game ended;
+/// - either way its score, period, clock and outcome are withheld.
+///
+/// Standings are those at the end of the day before `as_of`'s date, so
+/// results from earlier that day are missing too.
+#[derive(Clone, Copy)]
+pub struct ClientAt<'a> {
+    client: &'a Client,
+    as_of: DateTime<Utc>,
+}
+
+impl<'a> ClientAt<'a> {
+    /// The pinned instant.
+    pub fn as_of(&self) -> DateTime<Utc> {
+        self.as_of
+    }
+
+    /// The date calls default to: the UTC date of [`Self::as_of`].
+    pub fn date(&self) -> GameDate {
+        GameDate::Date(self.as_of.date_naive())
+    }
+
+    /// [`Client::daily_schedule`] for [`Self::date`], rewound to
+    /// [`Self::as_of`].
+    pub async fn daily_schedule(&self) -> Result<DailySchedule, NHLApiError> {
+        let mut schedule = self.client.daily_schedule(Some(self.date())).await?;
+        for game in &mut schedule.games {
+            game.rewind_to(self.as_of);
+        }
+        Ok(schedule)
+    }
+
+    /// [`Client::daily_scores`] for [`Self::date`], rewound to
+    /// [`Self::as_of`].
+    pub async fn daily_scores(&self) -> Result<DailyScores, NHLApiError> {
+        let mut scores = self.client.daily_scores(Some(self.date())).await?;
+        for game in &mut scores.games {
+            game.rewind_to(None, self.as_of);
+        }
+        Ok(scores)
+    }
+
+    /// [`Client::league_standings_for_date`] for the day before
+    /// [`Self::date`].
+    pub async fn league_standings(&self) -> Result<Vec<Standing>, NHLApiError> {
+        self.client
+            .league_standings_for_date(&GameDate::Date(
+                self.as_of.date_naive() - chrono::Duration::days(1),
+            ))
+            .await
+    }
+
+    /// [`Client::team_weekly_schedule`] for the week of [`Self::date`],
+    /// rewound to [`Self::as_of`].
+    pub async fn team_weekly_schedule(
+        &self,
+        team_abbr: &str,
+    ) -> Result<TeamScheduleResponse, NHLApiError> {
+        let mut week = self
+            .client
+            .team_weekly_schedule(team_abbr, Some(self.date()))
+            .await?;
+        for game in &mut week.games {
+            game.rewind_to(self.as_of);
+        }
+        Ok(week)
+    }
+
+    /// [`Client::team_today`] for [`Self::date`], with its games rewound to
+    /// [`Self::as_of`] and the previous day's standings.
+    pub async fn team_today(&self, team_abbr: &str) -> Result<TeamToday, NHLApiError> {
+        self.client
+            .team_today_as_of(team_abbr, Some(self.date()), Some(self.as_of))
+            .await
+    }
+}
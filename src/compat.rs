@@ -0,0 +1,741 @@
+//! Migration shims for fields whose Rust type changed in this release.
+//!
+//! The wire format of these fields is unchanged, but code matching on the
+//! old `String`s stops compiling. Each changed field keeps a `#[deprecated]`
+//! accessor named after it (`toi_str()`, `market_str()`, ...) returning the
+//! old form, and the aliases below name the old types, so downstream crates
+//! can move over one call site at a time. Everything here is removed in
+//! 0.10.
+//!
+//! Methods that were renamed or repurposed keep their old name the same
+//! way, as `#[deprecated]` forwarders to the replacement.
+//!
+//! [`CHANGED_FIELDS`] lists every field whose type changed or that moved,
+//! [`DEPRECATED_METHODS`] every forwarder; [`changelog`] renders both as
+//! Markdown.
+
+#![allow(deprecated)]
+
+use crate::types::{
+    GameClock, GoalieStats, PenaltySummary, PlayByPlay, PlayEvent, PlayEventDetails,
+    SeriesGameInfo, ShiftEntry, SkaterStats, TvBroadcast,
+};
+use crate::LocalizedString;
+
+/// Old type of `MM:SS` fields that are now [`crate::IceTime`].
+#[deprecated(since = "0.9.0", note = "use `IceTime`")]
+pub type IceTimeString = String;
+
+/// Old type of `"saves/shots"` fields that are now
+/// [`crate::types::SavesShots`].
+#[deprecated(since = "0.9.0", note = "use `SavesShots`")]
+pub type SavesShotsString = String;
+
+/// Old type of [`TvBroadcast::market`], now a
+/// [`crate::types::BroadcastMarket`].
+#[deprecated(since = "0.9.0", note = "use `BroadcastMarket`")]
+pub type BroadcastMarketString = String;
+
+/// A public field whose type changed, or that moved, since the last
+/// release.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct ChangedField {
+    /// Struct the field belongs to, e.g. `"SkaterStats"`.
+    pub type_name: &'static str,
+    pub field: &'static str,
+    pub old_type: &'static str,
+    /// New type, or the field's new path when it moved.
+    pub new_type: &'static str,
+    /// Deprecated accessor returning the old form, if there is one.
+    pub accessor: Option<&'static str>,
+}
+
+const fn changed(
+    type_name: &'static str,
+    field: &'static str,
+    old_type: &'static str,
+    new_type: &'static str,
+    accessor: Option<&'static str>,
+) -> ChangedField {
+    ChangedField {
+        type_name,
+        field,
+        old_type,
+        new_type,
+        accessor,
+    }
+}
+
+/// Every changed field, grouped by type. Keep in sync with the "Breaking
+/// changes" section of `CHANGELOG.md`; a test checks both directions.
+pub const CHANGED_FIELDS: &[ChangedField] = &[
+    changed(
+        "TvBroadcast",
+        "market",
+        "String",
+        "BroadcastMarket",
+        Some("market_str"),
+    ),
+    changed(
+        "GameClock",
+        "time_remaining",
+        "String",
+        "IceTime",
+        Some("time_remaining_str"),
+    ),
+    changed("TeamGameStats", "faceoff_wins", "i32", "Option<i32>", None),
+    changed("TeamGameStats", "faceoff_total", "i32", "Option<i32>", None),
+    changed("SkaterStats", "toi", "String", "IceTime", Some("toi_str")),
+    changed("GoalieStats", "toi", "String", "IceTime", Some("toi_str")),
+    changed(
+        "GoalieStats",
+        "even_strength_shots_against",
+        "String",
+        "SavesShots",
+        Some("even_strength_shots_against_str"),
+    ),
+    changed(
+        "GoalieStats",
+        "power_play_shots_against",
+        "String",
+        "SavesShots",
+        Some("power_play_shots_against_str"),
+    ),
+    changed(
+        "GoalieStats",
+        "shorthanded_shots_against",
+        "String",
+        "SavesShots",
+        Some("shorthanded_shots_against_str"),
+    ),
+    changed(
+        "GoalieStats",
+        "save_shots_against",
+        "String",
+        "SavesShots",
+        Some("save_shots_against_str"),
+    ),
+    changed(
+        "ShiftEntry",
+        "duration",
+        "String",
+        "IceTime",
+        Some("duration_str"),
+    ),
+    changed(
+        "ShiftEntry",
+        "start_time",
+        "String",
+        "IceTime",
+        Some("start_time_str"),
+    ),
+    changed(
+        "ShiftEntry",
+        "end_time",
+        "String",
+        "IceTime",
+        Some("end_time_str"),
+    ),
+    changed("ShiftEntry", "event_number", "i64", "Option<i64>", None),
+    changed("SkaterStats", "sweater_number", "i32", "Option<i32>", None),
+    changed("GoalieStats", "sweater_number", "i32", "Option<i32>", None),
+    changed("RosterSpot", "sweater_number", "i32", "Option<i32>", None),
+    changed(
+        "AssistSummary",
+        "sweater_number",
+        "i32",
+        "Option<i32>",
+        None,
+    ),
+    changed("DerivedStar", "sweater_no", "i32", "Option<i32>", None),
+    changed(
+        "RosterPlayer",
+        "height_in_inches",
+        "i32",
+        "Option<i32>",
+        None,
+    ),
+    changed(
+        "RosterPlayer",
+        "weight_in_pounds",
+        "i32",
+        "Option<i32>",
+        None,
+    ),
+    changed(
+        "RosterPlayer",
+        "height_in_centimeters",
+        "i32",
+        "Option<i32>",
+        None,
+    ),
+    changed(
+        "RosterPlayer",
+        "weight_in_kilograms",
+        "i32",
+        "Option<i32>",
+        None,
+    ),
+    changed(
+        "PlayerLanding",
+        "height_in_inches",
+        "i32",
+        "Option<i32>",
+        None,
+    ),
+    changed(
+        "PlayerLanding",
+        "weight_in_pounds",
+        "i32",
+        "Option<i32>",
+        None,
+    ),
+    changed(
+        "SeriesGameInfo",
+        "referees",
+        "Vec<LocalizedString>",
+        "officials.referees",
+        Some("referees"),
+    ),
+    changed(
+        "SeriesGameInfo",
+        "linesmen",
+        "Vec<LocalizedString>",
+        "officials.linesmen",
+        Some("linesmen"),
+    ),
+    changed(
+        "PenaltySummary",
+        "penalty_type",
+        "String",
+        "PenaltySeverity",
+        Some("penalty_type_str"),
+    ),
+    changed(
+        "PenaltySummary",
+        "desc_key",
+        "String",
+        "PenaltyDescKey",
+        Some("desc_key_str"),
+    ),
+    changed(
+        "PlayEventDetails",
+        "type_code",
+        "Option<String>",
+        "Option<PenaltySeverity>",
+        Some("type_code_str"),
+    ),
+    changed(
+        "PlayEventDetails",
+        "desc_key",
+        "Option<String>",
+        "Option<PenaltyDescKey>",
+        Some("desc_key_str"),
+    ),
+    changed(
+        "RosterPlayer",
+        "birth_city",
+        "LocalizedString",
+        "birth.city",
+        None,
+    ),
+    changed(
+        "RosterPlayer",
+        "birth_state_province",
+        "Option<LocalizedString>",
+        "birth.state_province",
+        None,
+    ),
+    changed(
+        "RosterPlayer",
+        "birth_country",
+        "String",
+        "birth.country_code",
+        None,
+    ),
+    changed(
+        "PlayerLanding",
+        "birth_city",
+        "Option<LocalizedString>",
+        "birth.city",
+        None,
+    ),
+    changed(
+        "PlayerLanding",
+        "birth_state_province",
+        "Option<LocalizedString>",
+        "birth.state_province",
+        None,
+    ),
+    changed(
+        "PlayerLanding",
+        "birth_country",
+        "Option<String>",
+        "birth.country_code",
+        None,
+    ),
+    changed(
+        "PlayerSearchResult",
+        "birth_city",
+        "Option<String>",
+        "birth.city",
+        None,
+    ),
+    changed(
+        "PlayerSearchResult",
+        "birth_state_province",
+        "Option<String>",
+        "birth.state_province",
+        None,
+    ),
+    changed(
+        "PlayerSearchResult",
+        "birth_country",
+        "Option<String>",
+        "birth.country_code",
+        None,
+    ),
+    changed(
+        "RankedProspect",
+        "birth_city",
+        "Option<String>",
+        "birth.city",
+        None,
+    ),
+    changed(
+        "RankedProspect",
+        "birth_state_province",
+        "Option<String>",
+        "birth.state_province",
+        None,
+    ),
+    changed(
+        "RankedProspect",
+        "birth_country",
+        "Option<String>",
+        "birth.country_code",
+        None,
+    ),
+    changed("BoxscoreTeam", "sog", "i32", "Option<i32>", None),
+    changed("MatchupTeam", "sog", "i32", "Option<i32>", None),
+    changed("SogValidation", "away_reported", "i32", "Option<i32>", None),
+    changed("SogValidation", "home_reported", "i32", "Option<i32>", None),
+];
+
+/// A method kept under its old name, forwarding to its replacement.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct DeprecatedMethod {
+    /// Type the method belongs to, e.g. `"PlayByPlay"`.
+    pub type_name: &'static str,
+    pub method: &'static str,
+    /// The call to use instead, e.g. `"shot_attempts(None)"`.
+    pub replacement: &'static str,
+}
+
+/// Every deprecated forwarder. Keep in sync with the "Deprecated" section
+/// of `CHANGELOG.md`; a test checks both directions.
+pub const DEPRECATED_METHODS: &[DeprecatedMethod] = &[
+    DeprecatedMethod {
+        type_name: "PlayByPlay",
+        method: "shots",
+        replacement: "shot_attempts(None)",
+    },
+    DeprecatedMethod {
+        type_name: "PlayByPlay",
+        method: "plays_in_period",
+        replacement: "events_in_period(period)",
+    },
+];
+
+/// [`CHANGED_FIELDS`] then [`DEPRECATED_METHODS`] as a Markdown list, one
+/// entry per line.
+pub fn changelog() -> String {
+    let methods = DEPRECATED_METHODS.iter().map(|method| {
+        format!(
+            "- `{}::{}()`: deprecated, use `{}`\n",
+            method.type_name, method.method, method.replacement
+        )
+    });
+    CHANGED_FIELDS
+        .iter()
+        .map(|change| {
+            let mut line = format!(
+                "- `{}.{}`: `{}` -> `{}`",
+                change.type_name, change.field, change.old_type, change.new_type
+            );
+            if let Some(accessor) = change.accessor {
+                line.push_str(&format!(
+                    " (old form: `{}::{}()`, deprecated)",
+                    change.type_name, accessor
+                ));
+            }
+            line.push('\n');
+            line
+        })
+        .chain(methods)
+        .collect()
+}
+
+impl PlayByPlay {
+    /// Every shot attempt: goals, shots on goal, missed and blocked shots.
+    #[deprecated(
+        since = "0.9.0",
+        note = "use `shot_attempts(None)`, or `shots_on_goal` for shots on goal only"
+    )]
+    pub fn shots(&self) -> Vec<&PlayEvent> {
+        self.shot_attempts(None)
+    }
+
+    /// Plays in one period.
+    #[deprecated(since = "0.9.0", note = "use `events_in_period`")]
+    pub fn plays_in_period(&self, period: i32) -> Vec<&PlayEvent> {
+        self.events_in_period(period)
+    }
+}
+
+impl TvBroadcast {
+    /// [`Self::market`] as its old one-letter code string.
+    #[deprecated(since = "0.9.0", note = "match on `market` (a `BroadcastMarket`)")]
+    pub fn market_str(&self) -> BroadcastMarketString {
+        self.market.code().to_string()
+    }
+}
+
+impl GameClock {
+    /// [`Self::time_remaining`] as its old `MM:SS` string.
+    #[deprecated(since = "0.9.0", note = "use `time_remaining` (an `IceTime`)")]
+    pub fn time_remaining_str(&self) -> IceTimeString {
+        self.time_remaining.to_string()
+    }
+}
+
+impl SkaterStats {
+    /// [`Self::toi`] as its old `MM:SS` string.
+    #[deprecated(since = "0.9.0", note = "use `toi` (an `IceTime`)")]
+    pub fn toi_str(&self) -> IceTimeString {
+        self.toi.to_string()
+    }
+}
+
+impl GoalieStats {
+    /// [`Self::toi`] as its old `MM:SS` string.
+    #[deprecated(since = "0.9.0", note = "use `toi` (an `IceTime`)")]
+    pub fn toi_str(&self) -> IceTimeString {
+        self.toi.to_string()
+    }
+
+    /// [`Self::even_strength_shots_against`] as its old `"saves/shots"`
+    /// string.
+    #[deprecated(
+        since = "0.9.0",
+        note = "use `even_strength_shots_against` (a `SavesShots`)"
+    )]
+    pub fn even_strength_shots_against_str(&self) -> SavesShotsString {
+        self.even_strength_shots_against.to_string()
+    }
+
+    /// [`Self::power_play_shots_against`] as its old `"saves/shots"` string.
+    #[deprecated(
+        since = "0.9.0",
+        note = "use `power_play_shots_against` (a `SavesShots`)"
+    )]
+    pub fn power_play_shots_against_str(&self) -> SavesShotsString {
+        self.power_play_shots_against.to_string()
+    }
+
+    /// [`Self::shorthanded_shots_against`] as its old `"saves/shots"`
+    /// string.
+    #[deprecated(
+        since = "0.9.0",
+        note = "use `shorthanded_shots_against` (a `SavesShots`)"
+    )]
+    pub fn shorthanded_shots_against_str(&self) -> SavesShotsString {
+        self.shorthanded_shots_against.to_string()
+    }
+
+    /// [`Self::save_shots_against`] as its old `"saves/shots"` string.
+    #[deprecated(since = "0.9.0", note = "use `save_shots_against` (a `SavesShots`)")]
+    pub fn save_shots_against_str(&self) -> SavesShotsString {
+        self.save_shots_against.to_string()
+    }
+}
+
+impl ShiftEntry {
+    /// [`Self::duration`] as its old `MM:SS` string.
+    #[deprecated(since = "0.9.0", note = "use `duration` (an `IceTime`)")]
+    pub fn duration_str(&self) -> IceTimeString {
+        self.duration.to_string()
+    }
+
+    /// [`Self::start_time`] as its old `MM:SS` string.
+    #[deprecated(since = "0.9.0", note = "use `start_time` (an `IceTime`)")]
+    pub fn start_time_str(&self) -> IceTimeString {
+        self.start_time.to_string()
+    }
+
+    /// [`Self::end_time`] as its old `MM:SS` string.
+    #[deprecated(since = "0.9.0", note = "use `end_time` (an `IceTime`)")]
+    pub fn end_time_str(&self) -> IceTimeString {
+        self.end_time.to_string()
+    }
+}
+
+impl SeriesGameInfo {
+    /// The referees, formerly a field of this struct.
+    #[deprecated(since = "0.9.0", note = "use `officials.referees`")]
+    pub fn referees(&self) -> &[LocalizedString] {
+        &self.officials.referees
+    }
+
+    /// The linesmen, formerly a field of this struct.
+    #[deprecated(since = "0.9.0", note = "use `officials.linesmen`")]
+    pub fn linesmen(&self) -> &[LocalizedString] {
+        &self.officials.linesmen
+    }
+}
+
+impl PenaltySummary {
+    /// [`Self::penalty_type`] as its old code string.
+    #[deprecated(
+        since = "0.9.0",
+        note = "match on `penalty_type` (a `PenaltySeverity`)"
+    )]
+    pub fn penalty_type_str(&self) -> String {
+        self.penalty_type.code().to_string()
+    }
+
+    /// [`Self::desc_key`] as its old string.
+    #[deprecated(since = "0.9.0", note = "use `desc_key` (a `PenaltyDescKey`)")]
+    pub fn desc_key_str(&self) -> String {
+        self.desc_key.to_string()
+    }
+}
+
+impl PlayEventDetails {
+    /// [`Self::type_code`] as its old code string.
+    #[deprecated(since = "0.9.0", note = "match on `type_code` (a `PenaltySeverity`)")]
+    pub fn type_code_str(&self) -> Option<String> {
+        self.type_code
+            .as_ref()
+            .map(|severity| severity.code().to_string())
+    }
+
+    /// [`Self::desc_key`] as its old string.
+    #[deprecated(since = "0.9.0", note = "use `desc_key` (a `PenaltyDescKey`)")]
+    pub fn desc_key_str(&self) -> Option<String> {
+        self.desc_key.as_ref().map(ToString::to_string)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::types::{Boxscore, BroadcastMarket, SeasonSeriesMatchup};
+    use std::collections::BTreeSet;
+
+    const CHANGELOG: &str = include_str!("../CHANGELOG.md");
+
+    /// The "Breaking changes" section of the unreleased changelog entry.
+    fn unreleased_breaking_changes() -> &'static str {
+        let unreleased = CHANGELOG.split("\n## ").nth(1).unwrap();
+        let section = unreleased.split("### Breaking changes").nth(1).unwrap();
+        section.split("\n### ").next().unwrap()
+    }
+
+    /// `Type.field` names in backticks, plus the `` /`field` `` ones that
+    /// follow them, e.g. `` `TeamGameStats.faceoff_wins`/`faceoff_total` ``.
+    fn documented_fields(section: &str) -> BTreeSet<(String, String)> {
+        let mut fields = BTreeSet::new();
+        let mut current_type: Option<String> = None;
+        let parts: Vec<&str> = section.split('`').collect();
+        for (index, part) in parts.iter().enumerate().skip(1).step_by(2) {
+            let before = parts[index - 1];
+            if let Some((type_name, field)) = part.split_once('.') {
+                let is_type = type_name.starts_with(char::is_uppercase)
+                    && type_name.chars().all(char::is_alphanumeric);
+                let is_field =
+                    !field.is_empty() && field.chars().all(|c| c.is_ascii_lowercase() || c == '_');
+                if is_type && is_field {
+                    current_type = Some(type_name.to_string());
+                    fields.insert((type_name.to_string(), field.to_string()));
+                    continue;
+                }
+            }
+            let continues =
+                before == "/" || before == ", " || before == ",\n  " || before == " and ";
+            let is_field = part.chars().all(|c| c.is_ascii_lowercase() || c == '_');
+            match &current_type {
+                Some(type_name) if continues && is_field => {
+                    fields.insert((type_name.clone(), part.to_string()));
+                }
+                _ => current_type = None,
+            }
+        }
+        fields
+    }
+
+    /// `` `Type::method()` `` names in the "Deprecated" section.
+    fn documented_methods() -> BTreeSet<(String, String)> {
+        let unreleased = CHANGELOG.split("\n## ").nth(1).unwrap();
+        let section = unreleased.split("### Deprecated").nth(1).unwrap();
+        let section = section.split("\n### ").next().unwrap();
+        section
+            .split('`')
+            .skip(1)
+            .step_by(2)
+            .filter_map(|part| {
+                let (type_name, method) = part.strip_suffix("()")?.split_once("::")?;
+                Some((type_name.to_string(), method.to_string()))
+            })
+            .collect()
+    }
+
+    #[test]
+    fn test_deprecated_methods_match_changelog() {
+        let listed: BTreeSet<(String, String)> = DEPRECATED_METHODS
+            .iter()
+            .map(|method| (method.type_name.to_string(), method.method.to_string()))
+            .collect();
+
+        assert_eq!(listed, documented_methods());
+    }
+
+    #[test]
+    fn test_play_by_play_forwarders_agree_with_replacements() {
+        let pbp: PlayByPlay =
+            serde_json::from_str(include_str!("../tests/fixtures/play_by_play.json")).unwrap();
+
+        assert_eq!(pbp.shots(), pbp.shot_attempts(None));
+        assert_eq!(pbp.shots().len(), 4);
+        assert!(pbp.shots().len() > pbp.shots_on_goal(None).len());
+        assert_eq!(pbp.plays_in_period(1), pbp.events_in_period(1));
+    }
+
+    #[test]
+    fn test_changed_fields_match_changelog() {
+        let documented = documented_fields(unreleased_breaking_changes());
+        let listed: BTreeSet<(String, String)> = CHANGED_FIELDS
+            .iter()
+            .map(|change| (change.type_name.to_string(), change.field.to_string()))
+            .collect();
+
+        assert_eq!(listed, documented);
+    }
+
+    #[test]
+    fn test_changelog_lists_every_field() {
+        let changelog = changelog();
+
+        assert_eq!(
+            changelog.lines().count(),
+            CHANGED_FIELDS.len() + DEPRECATED_METHODS.len()
+        );
+        assert!(changelog.contains(
+            "- `SkaterStats.toi`: `String` -> `IceTime` (old form: `SkaterStats::toi_str()`, deprecated)\n"
+        ));
+        assert!(changelog.contains("- `TeamGameStats.faceoff_wins`: `i32` -> `Option<i32>`\n"));
+        assert!(
+            changelog.contains("- `PlayByPlay::shots()`: deprecated, use `shot_attempts(None)`\n")
+        );
+    }
+
+    #[test]
+    fn test_boxscore_accessors_agree_with_typed_fields() {
+        let boxscore: Boxscore =
+            serde_json::from_str(include_str!("../tests/fixtures/boxscore.json")).unwrap();
+        let players = &boxscore.player_by_game_stats.away_team;
+
+        let skater = &players.forwards[0];
+        assert_eq!(skater.toi_str(), "12:31");
+        assert_eq!(skater.toi_str().parse(), Ok(skater.toi));
+
+        let goalie = &players.goalies[0];
+        assert_eq!(goalie.toi_str(), "29:45");
+        assert_eq!(goalie.save_shots_against_str(), "14/15");
+        for (old, typed) in [
+            (goalie.toi_str(), goalie.toi.to_string()),
+            (
+                goalie.even_strength_shots_against_str(),
+                goalie.even_strength_shots_against.to_string(),
+            ),
+            (
+                goalie.power_play_shots_against_str(),
+                goalie.power_play_shots_against.to_string(),
+            ),
+            (
+                goalie.shorthanded_shots_against_str(),
+                goalie.shorthanded_shots_against.to_string(),
+            ),
+        ] {
+            assert_eq!(old, typed);
+        }
+
+        let clock = &boxscore.clock;
+        assert_eq!(clock.time_remaining_str().parse(), Ok(clock.time_remaining));
+    }
+
+    #[test]
+    fn test_market_str_is_wire_code() {
+        let broadcast: TvBroadcast = serde_json::from_str(
+            r#"{"id": 1, "market": "A", "countryCode": "US", "network": "MSG", "sequenceNumber": 1}"#,
+        )
+        .unwrap();
+
+        assert_eq!(broadcast.market, BroadcastMarket::Away);
+        assert_eq!(broadcast.market_str(), "A");
+    }
+
+    #[test]
+    fn test_shift_entry_accessors_agree_with_typed_fields() {
+        let shift: ShiftEntry = serde_json::from_str(
+            r##"{"id": 1, "detailCode": 0, "duration": "00:45", "endTime": "01:30",
+                "eventNumber": 12, "firstName": "Nico", "gameId": 2024020001,
+                "hexValue": "#CE1126", "lastName": "Hischier", "period": 1,
+                "playerId": 8479407, "shiftNumber": 2, "startTime": "00:45",
+                "teamAbbrev": "NJD", "teamId": 1, "teamName": "New Jersey Devils",
+                "typeCode": 517}"##,
+        )
+        .unwrap();
+
+        assert_eq!(shift.duration_str(), "00:45");
+        assert_eq!(shift.start_time_str().parse(), Ok(shift.start_time));
+        assert_eq!(shift.end_time_str().parse(), Ok(shift.end_time));
+    }
+
+    #[test]
+    fn test_officials_accessors() {
+        let matchup: SeasonSeriesMatchup = serde_json::from_str(
+            r#"{"seasonSeries": [], "seasonSeriesWins": {"awayTeamWins": 0, "homeTeamWins": 0},
+                "gameInfo": {"referees": [{"default": "Wes McCauley"}],
+                    "linesmen": [{"default": "Steve Barton"}, {"default": "Ryan Daisy"}],
+                    "awayTeam": {"headCoach": {"default": "Sheldon Keefe"}, "scratches": []},
+                    "homeTeam": {"headCoach": {"default": "Lindy Ruff"}, "scratches": []}}}"#,
+        )
+        .unwrap();
+        let info = &matchup.game_info;
+
+        assert_eq!(info.referees(), info.officials.referees.as_slice());
+        assert_eq!(info.linesmen().len(), 2);
+    }
+
+    #[test]
+    fn test_penalty_accessors_are_wire_strings() {
+        let summary: crate::types::GameSummary =
+            serde_json::from_str(include_str!("../tests/fixtures/game_summary_fight.json"))
+                .unwrap();
+        let fight = &summary.penalties[1].penalties;
+
+        assert_eq!(fight[0].penalty_type_str(), "MAJ");
+        assert_eq!(fight[3].penalty_type_str(), "MIS");
+        assert_eq!(fight[3].desc_key_str(), "instigator-misconduct");
+
+        let details: PlayEventDetails =
+            serde_json::from_str(r#"{"typeCode": "BEN", "descKey": "too-many-men-on-the-ice"}"#)
+                .unwrap();
+        assert_eq!(details.type_code_str().as_deref(), Some("BEN"));
+        assert_eq!(
+            details.desc_key_str().as_deref(),
+            Some("too-many-men-on-the-ice")
+        );
+    }
+}
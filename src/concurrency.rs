@@ -0,0 +1,146 @@
+//! Bounded-concurrency fetches for building composite requests.
+//!
+//! [`bounded_fetch`] is the helper behind [`Client::fetch_many`] and
+//! [`Client::boxscores_for_date`]; it is public so composites built outside
+//! this crate get the same ordering and concurrency behavior.
+//!
+//! [`Client::fetch_many`]: crate::Client::fetch_many
+//! [`Client::boxscores_for_date`]: crate::Client::boxscores_for_date
+
+use futures_util::stream::{self, StreamExt};
+use std::future::Future;
+
+use crate::error::NHLApiError;
+
+/// Runs `fetch` for every item with at most `concurrency` calls in flight
+/// (`0` is treated as `1`), returning each item with its result in input
+/// order.
+///
+/// A failure for one item leaves the others intact. Calls start lazily as
+/// earlier ones finish, so at most `concurrency` requests are ever
+/// outstanding; dropping the returned future cancels everything still in
+/// flight.
+///
+/// ```no_run
+/// # async fn example(client: &nhl_api::Client) {
+/// use nhl_api::concurrency::bounded_fetch;
+///
+/// let teams = ["BOS", "TOR", "MTL"];
+/// for (team, result) in bounded_fetch(teams, 2, |team| client.roster_current(team)).await {
+///     match result {
+///         Ok(roster) => println!("{}: {} forwards", team, roster.forwards.len()),
+///         Err(e) => eprintln!("{}: {}", team, e),
+///     }
+/// }
+/// # }
+/// ```
+pub async fn bounded_fetch<I, T, F, Fut>(
+    items: impl IntoIterator<Item = I>,
+    concurrency: usize,
+    fetch: F,
+) -> Vec<(I, Result<T, NHLApiError>)>
+where
+    I: Clone,
+    F: Fn(I) -> Fut,
+    Fut: Future<Output = Result<T, NHLApiError>>,
+{
+    stream::iter(items)
+        .map(|item| {
+            let request = fetch(item.clone());
+            async move { (item, request.await) }
+        })
+        .buffered(concurrency.max(1))
+        .collect()
+        .await
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::sync::atomic::{AtomicUsize, Ordering};
+    use std::time::Duration;
+
+    #[tokio::test]
+    async fn test_bounded_fetch_preserves_input_order() {
+        let items: Vec<u64> = (1..=6).collect();
+        // Later items finish first.
+        let results = bounded_fetch(items.clone(), 6, |n| async move {
+            tokio::time::sleep(Duration::from_millis((10 - n) * 2)).await;
+            Ok::<_, NHLApiError>(n * 10)
+        })
+        .await;
+
+        let order: Vec<u64> = results.iter().map(|(n, _)| *n).collect();
+        assert_eq!(order, items);
+        for (n, result) in results {
+            assert_eq!(result.unwrap(), n * 10);
+        }
+    }
+
+    #[tokio::test]
+    async fn test_bounded_fetch_isolates_errors() {
+        let results = bounded_fetch(["BOS", "XXX", "TOR"], 2, |team| async move {
+            if team == "XXX" {
+                Err(NHLApiError::ResourceNotFound {
+                    message: "Not found".to_string(),
+                    status_code: 404,
+                })
+            } else {
+                Ok(team.len())
+            }
+        })
+        .await;
+
+        assert_eq!(results.len(), 3);
+        assert_eq!(results[1].0, "XXX");
+        assert!(results[1].1.is_err());
+        assert_eq!(results.iter().filter(|(_, r)| r.is_ok()).count(), 2);
+    }
+
+    #[tokio::test]
+    async fn test_bounded_fetch_respects_cap() {
+        let in_flight = AtomicUsize::new(0);
+        let peak = AtomicUsize::new(0);
+        let results = bounded_fetch(0..20, 3, |_| async {
+            let now = in_flight.fetch_add(1, Ordering::SeqCst) + 1;
+            peak.fetch_max(now, Ordering::SeqCst);
+            tokio::time::sleep(Duration::from_millis(5)).await;
+            in_flight.fetch_sub(1, Ordering::SeqCst);
+            Ok::<_, NHLApiError>(())
+        })
+        .await;
+
+        assert_eq!(results.len(), 20);
+        assert_eq!(peak.load(Ordering::SeqCst), 3);
+    }
+
+    #[tokio::test]
+    async fn test_bounded_fetch_zero_cap_runs_serially() {
+        let in_flight = AtomicUsize::new(0);
+        let peak = AtomicUsize::new(0);
+        bounded_fetch(0..4, 0, |_| async {
+            let now = in_flight.fetch_add(1, Ordering::SeqCst) + 1;
+            peak.fetch_max(now, Ordering::SeqCst);
+            tokio::time::sleep(Duration::from_millis(1)).await;
+            in_flight.fetch_sub(1, Ordering::SeqCst);
+            Ok::<_, NHLApiError>(())
+        })
+        .await;
+
+        assert_eq!(peak.load(Ordering::SeqCst), 1);
+    }
+
+    #[tokio::test]
+    async fn test_bounded_fetch_cancelled_on_drop() {
+        let started = AtomicUsize::new(0);
+        let fetch = bounded_fetch(0..10, 2, |_| async {
+            started.fetch_add(1, Ordering::SeqCst);
+            tokio::time::sleep(Duration::from_secs(60)).await;
+            Ok::<_, NHLApiError>(())
+        });
+        let timed_out = tokio::time::timeout(Duration::from_millis(20), fetch).await;
+
+        assert!(timed_out.is_err());
+        assert_eq!(started.load(Ordering::SeqCst), 2);
+    }
+}
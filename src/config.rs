@@ -1,13 +1,40 @@
+use std::collections::HashMap;
+use std::sync::Arc;
 use std::time::Duration;
 
 use reqwest::Client;
 
+use crate::observer::{RequestObserver, SharedObserver};
+use crate::transport::{SharedTransport, Transport};
+
 /// `User-Agent` header sent with every library-issued request unless the caller
 /// overrides it via [`ClientConfig::with_user_agent`].
 pub const DEFAULT_USER_AGENT: &str = concat!("nhl-api/", env!("CARGO_PKG_VERSION"));
 
 const DEFAULT_TIMEOUT: Duration = Duration::from_secs(10);
 
+/// Response cache capacity when caching is enabled.
+const DEFAULT_CACHE_MAX_ENTRIES: usize = 512;
+
+/// Delay before the first retry; each further retry doubles it.
+const DEFAULT_RETRY_BACKOFF_BASE: Duration = Duration::from_millis(500);
+
+/// Language of the stats host (`api.nhle.com/stats/rest/{lang}/...`) unless
+/// [`ClientConfig::with_stats_language`] picks another.
+pub const DEFAULT_STATS_LANGUAGE: &str = "en";
+
+/// What to do when a stats-host resource is missing in the configured
+/// language (see [`ClientConfig::with_locale_fallback`]).
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub enum LocaleFallback {
+    /// Retry a `404` once under `en/`, counting it in
+    /// [`ClientStats::locale_fallbacks`](crate::ClientStats::locale_fallbacks).
+    #[default]
+    Auto,
+    /// Return the `404`.
+    Never,
+}
+
 /// Configuration for the NHL API client.
 ///
 /// Construct via [`ClientConfig::default`] and refine with the chainable
@@ -30,13 +57,86 @@ const DEFAULT_TIMEOUT: Duration = Duration::from_secs(10);
 /// transport-shaping options (`timeout`, `ssl_verify`, `follow_redirects`) and
 /// the library's default `User-Agent`/`Accept` headers are **ignored**: the
 /// injected client owns its full configuration.
+///
+/// # Retries
+///
+/// Retries are off by default. [`with_max_retries`](Self::with_max_retries)
+/// enables them for `429`, `5xx`, and connect/timeout failures (never for
+/// other `4xx` responses), with exponential backoff starting at
+/// [`with_retry_backoff_base`](Self::with_retry_backoff_base). Retry settings
+/// apply to injected clients too, since the retry loop sits above the
+/// transport.
+///
+/// # Base URL overrides
+///
+/// [`with_api_web_base`](Self::with_api_web_base),
+/// [`with_api_stats_base`](Self::with_api_stats_base) and
+/// [`with_search_base`](Self::with_search_base) point a host at a different
+/// server (a caching proxy, an egress gateway, a fixture server in tests).
+/// Overrides must be absolute `http`/`https` URLs; anything else fails
+/// [`Client::with_config`](crate::Client::with_config).
+///
+/// # Response cache
+///
+/// [`with_cache_ttl`](Self::with_cache_ttl) turns on an in-memory cache of
+/// response bodies keyed by URL and query string. Entries live for the TTL,
+/// except that finished games (boxscore, play-by-play, landing) are kept
+/// until evicted and live game data (scores, right-rail, shift charts, games
+/// in progress) is never cached.
+///
+/// # Stats host language
+///
+/// Stats-host resources (franchises, shift charts) are requested under
+/// [`with_stats_language`](Self::with_stats_language) (`en` by default).
+/// Some exist only under `en/`; with the default
+/// [`LocaleFallback::Auto`] a `404` in another language is retried once in
+/// English.
+///
+/// # Request observer
+///
+/// [`with_request_observer`](Self::with_request_observer) hooks a
+/// [`RequestObserver`] into the request path; it sees each attempt's URL,
+/// status, timing and outcome.
+///
+/// # Custom transport
+///
+/// [`with_transport`](Self::with_transport) replaces HTTP altogether with a
+/// [`Transport`] that hands back response bodies, for tests that shouldn't
+/// touch the network.
+///
+/// # WebAssembly
+///
+/// On `wasm32` targets (build with the `wasm` feature) requests go through
+/// the browser's `fetch`, which owns the transport: `timeout`, `ssl_verify`
+/// and `follow_redirects` are ignored, browsers may drop the `User-Agent`,
+/// and retries are disabled since there is no timer to back off with.
+/// A [`Poller`](crate::poll::Poller) waits with
+/// [`ManualSleeper`](crate::poll::ManualSleeper) (polls only on `poll_now`)
+/// unless given a JS-timer [`Sleeper`](crate::poll::Sleeper), and the
+/// `blocking` feature doesn't apply.
 #[derive(Debug, Clone)]
 pub struct ClientConfig {
     pub(crate) timeout: Duration,
     pub(crate) ssl_verify: bool,
     pub(crate) follow_redirects: bool,
     pub(crate) user_agent: Option<String>,
+    pub(crate) default_headers: HashMap<String, String>,
     pub(crate) client: Option<Client>,
+    pub(crate) max_retries: u32,
+    pub(crate) retry_backoff_base: Duration,
+    pub(crate) respect_retry_after: bool,
+    pub(crate) retry_deadline: Option<Duration>,
+    pub(crate) skip_malformed_elements: bool,
+    pub(crate) verbose_decode_errors: bool,
+    pub(crate) api_web_base: Option<String>,
+    pub(crate) api_stats_base: Option<String>,
+    pub(crate) search_base: Option<String>,
+    pub(crate) cache_ttl: Option<Duration>,
+    pub(crate) cache_max_entries: usize,
+    pub(crate) stats_language: String,
+    pub(crate) locale_fallback: LocaleFallback,
+    pub(crate) request_observer: Option<SharedObserver>,
+    pub(crate) transport: Option<SharedTransport>,
 }
 
 impl Default for ClientConfig {
@@ -46,7 +146,23 @@ impl Default for ClientConfig {
             ssl_verify: true,
             follow_redirects: true,
             user_agent: None,
+            default_headers: HashMap::new(),
             client: None,
+            max_retries: 0,
+            retry_backoff_base: DEFAULT_RETRY_BACKOFF_BASE,
+            respect_retry_after: true,
+            retry_deadline: None,
+            skip_malformed_elements: false,
+            verbose_decode_errors: false,
+            api_web_base: None,
+            api_stats_base: None,
+            search_base: None,
+            cache_ttl: None,
+            cache_max_entries: DEFAULT_CACHE_MAX_ENTRIES,
+            stats_language: DEFAULT_STATS_LANGUAGE.to_string(),
+            locale_fallback: LocaleFallback::default(),
+            request_observer: None,
+            transport: None,
         }
     }
 }
@@ -89,17 +205,149 @@ impl ClientConfig {
         self
     }
 
+    /// Adds a header sent with every request, e.g. a contact address for
+    /// the NHL's ops team. Call repeatedly for several headers; a later call
+    /// with the same name replaces the earlier value. These take precedence
+    /// over the library's `Accept` and `User-Agent` defaults.
+    ///
+    /// Names and values are validated when the client is built. Ignored when
+    /// a custom client is supplied via
+    /// [`with_http_client`](Self::with_http_client).
+    pub fn with_default_header(
+        mut self,
+        name: impl Into<String>,
+        value: impl Into<String>,
+    ) -> Self {
+        self.default_headers.insert(name.into(), value.into());
+        self
+    }
+
     /// Supplies a pre-built [`reqwest::Client`] to use as-is.
     ///
     /// This is the escape hatch for retry/backoff, instrumentation, or
     /// custom-transport middleware. When set, the client is used unchanged and
-    /// every other option on this config (`timeout`, `ssl_verify`,
+    /// the transport options on this config (`timeout`, `ssl_verify`,
     /// `follow_redirects`, `user_agent`, and the default `Accept`/`User-Agent`
-    /// headers) is ignored: the caller owns the client's full configuration.
+    /// headers) are ignored: the caller owns the client's full configuration.
+    /// Retry settings still apply.
     pub fn with_http_client(mut self, client: Client) -> Self {
         self.client = Some(client);
         self
     }
+
+    /// Sets how many times a failed request is retried. `0` (the default)
+    /// disables retries.
+    ///
+    /// Only `429`, `5xx`, and connect/timeout failures are retried; `400`,
+    /// `401`, `404` and other client errors fail immediately.
+    pub fn with_max_retries(mut self, max_retries: u32) -> Self {
+        self.max_retries = max_retries;
+        self
+    }
+
+    /// Sets the delay before the first retry. Each subsequent retry doubles
+    /// the previous delay.
+    pub fn with_retry_backoff_base(mut self, base: Duration) -> Self {
+        self.retry_backoff_base = base;
+        self
+    }
+
+    /// Sets whether a `Retry-After` header on a `429` response replaces the
+    /// computed backoff delay. Defaults to `true`.
+    pub fn with_respect_retry_after(mut self, respect: bool) -> Self {
+        self.respect_retry_after = respect;
+        self
+    }
+
+    /// Caps the total time spent on one call across all attempts. A retry
+    /// whose delay would overrun the deadline is not attempted.
+    pub fn with_retry_deadline(mut self, deadline: Duration) -> Self {
+        self.retry_deadline = Some(deadline);
+        self
+    }
+
+    /// Enables fail-soft decoding of list endpoints (standings, schedules,
+    /// scores, franchises, shift charts, play-by-play events).
+    ///
+    /// When on, a list element that fails to deserialize is logged at `warn`
+    /// level, counted in [`ClientStats::skipped_elements`](crate::ClientStats),
+    /// and dropped, and the rest of the response is returned. Off by default:
+    /// one bad element fails the whole call.
+    pub fn with_skip_malformed_elements(mut self, skip: bool) -> Self {
+        self.skip_malformed_elements = skip;
+        self
+    }
+
+    /// Makes [`NHLApiError::DecodeError`](crate::NHLApiError::DecodeError)
+    /// also quote a snippet of the body around the failing field. Off by
+    /// default, since response bodies can be large and noisy in logs.
+    pub fn with_verbose_decode_errors(mut self, verbose: bool) -> Self {
+        self.verbose_decode_errors = verbose;
+        self
+    }
+
+    /// Enables the response cache, keeping entries for `ttl`. Off by default.
+    pub fn with_cache_ttl(mut self, ttl: Duration) -> Self {
+        self.cache_ttl = Some(ttl);
+        self
+    }
+
+    /// Caps how many responses the cache holds (default 512); the oldest
+    /// entry is evicted first. Has no effect unless
+    /// [`with_cache_ttl`](Self::with_cache_ttl) is set.
+    pub fn with_cache_max_entries(mut self, max_entries: usize) -> Self {
+        self.cache_max_entries = max_entries;
+        self
+    }
+
+    /// Replaces the `https://api-web.nhle.com/v1/` base URL.
+    pub fn with_api_web_base(mut self, base: impl Into<String>) -> Self {
+        self.api_web_base = Some(base.into());
+        self
+    }
+
+    /// Replaces the `https://api.nhle.com/stats/rest/` base URL.
+    pub fn with_api_stats_base(mut self, base: impl Into<String>) -> Self {
+        self.api_stats_base = Some(base.into());
+        self
+    }
+
+    /// Replaces the `https://search.d3.nhle.com/api/v1/` base URL.
+    pub fn with_search_base(mut self, base: impl Into<String>) -> Self {
+        self.search_base = Some(base.into());
+        self
+    }
+
+    /// Sets the language segment of stats-host paths, e.g. `"fr"` for
+    /// French names. Defaults to [`DEFAULT_STATS_LANGUAGE`].
+    pub fn with_stats_language(mut self, language: impl Into<String>) -> Self {
+        self.stats_language = language.into();
+        self
+    }
+
+    /// Sets whether a stats-host `404` in a language other than English is
+    /// retried under `en/`. Defaults to [`LocaleFallback::Auto`].
+    pub fn with_locale_fallback(mut self, fallback: LocaleFallback) -> Self {
+        self.locale_fallback = fallback;
+        self
+    }
+
+    /// Reports every HTTP attempt to `observer`, for metrics or tracing. See
+    /// [`observer`](crate::observer) for when each hook fires. None by
+    /// default.
+    pub fn with_request_observer(mut self, observer: Arc<dyn RequestObserver>) -> Self {
+        self.request_observer = Some(SharedObserver(observer));
+        self
+    }
+
+    /// Answers requests with `transport` instead of sending them over HTTP,
+    /// e.g. canned responses in tests. See [`transport`](crate::transport).
+    /// Decoding, caching, retries and the request observer still apply; the
+    /// HTTP options (timeout, headers, custom `reqwest::Client`) don't.
+    pub fn with_transport(mut self, transport: Arc<dyn Transport>) -> Self {
+        self.transport = Some(SharedTransport(transport));
+        self
+    }
 }
 
 #[cfg(test)]
@@ -113,7 +361,69 @@ mod tests {
         assert!(config.ssl_verify);
         assert!(config.follow_redirects);
         assert!(config.user_agent.is_none());
+        assert!(config.default_headers.is_empty());
         assert!(config.client.is_none());
+        assert_eq!(config.max_retries, 0);
+        assert_eq!(config.retry_backoff_base, DEFAULT_RETRY_BACKOFF_BASE);
+        assert!(config.respect_retry_after);
+        assert!(config.retry_deadline.is_none());
+        assert!(!config.skip_malformed_elements);
+        assert!(!config.verbose_decode_errors);
+        assert!(config.api_web_base.is_none());
+        assert!(config.api_stats_base.is_none());
+        assert!(config.search_base.is_none());
+        assert!(config.cache_ttl.is_none());
+        assert_eq!(config.cache_max_entries, DEFAULT_CACHE_MAX_ENTRIES);
+        assert_eq!(config.stats_language, DEFAULT_STATS_LANGUAGE);
+        assert_eq!(config.locale_fallback, LocaleFallback::Auto);
+    }
+
+    #[test]
+    fn test_client_config_stats_language_builder_methods() {
+        let config = ClientConfig::default()
+            .with_stats_language("fr")
+            .with_locale_fallback(LocaleFallback::Never);
+        assert_eq!(config.stats_language, "fr");
+        assert_eq!(config.locale_fallback, LocaleFallback::Never);
+    }
+
+    #[test]
+    fn test_client_config_cache_builder_methods() {
+        let config = ClientConfig::default()
+            .with_cache_ttl(Duration::from_secs(300))
+            .with_cache_max_entries(16);
+        assert_eq!(config.cache_ttl, Some(Duration::from_secs(300)));
+        assert_eq!(config.cache_max_entries, 16);
+    }
+
+    #[test]
+    fn test_client_config_with_default_header() {
+        let config = ClientConfig::default()
+            .with_default_header("X-Contact", "a@example.com")
+            .with_default_header("X-Team", "ops")
+            .with_default_header("X-Contact", "b@example.com");
+        assert_eq!(config.default_headers.len(), 2);
+        assert_eq!(config.default_headers["X-Contact"], "b@example.com");
+    }
+
+    #[test]
+    fn test_client_config_base_url_builder_methods() {
+        let config = ClientConfig::default()
+            .with_api_web_base("http://localhost:1/web/")
+            .with_api_stats_base("http://localhost:1/stats/")
+            .with_search_base("http://localhost:1/search/");
+        assert_eq!(
+            config.api_web_base.as_deref(),
+            Some("http://localhost:1/web/")
+        );
+        assert_eq!(
+            config.api_stats_base.as_deref(),
+            Some("http://localhost:1/stats/")
+        );
+        assert_eq!(
+            config.search_base.as_deref(),
+            Some("http://localhost:1/search/")
+        );
     }
 
     #[test]
@@ -128,12 +438,30 @@ mod tests {
             .with_timeout(Duration::from_secs(30))
             .with_ssl_verify(false)
             .with_follow_redirects(false)
-            .with_user_agent("test-agent/9.9");
+            .with_user_agent("test-agent/9.9")
+            .with_skip_malformed_elements(true)
+            .with_verbose_decode_errors(true);
 
         assert_eq!(config.timeout, Duration::from_secs(30));
         assert!(!config.ssl_verify);
         assert!(!config.follow_redirects);
         assert_eq!(config.user_agent.as_deref(), Some("test-agent/9.9"));
+        assert!(config.skip_malformed_elements);
+        assert!(config.verbose_decode_errors);
+    }
+
+    #[test]
+    fn test_client_config_retry_builder_methods() {
+        let config = ClientConfig::default()
+            .with_max_retries(3)
+            .with_retry_backoff_base(Duration::from_millis(50))
+            .with_respect_retry_after(false)
+            .with_retry_deadline(Duration::from_secs(5));
+
+        assert_eq!(config.max_retries, 3);
+        assert_eq!(config.retry_backoff_base, Duration::from_millis(50));
+        assert!(!config.respect_retry_after);
+        assert_eq!(config.retry_deadline, Some(Duration::from_secs(5)));
     }
 
     #[test]
@@ -0,0 +1,467 @@
+//! CSV export for spreadsheets (the `csv` feature).
+//!
+//! [`Boxscore::skaters_to_csv`], [`Boxscore::goalies_to_csv`],
+//! [`Standing::slice_to_csv`] and [`ClubStats::to_csv`] write a header row
+//! and one row per player or team. Columns are listed in the `*_COLUMNS`
+//! constants below, in order; new columns are only ever appended.
+//!
+//! Localized names are written in their `default` language. Times on ice
+//! appear twice: as `MM:SS` and as whole seconds in the following
+//! `*_seconds` column. Missing values are empty cells.
+//!
+//! ```no_run
+//! # async fn example(client: &nhl_api::Client) -> Result<(), Box<dyn std::error::Error>> {
+//! let boxscore = client.boxscore(2024020001).await?;
+//! let file = std::fs::File::create("skaters.csv")?;
+//! boxscore.skaters_to_csv(file)?;
+//! # Ok(())
+//! # }
+//! ```
+
+use std::io::Write;
+
+use crate::ice_time::IceTime;
+use crate::types::{Boxscore, BoxscoreTeam, ClubStats, GoalieStats, SkaterStats, Standing};
+
+/// Columns of [`Boxscore::skaters_to_csv`].
+pub const SKATER_COLUMNS: &[&str] = &[
+    "team",
+    "player_id",
+    "sweater_number",
+    "name",
+    "position",
+    "goals",
+    "assists",
+    "points",
+    "plus_minus",
+    "pim",
+    "hits",
+    "power_play_goals",
+    "sog",
+    "faceoff_winning_pctg",
+    "toi",
+    "toi_seconds",
+    "blocked_shots",
+    "shifts",
+    "giveaways",
+    "takeaways",
+];
+
+/// Columns of [`Boxscore::goalies_to_csv`]. The `*_shots_against` columns
+/// are `saves/shots`.
+pub const GOALIE_COLUMNS: &[&str] = &[
+    "team",
+    "player_id",
+    "sweater_number",
+    "name",
+    "starter",
+    "decision",
+    "shots_against",
+    "saves",
+    "goals_against",
+    "save_pctg",
+    "even_strength_shots_against",
+    "power_play_shots_against",
+    "shorthanded_shots_against",
+    "even_strength_goals_against",
+    "power_play_goals_against",
+    "shorthanded_goals_against",
+    "pim",
+    "toi",
+    "toi_seconds",
+];
+
+/// Columns of [`Standing::slice_to_csv`].
+pub const STANDING_COLUMNS: &[&str] = &[
+    "team_abbrev",
+    "team_name",
+    "team_common_name",
+    "conference_abbrev",
+    "conference_name",
+    "division_abbrev",
+    "division_name",
+    "games_played",
+    "wins",
+    "losses",
+    "ot_losses",
+    "points",
+];
+
+/// Columns of [`ClubStats::to_csv`]: shared columns, then skater-only and
+/// goalie-only ones, which are empty on the other kind of row.
+pub const CLUB_STATS_COLUMNS: &[&str] = &[
+    "player_id",
+    "first_name",
+    "last_name",
+    "position",
+    "games_played",
+    "goals",
+    "assists",
+    "points",
+    "penalty_minutes",
+    // Skaters
+    "plus_minus",
+    "power_play_goals",
+    "shorthanded_goals",
+    "game_winning_goals",
+    "overtime_goals",
+    "shots",
+    "shooting_pctg",
+    "avg_toi",
+    "avg_toi_seconds",
+    "avg_shifts_per_game",
+    "faceoff_win_pctg",
+    // Goalies
+    "games_started",
+    "wins",
+    "losses",
+    "overtime_losses",
+    "goals_against_average",
+    "save_percentage",
+    "shots_against",
+    "saves",
+    "goals_against",
+    "shutouts",
+    "toi",
+    "toi_seconds",
+];
+
+/// `MM:SS` and whole-seconds cells for a time on ice.
+fn toi_cells(toi: IceTime) -> [String; 2] {
+    [toi.to_string(), toi.as_seconds().to_string()]
+}
+
+/// [`toi_cells`] for a count of seconds as the club stats endpoint sends
+/// them, rounded to whole seconds.
+fn seconds_cells(seconds: f64) -> [String; 2] {
+    toi_cells(IceTime::from_seconds(seconds.max(0.0).round() as u32))
+}
+
+fn optional<T: ToString>(value: Option<T>) -> String {
+    value.map(|value| value.to_string()).unwrap_or_default()
+}
+
+fn skater_row(team: &BoxscoreTeam, skater: &SkaterStats) -> Vec<String> {
+    let [toi, toi_seconds] = toi_cells(skater.toi);
+    vec![
+        team.abbrev.clone(),
+        skater.player_id.to_string(),
+        optional(skater.sweater_number),
+        skater.name.default.clone(),
+        optional(skater.position.as_ref().map(|position| position.code())),
+        skater.goals.to_string(),
+        skater.assists.to_string(),
+        skater.points.to_string(),
+        skater.plus_minus.to_string(),
+        skater.pim.to_string(),
+        skater.hits.to_string(),
+        skater.power_play_goals.to_string(),
+        skater.sog.to_string(),
+        skater.faceoff_winning_pctg.to_string(),
+        toi,
+        toi_seconds,
+        skater.blocked_shots.to_string(),
+        skater.shifts.to_string(),
+        skater.giveaways.to_string(),
+        skater.takeaways.to_string(),
+    ]
+}
+
+fn goalie_row(team: &BoxscoreTeam, goalie: &GoalieStats) -> Vec<String> {
+    let [toi, toi_seconds] = toi_cells(goalie.toi);
+    vec![
+        team.abbrev.clone(),
+        goalie.player_id.to_string(),
+        optional(goalie.sweater_number),
+        goalie.name.default.clone(),
+        optional(goalie.starter),
+        optional(goalie.decision.as_ref().map(|decision| decision.code())),
+        goalie.shots_against.to_string(),
+        goalie.saves.to_string(),
+        goalie.goals_against.to_string(),
+        optional(goalie.save_pctg),
+        goalie.even_strength_shots_against.to_string(),
+        goalie.power_play_shots_against.to_string(),
+        goalie.shorthanded_shots_against.to_string(),
+        goalie.even_strength_goals_against.to_string(),
+        goalie.power_play_goals_against.to_string(),
+        goalie.shorthanded_goals_against.to_string(),
+        optional(goalie.pim),
+        toi,
+        toi_seconds,
+    ]
+}
+
+/// Writes `columns` and then `rows` to `writer`.
+fn write_csv<W: Write>(
+    writer: W,
+    columns: &[&str],
+    rows: impl IntoIterator<Item = Vec<String>>,
+) -> Result<(), csv::Error> {
+    let mut writer = csv::Writer::from_writer(writer);
+    writer.write_record(columns)?;
+    for row in rows {
+        writer.write_record(&row)?;
+    }
+    writer.flush()?;
+    Ok(())
+}
+
+impl Boxscore {
+    /// Writes every skater as CSV ([`SKATER_COLUMNS`]): away forwards and
+    /// defense, then home.
+    pub fn skaters_to_csv<W: Write>(&self, writer: W) -> Result<(), csv::Error> {
+        let stats = &self.player_by_game_stats;
+        let rows = [
+            (&self.away_team, &stats.away_team),
+            (&self.home_team, &stats.home_team),
+        ]
+        .into_iter()
+        .flat_map(|(team, players)| {
+            players
+                .forwards
+                .iter()
+                .chain(&players.defense)
+                .map(move |skater| skater_row(team, skater))
+        });
+        write_csv(writer, SKATER_COLUMNS, rows)
+    }
+
+    /// Writes every goalie as CSV ([`GOALIE_COLUMNS`]): away, then home.
+    pub fn goalies_to_csv<W: Write>(&self, writer: W) -> Result<(), csv::Error> {
+        let stats = &self.player_by_game_stats;
+        let rows = [
+            (&self.away_team, &stats.away_team),
+            (&self.home_team, &stats.home_team),
+        ]
+        .into_iter()
+        .flat_map(|(team, players)| {
+            players
+                .goalies
+                .iter()
+                .map(move |goalie| goalie_row(team, goalie))
+        });
+        write_csv(writer, GOALIE_COLUMNS, rows)
+    }
+}
+
+impl Standing {
+    /// Writes `standings` as CSV ([`STANDING_COLUMNS`]), in the given order.
+    pub fn slice_to_csv<W: Write>(standings: &[Standing], writer: W) -> Result<(), csv::Error> {
+        let rows = standings.iter().map(|standing| {
+            vec![
+                standing.team_abbrev.default.clone(),
+                standing.team_name.default.clone(),
+                standing.team_common_name.default.clone(),
+                optional(standing.conference_abbrev.as_ref()),
+                optional(standing.conference_name.as_ref()),
+                standing.division_abbrev.clone(),
+                standing.division_name.clone(),
+                standing.games_played().to_string(),
+                standing.wins.to_string(),
+                standing.losses.to_string(),
+                standing.ot_losses.to_string(),
+                standing.points.to_string(),
+            ]
+        });
+        write_csv(writer, STANDING_COLUMNS, rows)
+    }
+}
+
+impl ClubStats {
+    /// Writes skaters, then goalies, as one CSV table
+    /// ([`CLUB_STATS_COLUMNS`]). Goalie rows have position `G`.
+    pub fn to_csv<W: Write>(&self, writer: W) -> Result<(), csv::Error> {
+        const SKATER_ONLY: usize = 11;
+        const GOALIE_ONLY: usize = 12;
+
+        let skaters = self.skaters.iter().map(|skater| {
+            let [avg_toi, avg_toi_seconds] = seconds_cells(skater.avg_time_on_ice_per_game);
+            let mut row = vec![
+                skater.player_id.to_string(),
+                skater.first_name.default.clone(),
+                skater.last_name.default.clone(),
+                optional(skater.position.as_ref().map(|position| position.code())),
+                skater.games_played.to_string(),
+                skater.goals.to_string(),
+                skater.assists.to_string(),
+                skater.points.to_string(),
+                skater.penalty_minutes.to_string(),
+                skater.plus_minus.to_string(),
+                skater.power_play_goals.to_string(),
+                skater.shorthanded_goals.to_string(),
+                skater.game_winning_goals.to_string(),
+                skater.overtime_goals.to_string(),
+                skater.shots.to_string(),
+                skater.shooting_pctg.to_string(),
+                avg_toi,
+                avg_toi_seconds,
+                skater.avg_shifts_per_game.to_string(),
+                skater.faceoff_win_pctg.to_string(),
+            ];
+            row.resize(row.len() + GOALIE_ONLY, String::new());
+            row
+        });
+        let goalies = self.goalies.iter().map(|goalie| {
+            let [toi, toi_seconds] = seconds_cells(goalie.time_on_ice as f64);
+            let mut row = vec![
+                goalie.player_id.to_string(),
+                goalie.first_name.default.clone(),
+                goalie.last_name.default.clone(),
+                "G".to_string(),
+                goalie.games_played.to_string(),
+                goalie.goals.to_string(),
+                goalie.assists.to_string(),
+                goalie.points.to_string(),
+                goalie.penalty_minutes.to_string(),
+            ];
+            row.resize(row.len() + SKATER_ONLY, String::new());
+            row.extend([
+                goalie.games_started.to_string(),
+                goalie.wins.to_string(),
+                goalie.losses.to_string(),
+                goalie.overtime_losses.to_string(),
+                goalie.goals_against_average.to_string(),
+                goalie.save_percentage.to_string(),
+                goalie.shots_against.to_string(),
+                goalie.saves.to_string(),
+                goalie.goals_against.to_string(),
+                goalie.shutouts.to_string(),
+                toi,
+                toi_seconds,
+            ]);
+            row
+        });
+        write_csv(writer, CLUB_STATS_COLUMNS, skaters.chain(goalies))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const BOXSCORE_JSON: &str = include_str!("../tests/fixtures/boxscore.json");
+
+    /// Parses `bytes` back into a header row and data rows.
+    fn parse(bytes: &[u8]) -> (Vec<String>, Vec<csv::StringRecord>) {
+        let mut reader = csv::Reader::from_reader(bytes);
+        let headers = reader.headers().unwrap().iter().map(String::from).collect();
+        let rows = reader.records().map(Result::unwrap).collect();
+        (headers, rows)
+    }
+
+    fn cell<'a>(headers: &[String], row: &'a csv::StringRecord, column: &str) -> &'a str {
+        let index = headers.iter().position(|header| header == column).unwrap();
+        &row[index]
+    }
+
+    #[test]
+    fn test_skaters_to_csv() {
+        let boxscore: Boxscore = serde_json::from_str(BOXSCORE_JSON).unwrap();
+        let mut bytes = Vec::new();
+
+        boxscore.skaters_to_csv(&mut bytes).unwrap();
+
+        let (headers, rows) = parse(&bytes);
+        assert_eq!(headers, SKATER_COLUMNS);
+        assert_eq!(rows.len(), 6);
+        assert_eq!(cell(&headers, &rows[0], "team"), "NJD");
+        assert_eq!(cell(&headers, &rows[0], "name"), "N. Hischier");
+        assert_eq!(cell(&headers, &rows[0], "position"), "C");
+        assert_eq!(cell(&headers, &rows[0], "toi"), "12:31");
+        assert_eq!(cell(&headers, &rows[0], "toi_seconds"), "751");
+        assert_eq!(cell(&headers, &rows[0], "faceoff_winning_pctg"), "0.6");
+        assert_eq!(cell(&headers, &rows[3], "team"), "BUF");
+    }
+
+    #[test]
+    fn test_goalies_to_csv() {
+        let mut boxscore: Boxscore = serde_json::from_str(BOXSCORE_JSON).unwrap();
+        boxscore.player_by_game_stats.away_team.goalies[0].sweater_number = None;
+        let mut bytes = Vec::new();
+
+        boxscore.goalies_to_csv(&mut bytes).unwrap();
+
+        let (headers, rows) = parse(&bytes);
+        assert_eq!(headers, GOALIE_COLUMNS);
+        assert_eq!(rows.len(), 2);
+        assert_eq!(cell(&headers, &rows[0], "name"), "J. Markstrom");
+        assert_eq!(cell(&headers, &rows[0], "sweater_number"), "");
+        assert_eq!(cell(&headers, &rows[0], "starter"), "true");
+        assert_eq!(cell(&headers, &rows[0], "decision"), "");
+        assert_eq!(cell(&headers, &rows[0], "save_pctg"), "0.933");
+        assert_eq!(
+            cell(&headers, &rows[0], "even_strength_shots_against"),
+            "12/13"
+        );
+        assert_eq!(cell(&headers, &rows[0], "toi_seconds"), "1785");
+    }
+
+    #[test]
+    fn test_standings_slice_to_csv() {
+        let standings: Vec<Standing> = serde_json::from_str(
+            r#"[{"conferenceAbbrev": "E", "conferenceName": "Eastern",
+                 "divisionAbbrev": "A", "divisionName": "Atlantic",
+                 "teamName": {"default": "Montréal Canadiens", "fr": "Canadiens de Montréal"},
+                 "teamCommonName": {"default": "Canadiens"},
+                 "teamAbbrev": {"default": "MTL"}, "teamLogo": "",
+                 "wins": 5, "losses": 8, "otLosses": 2, "points": 12},
+                {"divisionAbbrev": "M", "divisionName": "Metropolitan",
+                 "teamName": {"default": "New Jersey Devils"},
+                 "teamCommonName": {"default": "Devils"},
+                 "teamAbbrev": {"default": "NJD"}, "teamLogo": "",
+                 "wins": 10, "losses": 4, "otLosses": 1, "points": 21}]"#,
+        )
+        .unwrap();
+        let mut bytes = Vec::new();
+
+        Standing::slice_to_csv(&standings, &mut bytes).unwrap();
+
+        let (headers, rows) = parse(&bytes);
+        assert_eq!(headers, STANDING_COLUMNS);
+        assert_eq!(rows.len(), 2);
+        assert_eq!(cell(&headers, &rows[0], "team_name"), "Montréal Canadiens");
+        assert_eq!(cell(&headers, &rows[0], "games_played"), "15");
+        assert_eq!(cell(&headers, &rows[1], "conference_abbrev"), "");
+        assert_eq!(cell(&headers, &rows[1], "points"), "21");
+    }
+
+    #[test]
+    fn test_club_stats_to_csv() {
+        let stats: ClubStats = serde_json::from_str(
+            r#"{"season": 20242025, "gameType": 2,
+                "skaters": [{"playerId": 8475233, "headshot": "",
+                    "firstName": {"default": "David"}, "lastName": {"default": "Savard"},
+                    "positionCode": "D", "gamesPlayed": 75, "goals": 1, "assists": 14,
+                    "points": 15, "plusMinus": -8, "penaltyMinutes": 36, "powerPlayGoals": 0,
+                    "shorthandedGoals": 0, "gameWinningGoals": 0, "overtimeGoals": 0,
+                    "shots": 48, "shootingPctg": 0.020833, "avgTimeOnIcePerGame": 995.36,
+                    "avgShiftsPerGame": 19.84, "faceoffWinPctg": 0.0}],
+                "goalies": [{"playerId": 8478470, "headshot": "",
+                    "firstName": {"default": "Sam"}, "lastName": {"default": "Montembeault"},
+                    "gamesPlayed": 62, "gamesStarted": 60, "wins": 31, "losses": 24,
+                    "overtimeLosses": 7, "goalsAgainstAverage": 2.818349,
+                    "savePercentage": 0.901669, "shotsAgainst": 1678, "saves": 1513,
+                    "goalsAgainst": 166, "shutouts": 4, "goals": 0, "assists": 1, "points": 1,
+                    "penaltyMinutes": 0, "timeOnIce": 212039}]}"#,
+        )
+        .unwrap();
+        let mut bytes = Vec::new();
+
+        stats.to_csv(&mut bytes).unwrap();
+
+        let (headers, rows) = parse(&bytes);
+        assert_eq!(headers, CLUB_STATS_COLUMNS);
+        assert_eq!(rows.len(), 2);
+        assert!(rows.iter().all(|row| row.len() == CLUB_STATS_COLUMNS.len()));
+        let (skater, goalie) = (&rows[0], &rows[1]);
+        assert_eq!(cell(&headers, skater, "last_name"), "Savard");
+        assert_eq!(cell(&headers, skater, "avg_toi"), "16:35");
+        assert_eq!(cell(&headers, skater, "avg_toi_seconds"), "995");
+        assert_eq!(cell(&headers, skater, "wins"), "");
+        assert_eq!(cell(&headers, goalie, "position"), "G");
+        assert_eq!(cell(&headers, goalie, "shots"), "");
+        assert_eq!(cell(&headers, goalie, "wins"), "31");
+        assert_eq!(cell(&headers, goalie, "toi_seconds"), "212039");
+    }
+}
@@ -9,6 +9,10 @@ const MIN_SEASON_ID: i64 = 10_000_000;
 const MAX_SEASON_ID: i64 = 99_999_999;
 /// Divisor separating the start and end year halves of a season id.
 const SEASON_YEAR_DIVISOR: i64 = 10_000;
+/// First day of a typical season, in its start year (October 1).
+const SEASON_FIRST_DAY: (u32, u32) = (10, 1);
+/// Last day of a typical season, in the following year (June 30).
+const SEASON_LAST_DAY: (u32, u32) = (6, 30);
 
 /// Errors produced when constructing or parsing a [`Season`].
 #[derive(Debug, Clone, PartialEq, Eq, Error)]
@@ -61,7 +65,7 @@ impl GameDate {
     }
 
     /// Convert to a concrete date (resolves "now" to today's date, UTC).
-    fn as_date(&self) -> NaiveDate {
+    pub(crate) fn as_date(&self) -> NaiveDate {
         match self {
             Self::Now => chrono::Utc::now().date_naive(),
             Self::Date(date) => *date,
@@ -124,7 +128,9 @@ impl<'de> serde::Deserialize<'de> for GameDate {
 /// `20232024`), but single-calendar-year seasons exist — the COVID-shortened
 /// 2020-21 season played entirely in 2021, and the 2004 World Cup uses
 /// `20042004` — so the end year is recorded as parsed rather than derived.
-#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+///
+/// Seasons order by start year, then end year.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, PartialOrd, Ord)]
 pub struct Season {
     start_year: u16,
     end_year: u16,
@@ -210,6 +216,42 @@ impl Season {
         Err(invalid())
     }
 
+    /// The season before this one, in the conventional cross-year form.
+    pub fn prev(&self) -> Self {
+        Self::new(self.start_year.saturating_sub(1))
+    }
+
+    /// The season after this one, in the conventional cross-year form.
+    pub fn next(&self) -> Self {
+        Self::new(self.start_year.saturating_add(1))
+    }
+
+    /// Every season from `from` through `to`, inclusive, by start year.
+    /// Empty when `to` starts before `from`; `.rev()` steps backwards.
+    ///
+    /// Seasons without games, like the 2004-05 lockout, are still yielded.
+    pub fn range(from: Season, to: Season) -> SeasonRange {
+        SeasonRange {
+            front: from.start_year,
+            back: to.start_year,
+            done: from.start_year > to.start_year,
+        }
+    }
+
+    /// Returns true if `date` falls between October 1 of the start year and
+    /// June 30 of the following year. These are typical boundaries, not the
+    /// actual schedule: the 2019-20 playoffs ran into September 2020, and
+    /// those dates belong to no season here. `GameDate::Now` is today (UTC).
+    pub fn contains(&self, date: &GameDate) -> bool {
+        let year = self.start_year as i32;
+        let first = NaiveDate::from_ymd_opt(year, SEASON_FIRST_DAY.0, SEASON_FIRST_DAY.1);
+        let last = NaiveDate::from_ymd_opt(year + 1, SEASON_LAST_DAY.0, SEASON_LAST_DAY.1);
+        match (first, last) {
+            (Some(first), Some(last)) => (first..=last).contains(&date.as_date()),
+            _ => false,
+        }
+    }
+
     /// Get the current NHL season based on the current date.
     ///
     /// NHL seasons typically start in October, so dates before October belong
@@ -230,6 +272,57 @@ impl Season {
     }
 }
 
+/// Iterator over consecutive seasons, from [`Season::range`].
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct SeasonRange {
+    front: u16,
+    back: u16,
+    done: bool,
+}
+
+impl Iterator for SeasonRange {
+    type Item = Season;
+
+    fn next(&mut self) -> Option<Season> {
+        if self.done {
+            return None;
+        }
+        let season = Season::new(self.front);
+        if self.front == self.back {
+            self.done = true;
+        } else {
+            self.front += 1;
+        }
+        Some(season)
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        let len = if self.done {
+            0
+        } else {
+            (self.back - self.front) as usize + 1
+        };
+        (len, Some(len))
+    }
+}
+
+impl DoubleEndedIterator for SeasonRange {
+    fn next_back(&mut self) -> Option<Season> {
+        if self.done {
+            return None;
+        }
+        let season = Season::new(self.back);
+        if self.front == self.back {
+            self.done = true;
+        } else {
+            self.back -= 1;
+        }
+        Some(season)
+    }
+}
+
+impl ExactSizeIterator for SeasonRange {}
+
 impl fmt::Display for Season {
     /// Human-readable `"YYYY-YYYY"` form (e.g. `"2023-2024"`). For the API wire
     /// format (`"20232024"`) use [`Season::to_api_string`].
@@ -1031,4 +1124,115 @@ mod tests {
     fn test_game_date_serde_rejects_garbage_string() {
         assert!(serde_json::from_str::<GameDate>("\"not-a-date\"").is_err());
     }
+
+    #[test]
+    fn test_season_prev_and_next() {
+        let season = Season::new(2023);
+        assert_eq!(season.prev(), Season::new(2022));
+        assert_eq!(season.next(), Season::new(2024));
+        assert_eq!(season.next().prev(), season);
+
+        // Stepping over the lockout lands on its (gameless) season id.
+        assert_eq!(Season::new(2003).next().id(), 20042005);
+        assert_eq!(Season::new(2005).prev().id(), 20042005);
+
+        // The World Cup's single-year id steps to cross-year neighbours.
+        let world_cup = Season::from_years(2004, 2004).unwrap();
+        assert_eq!(world_cup.next(), Season::new(2005));
+        assert_eq!(world_cup.prev(), Season::new(2003));
+    }
+
+    #[test]
+    fn test_season_ordering() {
+        let mut seasons = vec![Season::new(2023), Season::new(2000), Season::new(2011)];
+        seasons.sort();
+        assert_eq!(
+            seasons,
+            [Season::new(2000), Season::new(2011), Season::new(2023)]
+        );
+        assert!(Season::new(2004) < Season::new(2005));
+        assert!(Season::from_years(2004, 2004).unwrap() < Season::new(2004));
+        assert_eq!(Season::new(2019).max(Season::new(2020)), Season::new(2020));
+    }
+
+    #[test]
+    fn test_season_range_is_inclusive() {
+        let seasons: Vec<i32> = Season::range(Season::new(2000), Season::new(2023))
+            .map(|season| season.id())
+            .collect();
+        assert_eq!(seasons.len(), 24);
+        assert_eq!(seasons.first(), Some(&20002001));
+        assert_eq!(seasons.last(), Some(&20232024));
+        assert!(seasons.contains(&20042005));
+        assert!(seasons.windows(2).all(|pair| pair[1] - pair[0] == 10001));
+
+        let range = Season::range(Season::new(2000), Season::new(2023));
+        assert_eq!(range.len(), 24);
+    }
+
+    #[test]
+    fn test_season_range_reversed_and_edges() {
+        let backwards: Vec<Season> = Season::range(Season::new(2003), Season::new(2005))
+            .rev()
+            .collect();
+        assert_eq!(
+            backwards,
+            [Season::new(2005), Season::new(2004), Season::new(2003)]
+        );
+
+        let single: Vec<Season> = Season::range(Season::new(2004), Season::new(2004)).collect();
+        assert_eq!(single, [Season::new(2004)]);
+
+        let mut empty = Season::range(Season::new(2024), Season::new(2023));
+        assert_eq!(empty.len(), 0);
+        assert_eq!(empty.next(), None);
+        assert_eq!(empty.next_back(), None);
+
+        // Both ends meet in the middle without yielding a season twice.
+        let mut range = Season::range(Season::new(2000), Season::new(2002));
+        assert_eq!(range.next(), Some(Season::new(2000)));
+        assert_eq!(range.next_back(), Some(Season::new(2002)));
+        assert_eq!(range.next(), Some(Season::new(2001)));
+        assert_eq!(range.next_back(), None);
+        assert_eq!(range.next(), None);
+    }
+
+    #[test]
+    fn test_season_try_from_api_integer() {
+        assert_eq!(Season::try_from(20232024_i32), Ok(Season::new(2023)));
+        assert_eq!(Season::try_from(20042005_i32), Ok(Season::new(2004)));
+        assert_eq!(
+            Season::try_from(2023_i32),
+            Err(SeasonError::InvalidInteger(2023))
+        );
+        assert!(Season::try_from(20232025_i32).is_err());
+    }
+
+    #[test]
+    fn test_season_contains_date() {
+        let season = Season::new(2023);
+        let date = |y, m, d| GameDate::from_ymd(y, m, d).unwrap();
+
+        assert!(season.contains(&date(2023, 10, 1)));
+        assert!(season.contains(&date(2024, 2, 29)));
+        assert!(season.contains(&date(2024, 6, 30)));
+        assert!(!season.contains(&date(2023, 9, 30)));
+        assert!(!season.contains(&date(2024, 7, 1)));
+
+        // The lockout season still owns its calendar window.
+        let lockout = Season::new(2004);
+        assert!(lockout.contains(&date(2005, 1, 15)));
+        assert!(!Season::new(2003).contains(&date(2005, 1, 15)));
+
+        // Each in-season date belongs to exactly one season in a range.
+        let owners: Vec<Season> = Season::range(Season::new(2000), Season::new(2023))
+            .filter(|season| season.contains(&date(2011, 3, 1)))
+            .collect();
+        assert_eq!(owners, [Season::new(2010)]);
+
+        assert_eq!(
+            Season::current().contains(&GameDate::Now),
+            Season::current().contains(&GameDate::today())
+        );
+    }
 }
@@ -1,5 +1,8 @@
+use chrono::NaiveDate;
 use thiserror::Error;
 
+use crate::ids::GameId;
+
 #[derive(Error, Debug)]
 pub enum NHLApiError {
     #[error("Resource not found: {message}")]
@@ -23,13 +26,90 @@ pub enum NHLApiError {
     #[error("HTTP request failed: {0}")]
     RequestError(#[from] reqwest::Error),
 
-    #[error("unmarshaling response from {url}: {source}")]
-    JsonError {
+    /// The response body could not be deserialized. `path` is the JSON
+    /// pointer to the failing field (e.g. `/awayTeam/score`; `None` when the
+    /// failure is at the root or after it, such as trailing content), and
+    /// `snippet` the body text
+    /// around the failure when `ClientConfig::with_verbose_decode_errors` is
+    /// on.
+    #[error("decoding response from {url}{}: {source}", describe_location(.path, .snippet))]
+    DecodeError {
         url: String,
+        path: Option<String>,
+        snippet: Option<String>,
         #[source]
         source: serde_json::Error,
     },
 
+    /// The response was an HTML (or other markup) page instead of JSON, as
+    /// api-web serves during maintenance, with a `200` or a `5xx` status.
+    /// Retryable. `snippet` is the start of the body.
+    #[error("expected JSON from {url}, got {}: {snippet}", .content_type.as_deref().unwrap_or("a markup body"))]
+    NonJsonResponse {
+        url: String,
+        content_type: Option<String>,
+        snippet: String,
+    },
+
+    /// A retryable failure that persisted through every configured retry.
+    /// `source` is the error from the final attempt.
+    #[error("{source} (after {attempts} attempts)")]
+    RetriesExhausted {
+        attempts: u32,
+        #[source]
+        source: Box<NHLApiError>,
+    },
+
+    /// No game has this id: its landing page is a 404. Returned by
+    /// `Client::fetch_many_validated` instead of the generic
+    /// `ResourceNotFound`, which there means the game exists but the
+    /// requested resource doesn't.
+    #[error("Game not found: {game_id}")]
+    GameNotFound { game_id: GameId },
+
+    /// No standings exist on `date`: it is outside every season's standings
+    /// window in the season manifest. `nearest_season_end` is the last day
+    /// of the season before `date` and `nearest_season_start` the first day
+    /// of the one after, `None` when there is no such season (e.g. before
+    /// 1917).
+    #[error("no standings on {date}: {}", describe_adjacent_seasons(.nearest_season_end, .nearest_season_start))]
+    OutOfSeason {
+        date: NaiveDate,
+        nearest_season_start: Option<NaiveDate>,
+        nearest_season_end: Option<NaiveDate>,
+    },
+
+    /// An argument was rejected before any request was made.
+    #[error("Invalid input: {0}")]
+    InvalidInput(String),
+
     #[error("{0}")]
     Other(String),
 }
+
+/// Renders the optional path/snippet parts of a `DecodeError` message.
+fn describe_location(path: &Option<String>, snippet: &Option<String>) -> String {
+    let mut location = String::new();
+    if let Some(path) = path {
+        location.push_str(&format!(" at `{}`", path));
+    }
+    if let Some(snippet) = snippet {
+        location.push_str(&format!(" near `{}`", snippet));
+    }
+    location
+}
+
+/// Renders the seasons around an `OutOfSeason` date.
+fn describe_adjacent_seasons(end: &Option<NaiveDate>, start: &Option<NaiveDate>) -> String {
+    match (end, start) {
+        (Some(end), Some(start)) => {
+            format!(
+                "offseason (previous season ended {}, next starts {})",
+                end, start
+            )
+        }
+        (None, Some(start)) => format!("before the first season (starts {})", start),
+        (Some(end), None) => format!("after the last season listed (ended {})", end),
+        (None, None) => "the season manifest lists no seasons".to_string(),
+    }
+}
@@ -0,0 +1,75 @@
+//! The Cargo features this build was compiled with, and the combinations
+//! that can't work.
+//!
+//! [`FEATURES`] lists every feature of the crate and [`enabled`] (also
+//! [`Client::enabled_features`](crate::Client::enabled_features)) the ones
+//! compiled in, for bug reports. `tests/feature_matrix.rs` checks that each
+//! feature builds on its own and next to the others.
+
+#[cfg(all(feature = "blocking", target_arch = "wasm32"))]
+compile_error!(
+    "the `blocking` feature needs a tokio runtime, which wasm32 doesn't have; \
+     use the async `Client` there"
+);
+
+#[cfg(all(target_arch = "wasm32", target_os = "unknown", not(feature = "wasm")))]
+compile_error!(
+    "building for wasm32-unknown-unknown needs the `wasm` feature: without it \
+     reading the clock (`GameDate::today`, cache expiry) panics in the browser"
+);
+
+/// Every Cargo feature of this crate, in `Cargo.toml` order.
+pub const FEATURES: &[&str] = &[
+    "fixtures",
+    "test-util",
+    "binary-cache",
+    "blocking",
+    "wasm",
+    "csv",
+    "lenient-enums",
+];
+
+/// The features this build was compiled with, in [`FEATURES`] order.
+pub fn enabled() -> Vec<&'static str> {
+    [
+        ("fixtures", cfg!(feature = "fixtures")),
+        ("test-util", cfg!(feature = "test-util")),
+        ("binary-cache", cfg!(feature = "binary-cache")),
+        ("blocking", cfg!(feature = "blocking")),
+        ("wasm", cfg!(feature = "wasm")),
+        ("csv", cfg!(feature = "csv")),
+        ("lenient-enums", cfg!(feature = "lenient-enums")),
+    ]
+    .into_iter()
+    .filter_map(|(feature, on)| on.then_some(feature))
+    .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_features_match_cargo_toml() {
+        let manifest = include_str!("../Cargo.toml");
+        let section = manifest.split("[features]").nth(1).unwrap();
+        let section = section.split("\n[").next().unwrap();
+        let declared: Vec<&str> = section
+            .lines()
+            .filter(|line| !line.starts_with('#'))
+            .filter_map(|line| line.split_once(" = "))
+            .map(|(feature, _)| feature.trim())
+            .collect();
+
+        assert_eq!(declared, FEATURES);
+    }
+
+    #[test]
+    fn test_enabled_reports_compiled_features() {
+        let enabled = enabled();
+
+        assert!(enabled.iter().all(|feature| FEATURES.contains(feature)));
+        assert_eq!(enabled.contains(&"csv"), cfg!(feature = "csv"));
+        assert_eq!(enabled.contains(&"blocking"), cfg!(feature = "blocking"));
+    }
+}
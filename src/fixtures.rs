@@ -10,12 +10,36 @@
 //! constructor seeds those fields to a valid variant; every other field gets
 //! the simplest value that still round-trips (empty string/vec, `0`,
 //! `false`).
+//!
+//! [`StaticTransport`] goes one level down: it serves raw JSON bodies to a
+//! real [`Client`](crate::Client), so client methods can be tested offline.
+//!
+//! ```
+//! use std::sync::Arc;
+//! use nhl_api::fixtures::StaticTransport;
+//! use nhl_api::{Client, GameId};
+//!
+//! # async fn example() -> Result<(), nhl_api::NHLApiError> {
+//! let body = serde_json::to_string(&nhl_api::fixtures::boxscore()).unwrap();
+//! let transport = StaticTransport::new().with_response("gamecenter/0/boxscore", body);
+//! let client = Client::with_transport(Arc::new(transport))?;
+//! assert_eq!(client.boxscore(GameId::new(0)).await?.id, GameId::new(0));
+//! # Ok(())
+//! # }
+//! ```
+
+use std::collections::HashMap;
+
+use futures_util::future::{self, BoxFuture, FutureExt};
 
 use crate::date::Season;
+use crate::error::NHLApiError;
+use crate::ice_time::IceTime;
 use crate::ids::{GameId, TeamId};
+use crate::transport::{Endpoint, Transport};
 use crate::types::{
     Boxscore, BoxscoreTeam, GameClock, GameScheduleState, GameState, GameStory, GameType,
-    LocalizedString, PeriodDescriptor, PeriodType, PlayByPlay, PlayerByGameStats,
+    LocalizedString, Officials, PeriodDescriptor, PeriodType, PlayByPlay, PlayerByGameStats,
     SeasonSeriesMatchup, SeriesGameInfo, SeriesWins, ShiftChart, StoryTeam, TeamGameInfo,
     TeamPlayerStats,
 };
@@ -38,7 +62,7 @@ fn fixture_boxscore_team() -> BoxscoreTeam {
         common_name: LocalizedString::default(),
         abbrev: String::new(),
         score: 0,
-        sog: 0,
+        sog: None,
         logo: String::new(),
         dark_logo: String::new(),
         place_name: LocalizedString::default(),
@@ -48,7 +72,7 @@ fn fixture_boxscore_team() -> BoxscoreTeam {
 
 fn fixture_game_clock() -> GameClock {
     GameClock {
-        time_remaining: String::new(),
+        time_remaining: IceTime::ZERO,
         seconds_remaining: 0,
         running: false,
         in_intermission: false,
@@ -100,6 +124,9 @@ pub fn boxscore() -> Boxscore {
             away_team: fixture_team_player_stats(),
             home_team: fixture_team_player_stats(),
         },
+        game_info: None,
+        summary: None,
+        situation: None,
     }
 }
 
@@ -182,8 +209,7 @@ pub fn season_series_matchup() -> SeasonSeriesMatchup {
             home_team_wins: 0,
         },
         game_info: SeriesGameInfo {
-            referees: Vec::new(),
-            linesmen: Vec::new(),
+            officials: Officials::default(),
             away_team: TeamGameInfo {
                 head_coach: LocalizedString::default(),
                 scratches: Vec::new(),
@@ -193,6 +219,60 @@ pub fn season_series_matchup() -> SeasonSeriesMatchup {
                 scratches: Vec::new(),
             },
         },
+        team_game_stats: Vec::new(),
+        shots_by_period: Vec::new(),
+    }
+}
+
+/// A [`Transport`] serving canned JSON bodies keyed by resource path, e.g.
+/// `"gamecenter/2023020001/boxscore"` (stats-host paths include the
+/// language: `"en/franchise"`). The host and query parameters are ignored;
+/// a resource without a body fails with
+/// [`NHLApiError::ResourceNotFound`].
+#[derive(Debug, Clone, Default)]
+pub struct StaticTransport {
+    responses: HashMap<String, String>,
+}
+
+impl StaticTransport {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Serves `body` for `resource`, replacing any earlier body.
+    pub fn with_response(mut self, resource: impl Into<String>, body: impl Into<String>) -> Self {
+        self.insert(resource, body);
+        self
+    }
+
+    /// Serves `body` for `resource`, replacing any earlier body.
+    pub fn insert(&mut self, resource: impl Into<String>, body: impl Into<String>) {
+        self.responses.insert(resource.into(), body.into());
+    }
+}
+
+impl From<HashMap<String, String>> for StaticTransport {
+    fn from(responses: HashMap<String, String>) -> Self {
+        Self { responses }
+    }
+}
+
+impl Transport for StaticTransport {
+    fn get<'a>(
+        &'a self,
+        _endpoint: &'a Endpoint,
+        resource: &'a str,
+        _params: Option<&'a HashMap<String, String>>,
+    ) -> BoxFuture<'a, Result<String, NHLApiError>> {
+        let response =
+            self.responses
+                .get(resource)
+                .cloned()
+                .ok_or_else(|| NHLApiError::ResourceNotFound {
+                    message: format!("No fixture for {}", resource),
+                    status_code: 404,
+                });
+        future::ready(response).boxed()
     }
 }
 
@@ -247,4 +327,21 @@ mod tests {
             serde_json::from_str(&json).expect("fixture JSON must deserialize");
         assert_eq!(fixture, round_tripped);
     }
+
+    #[tokio::test]
+    async fn test_static_transport_serves_client_boxscore() {
+        let body = include_str!("../tests/fixtures/boxscore.json");
+        let game_id = serde_json::from_str::<Boxscore>(body).unwrap().id;
+        let transport =
+            StaticTransport::new().with_response(format!("gamecenter/{}/boxscore", game_id), body);
+        let client = crate::Client::with_transport(std::sync::Arc::new(transport)).unwrap();
+
+        let boxscore = client.boxscore(game_id).await.unwrap();
+
+        assert_eq!(boxscore.id, game_id);
+        assert!(matches!(
+            client.boxscore(GameId::new(2023020001)).await,
+            Err(NHLApiError::ResourceNotFound { .. })
+        ));
+    }
 }
@@ -0,0 +1,253 @@
+//! Matching teams to their franchises.
+//!
+//! The franchise endpoint has no team abbreviations, only each franchise's
+//! current full, place and common names, so a [`Team`] is matched by name.
+//! [`find_franchise`] tries, in order:
+//!
+//! 1. The full names, after [`normalize_team_name`]: accents folded
+//!    (`"Montréal"` = `"Montreal"`), periods and apostrophes dropped
+//!    (`"St. Louis"` = `"St Louis"`), `"Saint"` read as `"St"`, case and
+//!    spacing ignored.
+//! 2. Every name the team's abbreviation has gone by in
+//!    [`TEAM_BRANDINGS`], so `"Utah Mammoth"` finds a franchise still listed
+//!    as `"Utah Hockey Club"` and vice versa.
+//! 3. The common name (`"Canadiens"`), but only if exactly one franchise has
+//!    it.
+//!
+//! Relocated clubs keep their franchise under the new city's name (the
+//! Atlanta Thrashers are the Winnipeg Jets' franchise). [`relocated_to`] maps
+//! the old abbreviations to the current ones.
+
+use crate::team_branding::TEAM_BRANDINGS;
+use crate::types::{Franchise, Team};
+
+/// Abbreviations of relocated clubs, with the current team's abbreviation.
+/// Franchises that have since gone inactive (e.g. the Arizona Coyotes) are
+/// left out: there's no current team to map to.
+pub const RELOCATIONS: &[(&str, &str)] = &[
+    ("AFM", "CGY"), // Atlanta Flames, 1980
+    ("KCS", "NJD"), // Kansas City Scouts, 1976, via Colorado
+    ("CLR", "NJD"), // Colorado Rockies, 1982
+    ("MNS", "DAL"), // Minnesota North Stars, 1993
+    ("QUE", "COL"), // Quebec Nordiques, 1995
+    ("HFD", "CAR"), // Hartford Whalers, 1997
+    ("ATL", "WPG"), // Atlanta Thrashers, 2011
+];
+
+/// The current abbreviation of a relocated club, `None` for any other
+/// abbreviation. ASCII case-insensitive.
+pub fn relocated_to(abbrev: &str) -> Option<&'static str> {
+    RELOCATIONS
+        .iter()
+        .find(|(old, _)| old.eq_ignore_ascii_case(abbrev))
+        .map(|(_, current)| *current)
+}
+
+/// `name` lowercased, with accents folded, periods and apostrophes removed,
+/// hyphens read as spaces, `"saint"` shortened to `"st"` and whitespace
+/// collapsed.
+pub fn normalize_team_name(name: &str) -> String {
+    let folded: String = name
+        .chars()
+        .flat_map(char::to_lowercase)
+        .filter(|c| !matches!(c, '.' | '\'' | '’'))
+        .map(|c| match c {
+            'à' | 'á' | 'â' | 'ã' | 'ä' | 'å' => 'a',
+            'ç' => 'c',
+            'è' | 'é' | 'ê' | 'ë' => 'e',
+            'ì' | 'í' | 'î' | 'ï' => 'i',
+            'ñ' => 'n',
+            'ò' | 'ó' | 'ô' | 'õ' | 'ö' => 'o',
+            'ù' | 'ú' | 'û' | 'ü' => 'u',
+            'ý' | 'ÿ' => 'y',
+            '-' => ' ',
+            c => c,
+        })
+        .collect();
+    folded
+        .split_whitespace()
+        .map(|word| if word == "saint" { "st" } else { word })
+        .collect::<Vec<_>>()
+        .join(" ")
+}
+
+/// The franchise `team` belongs to, matched by name as described in the
+/// module docs. `None` when no rule finds exactly one franchise.
+pub fn find_franchise<'a>(team: &Team, franchises: &'a [Franchise]) -> Option<&'a Franchise> {
+    let name = normalize_team_name(&team.name);
+    if let Some(franchise) = franchises
+        .iter()
+        .find(|franchise| normalize_team_name(&franchise.full_name) == name)
+    {
+        return Some(franchise);
+    }
+
+    let branded_names: Vec<String> = TEAM_BRANDINGS
+        .iter()
+        .filter(|branding| branding.abbrev.eq_ignore_ascii_case(&team.abbr))
+        .flat_map(|branding| std::iter::once(&branding.canonical_name).chain(branding.aliases))
+        .map(|name| normalize_team_name(name))
+        .collect();
+    if let Some(franchise) = franchises
+        .iter()
+        .find(|franchise| branded_names.contains(&normalize_team_name(&franchise.full_name)))
+    {
+        return Some(franchise);
+    }
+
+    let common_name = normalize_team_name(&team.common_name);
+    let mut by_common_name = franchises
+        .iter()
+        .filter(|franchise| normalize_team_name(&franchise.team_common_name) == common_name);
+    match (by_common_name.next(), by_common_name.next()) {
+        (Some(franchise), None) if !common_name.is_empty() => Some(franchise),
+        _ => None,
+    }
+}
+
+/// Sets each team's `franchise_id` with [`find_franchise`]. Teams without a
+/// match are left as they are.
+pub fn enrich_teams_with_franchise_ids(teams: &mut [Team], franchises: &[Franchise]) {
+    for team in teams {
+        if let Some(franchise) = find_franchise(team, franchises) {
+            team.franchise_id = Some(franchise.id as i64);
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::types::{Conference, Division};
+    use crate::LocalizedString;
+
+    fn team(name: &str, common_name: &str, abbr: &str) -> Team {
+        Team {
+            name: name.to_string(),
+            common_name: common_name.to_string(),
+            place_name: LocalizedString::default(),
+            abbr: abbr.to_string(),
+            logo: String::new(),
+            conference: Conference {
+                abbr: String::new(),
+                name: String::new(),
+            },
+            division: Division {
+                abbr: String::new(),
+                name: String::new(),
+            },
+            franchise_id: None,
+        }
+    }
+
+    fn franchise(id: i32, full_name: &str, common_name: &str, place_name: &str) -> Franchise {
+        Franchise {
+            id,
+            full_name: full_name.to_string(),
+            team_common_name: common_name.to_string(),
+            team_place_name: place_name.to_string(),
+        }
+    }
+
+    fn franchises() -> Vec<Franchise> {
+        vec![
+            franchise(1, "Montréal Canadiens", "Canadiens", "Montréal"),
+            franchise(6, "Boston Bruins", "Bruins", "Boston"),
+            franchise(18, "St. Louis Blues", "Blues", "St. Louis"),
+            franchise(28, "Arizona Coyotes", "Coyotes", "Arizona"),
+            franchise(35, "Winnipeg Jets", "Jets", "Winnipeg"),
+            franchise(40, "Utah Hockey Club", "Utah Hockey Club", "Utah"),
+            franchise(98, "Montreal Wanderers", "Wanderers", "Montreal"),
+            franchise(99, "Toronto Blueshirts", "Blueshirts", "Toronto"),
+            franchise(100, "Toronto Arenas", "Arenas", "Toronto"),
+        ]
+    }
+
+    fn franchise_id(team: &Team) -> Option<i32> {
+        find_franchise(team, &franchises()).map(|franchise| franchise.id)
+    }
+
+    #[test]
+    fn test_normalize_team_name() {
+        assert_eq!(
+            normalize_team_name("Montréal Canadiens"),
+            "montreal canadiens"
+        );
+        assert_eq!(normalize_team_name(" St. Louis  Blues"), "st louis blues");
+        assert_eq!(normalize_team_name("Saint-Louis Blues"), "st louis blues");
+        assert_eq!(normalize_team_name("Québec Nordiques"), "quebec nordiques");
+    }
+
+    #[test]
+    fn test_find_franchise_folds_accents_and_punctuation() {
+        assert_eq!(
+            franchise_id(&team("Montreal Canadiens", "Canadiens", "MTL")),
+            Some(1)
+        );
+        assert_eq!(
+            franchise_id(&team("Montréal Canadiens", "Canadiens", "MTL")),
+            Some(1)
+        );
+        assert_eq!(
+            franchise_id(&team("St Louis Blues", "Blues", "STL")),
+            Some(18)
+        );
+        assert_eq!(
+            franchise_id(&team("Saint Louis Blues", "Blues", "STL")),
+            Some(18)
+        );
+    }
+
+    #[test]
+    fn test_find_franchise_utah_either_name() {
+        assert_eq!(
+            franchise_id(&team("Utah Mammoth", "Mammoth", "UTA")),
+            Some(40)
+        );
+        assert_eq!(
+            franchise_id(&team("Utah Hockey Club", "Utah Hockey Club", "UTA")),
+            Some(40)
+        );
+
+        let renamed = vec![franchise(40, "Utah Mammoth", "Mammoth", "Utah")];
+        let old_name = team("Utah Hockey Club", "Utah Hockey Club", "UTA");
+        assert_eq!(find_franchise(&old_name, &renamed).map(|f| f.id), Some(40));
+    }
+
+    #[test]
+    fn test_find_franchise_common_name_only_when_unique() {
+        // A city-less name still finds the one franchise called "Bruins".
+        assert_eq!(franchise_id(&team("Bruins", "Bruins", "BOS")), Some(6));
+        assert_eq!(franchise_id(&team("Seattle Kraken", "Kraken", "SEA")), None);
+        let ambiguous = vec![
+            franchise(1, "Montréal Canadiens", "Canadiens", "Montréal"),
+            franchise(2, "Montreal Canadiens (1909)", "Canadiens", "Montreal"),
+        ];
+        assert_eq!(
+            find_franchise(&team("Canadiens", "Canadiens", "MTL"), &ambiguous),
+            None
+        );
+    }
+
+    #[test]
+    fn test_relocated_to() {
+        assert_eq!(relocated_to("ATL"), Some("WPG"));
+        assert_eq!(relocated_to("que"), Some("COL"));
+        assert_eq!(relocated_to("WPG"), None);
+        assert_eq!(relocated_to("ARI"), None);
+    }
+
+    #[test]
+    fn test_enrich_teams_with_franchise_ids() {
+        let mut teams = vec![
+            team("Winnipeg Jets", "Jets", "WPG"),
+            team("Seattle Kraken", "Kraken", "SEA"),
+        ];
+        teams[1].franchise_id = Some(39);
+
+        enrich_teams_with_franchise_ids(&mut teams, &franchises());
+
+        assert_eq!(teams[0].franchise_id, Some(35));
+        assert_eq!(teams[1].franchise_id, Some(39));
+    }
+}
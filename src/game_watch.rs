@@ -0,0 +1,420 @@
+//! Live game updates from successive play-by-play snapshots.
+
+use futures_util::stream::{Stream, StreamExt};
+use std::collections::{HashSet, VecDeque};
+use std::pin::Pin;
+use std::sync::Arc;
+use std::task::{Context, Poll};
+use std::time::Duration;
+
+use crate::client::Client;
+use crate::error::NHLApiError;
+use crate::ids::GameId;
+use crate::poll::{PollHandle, Poller, Sleeper};
+use crate::types::{GameClock, GameOutcome, GameState, PlayByPlay, PlayEvent};
+
+/// The shortest wait between polls of [`Client::watch_game`] while the game
+/// is in an intermission.
+pub const INTERMISSION_POLL_INTERVAL: Duration = Duration::from_secs(60);
+
+/// One change between play-by-play snapshots, from [`Client::watch_game`].
+#[derive(Debug, Clone, PartialEq)]
+pub enum GameUpdate {
+    /// Plays not seen in an earlier snapshot, in `sortOrder` order.
+    NewPlays(Vec<PlayEvent>),
+    /// The clock moved, stopped or started, or an intermission began or
+    /// ended.
+    ClockUpdate(GameClock),
+    /// The score changed.
+    ScoreChange { home: i32, away: i32 },
+    /// The game state changed, e.g. `FUT` to `LIVE`.
+    GameStateChange(GameState),
+    /// The game is final; always the last update. `last_period_type` is
+    /// `None` when the payload had no outcome yet.
+    Ended(GameOutcome),
+}
+
+/// What the previous snapshots reported, for diffing the next one.
+#[derive(Debug, Default)]
+struct GameTracker {
+    state: Option<GameState>,
+    score: Option<(i32, i32)>,
+    clock: Option<GameClock>,
+    /// `eventId`s already reported, so a play shows up once even when the
+    /// feed inserts it behind later ones.
+    seen_plays: HashSet<i64>,
+    /// Highest `sortOrder` reported so far.
+    high_water_mark: Option<i32>,
+}
+
+impl GameTracker {
+    /// The updates `snapshot` brings, in order: state, plays, score, clock,
+    /// then `Ended` once the game is final. The first snapshot reports
+    /// everything.
+    fn update(&mut self, snapshot: &PlayByPlay) -> Vec<GameUpdate> {
+        let mut updates = Vec::new();
+
+        if self.state.as_ref() != Some(&snapshot.game_state) {
+            self.state = Some(snapshot.game_state.clone());
+            updates.push(GameUpdate::GameStateChange(snapshot.game_state.clone()));
+        }
+
+        let mut new_plays: Vec<PlayEvent> = snapshot
+            .plays
+            .iter()
+            .filter(|play| self.seen_plays.insert(play.event_id))
+            .cloned()
+            .collect();
+        if !new_plays.is_empty() {
+            new_plays.sort_by_key(|play| play.sort_order);
+            let last = new_plays.last().map(|play| play.sort_order);
+            self.high_water_mark = self.high_water_mark.max(last);
+            updates.push(GameUpdate::NewPlays(new_plays));
+        }
+
+        let score = (snapshot.home_team.score, snapshot.away_team.score);
+        if self.score != Some(score) {
+            self.score = Some(score);
+            updates.push(GameUpdate::ScoreChange {
+                home: score.0,
+                away: score.1,
+            });
+        }
+
+        if self.clock.as_ref() != Some(&snapshot.clock) {
+            self.clock = Some(snapshot.clock.clone());
+            updates.push(GameUpdate::ClockUpdate(snapshot.clock.clone()));
+        }
+
+        if snapshot.game_state.is_final() {
+            let outcome = snapshot.game_outcome.clone().unwrap_or(GameOutcome {
+                last_period_type: None,
+            });
+            updates.push(GameUpdate::Ended(outcome));
+        }
+
+        updates
+    }
+}
+
+/// Stream of [`GameUpdate`]s for one game. From [`Client::watch_game`].
+///
+/// Fetch errors are yielded and polling continues. The stream ends after
+/// [`GameUpdate::Ended`].
+#[derive(Debug)]
+pub struct GameWatch {
+    poller: Poller<PlayByPlay>,
+    tracker: GameTracker,
+    pending: VecDeque<GameUpdate>,
+    ended: bool,
+}
+
+impl GameWatch {
+    /// See [`Poller::with_sleeper`].
+    pub fn with_sleeper(mut self, sleeper: Arc<dyn Sleeper>) -> Self {
+        self.poller = self.poller.with_sleeper(sleeper);
+        self
+    }
+
+    /// See [`Poller::handle`].
+    pub fn handle(&self) -> PollHandle {
+        self.poller.handle()
+    }
+
+    /// The high-water mark: the highest `sortOrder` reported so far.
+    pub fn last_sort_order(&self) -> Option<i32> {
+        self.tracker.high_water_mark
+    }
+}
+
+impl Stream for GameWatch {
+    type Item = Result<GameUpdate, NHLApiError>;
+
+    fn poll_next(mut self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Option<Self::Item>> {
+        let this = &mut *self;
+        loop {
+            if let Some(update) = this.pending.pop_front() {
+                return Poll::Ready(Some(Ok(update)));
+            }
+            if this.ended {
+                return Poll::Ready(None);
+            }
+            match this.poller.poll_next_unpin(cx) {
+                Poll::Ready(Some(Ok(snapshot))) => {
+                    this.pending.extend(this.tracker.update(&snapshot));
+                    this.ended = snapshot.game_state.is_final();
+                }
+                Poll::Ready(Some(Err(e))) => return Poll::Ready(Some(Err(e))),
+                Poll::Ready(None) => return Poll::Ready(None),
+                Poll::Pending => return Poll::Pending,
+            }
+        }
+    }
+}
+
+impl Client {
+    /// Follow a game live: play-by-play is fetched every `poll_interval`
+    /// (at least [`INTERMISSION_POLL_INTERVAL`] during intermissions) and
+    /// each snapshot is diffed against the last into [`GameUpdate`]s.
+    ///
+    /// The first snapshot reports the current state, score, clock and every
+    /// play so far. Plays are tracked by `eventId`, so none is reported
+    /// twice. The stream ends once the game is final.
+    ///
+    /// ```no_run
+    /// # async fn example(client: &nhl_api::Client) {
+    /// use futures_util::StreamExt;
+    /// use nhl_api::GameUpdate;
+    /// use std::time::Duration;
+    ///
+    /// let mut updates = client.watch_game(2024020444, Duration::from_secs(10));
+    /// while let Some(update) = updates.next().await {
+    ///     match update {
+    ///         Ok(GameUpdate::ScoreChange { home, away }) => println!("{}-{}", away, home),
+    ///         Ok(GameUpdate::Ended(_)) => println!("final"),
+    ///         Ok(_) => {}
+    ///         Err(e) => eprintln!("poll failed: {}", e),
+    ///     }
+    /// }
+    /// # }
+    /// ```
+    pub fn watch_game(&self, game_id: impl Into<GameId>, poll_interval: Duration) -> GameWatch {
+        let poller = self
+            .watch_play_by_play(game_id, poll_interval)
+            .with_next_delay(move |result| match result {
+                Ok(snapshot) if snapshot.is_intermission() => {
+                    poll_interval.max(INTERMISSION_POLL_INTERVAL)
+                }
+                _ => poll_interval,
+            });
+        GameWatch {
+            poller,
+            tracker: GameTracker::default(),
+            pending: VecDeque::new(),
+            ended: false,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::config::ClientConfig;
+    use crate::test_util::{PollController, ResponseSequence};
+    use crate::types::PeriodType;
+    use serde_json::{json, Value};
+
+    const GAME_ID: i64 = 2024020444;
+
+    /// The play-by-play fixture in `state` with its first `plays` plays, a
+    /// `home`-`away` score and `seconds` left on the clock.
+    fn snapshot(state: &str, plays: usize, home: i32, away: i32, seconds: i32) -> Value {
+        let mut value: Value =
+            serde_json::from_str(include_str!("../tests/fixtures/play_by_play.json")).unwrap();
+        value["gameState"] = json!(state);
+        value["plays"].as_array_mut().unwrap().truncate(plays);
+        value["homeTeam"]["score"] = json!(home);
+        value["awayTeam"]["score"] = json!(away);
+        value["clock"]["secondsRemaining"] = json!(seconds);
+        value["clock"]["timeRemaining"] = json!(format!("{:02}:{:02}", seconds / 60, seconds % 60));
+        value["clock"]["running"] = json!(state == "LIVE");
+        value
+    }
+
+    fn decode(value: &Value) -> PlayByPlay {
+        serde_json::from_value(value.clone()).unwrap()
+    }
+
+    fn new_play_ids(updates: &[GameUpdate]) -> Vec<i64> {
+        updates
+            .iter()
+            .filter_map(|update| match update {
+                GameUpdate::NewPlays(plays) => Some(plays.iter().map(|play| play.event_id)),
+                _ => None,
+            })
+            .flatten()
+            .collect()
+    }
+
+    #[test]
+    fn test_tracker_first_snapshot_reports_everything() {
+        let mut tracker = GameTracker::default();
+
+        let updates = tracker.update(&decode(&snapshot("LIVE", 2, 0, 0, 1100)));
+
+        assert_eq!(updates[0], GameUpdate::GameStateChange(GameState::Live));
+        assert_eq!(new_play_ids(&updates), vec![151, 103]);
+        assert_eq!(updates[2], GameUpdate::ScoreChange { home: 0, away: 0 });
+        assert!(
+            matches!(updates[3], GameUpdate::ClockUpdate(ref clock) if clock.seconds_remaining == 1100)
+        );
+        assert_eq!(updates.len(), 4);
+    }
+
+    #[test]
+    fn test_tracker_reports_only_changes() {
+        let mut tracker = GameTracker::default();
+        tracker.update(&decode(&snapshot("LIVE", 2, 0, 0, 1100)));
+
+        let unchanged = tracker.update(&decode(&snapshot("LIVE", 2, 0, 0, 1100)));
+        let goal = tracker.update(&decode(&snapshot("LIVE", 4, 1, 0, 900)));
+
+        assert!(unchanged.is_empty());
+        assert_eq!(new_play_ids(&goal), vec![135, 121]);
+        assert!(goal.contains(&GameUpdate::ScoreChange { home: 1, away: 0 }));
+        assert!(!goal
+            .iter()
+            .any(|update| matches!(update, GameUpdate::GameStateChange(_))));
+        assert_eq!(tracker.high_water_mark, Some(70));
+    }
+
+    #[test]
+    fn test_tracker_reports_late_inserted_play_once() {
+        let mut tracker = GameTracker::default();
+        let mut value = snapshot("LIVE", 3, 0, 0, 1100);
+        let late = value["plays"].as_array_mut().unwrap().remove(1);
+        tracker.update(&decode(&value));
+
+        value["plays"].as_array_mut().unwrap().insert(1, late);
+        let updates = tracker.update(&decode(&value));
+        let again = tracker.update(&decode(&value));
+
+        assert_eq!(new_play_ids(&updates), vec![103]);
+        assert!(again.is_empty());
+    }
+
+    #[test]
+    fn test_tracker_ends_with_outcome() {
+        let mut tracker = GameTracker::default();
+        let mut value = snapshot("OFF", 6, 1, 2, 0);
+        value["gameOutcome"] = json!({"lastPeriodType": "REG"});
+
+        let updates = tracker.update(&decode(&value));
+
+        assert_eq!(
+            updates.last(),
+            Some(&GameUpdate::Ended(GameOutcome {
+                last_period_type: Some(PeriodType::Regulation),
+            }))
+        );
+    }
+
+    #[tokio::test]
+    async fn test_watch_game_follows_snapshots_until_final() {
+        let mut server = mockito::Server::new_async().await;
+        let bodies = ResponseSequence::new([
+            snapshot("FUT", 0, 0, 0, 1200).to_string(),
+            snapshot("LIVE", 2, 0, 0, 1100).to_string(),
+            snapshot("LIVE", 2, 0, 0, 1100).to_string(),
+            snapshot("LIVE", 6, 1, 2, 400).to_string(),
+            snapshot("OFF", 6, 1, 2, 0).to_string(),
+        ]);
+        let responses = bodies.clone();
+        server
+            .mock("GET", "/web/gamecenter/2024020444/play-by-play")
+            .with_status(200)
+            .with_body_from_request(move |_| responses.next_body().into_bytes())
+            .expect(5)
+            .create_async()
+            .await;
+        let config = ClientConfig::default().with_api_web_base(format!("{}/web/", server.url()));
+        let client = Client::with_config(config).unwrap();
+        let controller = PollController::new();
+        controller.advance();
+        controller.advance();
+        controller.advance();
+        controller.advance();
+
+        let updates: Vec<GameUpdate> = client
+            .watch_game(GAME_ID, Duration::from_secs(5))
+            .with_sleeper(controller.sleeper())
+            .map(Result::unwrap)
+            .collect()
+            .await;
+
+        assert_eq!(bodies.served(), 5);
+        assert_eq!(new_play_ids(&updates), vec![151, 103, 135, 121, 188, 274]);
+        let states: Vec<GameState> = updates
+            .iter()
+            .filter_map(|update| match update {
+                GameUpdate::GameStateChange(state) => Some(state.clone()),
+                _ => None,
+            })
+            .collect();
+        assert_eq!(
+            states,
+            vec![GameState::Future, GameState::Live, GameState::Off]
+        );
+        assert!(updates.contains(&GameUpdate::ScoreChange { home: 1, away: 2 }));
+        assert!(matches!(updates.last(), Some(GameUpdate::Ended(_))));
+    }
+
+    #[tokio::test]
+    async fn test_watch_game_backs_off_during_intermission() {
+        let mut server = mockito::Server::new_async().await;
+        let mut intermission = snapshot("LIVE", 2, 0, 0, 900);
+        intermission["clock"]["inIntermission"] = json!(true);
+        let bodies = ResponseSequence::new([
+            snapshot("LIVE", 2, 0, 0, 1100).to_string(),
+            intermission.to_string(),
+            snapshot("OFF", 2, 0, 0, 0).to_string(),
+        ]);
+        let responses = bodies.clone();
+        server
+            .mock("GET", "/web/gamecenter/2024020444/play-by-play")
+            .with_status(200)
+            .with_body_from_request(move |_| responses.next_body().into_bytes())
+            .create_async()
+            .await;
+        let config = ClientConfig::default().with_api_web_base(format!("{}/web/", server.url()));
+        let client = Client::with_config(config).unwrap();
+        let controller = PollController::new();
+        controller.advance();
+        controller.advance();
+
+        let count = client
+            .watch_game(GAME_ID, Duration::from_secs(5))
+            .with_sleeper(controller.sleeper())
+            .count()
+            .await;
+
+        assert!(count > 0);
+        assert_eq!(
+            controller.requested_sleeps(),
+            vec![Duration::from_secs(5), INTERMISSION_POLL_INTERVAL]
+        );
+    }
+
+    #[tokio::test]
+    async fn test_watch_game_yields_errors_and_keeps_polling() {
+        let mut server = mockito::Server::new_async().await;
+        let failing = server
+            .mock("GET", "/web/gamecenter/2024020444/play-by-play")
+            .with_status(500)
+            .expect(1)
+            .create_async()
+            .await;
+        let config = ClientConfig::default().with_api_web_base(format!("{}/web/", server.url()));
+        let client = Client::with_config(config).unwrap();
+        let controller = PollController::new();
+        let mut updates = client
+            .watch_game(GAME_ID, Duration::from_secs(5))
+            .with_sleeper(controller.sleeper());
+
+        assert!(updates.next().await.unwrap().is_err());
+        failing.assert_async().await;
+        failing.remove_async().await;
+        server
+            .mock("GET", "/web/gamecenter/2024020444/play-by-play")
+            .with_status(200)
+            .with_body(snapshot("OFF", 0, 0, 0, 0).to_string())
+            .create_async()
+            .await;
+        controller.advance();
+
+        assert_eq!(
+            updates.next().await.unwrap().unwrap(),
+            GameUpdate::GameStateChange(GameState::Off)
+        );
+    }
+}
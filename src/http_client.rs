@@ -1,22 +1,40 @@
-use crate::config::{ClientConfig, DEFAULT_USER_AGENT};
+use crate::cache::{CachePolicy, ResponseCache};
+use crate::config::{ClientConfig, LocaleFallback, DEFAULT_STATS_LANGUAGE, DEFAULT_USER_AGENT};
 use crate::error::NHLApiError;
-use reqwest::header::{HeaderMap, HeaderValue, ACCEPT, USER_AGENT};
-use reqwest::{Client, Response};
+use crate::instant::Instant;
+use crate::observer::{RequestInfo, ResponseInfo, ResponseOutcome, SharedObserver};
+use crate::stats::{ClientStats, StatsCounters};
+use crate::transport::SharedTransport;
+use reqwest::header::{
+    HeaderMap, HeaderName, HeaderValue, ACCEPT, CONTENT_TYPE, RETRY_AFTER, USER_AGENT,
+};
+use reqwest::{Client, Response, StatusCode};
+use serde::de::DeserializeOwned;
+use serde_json::Value;
+use std::borrow::Cow;
 use std::collections::HashMap;
-use tracing::debug;
+use std::fmt;
+use std::sync::Arc;
+use std::time::Duration;
+use tracing::{debug, warn};
 
+/// The NHL API hosts.
 #[derive(Debug, Clone)]
 pub enum Endpoint {
+    /// `api-web.nhle.com/v1`: schedules, scores, gamecenter, rosters.
     ApiWebV1,
     #[allow(dead_code)]
     ApiCore,
+    /// `api.nhle.com/stats/rest`: franchises, shift charts, season lists.
     ApiStats,
+    /// `search.d3.nhle.com/api/v1`: player search.
     SearchV1,
     #[cfg(test)]
     Custom(String),
 }
 
 impl Endpoint {
+    /// The host's default base URL, with a trailing slash.
     pub fn base_url(&self) -> &str {
         match self {
             Endpoint::ApiWebV1 => "https://api-web.nhle.com/v1/",
@@ -29,12 +47,337 @@ impl Endpoint {
     }
 }
 
+/// Base URLs from `ClientConfig` that replace an endpoint's default;
+/// `None` keeps [`Endpoint::base_url`].
+#[derive(Debug, Clone, Default)]
+struct BaseUrls {
+    api_web: Option<String>,
+    api_stats: Option<String>,
+    search: Option<String>,
+}
+
+impl BaseUrls {
+    fn new(
+        api_web: Option<String>,
+        api_stats: Option<String>,
+        search: Option<String>,
+    ) -> Result<Self, NHLApiError> {
+        Ok(Self {
+            api_web: validate_base_url("api_web_base", api_web)?,
+            api_stats: validate_base_url("api_stats_base", api_stats)?,
+            search: validate_base_url("search_base", search)?,
+        })
+    }
+
+    fn resolve<'a>(&'a self, endpoint: &'a Endpoint) -> &'a str {
+        let base = match endpoint {
+            Endpoint::ApiWebV1 => &self.api_web,
+            Endpoint::ApiStats => &self.api_stats,
+            Endpoint::SearchV1 => &self.search,
+            _ => &None,
+        };
+        base.as_deref().unwrap_or_else(|| endpoint.base_url())
+    }
+}
+
+/// Rejects overrides that aren't absolute `http`/`https` URLs, so a typo
+/// fails at construction rather than on the first request.
+fn validate_base_url(name: &str, base: Option<String>) -> Result<Option<String>, NHLApiError> {
+    let Some(base) = base else {
+        return Ok(None);
+    };
+    match reqwest::Url::parse(&base) {
+        Ok(url) if matches!(url.scheme(), "http" | "https") => Ok(Some(base)),
+        Ok(url) => Err(NHLApiError::Other(format!(
+            "invalid {}: unsupported scheme {:?} in {:?}",
+            name,
+            url.scheme(),
+            base
+        ))),
+        Err(error) => Err(NHLApiError::Other(format!(
+            "invalid {}: {:?}: {}",
+            name, base, error
+        ))),
+    }
+}
+
 /// Upper bound on how much of a non-2xx response body is read into an error
 /// message, so a large (or hostile) body can't be slurped into memory whole.
 const MAX_ERROR_BODY_BYTES: usize = 4096;
 
+/// How many bytes from the start of an HTML body are quoted in a
+/// `NonJsonResponse` snippet.
+const NON_JSON_SNIPPET_BYTES: usize = 200;
+
+/// How many bytes of body on each side of a decode failure are quoted in a
+/// `DecodeError` snippet.
+const DECODE_SNIPPET_RADIUS: usize = 40;
+
+/// Ceiling on any single retry delay, whether computed by backoff or taken
+/// from a `Retry-After` header.
+const MAX_RETRY_DELAY: Duration = Duration::from_secs(60);
+
+/// Retry settings lifted from `ClientConfig`.
+#[derive(Debug, Clone)]
+struct RetryPolicy {
+    max_retries: u32,
+    backoff_base: Duration,
+    respect_retry_after: bool,
+    deadline: Option<Duration>,
+}
+
+impl RetryPolicy {
+    /// Backoff before retry number `retry` (0-based): `base * 2^retry`,
+    /// capped at `MAX_RETRY_DELAY`.
+    fn backoff_delay(&self, retry: u32) -> Duration {
+        let factor = 2u32.saturating_pow(retry);
+        self.backoff_base
+            .saturating_mul(factor)
+            .min(MAX_RETRY_DELAY)
+    }
+
+    fn is_retryable_status(status: StatusCode) -> bool {
+        status == StatusCode::TOO_MANY_REQUESTS || status.is_server_error()
+    }
+
+    fn is_retryable_transport_error(error: &reqwest::Error) -> bool {
+        #[cfg(not(target_arch = "wasm32"))]
+        let connect = error.is_connect();
+        #[cfg(target_arch = "wasm32")]
+        let connect = false;
+        connect || error.is_timeout()
+    }
+}
+
+/// A failed attempt, with what the retry loop needs to decide on another.
+struct AttemptFailure {
+    error: NHLApiError,
+    /// `None` when no response arrived.
+    status: Option<StatusCode>,
+    retryable: bool,
+    retry_after: Option<Duration>,
+}
+
+impl AttemptFailure {
+    /// Classifies an error from a custom [`Transport`] the way the HTTP
+    /// path would: `429` and `5xx` statuses, HTML bodies and connect or
+    /// timeout errors are retryable.
+    ///
+    /// [`Transport`]: crate::transport::Transport
+    fn from_transport_error(error: NHLApiError) -> Self {
+        let status_code = match &error {
+            NHLApiError::ResourceNotFound { status_code, .. }
+            | NHLApiError::RateLimitExceeded { status_code, .. }
+            | NHLApiError::ServerError { status_code, .. }
+            | NHLApiError::BadRequest { status_code, .. }
+            | NHLApiError::Unauthorized { status_code, .. }
+            | NHLApiError::ApiError { status_code, .. } => Some(*status_code),
+            _ => None,
+        };
+        let status = status_code.and_then(|code| StatusCode::from_u16(code).ok());
+        let retryable = match &error {
+            NHLApiError::RequestError(error) => RetryPolicy::is_retryable_transport_error(error),
+            NHLApiError::NonJsonResponse { .. } => true,
+            _ => status.is_some_and(RetryPolicy::is_retryable_status),
+        };
+        Self {
+            error,
+            status,
+            retryable,
+            retry_after: None,
+        }
+    }
+}
+
+/// Parses a delay-seconds `Retry-After` value. HTTP-date values are not
+/// used by the NHL endpoints and fall back to the computed backoff.
+fn parse_retry_after(value: &HeaderValue) -> Option<Duration> {
+    value
+        .to_str()
+        .ok()?
+        .trim()
+        .parse::<u64>()
+        .ok()
+        .map(Duration::from_secs)
+}
+
+/// Drops the elements of the array(s) at `pointer` that fail to deserialize
+/// as `E`, returning how many were dropped.
+///
+/// `pointer` is a JSON pointer (`/standings`), where a `*` segment fans out
+/// over every element of the array at that point (`/gameWeek/*/games`).
+/// Paths that don't resolve to an array are left alone.
+fn prune_malformed<E: DeserializeOwned>(value: &mut Value, pointer: &str, url: &str) -> u64 {
+    let segments: Vec<&str> = pointer.split('/').filter(|s| !s.is_empty()).collect();
+    prune_malformed_at::<E>(value, &segments, url)
+}
+
+fn prune_malformed_at<E: DeserializeOwned>(value: &mut Value, segments: &[&str], url: &str) -> u64 {
+    let Some((segment, rest)) = segments.split_first() else {
+        let Value::Array(items) = value else {
+            return 0;
+        };
+        let before = items.len();
+        items.retain(|item| match E::deserialize(item) {
+            Ok(_) => true,
+            Err(error) => {
+                warn!(url = %url, error = %error, "Skipping malformed list element");
+                false
+            }
+        });
+        return (before - items.len()) as u64;
+    };
+
+    match value {
+        Value::Array(items) if *segment == "*" => items
+            .iter_mut()
+            .map(|item| prune_malformed_at::<E>(item, rest, url))
+            .sum(),
+        Value::Array(items) => segment
+            .parse::<usize>()
+            .ok()
+            .and_then(|index| items.get_mut(index))
+            .map_or(0, |item| prune_malformed_at::<E>(item, rest, url)),
+        Value::Object(map) => map
+            .get_mut(*segment)
+            .map_or(0, |item| prune_malformed_at::<E>(item, rest, url)),
+        _ => 0,
+    }
+}
+
+/// Whether a `Content-Type` announces a markup page rather than JSON.
+fn is_html_content_type(content_type: &str) -> bool {
+    let content_type = content_type.to_ascii_lowercase();
+    content_type.contains("html") || content_type.contains("xml")
+}
+
+/// What an error response's body adds to its error message: the API's own
+/// explanation from a JSON `{"message": ...}` or `{"error": ...}` body,
+/// nothing for a markup page, and the body itself otherwise.
+fn error_body_detail(body: &str) -> Cow<'_, str> {
+    if body.starts_with('<') {
+        return Cow::Borrowed("");
+    }
+    let explanation = serde_json::from_str::<Value>(body).ok().and_then(|value| {
+        ["message", "error"].into_iter().find_map(|key| {
+            let explanation = value.get(key)?.as_str()?.trim();
+            (!explanation.is_empty()).then(|| explanation.to_string())
+        })
+    });
+    explanation.map_or(Cow::Borrowed(body), Cow::Owned)
+}
+
+/// A `NonJsonResponse` quoting the start of `body`, whitespace collapsed.
+fn non_json_response(url: &str, content_type: Option<String>, body: &str) -> NHLApiError {
+    let mut end = body.len().min(NON_JSON_SNIPPET_BYTES);
+    while !body.is_char_boundary(end) {
+        end -= 1;
+    }
+    NHLApiError::NonJsonResponse {
+        url: url.to_string(),
+        content_type,
+        snippet: body[..end].split_whitespace().collect::<Vec<_>>().join(" "),
+    }
+}
+
+/// Renders a `serde_path_to_error` path as an RFC 6901 JSON pointer
+/// (`/awayTeam/score`, `/standings/3/teamAbbrev`). `None` at the root.
+fn json_pointer(path: &serde_path_to_error::Path) -> Option<String> {
+    use serde_path_to_error::Segment;
+
+    let pointer: String = path
+        .iter()
+        .map(|segment| {
+            let token = match segment {
+                Segment::Seq { index } => index.to_string(),
+                Segment::Map { key } => key.replace('~', "~0").replace('/', "~1"),
+                Segment::Enum { variant } => variant.replace('~', "~0").replace('/', "~1"),
+                Segment::Unknown => "?".to_string(),
+            };
+            format!("/{}", token)
+        })
+        .collect();
+    (!pointer.is_empty()).then_some(pointer)
+}
+
+/// Quotes the body around the line/column a `serde_json` error points at,
+/// clamped to UTF-8 boundaries and with whitespace runs collapsed.
+fn decode_error_snippet(body: &str, error: &serde_json::Error) -> Option<String> {
+    let line_start: usize = body
+        .split_inclusive('\n')
+        .take(error.line().saturating_sub(1))
+        .map(str::len)
+        .sum();
+    let offset = (line_start + error.column().saturating_sub(1)).min(body.len());
+
+    let mut start = offset.saturating_sub(DECODE_SNIPPET_RADIUS);
+    while !body.is_char_boundary(start) {
+        start -= 1;
+    }
+    let mut end = (offset + DECODE_SNIPPET_RADIUS).min(body.len());
+    while !body.is_char_boundary(end) {
+        end += 1;
+    }
+
+    let snippet = body[start..end]
+        .split_whitespace()
+        .collect::<Vec<_>>()
+        .join(" ");
+    (!snippet.is_empty()).then_some(snippet)
+}
+
+/// A response body and where it came from.
+struct Fetched {
+    url: String,
+    body: Arc<str>,
+    /// Key to store the body under once decoded; `None` when caching is off
+    /// or the body was itself served from the cache.
+    cache_key: Option<String>,
+    /// The attempt that fetched the body, held back until decoding settles
+    /// its outcome; `None` without a request observer or for cache hits.
+    observed: Option<ObservedAttempt>,
+}
+
+/// A successful attempt, as reported to the request observer.
+struct ObservedAttempt {
+    resource: String,
+    attempt: u32,
+    /// `None` when a custom transport served the body.
+    status: Option<u16>,
+    elapsed: Duration,
+}
+
 pub struct HttpClient {
     client: Client,
+    retry: RetryPolicy,
+    skip_malformed_elements: bool,
+    verbose_decode_errors: bool,
+    base_urls: BaseUrls,
+    cache: Option<ResponseCache>,
+    counters: StatsCounters,
+    stats_language: String,
+    locale_fallback: LocaleFallback,
+    observer: Option<SharedObserver>,
+    /// Answers requests in place of `client` when set.
+    transport: Option<SharedTransport>,
+}
+
+/// Leaves out the `reqwest::Client`, whose `Debug` output lists the default
+/// headers (which may carry credentials), and the base URL overrides, which
+/// may embed them.
+impl fmt::Debug for HttpClient {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("HttpClient")
+            .field("retry", &self.retry)
+            .field("skip_malformed_elements", &self.skip_malformed_elements)
+            .field("verbose_decode_errors", &self.verbose_decode_errors)
+            .field("cache_enabled", &self.cache.is_some())
+            .field("stats_language", &self.stats_language)
+            .field("locale_fallback", &self.locale_fallback)
+            .field("observer", &self.observer)
+            .field("transport", &self.transport)
+            .finish_non_exhaustive()
+    }
 }
 
 impl HttpClient {
@@ -44,16 +387,70 @@ impl HttpClient {
             ssl_verify,
             follow_redirects,
             user_agent,
+            default_headers,
             client,
+            max_retries,
+            retry_backoff_base,
+            respect_retry_after,
+            retry_deadline,
+            skip_malformed_elements,
+            verbose_decode_errors,
+            api_web_base,
+            api_stats_base,
+            search_base,
+            cache_ttl,
+            cache_max_entries,
+            stats_language,
+            locale_fallback,
+            request_observer,
+            transport,
         } = config;
 
+        let base_urls = BaseUrls::new(api_web_base, api_stats_base, search_base)?;
+        // No tokio timer to back off with in the browser.
+        let max_retries = if cfg!(target_arch = "wasm32") {
+            0
+        } else {
+            max_retries
+        };
+
         // Escape hatch: a caller-supplied client is used verbatim. All
-        // transport-shaping options and the default headers below are the
-        // caller's responsibility in that case (see `ClientConfig` docs).
-        if let Some(client) = client {
-            return Ok(Self { client });
-        }
+        // transport-shaping options and the default headers are the caller's
+        // responsibility in that case (see `ClientConfig` docs).
+        let client = match client {
+            Some(client) => client,
+            None => {
+                let headers = Self::build_default_headers(user_agent, default_headers)?;
+                Self::build_client(timeout, ssl_verify, follow_redirects, headers)?
+            }
+        };
 
+        Ok(Self {
+            client,
+            retry: RetryPolicy {
+                max_retries,
+                backoff_base: retry_backoff_base,
+                respect_retry_after,
+                deadline: retry_deadline,
+            },
+            skip_malformed_elements,
+            verbose_decode_errors,
+            base_urls,
+            cache: cache_ttl.map(|ttl| ResponseCache::new(ttl, cache_max_entries)),
+            counters: StatsCounters::default(),
+            stats_language,
+            locale_fallback,
+            observer: request_observer,
+            transport,
+        })
+    }
+
+    /// `Accept: application/json` and the `User-Agent`, then the caller's
+    /// `default_headers`, which win on a name clash.
+    fn build_default_headers(
+        user_agent: Option<String>,
+        default_headers: HashMap<String, String>,
+    ) -> Result<HeaderMap, NHLApiError> {
         let user_agent = user_agent.as_deref().unwrap_or(DEFAULT_USER_AGENT);
         let mut headers = HeaderMap::new();
         headers.insert(ACCEPT, HeaderValue::from_static("application/json"));
@@ -64,6 +461,25 @@ impl HttpClient {
             })?,
         );
 
+        for (name, value) in default_headers {
+            let header_name = HeaderName::from_bytes(name.as_bytes())
+                .map_err(|_| NHLApiError::Other(format!("invalid header name: {:?}", name)))?;
+            let header_value = HeaderValue::from_str(&value).map_err(|_| {
+                NHLApiError::Other(format!("invalid value for header {:?}: {:?}", name, value))
+            })?;
+            headers.insert(header_name, header_value);
+        }
+
+        Ok(headers)
+    }
+
+    #[cfg(not(target_arch = "wasm32"))]
+    fn build_client(
+        timeout: Duration,
+        ssl_verify: bool,
+        follow_redirects: bool,
+        headers: HeaderMap,
+    ) -> Result<Client, NHLApiError> {
         let mut client_builder = Client::builder()
             .timeout(timeout)
             .danger_accept_invalid_certs(!ssl_verify)
@@ -75,8 +491,24 @@ impl HttpClient {
             client_builder = client_builder.redirect(reqwest::redirect::Policy::none());
         }
 
-        let client = client_builder.build()?;
-        Ok(Self { client })
+        Ok(client_builder.build()?)
+    }
+
+    /// The browser's `fetch` owns timeouts, certificate checks and
+    /// redirects, so only the headers apply on wasm.
+    #[cfg(target_arch = "wasm32")]
+    fn build_client(
+        _timeout: Duration,
+        _ssl_verify: bool,
+        _follow_redirects: bool,
+        headers: HeaderMap,
+    ) -> Result<Client, NHLApiError> {
+        Ok(Client::builder().default_headers(headers).build()?)
+    }
+
+    /// Snapshot of this client's running counters.
+    pub fn stats(&self) -> ClientStats {
+        self.counters.snapshot()
     }
 
     fn error_from_status(status_code: u16, url: &str, body_snippet: &str) -> NHLApiError {
@@ -128,8 +560,9 @@ impl HttpClient {
             return Ok(response);
         }
 
-        // Bounded, best-effort read: a body we can't read (or that's empty)
-        // still yields a usable error, just without the extra detail.
+        // Bounded, best-effort read: a body we can't read (or that's empty,
+        // or an HTML error page) still yields a usable error, just without
+        // the extra detail.
         let body = response.bytes().await.unwrap_or_default();
         let truncated_len = body.len().min(MAX_ERROR_BODY_BYTES);
         let snippet = String::from_utf8_lossy(&body[..truncated_len]);
@@ -137,39 +570,493 @@ impl HttpClient {
         Err(Self::error_from_status(
             status.as_u16(),
             url,
-            snippet.trim(),
+            &error_body_detail(snippet.trim()),
         ))
     }
 
-    pub async fn get_json<T: serde::de::DeserializeOwned>(
+    /// Makes a single attempt, through the custom transport when one is set
+    /// and over HTTP otherwise. The status is `None` for a custom transport.
+    async fn send_once(
         &self,
-        endpoint: Endpoint,
+        endpoint: &Endpoint,
+        full_url: &str,
         resource: &str,
-        query_params: Option<HashMap<String, String>>,
-    ) -> Result<T, NHLApiError> {
-        let full_url = Self::build_url(endpoint.base_url(), resource);
+        query_params: Option<&HashMap<String, String>>,
+    ) -> Result<(Option<StatusCode>, String), AttemptFailure> {
+        match &self.transport {
+            Some(transport) => {
+                debug!(url = %full_url, "Sending request through custom transport");
+                let body = transport
+                    .0
+                    .get(endpoint, resource, query_params)
+                    .await
+                    .map_err(AttemptFailure::from_transport_error)?;
+                Ok((None, body))
+            }
+            None => {
+                let (status, body) = self.send_http(full_url, resource, query_params).await?;
+                Ok((Some(status), body))
+            }
+        }
+    }
 
+    /// Issues a single GET and reads the body, classifying any failure for
+    /// the retry loop. HTML bodies (maintenance pages) are retryable
+    /// `NonJsonResponse` failures.
+    async fn send_http(
+        &self,
+        full_url: &str,
+        resource: &str,
+        query_params: Option<&HashMap<String, String>>,
+    ) -> Result<(StatusCode, String), AttemptFailure> {
         debug!(url = %full_url, "Sending HTTP GET request");
 
-        let mut request = self.client.get(&full_url);
+        let mut request = self.client.get(full_url);
 
         if let Some(params) = query_params {
             debug!(params = ?params, "Adding query parameters");
-            request = request.query(&params);
+            request = request.query(params);
         }
 
-        let response = request.send().await?;
+        let response = request.send().await.map_err(|error| AttemptFailure {
+            status: None,
+            retryable: RetryPolicy::is_retryable_transport_error(&error),
+            retry_after: None,
+            error: error.into(),
+        })?;
         debug!(status = %response.status(), url = %full_url, "Received HTTP response");
 
-        let response = self.handle_response(response, resource).await?;
+        let status = response.status();
+        let retry_after = if status == StatusCode::TOO_MANY_REQUESTS {
+            response
+                .headers()
+                .get(RETRY_AFTER)
+                .and_then(parse_retry_after)
+        } else {
+            None
+        };
+        let content_type = response
+            .headers()
+            .get(CONTENT_TYPE)
+            .and_then(|value| value.to_str().ok())
+            .map(str::to_string);
+
+        // A maintenance page served with a 5xx status.
+        if status.is_server_error() && content_type.as_deref().is_some_and(is_html_content_type) {
+            let body = response.text().await.unwrap_or_default();
+            return Err(AttemptFailure {
+                error: non_json_response(full_url, content_type, &body),
+                status: Some(status),
+                retryable: true,
+                retry_after,
+            });
+        }
 
-        let body_text = response.text().await?;
-        let json =
-            serde_json::from_str::<T>(&body_text).map_err(|source| NHLApiError::JsonError {
-                url: full_url.clone(),
-                source,
+        let response = self
+            .handle_response(response, resource)
+            .await
+            .map_err(|error| AttemptFailure {
+                error,
+                status: Some(status),
+                retryable: RetryPolicy::is_retryable_status(status),
+                retry_after,
             })?;
-        debug!(url = %full_url, "Successfully deserialized response");
+        let body = response.text().await.map_err(|error| AttemptFailure {
+            status: Some(status),
+            retryable: RetryPolicy::is_retryable_transport_error(&error),
+            retry_after: None,
+            error: error.into(),
+        })?;
+
+        // A maintenance page served with a 200.
+        if content_type.as_deref().is_some_and(is_html_content_type)
+            || body.trim_start().starts_with('<')
+        {
+            return Err(AttemptFailure {
+                error: non_json_response(full_url, content_type, &body),
+                status: Some(status),
+                retryable: true,
+                retry_after: None,
+            });
+        }
+        Ok((status, body))
+    }
+
+    /// Sends the GET and reads the body, retrying retryable failures per the
+    /// `RetryPolicy`.
+    ///
+    /// When retries ran out on a retryable failure, the final error is
+    /// wrapped in `NHLApiError::RetriesExhausted` so callers can see the
+    /// attempt count. A non-retryable failure after a retry is returned
+    /// unwrapped.
+    ///
+    /// Failed attempts are reported to the request observer here; the
+    /// successful one is handed back for [`Self::observe_decode`].
+    async fn send_with_retry(
+        &self,
+        endpoint: &Endpoint,
+        full_url: &str,
+        resource: &str,
+        query_params: Option<&HashMap<String, String>>,
+    ) -> Result<(String, Option<ObservedAttempt>), NHLApiError> {
+        let started = Instant::now();
+        let mut attempts: u32 = 0;
+
+        loop {
+            attempts += 1;
+            let attempt_started = self.observer.as_ref().map(|observer| {
+                observer.0.on_request(&RequestInfo {
+                    url: full_url.to_string(),
+                    resource: resource.to_string(),
+                    attempt: attempts,
+                });
+                Instant::now()
+            });
+            let failure = match self
+                .send_once(endpoint, full_url, resource, query_params)
+                .await
+            {
+                Ok((status, body)) => {
+                    let observed = attempt_started.map(|attempt_started| ObservedAttempt {
+                        resource: resource.to_string(),
+                        attempt: attempts,
+                        status: status.map(|status| status.as_u16()),
+                        elapsed: attempt_started.elapsed(),
+                    });
+                    return Ok((body, observed));
+                }
+                Err(failure) => failure,
+            };
+            if let (Some(observer), Some(attempt_started)) = (&self.observer, attempt_started) {
+                observer.0.on_response(&ResponseInfo {
+                    url: full_url.to_string(),
+                    resource: resource.to_string(),
+                    attempt: attempts,
+                    status: failure.status.map(|status| status.as_u16()),
+                    elapsed: attempt_started.elapsed(),
+                    outcome: ResponseOutcome::of_error(&failure.error),
+                });
+            }
+
+            let retry_index = attempts - 1;
+            let mut delay = self.retry.backoff_delay(retry_index);
+            if self.retry.respect_retry_after {
+                if let Some(retry_after) = failure.retry_after {
+                    delay = retry_after.min(MAX_RETRY_DELAY);
+                }
+            }
+            let past_deadline = self
+                .retry
+                .deadline
+                .is_some_and(|deadline| started.elapsed() + delay > deadline);
+
+            if !failure.retryable || retry_index >= self.retry.max_retries || past_deadline {
+                // A non-retryable final failure (a 404 after a 503) is
+                // returned as-is, so callers matching on it still see it.
+                return Err(if failure.retryable && attempts > 1 {
+                    NHLApiError::RetriesExhausted {
+                        attempts,
+                        source: Box::new(failure.error),
+                    }
+                } else {
+                    failure.error
+                });
+            }
+
+            debug!(
+                url = %full_url,
+                attempt = attempts,
+                delay_ms = delay.as_millis() as u64,
+                error = %failure.error,
+                "Retrying HTTP GET request"
+            );
+            tokio::time::sleep(delay).await;
+        }
+    }
+
+    /// Fetches the response body as text via [`Self::fetch_text`]. Stats-host
+    /// resources are given without a language and requested under
+    /// `language` (the configured one when `None`), falling back to `en/` on
+    /// a `404` when [`LocaleFallback::Auto`] allows it.
+    async fn get_text(
+        &self,
+        endpoint: Endpoint,
+        language: Option<&str>,
+        resource: &str,
+        query_params: Option<HashMap<String, String>>,
+    ) -> Result<Fetched, NHLApiError> {
+        if !matches!(endpoint, Endpoint::ApiStats) {
+            return self.fetch_text(endpoint, resource, query_params).await;
+        }
+
+        let language = language.unwrap_or(&self.stats_language);
+        let localized = format!("{}/{}", language, resource);
+        let can_fall_back =
+            self.locale_fallback == LocaleFallback::Auto && language != DEFAULT_STATS_LANGUAGE;
+        match self
+            .fetch_text(Endpoint::ApiStats, &localized, query_params.clone())
+            .await
+        {
+            Err(NHLApiError::ResourceNotFound { .. }) if can_fall_back => {
+                debug!(
+                    resource = %localized,
+                    "Stats resource missing in configured language, retrying in English"
+                );
+                self.counters.add_locale_fallback();
+                let english = format!("{}/{}", DEFAULT_STATS_LANGUAGE, resource);
+                self.fetch_text(Endpoint::ApiStats, &english, query_params)
+                    .await
+            }
+            result => result,
+        }
+    }
+
+    /// Fetches the response body as text, from the cache when enabled and
+    /// holding a fresh entry.
+    async fn fetch_text(
+        &self,
+        endpoint: Endpoint,
+        resource: &str,
+        query_params: Option<HashMap<String, String>>,
+    ) -> Result<Fetched, NHLApiError> {
+        let full_url = Self::build_url(self.base_urls.resolve(&endpoint), resource);
+
+        let cache_key = self
+            .cache
+            .as_ref()
+            .map(|_| ResponseCache::key(&full_url, query_params.as_ref()));
+        if let (Some(cache), Some(key)) = (&self.cache, &cache_key) {
+            if let Some(body) = cache.get(key) {
+                debug!(url = %full_url, "Serving response from cache");
+                self.counters.add_cache_hit();
+                return Ok(Fetched {
+                    url: full_url,
+                    body,
+                    cache_key: None,
+                    observed: None,
+                });
+            }
+        }
+
+        let (body_text, observed) = self
+            .send_with_retry(&endpoint, &full_url, resource, query_params.as_ref())
+            .await?;
+
+        Ok(Fetched {
+            url: full_url,
+            body: Arc::from(body_text),
+            cache_key,
+            observed,
+        })
+    }
+
+    /// Stores a freshly fetched body once its decoded form has chosen a
+    /// policy. No-op for cache hits and when caching is off.
+    fn remember(&self, fetched: &Fetched, policy: CachePolicy) {
+        if let (Some(cache), Some(key)) = (&self.cache, &fetched.cache_key) {
+            cache.insert(key.clone(), Arc::clone(&fetched.body), policy);
+        }
+    }
+
+    /// Reports the attempt that fetched `fetched` to the request observer,
+    /// as a success or a decode failure per `decoded`, and passes `decoded`
+    /// through.
+    fn observe_decode<T>(
+        &self,
+        fetched: &Fetched,
+        decoded: Result<T, NHLApiError>,
+    ) -> Result<T, NHLApiError> {
+        if let (Some(observer), Some(observed)) = (&self.observer, &fetched.observed) {
+            observer.0.on_response(&ResponseInfo {
+                url: fetched.url.clone(),
+                resource: observed.resource.clone(),
+                attempt: observed.attempt,
+                status: observed.status,
+                elapsed: observed.elapsed,
+                outcome: match &decoded {
+                    Ok(_) => ResponseOutcome::Success,
+                    Err(error) => ResponseOutcome::of_error(error),
+                },
+            });
+        }
+        decoded
+    }
+
+    pub async fn get_json<T: DeserializeOwned>(
+        &self,
+        endpoint: Endpoint,
+        resource: &str,
+        query_params: Option<HashMap<String, String>>,
+    ) -> Result<T, NHLApiError> {
+        self.get_json_with(endpoint, resource, query_params, |_| CachePolicy::Ttl)
+            .await
+    }
+
+    /// [`Self::get_json`] with the cache policy picked from the decoded
+    /// response, e.g. caching a game for good once it is final.
+    pub async fn get_json_with<T: DeserializeOwned>(
+        &self,
+        endpoint: Endpoint,
+        resource: &str,
+        query_params: Option<HashMap<String, String>>,
+        cache_policy: impl FnOnce(&T) -> CachePolicy,
+    ) -> Result<T, NHLApiError> {
+        let fetched = self
+            .get_text(endpoint, None, resource, query_params)
+            .await?;
+        let json = self.observe_decode(&fetched, self.decode(&fetched.url, &fetched.body))?;
+        self.remember(&fetched, cache_policy(&json));
+        debug!(url = %fetched.url, "Successfully deserialized response");
+        Ok(json)
+    }
+
+    /// Deserializes a response body, tracking the JSON pointer to the
+    /// failing field and, when `verbose_decode_errors` is on, quoting the
+    /// body around it.
+    fn decode<T: DeserializeOwned>(&self, url: &str, body: &str) -> Result<T, NHLApiError> {
+        let mut deserializer = serde_json::Deserializer::from_str(body);
+        let result = serde_path_to_error::deserialize(&mut deserializer)
+            .map_err(|error| (json_pointer(error.path()), error.into_inner()))
+            .and_then(|value| {
+                // Same trailing-content check `serde_json::from_str` does.
+                deserializer
+                    .end()
+                    .map(|()| value)
+                    .map_err(|error| (None, error))
+            });
+
+        result.map_err(|(path, source)| self.decode_error(url, path, Some(body), source))
+    }
+
+    /// [`Self::decode`] for an already-parsed `Value` (no body text, so no
+    /// snippet).
+    fn decode_value<T: DeserializeOwned>(&self, url: &str, value: Value) -> Result<T, NHLApiError> {
+        serde_path_to_error::deserialize(value).map_err(|error| {
+            let path = json_pointer(error.path());
+            self.decode_error(url, path, None, error.into_inner())
+        })
+    }
+
+    fn decode_error(
+        &self,
+        url: &str,
+        path: Option<String>,
+        body: Option<&str>,
+        source: serde_json::Error,
+    ) -> NHLApiError {
+        let snippet = body
+            .filter(|_| self.verbose_decode_errors)
+            .and_then(|body| decode_error_snippet(body, &source));
+        NHLApiError::DecodeError {
+            url: url.to_string(),
+            path,
+            snippet,
+            source,
+        }
+    }
+
+    /// [`Self::get_json`] for list-bearing responses whose array(s) of `E`
+    /// sit at `array_pointer` (see [`prune_malformed`] for the syntax).
+    ///
+    /// Identical to `get_json` unless the client was configured with
+    /// `skip_malformed_elements`, in which case elements that fail to
+    /// deserialize are dropped and counted instead of failing the call.
+    pub async fn get_json_list<T: DeserializeOwned, E: DeserializeOwned>(
+        &self,
+        endpoint: Endpoint,
+        resource: &str,
+        query_params: Option<HashMap<String, String>>,
+        array_pointer: &str,
+    ) -> Result<T, NHLApiError> {
+        self.get_json_list_with::<T, E>(endpoint, resource, query_params, array_pointer, |_| {
+            CachePolicy::Ttl
+        })
+        .await
+    }
+
+    /// [`Self::get_json_list`] with a per-response cache policy, as in
+    /// [`Self::get_json_with`].
+    pub async fn get_json_list_with<T: DeserializeOwned, E: DeserializeOwned>(
+        &self,
+        endpoint: Endpoint,
+        resource: &str,
+        query_params: Option<HashMap<String, String>>,
+        array_pointer: &str,
+        cache_policy: impl FnOnce(&T) -> CachePolicy,
+    ) -> Result<T, NHLApiError> {
+        self.get_json_list_at::<T, E>(
+            endpoint,
+            None,
+            resource,
+            query_params,
+            array_pointer,
+            cache_policy,
+        )
+        .await
+    }
+
+    /// [`Self::get_json_list_with`] for a stats-host resource, requested in
+    /// `language` instead of the configured stats language when given.
+    pub async fn get_stats_json_list<T: DeserializeOwned, E: DeserializeOwned>(
+        &self,
+        language: Option<&str>,
+        resource: &str,
+        query_params: Option<HashMap<String, String>>,
+        array_pointer: &str,
+        cache_policy: impl FnOnce(&T) -> CachePolicy,
+    ) -> Result<T, NHLApiError> {
+        self.get_json_list_at::<T, E>(
+            Endpoint::ApiStats,
+            language,
+            resource,
+            query_params,
+            array_pointer,
+            cache_policy,
+        )
+        .await
+    }
+
+    async fn get_json_list_at<T: DeserializeOwned, E: DeserializeOwned>(
+        &self,
+        endpoint: Endpoint,
+        language: Option<&str>,
+        resource: &str,
+        query_params: Option<HashMap<String, String>>,
+        array_pointer: &str,
+        cache_policy: impl FnOnce(&T) -> CachePolicy,
+    ) -> Result<T, NHLApiError> {
+        let fetched = self
+            .get_text(endpoint, language, resource, query_params)
+            .await?;
+        let json =
+            self.observe_decode(&fetched, self.decode_list::<T, E>(&fetched, array_pointer))?;
+        self.remember(&fetched, cache_policy(&json));
+        Ok(json)
+    }
+
+    /// Decodes a list-bearing body, pruning malformed elements first when
+    /// `skip_malformed_elements` is on.
+    fn decode_list<T: DeserializeOwned, E: DeserializeOwned>(
+        &self,
+        fetched: &Fetched,
+        array_pointer: &str,
+    ) -> Result<T, NHLApiError> {
+        let full_url = &fetched.url;
+        if !self.skip_malformed_elements {
+            let json = self.decode(full_url, &fetched.body)?;
+            debug!(url = %full_url, "Successfully deserialized response");
+            return Ok(json);
+        }
+
+        let mut value: Value = serde_json::from_str(&fetched.body)
+            .map_err(|source| self.decode_error(full_url, None, Some(&fetched.body), source))?;
+        let skipped = prune_malformed::<E>(&mut value, array_pointer, full_url);
+        if skipped > 0 {
+            self.counters.add_skipped_elements(skipped);
+        }
+
+        let json = self.decode_value(full_url, value)?;
+        debug!(url = %full_url, skipped, "Successfully deserialized list response");
         Ok(json)
     }
 }
@@ -177,7 +1064,11 @@ impl HttpClient {
 #[cfg(test)]
 mod tests {
     use super::*;
-    use std::time::Duration;
+    use crate::observer::RequestObserver;
+    use crate::transport::Transport;
+    use crate::types::{Standing, StandingsResponse};
+    use futures_util::future::{self, BoxFuture, FutureExt};
+    use std::sync::Mutex;
 
     fn assert_config_creates_client(config: ClientConfig) {
         let client = HttpClient::new(config);
@@ -239,6 +1130,87 @@ mod tests {
         assert_eq!(endpoint1.base_url(), endpoint2.base_url());
     }
 
+    // ===== Base URL Override Tests =====
+
+    #[test]
+    fn test_base_urls_default_to_endpoint_base() {
+        let base_urls = BaseUrls::default();
+        assert_eq!(
+            base_urls.resolve(&Endpoint::ApiWebV1),
+            "https://api-web.nhle.com/v1/"
+        );
+        assert_eq!(
+            base_urls.resolve(&Endpoint::SearchV1),
+            "https://search.d3.nhle.com/api/v1/"
+        );
+    }
+
+    #[test]
+    fn test_base_urls_overrides_apply_per_endpoint() {
+        let base_urls = BaseUrls::new(
+            Some("http://localhost:1/web/".to_string()),
+            None,
+            Some("http://localhost:1/search/".to_string()),
+        )
+        .unwrap();
+        assert_eq!(
+            base_urls.resolve(&Endpoint::ApiWebV1),
+            "http://localhost:1/web/"
+        );
+        assert_eq!(
+            base_urls.resolve(&Endpoint::ApiStats),
+            "https://api.nhle.com/stats/rest/"
+        );
+        assert_eq!(
+            base_urls.resolve(&Endpoint::SearchV1),
+            "http://localhost:1/search/"
+        );
+        assert_eq!(
+            base_urls.resolve(&Endpoint::ApiCore),
+            "https://api.nhle.com/"
+        );
+    }
+
+    #[test]
+    fn test_http_client_rejects_unparseable_base_url() {
+        let config = ClientConfig::default().with_api_stats_base("not a url");
+        match HttpClient::new(config) {
+            Err(NHLApiError::Other(message)) => {
+                assert!(message.contains("api_stats_base"), "{}", message)
+            }
+            other => panic!("Expected Other error, got {:?}", other.map(|_| ())),
+        }
+    }
+
+    #[test]
+    fn test_http_client_rejects_non_http_base_url() {
+        let config = ClientConfig::default().with_search_base("ftp://localhost/");
+        assert!(matches!(
+            HttpClient::new(config),
+            Err(NHLApiError::Other(message)) if message.contains("search_base")
+        ));
+    }
+
+    #[tokio::test]
+    async fn test_get_json_uses_api_web_base_override() {
+        let mut server = mockito::Server::new_async().await;
+        let mock = server
+            .mock("GET", "/proxy/v1/ping")
+            .with_status(200)
+            .with_body("{}")
+            .create_async()
+            .await;
+
+        let config =
+            ClientConfig::default().with_api_web_base(format!("{}/proxy/v1/", server.url()));
+        let _: Value = HttpClient::new(config)
+            .unwrap()
+            .get_json(Endpoint::ApiWebV1, "ping", None)
+            .await
+            .unwrap();
+        mock.assert_async().await;
+    }
+
     // ===== URL Building Tests =====
 
     #[test]
@@ -585,41 +1557,104 @@ mod tests {
         }
     }
 
-    #[tokio::test]
-    async fn test_get_json_404_with_error_body_included_in_message() {
-        use serde::Deserialize;
-
-        #[derive(Debug, Deserialize)]
-        struct TestResponse {}
-
+    /// Serves `body` with `status` at `/{resource}` and returns the error
+    /// `get_json` maps it to.
+    async fn error_for_body(
+        status: usize,
+        content_type: &str,
+        resource: &str,
+        body: &str,
+    ) -> NHLApiError {
         let mut server = mockito::Server::new_async().await;
         let _mock = server
-            .mock("GET", "/missing-player")
-            .with_status(404)
-            .with_header("content-type", "application/json")
-            .with_body(r#"{"error": "player not found", "code": "NOT_FOUND"}"#)
+            .mock("GET", format!("/{}", resource).as_str())
+            .with_status(status)
+            .with_header("content-type", content_type)
+            .with_body(body)
             .create_async()
             .await;
 
-        let config = ClientConfig::default();
-        let http_client = HttpClient::new(config).unwrap();
-
-        let endpoint = Endpoint::Custom(server.url());
-        let result: Result<TestResponse, NHLApiError> =
-            http_client.get_json(endpoint, "missing-player", None).await;
+        let http_client = HttpClient::new(ClientConfig::default()).unwrap();
+        let result: Result<Value, NHLApiError> = http_client
+            .get_json(Endpoint::Custom(server.url()), resource, None)
+            .await;
+        result.unwrap_err()
+    }
 
-        match result.unwrap_err() {
+    #[tokio::test]
+    async fn test_get_json_404_with_error_body_included_in_message() {
+        let error = error_for_body(
+            404,
+            "application/json",
+            "missing-player",
+            r#"{"error": "player not found", "code": "NOT_FOUND"}"#,
+        )
+        .await;
+
+        match error {
             NHLApiError::ResourceNotFound { message, .. } => {
-                assert!(
-                    message.contains(r#"{"error": "player not found", "code": "NOT_FOUND"}"#),
-                    "expected message to contain the response body snippet, got: {}",
-                    message
+                assert_eq!(
+                    message,
+                    "Request to missing-player failed: player not found"
                 );
             }
             other => panic!("Expected ResourceNotFound error, got {:?}", other),
         }
     }
 
+    #[tokio::test]
+    async fn test_get_json_400_with_json_message_shown_in_display() {
+        let error = error_for_body(
+            400,
+            "application/json",
+            "gamecenter/1/boxscore",
+            r#"{"message": "Invalid game id"}"#,
+        )
+        .await;
+
+        assert!(matches!(
+            error,
+            NHLApiError::BadRequest {
+                status_code: 400,
+                ..
+            }
+        ));
+        assert_eq!(
+            error.to_string(),
+            "Bad request: Request to gamecenter/1/boxscore failed: Invalid game id"
+        );
+    }
+
+    #[tokio::test]
+    async fn test_get_json_400_with_html_page_falls_back_to_status() {
+        let error = error_for_body(
+            400,
+            "text/html",
+            "bad",
+            "<html><body><h1>400 Bad Request</h1></body></html>",
+        )
+        .await;
+
+        match error {
+            NHLApiError::BadRequest { message, .. } => {
+                assert_eq!(message, "Request to bad failed");
+            }
+            other => panic!("Expected BadRequest error, got {:?}", other),
+        }
+    }
+
+    #[tokio::test]
+    async fn test_get_json_error_json_without_message_keeps_body() {
+        let error = error_for_body(404, "application/json", "gone", r#"{"code": 404}"#).await;
+
+        match error {
+            NHLApiError::ResourceNotFound { message, .. } => {
+                assert_eq!(message, r#"Request to gone failed: {"code": 404}"#);
+            }
+            other => panic!("Expected ResourceNotFound error, got {:?}", other),
+        }
+    }
+
     #[tokio::test]
     async fn test_get_json_error_body_truncated_to_cap() {
         use serde::Deserialize;
@@ -688,14 +1723,14 @@ mod tests {
         // Should fail during deserialization, and the wrapped error carries
         // the request URL (so callers can tell which endpoint misbehaved).
         match result.unwrap_err() {
-            NHLApiError::JsonError { url, .. } => {
+            NHLApiError::DecodeError { url, .. } => {
                 assert!(
                     url.contains("bad-json"),
-                    "expected JSON error url to contain the request path, got: {}",
+                    "expected decode error url to contain the request path, got: {}",
                     url
                 );
             }
-            other => panic!("Expected JsonError, got {:?}", other),
+            other => panic!("Expected DecodeError, got {:?}", other),
         }
     }
 
@@ -808,6 +1843,668 @@ mod tests {
         );
     }
 
+    // ===== Retry tests =====
+
+    fn retry_policy(max_retries: u32, backoff_base: Duration) -> RetryPolicy {
+        RetryPolicy {
+            max_retries,
+            backoff_base,
+            respect_retry_after: true,
+            deadline: None,
+        }
+    }
+
+    fn retrying_client(max_retries: u32) -> HttpClient {
+        let config = ClientConfig::default()
+            .with_max_retries(max_retries)
+            .with_retry_backoff_base(Duration::from_millis(1));
+        HttpClient::new(config).unwrap()
+    }
+
+    #[test]
+    fn test_retry_backoff_delay_doubles_and_caps() {
+        let policy = retry_policy(10, Duration::from_millis(100));
+        let delays: Vec<Duration> = (0..4).map(|retry| policy.backoff_delay(retry)).collect();
+        assert_eq!(
+            delays,
+            vec![
+                Duration::from_millis(100),
+                Duration::from_millis(200),
+                Duration::from_millis(400),
+                Duration::from_millis(800),
+            ]
+        );
+        assert_eq!(policy.backoff_delay(20), MAX_RETRY_DELAY);
+        assert_eq!(policy.backoff_delay(u32::MAX), MAX_RETRY_DELAY);
+    }
+
+    #[test]
+    fn test_retry_retryable_statuses() {
+        for code in [429, 500, 502, 503, 599] {
+            let status = StatusCode::from_u16(code).unwrap();
+            assert!(RetryPolicy::is_retryable_status(status), "{}", code);
+        }
+        for code in [400, 401, 403, 404, 418] {
+            let status = StatusCode::from_u16(code).unwrap();
+            assert!(!RetryPolicy::is_retryable_status(status), "{}", code);
+        }
+    }
+
+    #[test]
+    fn test_parse_retry_after() {
+        assert_eq!(
+            parse_retry_after(&HeaderValue::from_static("7")),
+            Some(Duration::from_secs(7))
+        );
+        assert_eq!(
+            parse_retry_after(&HeaderValue::from_static(" 0 ")),
+            Some(Duration::ZERO)
+        );
+        assert_eq!(
+            parse_retry_after(&HeaderValue::from_static("Wed, 21 Oct 2015 07:28:00 GMT")),
+            None
+        );
+    }
+
+    #[tokio::test]
+    async fn test_get_json_no_retry_by_default() {
+        let mut server = mockito::Server::new_async().await;
+        let mock = server
+            .mock("GET", "/flaky")
+            .with_status(503)
+            .expect(1)
+            .create_async()
+            .await;
+
+        let http_client = HttpClient::new(ClientConfig::default()).unwrap();
+        let result: Result<serde_json::Value, NHLApiError> = http_client
+            .get_json(Endpoint::Custom(server.url()), "flaky", None)
+            .await;
+
+        mock.assert_async().await;
+        assert!(matches!(result, Err(NHLApiError::ServerError { .. })));
+    }
+
+    #[tokio::test]
+    async fn test_get_json_retries_until_success() {
+        let mut server = mockito::Server::new_async().await;
+        // Mockito serves the first mock that still expects requests, so the
+        // two 503s are consumed before the 200.
+        let failing = server
+            .mock("GET", "/flaky")
+            .with_status(503)
+            .expect(2)
+            .create_async()
+            .await;
+        let succeeding = server
+            .mock("GET", "/flaky")
+            .with_status(200)
+            .with_body(r#"{"ok": true}"#)
+            .expect(1)
+            .create_async()
+            .await;
+
+        let result: Result<serde_json::Value, NHLApiError> = retrying_client(3)
+            .get_json(Endpoint::Custom(server.url()), "flaky", None)
+            .await;
+
+        failing.assert_async().await;
+        succeeding.assert_async().await;
+        assert_eq!(result.unwrap()["ok"], true);
+    }
+
+    const MAINTENANCE_PAGE: &str =
+        "<!DOCTYPE html>\n<html><body><h1>We'll be right back</h1></body></html>";
+
+    #[tokio::test]
+    async fn test_get_json_html_200_is_non_json_response() {
+        let mut server = mockito::Server::new_async().await;
+        let mock = server
+            .mock("GET", "/maintenance")
+            .with_status(200)
+            .with_header("content-type", "text/html; charset=utf-8")
+            .with_body(MAINTENANCE_PAGE)
+            .expect(1)
+            .create_async()
+            .await;
+
+        let http_client = HttpClient::new(ClientConfig::default()).unwrap();
+        let result: Result<serde_json::Value, NHLApiError> = http_client
+            .get_json(Endpoint::Custom(server.url()), "maintenance", None)
+            .await;
+
+        mock.assert_async().await;
+        match result.unwrap_err() {
+            NHLApiError::NonJsonResponse {
+                url,
+                content_type,
+                snippet,
+            } => {
+                assert!(url.ends_with("/maintenance"), "{}", url);
+                assert_eq!(content_type.as_deref(), Some("text/html; charset=utf-8"));
+                assert!(snippet.starts_with("<!DOCTYPE html> <html>"), "{}", snippet);
+            }
+            other => panic!("Expected NonJsonResponse, got {:?}", other),
+        }
+    }
+
+    #[tokio::test]
+    async fn test_get_json_markup_body_without_content_type_is_non_json_response() {
+        let mut server = mockito::Server::new_async().await;
+        let _mock = server
+            .mock("GET", "/maintenance")
+            .with_status(200)
+            .with_body(MAINTENANCE_PAGE)
+            .create_async()
+            .await;
+
+        let http_client = HttpClient::new(ClientConfig::default()).unwrap();
+        let result: Result<serde_json::Value, NHLApiError> = http_client
+            .get_json(Endpoint::Custom(server.url()), "maintenance", None)
+            .await;
+
+        assert!(matches!(
+            result,
+            Err(NHLApiError::NonJsonResponse {
+                content_type: None,
+                ..
+            })
+        ));
+    }
+
+    #[tokio::test]
+    async fn test_get_json_retries_html_pages_until_json() {
+        let mut server = mockito::Server::new_async().await;
+        let unavailable = server
+            .mock("GET", "/flaky")
+            .with_status(503)
+            .with_header("content-type", "text/html")
+            .with_body(MAINTENANCE_PAGE)
+            .expect(1)
+            .create_async()
+            .await;
+        let maintenance = server
+            .mock("GET", "/flaky")
+            .with_status(200)
+            .with_header("content-type", "text/html")
+            .with_body(MAINTENANCE_PAGE)
+            .expect(1)
+            .create_async()
+            .await;
+        let succeeding = server
+            .mock("GET", "/flaky")
+            .with_status(200)
+            .with_header("content-type", "application/json")
+            .with_body(r#"{"ok": true}"#)
+            .expect(1)
+            .create_async()
+            .await;
+
+        let result: Result<serde_json::Value, NHLApiError> = retrying_client(3)
+            .get_json(Endpoint::Custom(server.url()), "flaky", None)
+            .await;
+
+        unavailable.assert_async().await;
+        maintenance.assert_async().await;
+        succeeding.assert_async().await;
+        assert_eq!(result.unwrap()["ok"], true);
+    }
+
+    #[tokio::test]
+    async fn test_get_json_html_503_exhausts_retries_as_non_json_response() {
+        let mut server = mockito::Server::new_async().await;
+        let mock = server
+            .mock("GET", "/down")
+            .with_status(503)
+            .with_header("content-type", "text/html")
+            .with_body(MAINTENANCE_PAGE)
+            .expect(2)
+            .create_async()
+            .await;
+
+        let result: Result<serde_json::Value, NHLApiError> = retrying_client(1)
+            .get_json(Endpoint::Custom(server.url()), "down", None)
+            .await;
+
+        mock.assert_async().await;
+        match result.unwrap_err() {
+            NHLApiError::RetriesExhausted { attempts, source } => {
+                assert_eq!(attempts, 2);
+                assert!(matches!(*source, NHLApiError::NonJsonResponse { .. }));
+            }
+            other => panic!("Expected RetriesExhausted, got {:?}", other),
+        }
+    }
+
+    #[tokio::test]
+    async fn test_get_json_retries_exhausted_reports_attempts() {
+        let mut server = mockito::Server::new_async().await;
+        let mock = server
+            .mock("GET", "/down")
+            .with_status(502)
+            .expect(3)
+            .create_async()
+            .await;
+
+        let result: Result<serde_json::Value, NHLApiError> = retrying_client(2)
+            .get_json(Endpoint::Custom(server.url()), "down", None)
+            .await;
+
+        mock.assert_async().await;
+        let error = result.unwrap_err();
+        assert!(
+            error.to_string().ends_with("(after 3 attempts)"),
+            "unexpected message: {}",
+            error
+        );
+        match error {
+            NHLApiError::RetriesExhausted { attempts, source } => {
+                assert_eq!(attempts, 3);
+                assert!(matches!(
+                    *source,
+                    NHLApiError::ServerError {
+                        status_code: 502,
+                        ..
+                    }
+                ));
+            }
+            other => panic!("Expected RetriesExhausted, got {:?}", other),
+        }
+    }
+
+    #[tokio::test]
+    async fn test_get_json_does_not_retry_client_errors() {
+        for status in [400, 401, 404] {
+            let mut server = mockito::Server::new_async().await;
+            let mock = server
+                .mock("GET", "/client-error")
+                .with_status(status)
+                .expect(1)
+                .create_async()
+                .await;
+
+            let result: Result<serde_json::Value, NHLApiError> = retrying_client(3)
+                .get_json(Endpoint::Custom(server.url()), "client-error", None)
+                .await;
+
+            mock.assert_async().await;
+            assert!(
+                !matches!(result, Err(NHLApiError::RetriesExhausted { .. }) | Ok(_)),
+                "status {} should fail without retrying",
+                status
+            );
+        }
+    }
+
+    #[tokio::test]
+    async fn test_get_json_respects_retry_after_on_429() {
+        let mut server = mockito::Server::new_async().await;
+        let limited = server
+            .mock("GET", "/busy")
+            .with_status(429)
+            .with_header("retry-after", "0")
+            .expect(1)
+            .create_async()
+            .await;
+        let ok = server
+            .mock("GET", "/busy")
+            .with_status(200)
+            .with_body("{}")
+            .create_async()
+            .await;
+
+        // A backoff this long would stall the test; `Retry-After: 0` must
+        // replace it.
+        let config = ClientConfig::default()
+            .with_max_retries(1)
+            .with_retry_backoff_base(Duration::from_secs(30));
+        let http_client = HttpClient::new(config).unwrap();
+        let result: Result<serde_json::Value, NHLApiError> = tokio::time::timeout(
+            Duration::from_secs(5),
+            http_client.get_json(Endpoint::Custom(server.url()), "busy", None),
+        )
+        .await
+        .expect("Retry-After should override the backoff delay");
+
+        limited.assert_async().await;
+        ok.assert_async().await;
+        assert!(result.is_ok());
+    }
+
+    #[tokio::test]
+    async fn test_get_json_retry_deadline_stops_retries() {
+        let mut server = mockito::Server::new_async().await;
+        let mock = server
+            .mock("GET", "/slow")
+            .with_status(503)
+            .expect(1)
+            .create_async()
+            .await;
+
+        let config = ClientConfig::default()
+            .with_max_retries(5)
+            .with_retry_backoff_base(Duration::from_secs(10))
+            .with_retry_deadline(Duration::from_secs(1));
+        let http_client = HttpClient::new(config).unwrap();
+        let result: Result<serde_json::Value, NHLApiError> = http_client
+            .get_json(Endpoint::Custom(server.url()), "slow", None)
+            .await;
+
+        mock.assert_async().await;
+        // No retry happened, so the original error is returned unwrapped.
+        assert!(matches!(result, Err(NHLApiError::ServerError { .. })));
+    }
+
+    #[tokio::test]
+    async fn test_get_json_retries_connection_errors() {
+        // Bind then drop a listener to get a local port nothing listens on.
+        let port = std::net::TcpListener::bind("127.0.0.1:0")
+            .unwrap()
+            .local_addr()
+            .unwrap()
+            .port();
+        let endpoint = Endpoint::Custom(format!("http://127.0.0.1:{}/", port));
+
+        let result: Result<serde_json::Value, NHLApiError> = retrying_client(1)
+            .get_json(endpoint, "unreachable", None)
+            .await;
+
+        match result.unwrap_err() {
+            NHLApiError::RetriesExhausted { attempts, source } => {
+                assert_eq!(attempts, 2);
+                assert!(matches!(*source, NHLApiError::RequestError(_)));
+            }
+            other => panic!("Expected RetriesExhausted, got {:?}", other),
+        }
+    }
+
+    // ===== Fail-soft list decoding tests =====
+
+    #[test]
+    fn test_prune_malformed_top_level_array() {
+        let mut value = serde_json::json!({"data": [1, "two", 3, null]});
+        let skipped = prune_malformed::<i32>(&mut value, "/data", "test");
+        assert_eq!(skipped, 2);
+        assert_eq!(value, serde_json::json!({"data": [1, 3]}));
+    }
+
+    #[test]
+    fn test_prune_malformed_wildcard_segment() {
+        let mut value = serde_json::json!({
+            "gameWeek": [
+                {"date": "a", "games": [1, "x"]},
+                {"date": "b", "games": ["y", 2, 3]}
+            ]
+        });
+        let skipped = prune_malformed::<i32>(&mut value, "/gameWeek/*/games", "test");
+        assert_eq!(skipped, 2);
+        assert_eq!(value["gameWeek"][0]["games"], serde_json::json!([1]));
+        assert_eq!(value["gameWeek"][1]["games"], serde_json::json!([2, 3]));
+    }
+
+    #[test]
+    fn test_prune_malformed_unresolved_pointer_is_noop() {
+        let original = serde_json::json!({"data": {"not": "an array"}});
+        let mut value = original.clone();
+        assert_eq!(prune_malformed::<i32>(&mut value, "/data", "test"), 0);
+        assert_eq!(prune_malformed::<i32>(&mut value, "/missing/0", "test"), 0);
+        assert_eq!(value, original);
+    }
+
+    /// Three standings rows, the middle one with a non-numeric `wins`.
+    const STANDINGS_WITH_CORRUPT_ROW: &str = r#"{
+        "standings": [
+            {
+                "divisionAbbrev": "A", "divisionName": "Atlantic",
+                "teamName": {"default": "Florida Panthers"},
+                "teamCommonName": {"default": "Panthers"},
+                "teamAbbrev": {"default": "FLA"}, "teamLogo": "",
+                "wins": 52, "losses": 24, "otLosses": 6, "points": 110
+            },
+            {
+                "divisionAbbrev": "A", "divisionName": "Atlantic",
+                "teamName": {"default": "Boston Bruins"},
+                "teamCommonName": {"default": "Bruins"},
+                "teamAbbrev": {"default": "BOS"}, "teamLogo": "",
+                "wins": "forty-seven", "losses": 20, "otLosses": 15, "points": 109
+            },
+            {
+                "divisionAbbrev": "A", "divisionName": "Atlantic",
+                "teamName": {"default": "Toronto Maple Leafs"},
+                "teamCommonName": {"default": "Maple Leafs"},
+                "teamAbbrev": {"default": "TOR"}, "teamLogo": "",
+                "wins": 46, "losses": 26, "otLosses": 10, "points": 102
+            }
+        ]
+    }"#;
+
+    async fn fetch_corrupt_standings(
+        config: ClientConfig,
+        body: &str,
+    ) -> (HttpClient, Result<StandingsResponse, NHLApiError>) {
+        let mut server = mockito::Server::new_async().await;
+        let _mock = server
+            .mock("GET", "/standings/now")
+            .with_status(200)
+            .with_body(body)
+            .create_async()
+            .await;
+
+        let http_client = HttpClient::new(config).unwrap();
+        let result = http_client
+            .get_json_list::<StandingsResponse, Standing>(
+                Endpoint::Custom(server.url()),
+                "standings/now",
+                None,
+                "/standings",
+            )
+            .await;
+        (http_client, result)
+    }
+
+    #[tokio::test]
+    async fn test_get_json_list_strict_mode_fails_on_corrupt_row() {
+        let (http_client, result) =
+            fetch_corrupt_standings(ClientConfig::default(), STANDINGS_WITH_CORRUPT_ROW).await;
+        assert!(matches!(result, Err(NHLApiError::DecodeError { .. })));
+        assert_eq!(http_client.stats().skipped_elements, 0);
+    }
+
+    #[tokio::test]
+    async fn test_get_json_list_lenient_mode_skips_corrupt_row() {
+        let config = ClientConfig::default().with_skip_malformed_elements(true);
+        let (http_client, result) =
+            fetch_corrupt_standings(config, STANDINGS_WITH_CORRUPT_ROW).await;
+
+        let standings = result.unwrap().standings;
+        let abbrevs: Vec<&str> = standings
+            .iter()
+            .map(|s| s.team_abbrev.default.as_str())
+            .collect();
+        assert_eq!(abbrevs, vec!["FLA", "TOR"]);
+        assert_eq!(http_client.stats().skipped_elements, 1);
+    }
+
+    #[tokio::test]
+    async fn test_get_json_list_missing_required_field() {
+        let body = STANDINGS_WITH_CORRUPT_ROW.replace(r#""wins": "forty-seven", "#, "");
+        assert!(!body.contains("forty-seven"));
+
+        let (http_client, result) = fetch_corrupt_standings(ClientConfig::default(), &body).await;
+        assert!(matches!(result, Err(NHLApiError::DecodeError { .. })));
+        assert_eq!(http_client.stats().skipped_elements, 0);
+
+        let config = ClientConfig::default().with_skip_malformed_elements(true);
+        let (http_client, result) = fetch_corrupt_standings(config, &body).await;
+        assert_eq!(result.unwrap().standings.len(), 2);
+        assert_eq!(http_client.stats().skipped_elements, 1);
+    }
+
+    // ===== Decode error context tests =====
+
+    #[derive(Debug, serde::Deserialize)]
+    #[allow(dead_code)]
+    struct NestedResponse {
+        id: i32,
+        #[serde(rename = "awayTeam")]
+        away_team: NestedTeam,
+    }
+
+    #[derive(Debug, serde::Deserialize)]
+    #[allow(dead_code)]
+    struct NestedTeam {
+        abbrev: String,
+        score: i32,
+    }
+
+    const WRONG_NESTED_TYPE: &str = r#"{"id": 1, "awayTeam": {"abbrev": "TOR", "score": "three"}}"#;
+
+    async fn fetch_wrong_nested_type(config: ClientConfig) -> NHLApiError {
+        let mut server = mockito::Server::new_async().await;
+        let _mock = server
+            .mock("GET", "/nested")
+            .with_status(200)
+            .with_body(WRONG_NESTED_TYPE)
+            .create_async()
+            .await;
+
+        HttpClient::new(config)
+            .unwrap()
+            .get_json::<NestedResponse>(Endpoint::Custom(server.url()), "nested", None)
+            .await
+            .unwrap_err()
+    }
+
+    #[tokio::test]
+    async fn test_get_json_decode_error_reports_url_and_pointer() {
+        let error = fetch_wrong_nested_type(ClientConfig::default()).await;
+
+        let message = error.to_string();
+        match error {
+            NHLApiError::DecodeError {
+                url, path, snippet, ..
+            } => {
+                assert!(url.ends_with("/nested"), "url: {}", url);
+                assert_eq!(path.as_deref(), Some("/awayTeam/score"));
+                assert!(snippet.is_none());
+            }
+            other => panic!("Expected DecodeError, got {:?}", other),
+        }
+        assert!(
+            message.contains("/nested at `/awayTeam/score`"),
+            "{}",
+            message
+        );
+        assert!(message.contains("invalid type"), "{}", message);
+    }
+
+    #[tokio::test]
+    async fn test_get_json_verbose_decode_error_adds_snippet() {
+        let config = ClientConfig::default().with_verbose_decode_errors(true);
+        let error = fetch_wrong_nested_type(config).await;
+
+        let message = error.to_string();
+        match error {
+            NHLApiError::DecodeError { path, snippet, .. } => {
+                assert_eq!(path.as_deref(), Some("/awayTeam/score"));
+                assert!(snippet.unwrap().contains(r#""three""#));
+            }
+            other => panic!("Expected DecodeError, got {:?}", other),
+        }
+        assert!(message.contains(r#"near `"#), "{}", message);
+    }
+
+    #[tokio::test]
+    async fn test_get_json_list_decode_error_pointer_includes_index() {
+        let mut server = mockito::Server::new_async().await;
+        let _mock = server
+            .mock("GET", "/list")
+            .with_status(200)
+            .with_body(
+                r#"{"items": [{"abbrev": "TOR", "score": 1}, {"abbrev": "MTL", "score": null}]}"#,
+            )
+            .create_async()
+            .await;
+
+        #[derive(Debug, serde::Deserialize)]
+        #[allow(dead_code)]
+        struct Items {
+            items: Vec<NestedTeam>,
+        }
+
+        let error = HttpClient::new(ClientConfig::default())
+            .unwrap()
+            .get_json_list::<Items, NestedTeam>(
+                Endpoint::Custom(server.url()),
+                "list",
+                None,
+                "/items",
+            )
+            .await
+            .unwrap_err();
+
+        assert!(
+            matches!(&error, NHLApiError::DecodeError { path: Some(path), .. } if path == "/items/1/score"),
+            "{:?}",
+            error
+        );
+    }
+
+    #[test]
+    fn test_json_pointer_escapes_tokens() {
+        let body = r#"{"a/b": {"c~d": [1, "x"]}}"#;
+        let mut deserializer = serde_json::Deserializer::from_str(body);
+        let error = serde_path_to_error::deserialize::<
+            _,
+            std::collections::BTreeMap<String, std::collections::BTreeMap<String, Vec<i32>>>,
+        >(&mut deserializer)
+        .unwrap_err();
+        assert_eq!(json_pointer(error.path()).as_deref(), Some("/a~1b/c~0d/1"));
+    }
+
+    #[tokio::test]
+    async fn test_get_json_verbose_decode_error_trailing_content() {
+        let mut server = mockito::Server::new_async().await;
+        let _mock = server
+            .mock("GET", "/trailing")
+            .with_status(200)
+            .with_body(r#"{"id": 1, "awayTeam": {"abbrev": "TOR", "score": 3}} junk"#)
+            .create_async()
+            .await;
+
+        let config = ClientConfig::default().with_verbose_decode_errors(true);
+        let error = HttpClient::new(config)
+            .unwrap()
+            .get_json::<NestedResponse>(Endpoint::Custom(server.url()), "trailing", None)
+            .await
+            .unwrap_err();
+
+        assert!(
+            matches!(error, NHLApiError::DecodeError { path: None, .. }),
+            "{:?}",
+            error
+        );
+    }
+
+    #[test]
+    fn test_decode_error_snippet_multiline_body() {
+        let body = "{\n  \"a\": 1,\n  \"b\": \"oops\"\n}";
+        let error =
+            serde_json::from_str::<std::collections::BTreeMap<String, i32>>(body).unwrap_err();
+        let snippet = decode_error_snippet(body, &error).unwrap();
+        assert!(snippet.contains(r#""b": "oops""#), "{}", snippet);
+        assert!(!snippet.contains('\n'));
+    }
+
+    #[test]
+    fn test_decode_error_snippet_respects_char_boundaries() {
+        let body = format!(r#"{{"name": "{}", "n": "x"}}"#, "é".repeat(60));
+        let error =
+            serde_json::from_str::<std::collections::BTreeMap<String, i32>>(&body).unwrap_err();
+        // Must not panic slicing inside a multi-byte character.
+        assert!(decode_error_snippet(&body, &error).is_some());
+    }
+
     // ===== Header / config surface tests (step 4.2) =====
 
     #[tokio::test]
@@ -888,6 +2585,73 @@ mod tests {
         );
     }
 
+    #[tokio::test]
+    async fn test_get_json_sends_custom_default_headers() {
+        let mut server = mockito::Server::new_async().await;
+        let mock = server
+            .mock("GET", "/headers")
+            .match_header("user-agent", "ops-tracked/1.0")
+            .match_header("x-contact", "ops@example.com")
+            .match_header("accept", "application/json")
+            .with_status(200)
+            .with_body("{}")
+            .create_async()
+            .await;
+
+        let config = ClientConfig::default()
+            .with_user_agent("ops-tracked/1.0")
+            .with_default_header("X-Contact", "ops@example.com");
+        let _: Value = HttpClient::new(config)
+            .unwrap()
+            .get_json(Endpoint::Custom(server.url()), "headers", None)
+            .await
+            .unwrap();
+        mock.assert_async().await;
+    }
+
+    #[tokio::test]
+    async fn test_get_json_default_header_overrides_accept() {
+        let mut server = mockito::Server::new_async().await;
+        let mock = server
+            .mock("GET", "/accept-override")
+            .match_header("accept", "application/vnd.custom+json")
+            .with_status(200)
+            .with_body("{}")
+            .create_async()
+            .await;
+
+        let config =
+            ClientConfig::default().with_default_header("Accept", "application/vnd.custom+json");
+        let _: Value = HttpClient::new(config)
+            .unwrap()
+            .get_json(Endpoint::Custom(server.url()), "accept-override", None)
+            .await
+            .unwrap();
+        mock.assert_async().await;
+    }
+
+    #[test]
+    fn test_http_client_rejects_invalid_default_header_name() {
+        let config = ClientConfig::default().with_default_header("bad header", "x");
+        match HttpClient::new(config) {
+            Err(NHLApiError::Other(message)) => {
+                assert!(message.contains("invalid header name"), "{}", message)
+            }
+            other => panic!("Expected Other error, got {:?}", other.map(|_| ())),
+        }
+    }
+
+    #[test]
+    fn test_http_client_rejects_invalid_default_header_value() {
+        let config = ClientConfig::default().with_default_header("x-trace", "line\nbreak");
+        match HttpClient::new(config) {
+            Err(NHLApiError::Other(message)) => {
+                assert!(message.contains("x-trace"), "{}", message)
+            }
+            other => panic!("Expected Other error, got {:?}", other.map(|_| ())),
+        }
+    }
+
     #[tokio::test]
     async fn test_get_json_uses_injected_http_client() {
         use serde::Deserialize;
@@ -928,4 +2692,286 @@ mod tests {
             "the injected client's marker header should reach the server"
         );
     }
+
+    // ===== Request observer tests =====
+
+    /// Records each hook call as `("request" | "response", attempt)` plus
+    /// the response infos.
+    #[derive(Default)]
+    struct RecordingObserver {
+        calls: Mutex<Vec<(&'static str, u32)>>,
+        responses: Mutex<Vec<ResponseInfo>>,
+    }
+
+    impl RequestObserver for RecordingObserver {
+        fn on_request(&self, request: &RequestInfo) {
+            self.calls
+                .lock()
+                .unwrap()
+                .push(("request", request.attempt));
+        }
+
+        fn on_response(&self, response: &ResponseInfo) {
+            self.calls
+                .lock()
+                .unwrap()
+                .push(("response", response.attempt));
+            self.responses.lock().unwrap().push(response.clone());
+        }
+    }
+
+    fn observed_client(config: ClientConfig) -> (HttpClient, Arc<RecordingObserver>) {
+        let observer = Arc::new(RecordingObserver::default());
+        let config = config.with_request_observer(observer.clone());
+        (HttpClient::new(config).unwrap(), observer)
+    }
+
+    #[tokio::test]
+    async fn test_observer_reports_each_attempt_of_a_retried_success() {
+        let mut server = mockito::Server::new_async().await;
+        let _unavailable = server
+            .mock("GET", "/flaky")
+            .with_status(503)
+            .expect(1)
+            .create_async()
+            .await;
+        let _succeeding = server
+            .mock("GET", "/flaky")
+            .with_status(200)
+            .with_body(r#"{"ok": true}"#)
+            .create_async()
+            .await;
+        let (http_client, observer) = observed_client(
+            ClientConfig::default()
+                .with_max_retries(1)
+                .with_retry_backoff_base(Duration::from_millis(1)),
+        );
+
+        let _: Value = http_client
+            .get_json(Endpoint::Custom(server.url()), "flaky", None)
+            .await
+            .unwrap();
+
+        assert_eq!(
+            *observer.calls.lock().unwrap(),
+            [
+                ("request", 1),
+                ("response", 1),
+                ("request", 2),
+                ("response", 2)
+            ]
+        );
+        let responses = observer.responses.lock().unwrap();
+        assert_eq!(
+            (responses[0].status, responses[0].outcome),
+            (Some(503), ResponseOutcome::ErrorStatus)
+        );
+        assert_eq!(
+            (responses[1].status, responses[1].outcome),
+            (Some(200), ResponseOutcome::Success)
+        );
+        assert_eq!(responses[1].url, format!("{}/flaky", server.url()));
+        assert_eq!(responses[1].resource, "flaky");
+    }
+
+    #[tokio::test]
+    async fn test_observer_reports_not_found() {
+        let mut server = mockito::Server::new_async().await;
+        let _mock = server
+            .mock("GET", "/missing")
+            .with_status(404)
+            .create_async()
+            .await;
+        let (http_client, observer) = observed_client(ClientConfig::default());
+
+        let result: Result<Value, NHLApiError> = http_client
+            .get_json(Endpoint::Custom(server.url()), "missing", None)
+            .await;
+
+        assert!(matches!(result, Err(NHLApiError::ResourceNotFound { .. })));
+        assert_eq!(
+            *observer.calls.lock().unwrap(),
+            [("request", 1), ("response", 1)]
+        );
+        let response = &observer.responses.lock().unwrap()[0];
+        assert_eq!(
+            (response.status, response.outcome),
+            (Some(404), ResponseOutcome::ErrorStatus)
+        );
+    }
+
+    #[tokio::test]
+    async fn test_observer_reports_decode_failure() {
+        let mut server = mockito::Server::new_async().await;
+        let _mock = server
+            .mock("GET", "/standings/now")
+            .with_status(200)
+            .with_body(r#"{"standings": [{"teamName": 7}]}"#)
+            .create_async()
+            .await;
+        let (http_client, observer) = observed_client(ClientConfig::default());
+
+        let result: Result<StandingsResponse, NHLApiError> = http_client
+            .get_json_list::<_, Standing>(
+                Endpoint::Custom(server.url()),
+                "standings/now",
+                None,
+                "/standings",
+            )
+            .await;
+
+        assert!(matches!(result, Err(NHLApiError::DecodeError { .. })));
+        assert_eq!(
+            *observer.calls.lock().unwrap(),
+            [("request", 1), ("response", 1)]
+        );
+        let response = &observer.responses.lock().unwrap()[0];
+        assert_eq!(
+            (response.status, response.outcome),
+            (Some(200), ResponseOutcome::Decode)
+        );
+    }
+
+    #[tokio::test]
+    async fn test_observer_skips_cache_hits() {
+        let mut server = mockito::Server::new_async().await;
+        let _mock = server
+            .mock("GET", "/cached")
+            .with_status(200)
+            .with_body("{}")
+            .expect(1)
+            .create_async()
+            .await;
+        let (http_client, observer) =
+            observed_client(ClientConfig::default().with_cache_ttl(Duration::from_secs(60)));
+
+        for _ in 0..2 {
+            let _: Value = http_client
+                .get_json(Endpoint::Custom(server.url()), "cached", None)
+                .await
+                .unwrap();
+        }
+
+        assert_eq!(
+            *observer.calls.lock().unwrap(),
+            [("request", 1), ("response", 1)]
+        );
+    }
+
+    // ===== Custom transport tests =====
+
+    /// A resource and its query parameters, as a transport received them.
+    type TransportRequest = (String, Option<HashMap<String, String>>);
+
+    /// Answers from a script of results, one per call, recording the
+    /// requests it was asked for.
+    struct ScriptedTransport {
+        script: Mutex<Vec<Result<String, NHLApiError>>>,
+        requests: Mutex<Vec<TransportRequest>>,
+    }
+
+    impl ScriptedTransport {
+        fn new(mut script: Vec<Result<String, NHLApiError>>) -> Arc<Self> {
+            script.reverse();
+            Arc::new(Self {
+                script: Mutex::new(script),
+                requests: Mutex::default(),
+            })
+        }
+    }
+
+    impl Transport for ScriptedTransport {
+        fn get<'a>(
+            &'a self,
+            _endpoint: &'a Endpoint,
+            resource: &'a str,
+            params: Option<&'a HashMap<String, String>>,
+        ) -> BoxFuture<'a, Result<String, NHLApiError>> {
+            self.requests
+                .lock()
+                .unwrap()
+                .push((resource.to_string(), params.cloned()));
+            let next = self.script.lock().unwrap().pop().expect("script exhausted");
+            future::ready(next).boxed()
+        }
+    }
+
+    fn server_error() -> NHLApiError {
+        NHLApiError::ServerError {
+            message: "unavailable".to_string(),
+            status_code: 503,
+        }
+    }
+
+    #[tokio::test]
+    async fn test_custom_transport_serves_and_retries() {
+        let transport =
+            ScriptedTransport::new(vec![Err(server_error()), Ok(r#"{"ok": true}"#.to_string())]);
+        let (http_client, observer) = observed_client(
+            ClientConfig::default()
+                .with_transport(transport.clone())
+                .with_max_retries(1)
+                .with_retry_backoff_base(Duration::from_millis(1)),
+        );
+        let params = HashMap::from([("date".to_string(), "2024-03-09".to_string())]);
+
+        let body: Value = http_client
+            .get_json(Endpoint::ApiWebV1, "score/now", Some(params.clone()))
+            .await
+            .unwrap();
+
+        assert_eq!(body["ok"], true);
+        assert_eq!(
+            *transport.requests.lock().unwrap(),
+            vec![
+                ("score/now".to_string(), Some(params.clone())),
+                ("score/now".to_string(), Some(params)),
+            ]
+        );
+        let responses = observer.responses.lock().unwrap();
+        assert_eq!(
+            (responses[0].status, responses[0].outcome),
+            (Some(503), ResponseOutcome::ErrorStatus)
+        );
+        assert_eq!(
+            (responses[1].status, responses[1].outcome),
+            (None, ResponseOutcome::Success)
+        );
+        assert_eq!(responses[1].url, "https://api-web.nhle.com/v1/score/now");
+    }
+
+    #[tokio::test]
+    async fn test_custom_transport_not_found_is_not_retried() {
+        let transport = ScriptedTransport::new(vec![Err(NHLApiError::ResourceNotFound {
+            message: "missing".to_string(),
+            status_code: 404,
+        })]);
+        let config = ClientConfig::default()
+            .with_transport(transport.clone())
+            .with_max_retries(3);
+        let http_client = HttpClient::new(config).unwrap();
+
+        let result: Result<Value, NHLApiError> = http_client
+            .get_json(Endpoint::ApiWebV1, "missing", None)
+            .await;
+
+        assert!(matches!(result, Err(NHLApiError::ResourceNotFound { .. })));
+        assert_eq!(transport.requests.lock().unwrap().len(), 1);
+    }
+
+    #[tokio::test]
+    async fn test_custom_transport_requests_stats_resources_by_language() {
+        let transport = ScriptedTransport::new(vec![Ok("{}".to_string())]);
+        let config = ClientConfig::default()
+            .with_transport(transport.clone())
+            .with_stats_language("fr");
+        let http_client = HttpClient::new(config).unwrap();
+
+        let _: Value = http_client
+            .get_json(Endpoint::ApiStats, "franchise", None)
+            .await
+            .unwrap();
+
+        assert_eq!(transport.requests.lock().unwrap()[0].0, "fr/franchise");
+    }
 }
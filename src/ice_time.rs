@@ -0,0 +1,253 @@
+use serde::de::{Error as DeError, Visitor};
+use serde::{Deserialize, Deserializer, Serialize, Serializer};
+use std::fmt;
+use std::iter::Sum;
+use std::ops::{Add, AddAssign, Sub};
+use std::str::FromStr;
+use thiserror::Error;
+
+/// Error returned when a string is not a `"MM:SS"` time.
+#[derive(Debug, Clone, PartialEq, Eq, Error)]
+#[error("invalid ice time: {0:?} (expected \"MM:SS\")")]
+pub struct ParseIceTimeError(String);
+
+/// A time on ice, game clock or shift length, stored as whole seconds.
+///
+/// The API sends these as `"MM:SS"` strings. Minutes are not capped at 59:
+/// goalie TOI is often `"59:38"` or more with overtime, and season aggregates
+/// look like `"995:36"`. Serializes back to the same `"MM:SS"` form.
+///
+/// ```
+/// use nhl_api::IceTime;
+///
+/// let first: IceTime = "18:15".parse().unwrap();
+/// let second: IceTime = "20:50".parse().unwrap();
+/// assert_eq!((first + second).to_string(), "39:05");
+/// ```
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub struct IceTime(u32);
+
+impl IceTime {
+    /// No time on ice (`"00:00"`).
+    pub const ZERO: Self = Self(0);
+
+    /// Create from a number of seconds.
+    pub const fn from_seconds(seconds: u32) -> Self {
+        Self(seconds)
+    }
+
+    /// Total seconds.
+    pub const fn as_seconds(&self) -> u32 {
+        self.0
+    }
+
+    /// Whole minutes (the `MM` part, may exceed 59).
+    pub const fn minutes(&self) -> u32 {
+        self.0 / 60
+    }
+
+    /// The `SS` part (0-59).
+    pub const fn seconds(&self) -> u32 {
+        self.0 % 60
+    }
+
+    /// Total minutes as a fraction, e.g. `18.25` for `"18:15"`.
+    pub fn as_minutes_f64(&self) -> f64 {
+        f64::from(self.0) / 60.0
+    }
+}
+
+impl fmt::Display for IceTime {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{:02}:{:02}", self.minutes(), self.seconds())
+    }
+}
+
+impl FromStr for IceTime {
+    type Err = ParseIceTimeError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let invalid = || ParseIceTimeError(s.to_string());
+        let (minutes, seconds) = s.split_once(':').ok_or_else(invalid)?;
+        let all_digits = |part: &str| !part.is_empty() && part.bytes().all(|b| b.is_ascii_digit());
+        if !all_digits(minutes) || seconds.len() != 2 || !all_digits(seconds) {
+            return Err(invalid());
+        }
+        let minutes: u32 = minutes.parse().map_err(|_| invalid())?;
+        let seconds: u32 = seconds.parse().map_err(|_| invalid())?;
+        if seconds >= 60 {
+            return Err(invalid());
+        }
+        minutes
+            .checked_mul(60)
+            .and_then(|total| total.checked_add(seconds))
+            .map(Self)
+            .ok_or_else(invalid)
+    }
+}
+
+impl Add for IceTime {
+    type Output = Self;
+
+    fn add(self, rhs: Self) -> Self {
+        Self(self.0 + rhs.0)
+    }
+}
+
+impl AddAssign for IceTime {
+    fn add_assign(&mut self, rhs: Self) {
+        self.0 += rhs.0;
+    }
+}
+
+impl Sub for IceTime {
+    type Output = Self;
+
+    /// Saturates at [`IceTime::ZERO`].
+    fn sub(self, rhs: Self) -> Self {
+        Self(self.0.saturating_sub(rhs.0))
+    }
+}
+
+impl Sum for IceTime {
+    fn sum<I: Iterator<Item = Self>>(iter: I) -> Self {
+        iter.fold(Self::ZERO, Add::add)
+    }
+}
+
+impl<'a> Sum<&'a IceTime> for IceTime {
+    fn sum<I: Iterator<Item = &'a Self>>(iter: I) -> Self {
+        iter.copied().sum()
+    }
+}
+
+impl From<IceTime> for std::time::Duration {
+    fn from(time: IceTime) -> Self {
+        std::time::Duration::from_secs(u64::from(time.0))
+    }
+}
+
+impl From<IceTime> for chrono::Duration {
+    fn from(time: IceTime) -> Self {
+        chrono::Duration::seconds(i64::from(time.0))
+    }
+}
+
+impl Serialize for IceTime {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        serializer.collect_str(self)
+    }
+}
+
+impl<'de> Deserialize<'de> for IceTime {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        struct IceTimeVisitor;
+
+        impl Visitor<'_> for IceTimeVisitor {
+            type Value = IceTime;
+
+            fn expecting(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+                f.write_str("an ice time as an \"MM:SS\" string")
+            }
+
+            fn visit_str<E>(self, value: &str) -> Result<IceTime, E>
+            where
+                E: DeError,
+            {
+                value.parse().map_err(E::custom)
+            }
+        }
+
+        deserializer.deserialize_str(IceTimeVisitor)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_ice_time_parse_and_display() {
+        let toi: IceTime = "18:15".parse().unwrap();
+        assert_eq!(toi.as_seconds(), 1095);
+        assert_eq!(toi.minutes(), 18);
+        assert_eq!(toi.seconds(), 15);
+        assert_eq!(toi.to_string(), "18:15");
+        assert_eq!(IceTime::ZERO.to_string(), "00:00");
+        assert_eq!(IceTime::from_seconds(5).to_string(), "00:05");
+    }
+
+    #[test]
+    fn test_ice_time_minutes_past_sixty() {
+        assert_eq!("59:38".parse::<IceTime>().unwrap().as_seconds(), 3578);
+        assert_eq!("65:02".parse::<IceTime>().unwrap().as_seconds(), 3902);
+
+        let season: IceTime = "995:36".parse().unwrap();
+        assert_eq!(season.as_seconds(), 995 * 60 + 36);
+        assert_eq!(season.to_string(), "995:36");
+    }
+
+    #[test]
+    fn test_ice_time_parse_rejects_malformed() {
+        for input in [
+            "", "18", "18:", ":15", "18:5", "18:60", "-1:00", "18:15:00", "aa:bb",
+        ] {
+            assert!(input.parse::<IceTime>().is_err(), "{:?}", input);
+        }
+        assert!("99999999:00".parse::<IceTime>().is_err());
+    }
+
+    #[test]
+    fn test_ice_time_arithmetic() {
+        let a = IceTime::from_seconds(90);
+        let b = IceTime::from_seconds(45);
+        assert_eq!(a + b, IceTime::from_seconds(135));
+        assert_eq!(a - b, IceTime::from_seconds(45));
+        assert_eq!(b - a, IceTime::ZERO);
+
+        let mut total = IceTime::ZERO;
+        total += a;
+        assert_eq!(total, a);
+        assert!(b < a);
+
+        let times = [a, b, b];
+        assert_eq!(times.iter().sum::<IceTime>(), IceTime::from_seconds(180));
+        assert_eq!(
+            times.into_iter().sum::<IceTime>(),
+            IceTime::from_seconds(180)
+        );
+        assert_eq!(Vec::<IceTime>::new().iter().sum::<IceTime>(), IceTime::ZERO);
+    }
+
+    #[test]
+    fn test_ice_time_duration_conversions() {
+        let toi: IceTime = "18:15".parse().unwrap();
+        assert_eq!(
+            std::time::Duration::from(toi),
+            std::time::Duration::from_secs(1095)
+        );
+        assert_eq!(chrono::Duration::from(toi), chrono::Duration::seconds(1095));
+        assert_eq!(toi.as_minutes_f64(), 18.25);
+    }
+
+    #[test]
+    fn test_ice_time_serde_round_trip() {
+        for text in ["\"00:00\"", "\"18:15\"", "\"59:38\"", "\"995:36\""] {
+            let toi: IceTime = serde_json::from_str(text).unwrap();
+            assert_eq!(serde_json::to_string(&toi).unwrap(), text);
+        }
+    }
+
+    #[test]
+    fn test_ice_time_deserialize_rejects_non_strings() {
+        assert!(serde_json::from_str::<IceTime>("1095").is_err());
+        assert!(serde_json::from_str::<IceTime>("null").is_err());
+        assert!(serde_json::from_str::<IceTime>("\"18:75\"").is_err());
+    }
+}
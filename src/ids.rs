@@ -1,3 +1,5 @@
+use crate::date::Season;
+use crate::types::GameType;
 use serde::de::{Error as DeError, Visitor};
 use serde::{Deserialize, Deserializer, Serialize, Serializer};
 use std::fmt;
@@ -119,6 +121,25 @@ numeric_id!(
     GameId, GameIdVisitor, "game ID"
 );
 
+impl GameId {
+    /// The season encoded in the first four digits, e.g. 2023-2024 for
+    /// `2023030111`. `None` unless the id has exactly 10 digits.
+    pub fn season(&self) -> Option<Season> {
+        if !(1_000_000_000..=9_999_999_999).contains(&self.0) {
+            return None;
+        }
+        u16::try_from(self.0 / 1_000_000).ok().map(Season::new)
+    }
+
+    /// The game type encoded in digits 5-6, e.g. `Playoffs` for
+    /// `2023030111`. `None` for ids that aren't 10 digits or carry an unknown
+    /// code.
+    pub fn game_type(&self) -> Option<GameType> {
+        self.season()?;
+        GameType::from_int((self.0 / 10_000 % 100) as i32)
+    }
+}
+
 numeric_id!(
     /// A unique NHL player identifier.
     ///
@@ -472,6 +493,27 @@ mod tests {
         assert_eq!(set.len(), 2);
     }
 
+    #[test]
+    fn test_game_id_season_and_game_type() {
+        let playoff = GameId::new(2023030111);
+        assert_eq!(playoff.season(), Some(Season::new(2023)));
+        assert_eq!(playoff.game_type(), Some(GameType::Playoffs));
+
+        let regular = GameId::new(2024020001);
+        assert_eq!(regular.season().map(|s| s.id()), Some(20242025));
+        assert_eq!(regular.game_type(), Some(GameType::RegularSeason));
+    }
+
+    #[test]
+    fn test_game_id_season_and_game_type_invalid() {
+        for id in [0, 12345, -2023030111, 20230301110] {
+            assert_eq!(GameId::new(id).season(), None, "{}", id);
+            assert_eq!(GameId::new(id).game_type(), None, "{}", id);
+        }
+        // Well-formed length, unknown game type code.
+        assert_eq!(GameId::new(2023990001).game_type(), None);
+    }
+
     #[test]
     fn test_game_id_player_id_team_id_are_distinct_types() {
         // Compile-time check that the macro-generated types are not
@@ -0,0 +1,43 @@
+//! `Instant` for cache expiry and retry deadlines.
+//!
+//! `std::time::Instant::now` panics on `wasm32-unknown-unknown`, so there
+//! it is backed by the wall clock (`chrono::Utc::now`, i.e. `Date.now()`)
+//! instead, with millisecond resolution.
+
+#[cfg(not(target_arch = "wasm32"))]
+pub(crate) use std::time::Instant;
+
+#[cfg(target_arch = "wasm32")]
+pub(crate) use wasm::Instant;
+
+#[cfg(target_arch = "wasm32")]
+mod wasm {
+    use chrono::{DateTime, TimeDelta, Utc};
+    use std::ops::Add;
+    use std::time::Duration;
+
+    #[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord)]
+    pub(crate) struct Instant(DateTime<Utc>);
+
+    impl Instant {
+        pub(crate) fn now() -> Self {
+            Self(Utc::now())
+        }
+
+        /// Zero if the wall clock went backwards.
+        pub(crate) fn elapsed(&self) -> Duration {
+            (Utc::now() - self.0).to_std().unwrap_or_default()
+        }
+    }
+
+    impl Add<Duration> for Instant {
+        type Output = Self;
+
+        fn add(self, duration: Duration) -> Self {
+            let later = TimeDelta::from_std(duration)
+                .ok()
+                .and_then(|delta| self.0.checked_add_signed(delta));
+            Self(later.unwrap_or(DateTime::<Utc>::MAX_UTC))
+        }
+    }
+}
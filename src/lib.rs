@@ -1,50 +1,109 @@
+pub mod analytics;
+#[cfg(feature = "binary-cache")]
+pub mod binary;
+#[cfg(feature = "blocking")]
+pub mod blocking;
+mod bulk;
+mod cache;
 mod client;
+mod client_at;
+pub mod compat;
+pub mod concurrency;
 mod config;
+#[cfg(feature = "csv")]
+pub mod csv_export;
 mod date;
 mod error;
-#[cfg(feature = "fixtures")]
+pub mod features;
+#[cfg(any(test, feature = "fixtures"))]
 pub mod fixtures;
+pub mod franchises;
+mod game_watch;
 mod http_client;
+mod ice_time;
 mod ids;
+mod instant;
+pub mod observer;
+pub mod poll;
+mod stats;
+mod team_abbrev;
+pub mod team_branding;
+#[cfg(any(test, feature = "test-util"))]
+pub mod test_util;
+pub mod transport;
 mod types;
 
 // Client
-pub use client::Client;
+pub use client::{Client, SHIFT_CHART_BATCH_SIZE};
+pub use client_at::ClientAt;
+
+// Live game updates
+pub use game_watch::{GameUpdate, GameWatch, INTERMISSION_POLL_INTERVAL};
+
+// Bulk fetches
+pub use bulk::{GameResource, DEFAULT_BULK_CONCURRENCY};
 
 // Config
-pub use config::{ClientConfig, DEFAULT_USER_AGENT};
+pub use config::{ClientConfig, LocaleFallback, DEFAULT_STATS_LANGUAGE, DEFAULT_USER_AGENT};
 
 // Date and Season
-pub use date::{GameDate, Season, SeasonError};
+pub use date::{GameDate, Season, SeasonError, SeasonRange};
 
 // Error types
 pub use error::NHLApiError;
 
+// Stats
+pub use stats::ClientStats;
+
 // IDs
 pub use ids::{GameId, PlayerId, TeamId};
 
+// Team abbreviations
+pub use team_abbrev::{ParseTeamAbbrevError, TeamAbbrev};
+
+// Time on ice
+pub use ice_time::{IceTime, ParseIceTimeError};
+
 // Common types
 pub use types::{
-    Conference, Division, Franchise, FranchisesResponse, LocalizedString, Roster, RosterPlayer,
-    Team,
+    country_name, BirthInfo, Conference, Division, Franchise, FranchisesResponse, LocalizedString,
+    Roster, RosterChange, RosterDiff, RosterPlayer, Team,
 };
 
+// Start times
+pub use types::{GameVenueTime, ParseGameTimeError};
+
 // Boxscore types
 pub use types::{
-    Boxscore, BoxscoreTeam, GameClock, GoalieStats, PeriodDescriptor, PlayerByGameStats,
-    SkaterStats, SpecialEvent, TeamGameStats, TeamPlayerStats, TvBroadcast,
+    Boxscore, BoxscorePlayer, BoxscoreSummary, BoxscoreTeam, GameClock, GoalieStats,
+    ParseSavesShotsError, PeriodDescriptor, PlayerByGameStats, SavesShots, SkaterStats,
+    SpecialEvent, StatProvenance, StatSource, TeamGameStats, TeamPlayerStats, TvBroadcast,
 };
 
 // Club stats types
-pub use types::{ClubGoalieStats, ClubSkaterStats, ClubStats, SeasonGameTypes};
+pub use types::{
+    ClubGoalieStats, ClubSkaterStats, ClubStats, ClubStatsTotals, GoalieComparison,
+    MatchupClubStats, SeasonGameTypes,
+};
 
 // Game center types
 pub use types::{
-    AssistSummary, GameMatchup, GameOutcome, GameSituation, GameStory, GameSummary, GoalSummary,
-    MatchupTeam, PenaltyPlayer, PenaltySummary, PeriodPenalties, PeriodScoring, PlayByPlay,
-    PlayEvent, PlayEventDetails, PlayEventType, RosterSpot, ScratchedPlayer, SeasonSeriesMatchup,
-    SeriesGame, SeriesGameInfo, SeriesTeam, SeriesWins, ShiftChart, ShiftEntry, ShootoutAttempt,
-    StoryTeam, TeamGameInfo, ThreeStar,
+    AssistSummary, GameExistence, GameMatchup, GameOfficials, GameOutcome, GameSituation,
+    GameStory, GameSummary, GoalSummary, LineCombination, MatchupTeam, Official, OfficialRole,
+    Officials, OnIcePlayer, OnIceSituation, PenaltyBoxEntry, PenaltyDescKey, PenaltyPlayer,
+    PenaltySummary, PeriodPenalties, PeriodScoring, PeriodShots, PlayByPlay, PlayEvent,
+    PlayEventDetails, PlayEventType, PlayerEventCounts, RightRailTeamStat, RosterSpot,
+    ScratchedPlayer, SeasonSeriesMatchup, SeriesGame, SeriesGameInfo, SeriesTeam, SeriesTeamRecord,
+    SeriesWins, ShiftChart, ShiftEntry, ShootoutAttempt, SituationTeam, SogPoint, SogValidation,
+    StoryTeam, TeamGameInfo, TeamStatValue, ThreeStar,
+};
+
+// Rink geometry
+pub use types::rink;
+
+// Legacy (pre-2010) play-by-play
+pub use types::{
+    LegacyConversion, LegacyPlayByPlay, LegacyPlayEvent, LegacyRosterSpot, LegacyTeam,
 };
 
 // Game state types
@@ -55,20 +114,37 @@ pub use types::GameType;
 
 // Enum types
 pub use types::{
-    DefendingSide, GameScheduleState, GoalieDecision, Handedness, HomeRoad, PeriodType, Position,
-    UnknownEnumValue, ZoneCode,
+    BroadcastMarket, DefendingSide, GameScheduleState, GoalieDecision, Handedness, HomeRoad,
+    PenaltySeverity, PeriodType, Position, UnknownEnumValue, ZoneCode,
+};
+
+// Draft types
+pub use types::{
+    prospect_category, DraftPick, DraftPicks, DraftRankings, ProspectCategory, RankedProspect,
+};
+
+// Stat leaders
+pub use types::{
+    GoalieLeaderCategory, GoalieStatsLeaders, SkaterLeaderCategory, SkaterStatsLeaders, StatLeader,
 };
 
 // Player types
 pub use types::{
-    Award, AwardSeason, CareerTotals, DraftDetails, FeaturedStats, GameLog, PlayerGameLog,
-    PlayerLanding, PlayerSearchResult, PlayerStats, SeasonTotal,
+    Award, AwardSeason, CareerTotals, DraftDetails, FeaturedStats, GameLog, GameLogTotals,
+    PlayerGameLog, PlayerLanding, PlayerSearchResult, PlayerStats, SeasonTotal, SpotlightPlayer,
+};
+
+// Playoff types
+pub use types::{
+    PlayoffSeriesGame, PlayoffSeriesGameTeam, PlayoffSeriesSchedule, PlayoffSeriesStatus,
+    PlayoffSeriesTeam,
 };
 
 // Schedule types
 pub use types::{
-    DailySchedule, DailyScores, GameDay, GameScore, ScheduleGame, ScheduleTeam,
-    TeamScheduleResponse, WeeklyScheduleResponse,
+    DailySchedule, DailyScores, DecidingPlayer, GameDay, GameScore, OddsPartner,
+    ParseTeamRecordError, ScheduleGame, ScheduleTeam, ScoreboardGame, TeamRecord,
+    TeamScheduleResponse, TeamToday, WeeklyScheduleResponse,
 };
 
 // Standings types
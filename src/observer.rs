@@ -0,0 +1,128 @@
+//! Hooks into the request lifecycle, for metrics and tracing.
+//!
+//! A [`RequestObserver`] set with
+//! [`ClientConfig::with_request_observer`](crate::ClientConfig::with_request_observer)
+//! hears about every HTTP attempt the client makes:
+//! [`on_request`](RequestObserver::on_request) just before it is sent and
+//! [`on_response`](RequestObserver::on_response) once its outcome is known.
+//! A retried request reports each attempt separately, numbered from 1.
+//! Successful attempts are reported after the body has been decoded, so a
+//! payload that doesn't deserialize shows up as [`ResponseOutcome::Decode`].
+//! Responses served from the cache make no request and aren't reported.
+//!
+//! Without an observer none of this runs: the client skips building the
+//! info structs and reading the clock.
+
+use std::fmt;
+use std::sync::Arc;
+use std::time::Duration;
+
+use crate::error::NHLApiError;
+
+/// An attempt about to be sent.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct RequestInfo {
+    /// Full URL, without the query string.
+    pub url: String,
+    /// Path relative to the host's base URL, e.g. `"standings/now"`.
+    pub resource: String,
+    /// 1 for the first attempt, 2 for the first retry, and so on.
+    pub attempt: u32,
+}
+
+/// How an attempt ended.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum ResponseOutcome {
+    /// The body was received and decoded.
+    Success,
+    /// The server answered with a non-success status.
+    ErrorStatus,
+    /// The body was an HTML page instead of JSON.
+    NonJson,
+    /// Connecting, sending or reading the body failed.
+    Transport,
+    /// The body didn't deserialize into the expected type.
+    Decode,
+}
+
+impl ResponseOutcome {
+    /// The outcome a failed attempt's error stands for.
+    pub(crate) fn of_error(error: &NHLApiError) -> Self {
+        match error {
+            NHLApiError::RequestError(_) => Self::Transport,
+            NHLApiError::NonJsonResponse { .. } => Self::NonJson,
+            NHLApiError::DecodeError { .. } => Self::Decode,
+            NHLApiError::RetriesExhausted { source, .. } => Self::of_error(source),
+            _ => Self::ErrorStatus,
+        }
+    }
+}
+
+/// A finished attempt.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ResponseInfo {
+    /// Full URL, without the query string.
+    pub url: String,
+    /// Path relative to the host's base URL.
+    pub resource: String,
+    /// Same numbering as [`RequestInfo::attempt`].
+    pub attempt: u32,
+    /// HTTP status; `None` when no response arrived or a custom
+    /// [`Transport`](crate::transport::Transport) answered.
+    pub status: Option<u16>,
+    /// From sending the request to reading the body (or failing to).
+    pub elapsed: Duration,
+    pub outcome: ResponseOutcome,
+}
+
+/// Receives request lifecycle events. Both methods default to doing nothing,
+/// so an implementation only overrides what it needs.
+///
+/// Called inline on the request path: keep the work cheap, or hand it off.
+pub trait RequestObserver: Send + Sync {
+    fn on_request(&self, _request: &RequestInfo) {}
+
+    fn on_response(&self, _response: &ResponseInfo) {}
+}
+
+/// The configured observer, with a `Debug` impl so [`ClientConfig`]
+/// can keep deriving it.
+///
+/// [`ClientConfig`]: crate::ClientConfig
+#[derive(Clone)]
+pub(crate) struct SharedObserver(pub(crate) Arc<dyn RequestObserver>);
+
+impl fmt::Debug for SharedObserver {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str("RequestObserver")
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_response_outcome_of_error() {
+        let status = NHLApiError::ResourceNotFound {
+            message: String::new(),
+            status_code: 404,
+        };
+        assert_eq!(
+            ResponseOutcome::of_error(&status),
+            ResponseOutcome::ErrorStatus
+        );
+        let exhausted = NHLApiError::RetriesExhausted {
+            attempts: 2,
+            source: Box::new(NHLApiError::NonJsonResponse {
+                url: String::new(),
+                content_type: None,
+                snippet: String::new(),
+            }),
+        };
+        assert_eq!(
+            ResponseOutcome::of_error(&exhausted),
+            ResponseOutcome::NonJson
+        );
+    }
+}
@@ -0,0 +1,407 @@
+//! Polling streams that re-fetch an endpoint on an interval.
+//!
+//! A [`Poller`] fetches once as soon as it is first polled, then again
+//! after every `interval`. Errors are yielded like snapshots and polling
+//! carries on, so the stream never ends on its own; drop it to stop.
+//! [`Client::watch_scores`] and [`Client::watch_play_by_play`] are built on
+//! it, and [`Poller::new`] takes any fetch closure for other endpoints.
+//!
+//! The wait between polls goes through a [`Sleeper`] (by default
+//! `TokioSleeper`). [`PollHandle::poll_now`] cuts the current wait short,
+//! e.g. when the user taps "refresh"; tests can swap in
+//! `test_util::PollController` to step through polls without sleeping.
+//!
+//! On `wasm32` there is no tokio timer (`tokio::time::sleep` panics there),
+//! so `TokioSleeper` doesn't exist and the default is [`ManualSleeper`]: a
+//! poller fetches once, then only on [`PollHandle::poll_now`]. For polling
+//! on an interval in the browser, pass [`Poller::with_sleeper`] a
+//! [`Sleeper`] built on the JS timers (e.g. `gloo-timers`).
+//!
+//! ```no_run
+//! # async fn example(client: &nhl_api::Client) {
+//! use futures_util::StreamExt;
+//! use std::time::Duration;
+//!
+//! let mut scores = client.watch_scores(None, Duration::from_secs(30));
+//! let refresh = scores.handle();
+//! // ... hand `refresh` to the UI, which calls `refresh.poll_now()` ...
+//! while let Some(result) = scores.next().await {
+//!     match result {
+//!         Ok(scores) => println!("{} games", scores.games.len()),
+//!         Err(e) => eprintln!("poll failed: {}", e),
+//!     }
+//! }
+//! # }
+//! ```
+
+use futures_util::future::{self, BoxFuture, FutureExt};
+use futures_util::stream::{self, BoxStream, Stream, StreamExt};
+use std::fmt;
+use std::future::Future;
+use std::pin::{pin, Pin};
+use std::sync::Arc;
+use std::task::{Context, Poll};
+use std::time::Duration;
+use tokio::sync::Notify;
+
+use crate::client::Client;
+use crate::date::GameDate;
+use crate::error::NHLApiError;
+use crate::ids::GameId;
+use crate::types::{DailyScores, PlayByPlay};
+
+/// Waits between polls. Implement it to control how a [`Poller`] passes
+/// time; `TokioSleeper` is the default, [`ManualSleeper`] on `wasm32`.
+pub trait Sleeper: Send + Sync {
+    /// A future that completes once `duration` has passed.
+    fn sleep(&self, duration: Duration) -> BoxFuture<'static, ()>;
+}
+
+/// [`Sleeper`] backed by [`tokio::time::sleep`], so it follows tokio's
+/// paused clock in tests. Not available on `wasm32`, which has no tokio
+/// timer.
+#[cfg(not(target_arch = "wasm32"))]
+#[derive(Debug, Clone, Copy, Default)]
+pub struct TokioSleeper;
+
+#[cfg(not(target_arch = "wasm32"))]
+impl Sleeper for TokioSleeper {
+    fn sleep(&self, duration: Duration) -> BoxFuture<'static, ()> {
+        tokio::time::sleep(duration).boxed()
+    }
+}
+
+/// [`Sleeper`] whose waits never end by themselves, so the [`Poller`]
+/// fetches again only on [`PollHandle::poll_now`]. The default on `wasm32`;
+/// elsewhere, use it for refresh-on-demand polling.
+#[derive(Debug, Clone, Copy, Default)]
+pub struct ManualSleeper;
+
+impl Sleeper for ManualSleeper {
+    fn sleep(&self, _duration: Duration) -> BoxFuture<'static, ()> {
+        future::pending().boxed()
+    }
+}
+
+#[cfg(not(target_arch = "wasm32"))]
+fn default_sleeper() -> Arc<dyn Sleeper> {
+    Arc::new(TokioSleeper)
+}
+
+#[cfg(target_arch = "wasm32")]
+fn default_sleeper() -> Arc<dyn Sleeper> {
+    Arc::new(ManualSleeper)
+}
+
+/// Forces an immediate poll of the [`Poller`] it came from. Cheap to clone
+/// and usable from any task.
+#[derive(Debug, Clone, Default)]
+pub struct PollHandle {
+    notify: Arc<Notify>,
+}
+
+impl PollHandle {
+    /// Ends the current wait so the next poll happens right away. Called
+    /// while a fetch is in flight, the following wait is skipped instead;
+    /// several calls before the next poll count as one.
+    pub fn poll_now(&self) {
+        self.notify.notify_one();
+    }
+}
+
+type Fetch<T> = Box<dyn FnMut() -> BoxFuture<'static, Result<T, NHLApiError>> + Send>;
+type NextDelay<T> = Box<dyn Fn(&Result<T, NHLApiError>) -> Duration + Send>;
+
+/// Stream of `fetch` results, one per poll. See the [module docs](self).
+pub struct Poller<T> {
+    interval: Duration,
+    sleeper: Arc<dyn Sleeper>,
+    handle: PollHandle,
+    fetch: Option<Fetch<T>>,
+    next_delay: Option<NextDelay<T>>,
+    stream: Option<BoxStream<'static, Result<T, NHLApiError>>>,
+}
+
+impl<T: Send + 'static> Poller<T> {
+    /// Polls `fetch` now and then every `interval`.
+    pub fn new<F, Fut>(interval: Duration, mut fetch: F) -> Self
+    where
+        F: FnMut() -> Fut + Send + 'static,
+        Fut: Future<Output = Result<T, NHLApiError>> + Send + 'static,
+    {
+        Self {
+            interval,
+            sleeper: default_sleeper(),
+            handle: PollHandle::default(),
+            fetch: Some(Box::new(move || fetch().boxed())),
+            next_delay: None,
+            stream: None,
+        }
+    }
+
+    /// Waits between polls with `sleeper` instead of the default. Takes
+    /// effect only before the stream is first polled.
+    pub fn with_sleeper(mut self, sleeper: Arc<dyn Sleeper>) -> Self {
+        self.sleeper = sleeper;
+        self
+    }
+
+    /// Chooses the wait after each poll from its result instead of always
+    /// waiting `interval`, e.g. to back off while nothing changes. Takes
+    /// effect only before the stream is first polled.
+    pub fn with_next_delay<D>(mut self, next_delay: D) -> Self
+    where
+        D: Fn(&Result<T, NHLApiError>) -> Duration + Send + 'static,
+    {
+        self.next_delay = Some(Box::new(next_delay));
+        self
+    }
+
+    /// A handle for forcing polls from elsewhere, e.g. a UI task.
+    pub fn handle(&self) -> PollHandle {
+        self.handle.clone()
+    }
+
+    /// Shorthand for `self.handle().poll_now()`.
+    pub fn poll_now(&self) {
+        self.handle.poll_now();
+    }
+
+    /// The wait between polls, unless [`Self::with_next_delay`] picks
+    /// another.
+    pub fn interval(&self) -> Duration {
+        self.interval
+    }
+
+    fn start(&mut self) -> BoxStream<'static, Result<T, NHLApiError>> {
+        let fetch = self.fetch.take().expect("a poller starts once");
+        let next_delay = self.next_delay.take();
+        let interval = self.interval;
+        let sleeper = Arc::clone(&self.sleeper);
+        let notify = Arc::clone(&self.handle.notify);
+        // The state carries the wait before the next fetch; none before the first.
+        stream::unfold(
+            (fetch, next_delay, None),
+            move |(mut fetch, next_delay, wait)| {
+                let sleeper = Arc::clone(&sleeper);
+                let notify = Arc::clone(&notify);
+                async move {
+                    if let Some(wait) = wait {
+                        let forced = pin!(notify.notified());
+                        future::select(sleeper.sleep(wait), forced).await;
+                    }
+                    let result = fetch().await;
+                    let wait = next_delay
+                        .as_ref()
+                        .map_or(interval, |next_delay| next_delay(&result));
+                    Some((result, (fetch, next_delay, Some(wait))))
+                }
+            },
+        )
+        .boxed()
+    }
+}
+
+impl<T: Send + 'static> Stream for Poller<T> {
+    type Item = Result<T, NHLApiError>;
+
+    fn poll_next(mut self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Option<Self::Item>> {
+        let this = &mut *self;
+        let stream = match &mut this.stream {
+            Some(stream) => stream,
+            None => {
+                let started = this.start();
+                this.stream.insert(started)
+            }
+        };
+        stream.poll_next_unpin(cx)
+    }
+}
+
+impl<T> fmt::Debug for Poller<T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("Poller")
+            .field("interval", &self.interval)
+            .field("started", &self.stream.is_some())
+            .finish_non_exhaustive()
+    }
+}
+
+impl Client {
+    /// [`Self::daily_scores`] for `date` (today if `None`), re-fetched every
+    /// `interval`. See [`Poller`].
+    pub fn watch_scores(&self, date: Option<GameDate>, interval: Duration) -> Poller<DailyScores> {
+        let client = self.clone();
+        Poller::new(interval, move || {
+            let client = client.clone();
+            let date = date.clone();
+            async move { client.daily_scores(date).await }
+        })
+    }
+
+    /// [`Self::play_by_play`] for one game, re-fetched every `interval`.
+    /// The stream keeps polling after the game ends; drop it once
+    /// [`PlayByPlay::game_state`] is final. See [`Poller`].
+    pub fn watch_play_by_play(
+        &self,
+        game_id: impl Into<GameId>,
+        interval: Duration,
+    ) -> Poller<PlayByPlay> {
+        let client = self.clone();
+        let game_id = game_id.into();
+        Poller::new(interval, move || {
+            let client = client.clone();
+            async move { client.play_by_play(game_id).await }
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::config::ClientConfig;
+    use crate::test_util::PollController;
+    use std::sync::atomic::{AtomicUsize, Ordering};
+
+    const HOUR: Duration = Duration::from_secs(3600);
+
+    fn counting_poller(calls: &Arc<AtomicUsize>) -> Poller<usize> {
+        let calls = Arc::clone(calls);
+        Poller::new(HOUR, move || {
+            let n = calls.fetch_add(1, Ordering::SeqCst) + 1;
+            async move { Ok(n) }
+        })
+    }
+
+    #[tokio::test]
+    async fn test_watch_scores_polls_on_controller_advance() {
+        let mut server = mockito::Server::new_async().await;
+        let mock = server
+            .mock("GET", "/web/score/2024-11-09")
+            .with_status(200)
+            .with_body(r#"{"prevDate": "2024-11-08", "currentDate": "2024-11-09", "nextDate": "2024-11-10", "games": []}"#)
+            .expect(3)
+            .create_async()
+            .await;
+        let config = ClientConfig::default().with_api_web_base(format!("{}/web/", server.url()));
+        let client = Client::with_config(config).unwrap();
+        let controller = PollController::new();
+        let date = GameDate::from_ymd(2024, 11, 9).unwrap();
+        let mut scores = client
+            .watch_scores(Some(date), HOUR)
+            .with_sleeper(controller.sleeper());
+
+        for _ in 0..3 {
+            let snapshot = scores.next().await.unwrap().unwrap();
+            assert_eq!(snapshot.current_date, "2024-11-09");
+            controller.advance();
+        }
+
+        mock.assert_async().await;
+        assert_eq!(controller.requested_sleeps(), vec![HOUR, HOUR]);
+    }
+
+    #[tokio::test]
+    async fn test_watch_play_by_play_yields_errors_and_keeps_polling() {
+        let mut server = mockito::Server::new_async().await;
+        let mock = server
+            .mock("GET", "/web/gamecenter/2024020500/play-by-play")
+            .with_status(404)
+            .expect(2)
+            .create_async()
+            .await;
+        let config = ClientConfig::default().with_api_web_base(format!("{}/web/", server.url()));
+        let client = Client::with_config(config).unwrap();
+        let controller = PollController::new();
+        let mut plays = client
+            .watch_play_by_play(2024020500, HOUR)
+            .with_sleeper(controller.sleeper());
+
+        assert!(plays.next().await.unwrap().is_err());
+        controller.advance();
+        assert!(plays.next().await.unwrap().is_err());
+
+        mock.assert_async().await;
+    }
+
+    #[tokio::test(start_paused = true)]
+    async fn test_poll_now_skips_the_wait() {
+        let calls = Arc::new(AtomicUsize::new(0));
+        let mut poller = counting_poller(&calls);
+        let start = tokio::time::Instant::now();
+
+        assert_eq!(poller.next().await.unwrap().unwrap(), 1);
+        poller.handle().poll_now();
+        assert_eq!(poller.next().await.unwrap().unwrap(), 2);
+
+        assert_eq!(start.elapsed(), Duration::ZERO);
+    }
+
+    #[tokio::test(start_paused = true)]
+    async fn test_poller_waits_the_interval_between_polls() {
+        let calls = Arc::new(AtomicUsize::new(0));
+        let mut poller = counting_poller(&calls);
+        let start = tokio::time::Instant::now();
+
+        poller.next().await.unwrap().unwrap();
+        assert_eq!(start.elapsed(), Duration::ZERO);
+        poller.next().await.unwrap().unwrap();
+
+        assert_eq!(start.elapsed(), HOUR);
+    }
+
+    #[tokio::test(start_paused = true)]
+    async fn test_poll_now_during_wait_from_another_task() {
+        let calls = Arc::new(AtomicUsize::new(0));
+        let mut poller = counting_poller(&calls);
+        let handle = poller.handle();
+        poller.next().await.unwrap().unwrap();
+
+        tokio::spawn(async move {
+            tokio::time::sleep(Duration::from_secs(5)).await;
+            handle.poll_now();
+        });
+        let start = tokio::time::Instant::now();
+        poller.next().await.unwrap().unwrap();
+
+        assert_eq!(start.elapsed(), Duration::from_secs(5));
+        assert_eq!(calls.load(Ordering::SeqCst), 2);
+    }
+
+    #[tokio::test(start_paused = true)]
+    async fn test_next_delay_picks_the_wait_from_the_result() {
+        let calls = Arc::new(AtomicUsize::new(0));
+        let mut poller = counting_poller(&calls)
+            .with_next_delay(|result| Duration::from_secs(*result.as_ref().unwrap() as u64));
+        let start = tokio::time::Instant::now();
+
+        for _ in 0..3 {
+            poller.next().await.unwrap().unwrap();
+        }
+
+        // 1s after the first poll, 2s after the second
+        assert_eq!(start.elapsed(), Duration::from_secs(3));
+    }
+
+    #[tokio::test(start_paused = true)]
+    async fn test_manual_sleeper_waits_for_poll_now() {
+        let calls = Arc::new(AtomicUsize::new(0));
+        let mut poller = counting_poller(&calls).with_sleeper(Arc::new(ManualSleeper));
+        poller.next().await.unwrap().unwrap();
+
+        let waited = tokio::time::timeout(HOUR * 24, poller.next()).await;
+        assert!(waited.is_err(), "polled again without poll_now");
+        poller.poll_now();
+        assert_eq!(poller.next().await.unwrap().unwrap(), 2);
+    }
+
+    #[tokio::test]
+    async fn test_poller_is_lazy() {
+        let calls = Arc::new(AtomicUsize::new(0));
+        let poller = counting_poller(&calls);
+
+        drop(poller);
+
+        assert_eq!(calls.load(Ordering::SeqCst), 0);
+    }
+}
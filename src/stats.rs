@@ -0,0 +1,82 @@
+use std::sync::atomic::{AtomicU64, Ordering};
+
+/// Snapshot of a [`Client`](crate::Client)'s running counters, returned by
+/// [`Client::stats`](crate::Client::stats).
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct ClientStats {
+    /// List elements dropped because they failed to deserialize while
+    /// [`ClientConfig::with_skip_malformed_elements`](crate::ClientConfig::with_skip_malformed_elements)
+    /// was enabled.
+    pub skipped_elements: u64,
+    /// Responses served from the cache enabled by
+    /// [`ClientConfig::with_cache_ttl`](crate::ClientConfig::with_cache_ttl)
+    /// instead of the network.
+    pub cache_hits: u64,
+    /// Stats-host responses fetched under `en/` after a `404` in the
+    /// language set by
+    /// [`ClientConfig::with_stats_language`](crate::ClientConfig::with_stats_language).
+    pub locale_fallbacks: u64,
+}
+
+/// Live counters shared by the HTTP layer; snapshotted into [`ClientStats`].
+#[derive(Debug, Default)]
+pub(crate) struct StatsCounters {
+    skipped_elements: AtomicU64,
+    cache_hits: AtomicU64,
+    locale_fallbacks: AtomicU64,
+}
+
+impl StatsCounters {
+    pub(crate) fn add_skipped_elements(&self, count: u64) {
+        self.skipped_elements.fetch_add(count, Ordering::Relaxed);
+    }
+
+    pub(crate) fn add_cache_hit(&self) {
+        self.cache_hits.fetch_add(1, Ordering::Relaxed);
+    }
+
+    pub(crate) fn add_locale_fallback(&self) {
+        self.locale_fallbacks.fetch_add(1, Ordering::Relaxed);
+    }
+
+    pub(crate) fn snapshot(&self) -> ClientStats {
+        ClientStats {
+            skipped_elements: self.skipped_elements.load(Ordering::Relaxed),
+            cache_hits: self.cache_hits.load(Ordering::Relaxed),
+            locale_fallbacks: self.locale_fallbacks.load(Ordering::Relaxed),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_stats_counters_start_at_zero() {
+        assert_eq!(StatsCounters::default().snapshot(), ClientStats::default());
+    }
+
+    #[test]
+    fn test_stats_counters_accumulate_skipped_elements() {
+        let counters = StatsCounters::default();
+        counters.add_skipped_elements(2);
+        counters.add_skipped_elements(3);
+        assert_eq!(counters.snapshot().skipped_elements, 5);
+    }
+
+    #[test]
+    fn test_stats_counters_count_cache_hits() {
+        let counters = StatsCounters::default();
+        counters.add_cache_hit();
+        counters.add_cache_hit();
+        assert_eq!(counters.snapshot().cache_hits, 2);
+    }
+
+    #[test]
+    fn test_stats_counters_count_locale_fallbacks() {
+        let counters = StatsCounters::default();
+        counters.add_locale_fallback();
+        assert_eq!(counters.snapshot().locale_fallbacks, 1);
+    }
+}
@@ -0,0 +1,349 @@
+//! Team abbreviations, checked against the league's current and former clubs.
+//!
+//! Team endpoints take the abbreviation in the path and answer a typo
+//! (`"MTl"`) or a former club on a current-only route (`"PHX"`) with a bare
+//! 404. [`TeamAbbrev`] normalizes case and lets the client reject unknown
+//! codes before any request, naming the current team when a former club's
+//! code is used.
+
+use std::borrow::Cow;
+use std::fmt;
+use std::str::FromStr;
+use thiserror::Error;
+
+/// Error returned for a team abbreviation that isn't a current club's.
+#[derive(Debug, Clone, PartialEq, Eq, Error)]
+pub enum ParseTeamAbbrevError {
+    /// Not a current or former club's abbreviation.
+    #[error("unknown team abbreviation: {0:?} (expected one of the active teams, e.g. \"MTL\")")]
+    Unknown(String),
+    /// A former club's abbreviation where only current clubs are accepted.
+    #[error(
+        "{abbrev:?} is a historical team abbreviation ({name}){}",
+        successor_hint(.successor)
+    )]
+    Historical {
+        abbrev: &'static str,
+        name: &'static str,
+        /// The current club that carried on from it, if any.
+        successor: Option<&'static str>,
+    },
+}
+
+fn successor_hint(successor: &Option<&'static str>) -> String {
+    match successor {
+        Some(successor) => format!("; the current team is {:?}", successor),
+        None => " with no current team".to_string(),
+    }
+}
+
+/// A former club: its abbreviation, name and the current club that carried
+/// on from it.
+struct HistoricalTeam {
+    abbrev: &'static str,
+    name: &'static str,
+    successor: Option<&'static str>,
+}
+
+const fn historical(
+    abbrev: &'static str,
+    name: &'static str,
+    successor: Option<&'static str>,
+) -> HistoricalTeam {
+    HistoricalTeam {
+        abbrev,
+        name,
+        successor,
+    }
+}
+
+const fn active(abbrev: &'static str) -> TeamAbbrev {
+    TeamAbbrev(Cow::Borrowed(abbrev))
+}
+
+const ACTIVE: &[TeamAbbrev] = &[
+    active("ANA"),
+    active("BOS"),
+    active("BUF"),
+    active("CAR"),
+    active("CBJ"),
+    active("CGY"),
+    active("CHI"),
+    active("COL"),
+    active("DAL"),
+    active("DET"),
+    active("EDM"),
+    active("FLA"),
+    active("LAK"),
+    active("MIN"),
+    active("MTL"),
+    active("NJD"),
+    active("NSH"),
+    active("NYI"),
+    active("NYR"),
+    active("OTT"),
+    active("PHI"),
+    active("PIT"),
+    active("SEA"),
+    active("SJS"),
+    active("STL"),
+    active("TBL"),
+    active("TOR"),
+    active("UTA"),
+    active("VAN"),
+    active("VGK"),
+    active("WPG"),
+    active("WSH"),
+];
+
+// Relocations agree with `franchises::RELOCATIONS`. The Coyotes' franchise
+// went inactive, but Utah took over its roster, so its codes point there.
+const HISTORICAL: &[HistoricalTeam] = &[
+    historical("AFM", "Atlanta Flames", Some("CGY")),
+    historical("ARI", "Arizona Coyotes", Some("UTA")),
+    historical("ATL", "Atlanta Thrashers", Some("WPG")),
+    historical("BRK", "Brooklyn Americans", None),
+    historical("CGS", "California Golden Seals", None),
+    historical("CLE", "Cleveland Barons", None),
+    historical("CLR", "Colorado Rockies", Some("NJD")),
+    historical("DCG", "Detroit Cougars", Some("DET")),
+    historical("DFL", "Detroit Falcons", Some("DET")),
+    historical("HAM", "Hamilton Tigers", None),
+    historical("HFD", "Hartford Whalers", Some("CAR")),
+    historical("KCS", "Kansas City Scouts", Some("NJD")),
+    historical("MMR", "Montreal Maroons", None),
+    historical("MNS", "Minnesota North Stars", Some("DAL")),
+    historical("MWN", "Montreal Wanderers", None),
+    historical("NYA", "New York Americans", None),
+    historical("OAK", "Oakland Seals", None),
+    historical("PHX", "Phoenix Coyotes", Some("UTA")),
+    historical("PIR", "Pittsburgh Pirates", None),
+    historical("QBD", "Quebec Bulldogs", None),
+    historical("QUA", "Philadelphia Quakers", None),
+    historical("QUE", "Quebec Nordiques", Some("COL")),
+    historical("SEN", "Ottawa Senators (1917)", None),
+    historical("SLE", "St. Louis Eagles", None),
+    historical("TAN", "Toronto Arenas", Some("TOR")),
+    historical("TSP", "Toronto St. Patricks", Some("TOR")),
+    historical("WIN", "Winnipeg Jets (1979)", Some("UTA")),
+];
+
+/// A team abbreviation, uppercased.
+///
+/// Parsing (`"mtl".parse::<TeamAbbrev>()`) accepts only the active clubs
+/// in [`TeamAbbrev::all_active`]; [`TeamAbbrev::parse_historical`] also
+/// accepts former clubs. Converting with `From<&str>` only normalizes case,
+/// so client methods can keep taking plain strings and check them before
+/// any request.
+///
+/// ```
+/// use nhl_api::{ParseTeamAbbrevError, TeamAbbrev};
+///
+/// assert_eq!("mtl".parse::<TeamAbbrev>()?.as_str(), "MTL");
+/// let err = "PHX".parse::<TeamAbbrev>().unwrap_err();
+/// assert_eq!(
+///     err.to_string(),
+///     "\"PHX\" is a historical team abbreviation (Phoenix Coyotes); the current team is \"UTA\""
+/// );
+/// assert!(TeamAbbrev::parse_historical("PHX").is_ok());
+/// # Ok::<(), ParseTeamAbbrevError>(())
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub struct TeamAbbrev(Cow<'static, str>);
+
+impl TeamAbbrev {
+    /// The league's current clubs, alphabetically.
+    pub const fn all_active() -> &'static [TeamAbbrev] {
+        ACTIVE
+    }
+
+    /// Parse a current or former club's abbreviation.
+    pub fn parse_historical(s: &str) -> Result<Self, ParseTeamAbbrevError> {
+        let abbrev = Self::from(s);
+        abbrev.require_known()?;
+        Ok(abbrev)
+    }
+
+    /// The abbreviation, e.g. `"MTL"`.
+    pub fn as_str(&self) -> &str {
+        &self.0
+    }
+
+    /// Whether this is a current club.
+    pub fn is_active(&self) -> bool {
+        ACTIVE.contains(self)
+    }
+
+    /// Whether this is a former club.
+    pub fn is_historical(&self) -> bool {
+        self.historical().is_some()
+    }
+
+    /// The current club that carried on from a former one, e.g. `"WPG"` for
+    /// `"ATL"`. `None` for current clubs and clubs that folded.
+    pub fn successor(&self) -> Option<TeamAbbrev> {
+        self.historical()?.successor.map(Self::from)
+    }
+
+    /// `Ok` for a current club.
+    pub fn require_active(&self) -> Result<(), ParseTeamAbbrevError> {
+        if self.is_active() {
+            return Ok(());
+        }
+        match self.historical() {
+            Some(team) => Err(ParseTeamAbbrevError::Historical {
+                abbrev: team.abbrev,
+                name: team.name,
+                successor: team.successor,
+            }),
+            None => Err(ParseTeamAbbrevError::Unknown(self.0.to_string())),
+        }
+    }
+
+    /// `Ok` for a current or former club.
+    pub fn require_known(&self) -> Result<(), ParseTeamAbbrevError> {
+        if self.is_active() || self.is_historical() {
+            Ok(())
+        } else {
+            Err(ParseTeamAbbrevError::Unknown(self.0.to_string()))
+        }
+    }
+
+    fn historical(&self) -> Option<&'static HistoricalTeam> {
+        HISTORICAL.iter().find(|team| team.abbrev == self.as_str())
+    }
+}
+
+impl From<&str> for TeamAbbrev {
+    fn from(s: &str) -> Self {
+        let upper = s.trim().to_ascii_uppercase();
+        // Borrow the registry's copy when there is one.
+        ACTIVE
+            .iter()
+            .map(TeamAbbrev::as_str)
+            .chain(HISTORICAL.iter().map(|team| team.abbrev))
+            .find(|abbrev| *abbrev == upper)
+            .map_or(Self(Cow::Owned(upper)), |abbrev| {
+                Self(Cow::Borrowed(abbrev))
+            })
+    }
+}
+
+impl From<&String> for TeamAbbrev {
+    fn from(s: &String) -> Self {
+        Self::from(s.as_str())
+    }
+}
+
+impl From<String> for TeamAbbrev {
+    fn from(s: String) -> Self {
+        Self::from(s.as_str())
+    }
+}
+
+impl From<&TeamAbbrev> for TeamAbbrev {
+    fn from(abbrev: &TeamAbbrev) -> Self {
+        abbrev.clone()
+    }
+}
+
+impl FromStr for TeamAbbrev {
+    type Err = ParseTeamAbbrevError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let abbrev = Self::from(s);
+        abbrev.require_active()?;
+        Ok(abbrev)
+    }
+}
+
+impl AsRef<str> for TeamAbbrev {
+    fn as_ref(&self) -> &str {
+        &self.0
+    }
+}
+
+impl fmt::Display for TeamAbbrev {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(&self.0)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse_normalizes_case() {
+        for input in ["MTL", "mtl", "MTl", " mtl "] {
+            assert_eq!(input.parse::<TeamAbbrev>().unwrap().as_str(), "MTL");
+        }
+        assert_eq!(TeamAbbrev::from("uta").to_string(), "UTA");
+    }
+
+    #[test]
+    fn test_unknown_abbreviation_error_message() {
+        let err = "MTX".parse::<TeamAbbrev>().unwrap_err();
+        assert_eq!(err, ParseTeamAbbrevError::Unknown("MTX".to_string()));
+        assert_eq!(
+            err.to_string(),
+            "unknown team abbreviation: \"MTX\" (expected one of the active teams, e.g. \"MTL\")"
+        );
+        assert!(TeamAbbrev::parse_historical("MTX").is_err());
+    }
+
+    #[test]
+    fn test_historical_abbreviations() {
+        let err = "ari".parse::<TeamAbbrev>().unwrap_err();
+        assert_eq!(
+            err.to_string(),
+            "\"ARI\" is a historical team abbreviation (Arizona Coyotes); the current team is \"UTA\""
+        );
+        assert_eq!(
+            "HAM".parse::<TeamAbbrev>().unwrap_err().to_string(),
+            "\"HAM\" is a historical team abbreviation (Hamilton Tigers) with no current team"
+        );
+
+        let atlanta = TeamAbbrev::parse_historical("atl").unwrap();
+        assert!(atlanta.is_historical());
+        assert!(!atlanta.is_active());
+        assert_eq!(atlanta.successor().unwrap().as_str(), "WPG");
+        assert_eq!(TeamAbbrev::from("WPG").successor(), None);
+    }
+
+    #[test]
+    fn test_str_converts_through_into() {
+        fn takes(team: impl Into<TeamAbbrev>) -> TeamAbbrev {
+            team.into()
+        }
+        let owned = String::from("tor");
+        assert_eq!(takes("tor"), takes(&owned));
+        assert_eq!(takes(owned).as_str(), "TOR");
+        assert_eq!(takes("xyz").as_str(), "XYZ");
+        assert!(takes("xyz").require_known().is_err());
+    }
+
+    #[test]
+    fn test_registry() {
+        let active = TeamAbbrev::all_active();
+        assert_eq!(active.len(), 32);
+        assert!(active.windows(2).all(|pair| pair[0] < pair[1]));
+        assert!(active.iter().all(|abbrev| !abbrev.is_historical()));
+        assert!(HISTORICAL
+            .windows(2)
+            .all(|pair| pair[0].abbrev < pair[1].abbrev));
+        for team in HISTORICAL {
+            assert!(team
+                .successor
+                .is_none_or(|successor| TeamAbbrev::from(successor).is_active()));
+        }
+        for (old, current) in crate::franchises::RELOCATIONS {
+            assert_eq!(
+                TeamAbbrev::from(*old).successor(),
+                Some(TeamAbbrev::from(*current)),
+                "{}",
+                old
+            );
+        }
+    }
+}
@@ -0,0 +1,157 @@
+//! Franchise branding changes that leave payloads disagreeing on a team's name.
+//!
+//! When a club rebrands, endpoints and caches don't switch over together: the
+//! Utah franchise appears as both "Utah Hockey Club" and "Utah Mammoth" for
+//! the same season depending on which endpoint served it and how old the
+//! cached copy is. Name-keyed joins across those payloads split one club into
+//! two rows. [`TEAM_BRANDINGS`] records each such change; resolve names
+//! through [`canonical_team_name`] or [`canonicalize_team_name`] before
+//! joining.
+
+use crate::date::Season;
+
+/// One branding era for a franchise: the name it is known by for a span of
+/// seasons, plus the other names payloads use for it in that span.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct TeamBranding {
+    /// Team abbreviation, e.g. `"UTA"`.
+    pub abbrev: &'static str,
+    /// Start year of the first season this branding covers.
+    pub first_season: u16,
+    /// Start year of the last season this branding covers; `None` if current.
+    pub last_season: Option<u16>,
+    /// The name rows for this era should collapse to.
+    pub canonical_name: &'static str,
+    /// Other names payloads use for the club during this era.
+    pub aliases: &'static [&'static str],
+}
+
+impl TeamBranding {
+    /// Whether this branding applies to `season`.
+    pub fn covers(&self, season: Season) -> bool {
+        let year = season.start_year();
+        year >= self.first_season && self.last_season.is_none_or(|last| year <= last)
+    }
+
+    /// Whether `name` is this branding's canonical name or one of its aliases
+    /// (ASCII case-insensitive, surrounding whitespace ignored).
+    pub fn matches_name(&self, name: &str) -> bool {
+        let name = name.trim();
+        std::iter::once(&self.canonical_name)
+            .chain(self.aliases)
+            .any(|candidate| candidate.eq_ignore_ascii_case(name))
+    }
+}
+
+/// Known mid-stream branding changes. Add one entry per era.
+pub const TEAM_BRANDINGS: &[TeamBranding] = &[
+    // Played 2024-25 as Utah Hockey Club; the Mammoth name was announced in
+    // May 2025 and back-filled into some 2024-25 payloads.
+    TeamBranding {
+        abbrev: "UTA",
+        first_season: 2024,
+        last_season: Some(2024),
+        canonical_name: "Utah Hockey Club",
+        aliases: &["Utah Mammoth", "Utah"],
+    },
+    TeamBranding {
+        abbrev: "UTA",
+        first_season: 2025,
+        last_season: None,
+        canonical_name: "Utah Mammoth",
+        aliases: &["Utah Hockey Club", "Utah"],
+    },
+];
+
+/// The branding entry for `abbrev` in `season`, if it has a recorded change.
+pub fn team_branding(abbrev: &str, season: Season) -> Option<&'static TeamBranding> {
+    TEAM_BRANDINGS
+        .iter()
+        .find(|branding| branding.abbrev.eq_ignore_ascii_case(abbrev) && branding.covers(season))
+}
+
+/// Canonical name for `abbrev` in `season`. `None` for teams without a
+/// recorded branding change, whose names can be used as-is.
+pub fn canonical_team_name(abbrev: &str, season: Season) -> Option<&'static str> {
+    team_branding(abbrev, season).map(|branding| branding.canonical_name)
+}
+
+/// Canonical name for a team name string as it appeared in a `season`
+/// payload. `None` when the name isn't part of a recorded branding change.
+pub fn canonicalize_team_name(name: &str, season: Season) -> Option<&'static str> {
+    TEAM_BRANDINGS
+        .iter()
+        .find(|branding| branding.covers(season) && branding.matches_name(name))
+        .map(|branding| branding.canonical_name)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_canonical_team_name_utah_by_season() {
+        assert_eq!(
+            canonical_team_name("UTA", Season::new(2024)),
+            Some("Utah Hockey Club")
+        );
+        assert_eq!(
+            canonical_team_name("UTA", Season::new(2025)),
+            Some("Utah Mammoth")
+        );
+        assert_eq!(
+            canonical_team_name("uta", Season::new(2030)),
+            Some("Utah Mammoth")
+        );
+    }
+
+    #[test]
+    fn test_canonical_team_name_unrecorded() {
+        assert_eq!(canonical_team_name("TOR", Season::new(2024)), None);
+        assert_eq!(canonical_team_name("UTA", Season::new(2023)), None);
+    }
+
+    #[test]
+    fn test_canonicalize_team_name_both_utah_names_collapse_in_2024_25() {
+        let season = Season::new(2024);
+        let old = canonicalize_team_name("Utah Hockey Club", season);
+        let new = canonicalize_team_name("Utah Mammoth", season);
+        assert_eq!(old, Some("Utah Hockey Club"));
+        assert_eq!(old, new);
+        assert_eq!(old, canonical_team_name("UTA", season));
+    }
+
+    #[test]
+    fn test_canonicalize_team_name_ignores_case_and_whitespace() {
+        assert_eq!(
+            canonicalize_team_name("  utah mammoth ", Season::new(2025)),
+            Some("Utah Mammoth")
+        );
+    }
+
+    #[test]
+    fn test_canonicalize_team_name_unknown() {
+        assert_eq!(
+            canonicalize_team_name("Toronto Maple Leafs", Season::new(2024)),
+            None
+        );
+    }
+
+    #[test]
+    fn test_team_brandings_do_not_overlap() {
+        for (i, a) in TEAM_BRANDINGS.iter().enumerate() {
+            for b in &TEAM_BRANDINGS[i + 1..] {
+                if a.abbrev != b.abbrev {
+                    continue;
+                }
+                let a_end = a.last_season.unwrap_or(u16::MAX);
+                let b_end = b.last_season.unwrap_or(u16::MAX);
+                assert!(
+                    a_end < b.first_season || b_end < a.first_season,
+                    "overlapping brandings for {}",
+                    a.abbrev
+                );
+            }
+        }
+    }
+}
@@ -0,0 +1,411 @@
+//! Golden-file tests: compare a derived value against committed JSON.
+//!
+//! Analytics outputs (derived stars, goalie usage, team stats, shot
+//! attempts) are easy to change by accident and tedious to assert field by
+//! field. [`assert_matches`] serializes the value, normalizes it and compares
+//! it with `tests/golden/<name>.json` under the crate being tested:
+//!
+//! ```ignore
+//! use nhl_api::test_util::golden;
+//!
+//! golden::assert_matches("three_stars", &nhl_api::analytics::derive_three_stars(&boxscore));
+//! ```
+//!
+//! Set `UPDATE_GOLDEN=1` to write the current output instead of comparing,
+//! then review the file diff before committing it. Numbers are compared
+//! with the harness's tolerance ([`DEFAULT_TOLERANCE`] unless set) so `f32`
+//! distances and angles don't fail on the last bit, and written files round
+//! floats to the same precision; a mismatch panics with one line per
+//! differing path.
+
+use serde::Serialize;
+use serde_json::{Map, Value};
+use std::fmt;
+use std::path::{Path, PathBuf};
+use thiserror::Error;
+
+/// Relative tolerance for numbers, scaled by the larger magnitude (and
+/// never below 1, so values near zero compare absolutely).
+pub const DEFAULT_TOLERANCE: f64 = 1e-6;
+
+/// Environment variable that switches comparisons to rewriting the files.
+pub const UPDATE_VAR: &str = "UPDATE_GOLDEN";
+
+/// Differences listed in a mismatch message before the rest are counted.
+const MAX_REPORTED: usize = 50;
+
+/// Compare `value` with `tests/golden/<name>.json` using the defaults.
+///
+/// # Panics
+/// If the file is missing or differs, with the differing paths.
+pub fn assert_matches<T: Serialize + ?Sized>(name: &str, value: &T) {
+    Golden::default().assert_matches(name, value);
+}
+
+/// A golden-file directory with its comparison settings.
+#[derive(Debug, Clone)]
+pub struct Golden {
+    dir: PathBuf,
+    tolerance: f64,
+    update: bool,
+}
+
+impl Golden {
+    /// Golden files in `dir`, updating them when `UPDATE_GOLDEN=1` is set.
+    pub fn new(dir: impl Into<PathBuf>) -> Self {
+        Self {
+            dir: dir.into(),
+            tolerance: DEFAULT_TOLERANCE,
+            update: std::env::var(UPDATE_VAR).is_ok_and(|value| value == "1"),
+        }
+    }
+
+    /// Relative tolerance for numbers; `0.0` compares them exactly. Written
+    /// files round floats to the decimal place the tolerance allows.
+    pub fn tolerance(mut self, tolerance: f64) -> Self {
+        self.tolerance = tolerance;
+        self
+    }
+
+    /// Write the files instead of comparing, regardless of `UPDATE_GOLDEN`.
+    pub fn update(mut self, update: bool) -> Self {
+        self.update = update;
+        self
+    }
+
+    /// The file for `name`.
+    pub fn path(&self, name: &str) -> PathBuf {
+        self.dir.join(format!("{}.json", name))
+    }
+
+    /// Compare `value` with the golden file for `name`, or write it when
+    /// updating.
+    pub fn check<T: Serialize + ?Sized>(&self, name: &str, value: &T) -> Result<(), GoldenError> {
+        let path = self.path(name);
+        let actual = serde_json::to_value(value)?;
+        if self.update {
+            std::fs::create_dir_all(&self.dir).map_err(|err| GoldenError::io(&self.dir, err))?;
+            let mut text = serde_json::to_string_pretty(&normalize(actual, self.tolerance))?;
+            text.push('\n');
+            return std::fs::write(&path, text).map_err(|err| GoldenError::io(&path, err));
+        }
+
+        let text = std::fs::read_to_string(&path).map_err(|err| match err.kind() {
+            std::io::ErrorKind::NotFound => GoldenError::Missing(path.clone()),
+            _ => GoldenError::io(&path, err),
+        })?;
+        let expected: Value = serde_json::from_str(&text)?;
+        let differences = diff(&expected, &actual, self.tolerance);
+        if differences.is_empty() {
+            Ok(())
+        } else {
+            Err(GoldenError::Mismatch { path, differences })
+        }
+    }
+
+    /// [`Self::check`], panicking with the error's message.
+    ///
+    /// # Panics
+    /// If the file is missing, unreadable or differs.
+    pub fn assert_matches<T: Serialize + ?Sized>(&self, name: &str, value: &T) {
+        if let Err(err) = self.check(name, value) {
+            panic!("{}", err);
+        }
+    }
+}
+
+/// `tests/golden` under `CARGO_MANIFEST_DIR` (set by `cargo test`), or
+/// under the working directory when that isn't set.
+impl Default for Golden {
+    fn default() -> Self {
+        let root = std::env::var_os("CARGO_MANIFEST_DIR").map_or_else(PathBuf::new, PathBuf::from);
+        Self::new(root.join("tests").join("golden"))
+    }
+}
+
+/// Error returned by [`Golden::check`].
+#[derive(Debug, Error)]
+pub enum GoldenError {
+    #[error("golden file {} is missing; run with {}=1 to create it", .0.display(), UPDATE_VAR)]
+    Missing(PathBuf),
+    #[error(
+        "{} differs from the golden file ({} difference{}); run with {}=1 to accept\n{}",
+        .path.display(),
+        .differences.len(),
+        if .differences.len() == 1 { "" } else { "s" },
+        UPDATE_VAR,
+        report(.differences)
+    )]
+    Mismatch {
+        path: PathBuf,
+        differences: Vec<Difference>,
+    },
+    #[error("golden file {}: {source}", .path.display())]
+    Io {
+        path: PathBuf,
+        source: std::io::Error,
+    },
+    #[error("golden value isn't valid JSON: {0}")]
+    Json(#[from] serde_json::Error),
+}
+
+impl GoldenError {
+    fn io(path: &Path, source: std::io::Error) -> Self {
+        Self::Io {
+            path: path.to_path_buf(),
+            source,
+        }
+    }
+}
+
+fn report(differences: &[Difference]) -> String {
+    let mut lines: Vec<String> = differences
+        .iter()
+        .take(MAX_REPORTED)
+        .map(|difference| format!("  {}", difference))
+        .collect();
+    if differences.len() > MAX_REPORTED {
+        lines.push(format!(
+            "  ... and {} more",
+            differences.len() - MAX_REPORTED
+        ));
+    }
+    lines.join("\n")
+}
+
+/// One path where the golden value and the actual one disagree. `None`
+/// on a side where the path doesn't exist.
+#[derive(Debug, Clone, PartialEq)]
+pub struct Difference {
+    /// JSON pointer, e.g. `/2/distance`; empty for the root.
+    pub path: String,
+    pub expected: Option<Value>,
+    pub actual: Option<Value>,
+}
+
+impl fmt::Display for Difference {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let path = if self.path.is_empty() {
+            "/"
+        } else {
+            &self.path
+        };
+        match (&self.expected, &self.actual) {
+            (Some(expected), Some(actual)) => {
+                write!(f, "{}: expected {}, got {}", path, expected, actual)
+            }
+            (Some(expected), None) => write!(f, "{}: expected {}, missing", path, expected),
+            (None, Some(actual)) => write!(f, "{}: unexpected {}", path, actual),
+            (None, None) => write!(f, "{}", path),
+        }
+    }
+}
+
+/// Sort object keys and round floats to the fewest decimals that stay
+/// within `tolerance` (six for [`DEFAULT_TOLERANCE`]), so a written golden
+/// file doesn't churn on `f32`-to-`f64` noise. A tolerance of `0.0` leaves
+/// floats as they are.
+pub fn normalize(value: Value, tolerance: f64) -> Value {
+    let scale = (tolerance > 0.0 && tolerance.is_finite())
+        .then(|| 10f64.powi((-tolerance.log10()).ceil() as i32));
+    round(value, scale)
+}
+
+fn round(value: Value, scale: Option<f64>) -> Value {
+    match value {
+        Value::Object(map) => {
+            let mut entries: Vec<(String, Value)> = map.into_iter().collect();
+            entries.sort_by(|a, b| a.0.cmp(&b.0));
+            Value::Object(
+                entries
+                    .into_iter()
+                    .map(|(key, value)| (key, round(value, scale)))
+                    .collect::<Map<String, Value>>(),
+            )
+        }
+        Value::Array(values) => Value::Array(
+            values
+                .into_iter()
+                .map(|value| round(value, scale))
+                .collect(),
+        ),
+        Value::Number(number) if number.is_f64() => {
+            let (Some(scale), Some(float)) = (scale, number.as_f64()) else {
+                return Value::Number(number);
+            };
+            let rounded = (float * scale).round() / scale;
+            serde_json::Number::from_f64(rounded).map_or(Value::Number(number), Value::Number)
+        }
+        other => other,
+    }
+}
+
+/// Every path where `actual` differs from `expected`, in document order.
+/// Numbers match within `tolerance` (relative, see [`DEFAULT_TOLERANCE`]).
+pub fn diff(expected: &Value, actual: &Value, tolerance: f64) -> Vec<Difference> {
+    let mut differences = Vec::new();
+    collect(expected, actual, String::new(), tolerance, &mut differences);
+    differences
+}
+
+fn collect(
+    expected: &Value,
+    actual: &Value,
+    path: String,
+    tolerance: f64,
+    differences: &mut Vec<Difference>,
+) {
+    match (expected, actual) {
+        (Value::Object(expected), Value::Object(actual)) => {
+            for (key, value) in expected {
+                let child = format!("{}/{}", path, escape(key));
+                match actual.get(key) {
+                    Some(actual_value) => {
+                        collect(value, actual_value, child, tolerance, differences)
+                    }
+                    None => differences.push(Difference {
+                        path: child,
+                        expected: Some(value.clone()),
+                        actual: None,
+                    }),
+                }
+            }
+            for (key, value) in actual {
+                if !expected.contains_key(key) {
+                    differences.push(Difference {
+                        path: format!("{}/{}", path, escape(key)),
+                        expected: None,
+                        actual: Some(value.clone()),
+                    });
+                }
+            }
+        }
+        (Value::Array(expected), Value::Array(actual)) => {
+            for index in 0..expected.len().max(actual.len()) {
+                let child = format!("{}/{}", path, index);
+                match (expected.get(index), actual.get(index)) {
+                    (Some(value), Some(actual_value)) => {
+                        collect(value, actual_value, child, tolerance, differences)
+                    }
+                    (value, actual_value) => differences.push(Difference {
+                        path: child,
+                        expected: value.cloned(),
+                        actual: actual_value.cloned(),
+                    }),
+                }
+            }
+        }
+        (Value::Number(a), Value::Number(b)) if numbers_match(a, b, tolerance) => {}
+        (expected, actual) if expected == actual => {}
+        (expected, actual) => differences.push(Difference {
+            path,
+            expected: Some(expected.clone()),
+            actual: Some(actual.clone()),
+        }),
+    }
+}
+
+fn numbers_match(a: &serde_json::Number, b: &serde_json::Number, tolerance: f64) -> bool {
+    if a == b {
+        return true;
+    }
+    match (a.as_f64(), b.as_f64()) {
+        (Some(a), Some(b)) => (a - b).abs() <= tolerance * a.abs().max(b.abs()).max(1.0),
+        _ => false,
+    }
+}
+
+/// Escape a key for a JSON pointer (RFC 6901).
+fn escape(key: &str) -> String {
+    key.replace('~', "~0").replace('/', "~1")
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use serde_json::json;
+
+    #[test]
+    fn test_diff_reports_each_path() {
+        let expected = json!({"stars": [{"id": 1, "pts": 3}, {"id": 2}], "team": "MTL"});
+        let actual = json!({"stars": [{"id": 1, "pts": 2}], "team": "MTL", "extra": true});
+
+        let lines: Vec<String> = diff(&expected, &actual, DEFAULT_TOLERANCE)
+            .iter()
+            .map(ToString::to_string)
+            .collect();
+        assert_eq!(
+            lines,
+            [
+                "/stars/0/pts: expected 3, got 2",
+                "/stars/1: expected {\"id\":2}, missing",
+                "/extra: unexpected true",
+            ]
+        );
+    }
+
+    #[test]
+    fn test_numbers_compare_within_tolerance() {
+        let f32_noise = json!(12.300000190734863);
+        assert!(diff(&json!(12.3), &f32_noise, DEFAULT_TOLERANCE).is_empty());
+        assert_eq!(diff(&json!(12.3), &f32_noise, 0.0).len(), 1);
+        assert!(diff(&json!(12.3), &json!(12.4), DEFAULT_TOLERANCE).len() == 1);
+        assert!(diff(&json!(3), &json!(3.0), 0.0).is_empty());
+        assert_eq!(diff(&json!("3"), &json!(3), DEFAULT_TOLERANCE).len(), 1);
+    }
+
+    #[test]
+    fn test_normalize_rounds_floats_and_sorts_keys() {
+        let value = normalize(
+            json!({"b": 31.234567890123, "a": [0.1f32], "c": 4}),
+            DEFAULT_TOLERANCE,
+        );
+        assert_eq!(
+            serde_json::to_string(&value).unwrap(),
+            r#"{"a":[0.1],"b":31.234568,"c":4}"#
+        );
+    }
+
+    #[test]
+    fn test_normalize_rounds_to_the_tolerance() {
+        let value = json!([31.234567890123, 0.1f32]);
+        let text = |tolerance| serde_json::to_string(&normalize(value.clone(), tolerance)).unwrap();
+
+        assert_eq!(text(1e-3), "[31.235,0.1]");
+        assert_eq!(text(5e-4), "[31.2346,0.1]");
+        assert_eq!(text(1e-9), "[31.23456789,0.100000001]");
+        assert_eq!(text(0.0), "[31.234567890123,0.10000000149011612]");
+    }
+
+    /// Files written with a tolerance are compared against the unrounded
+    /// value within that tolerance, looser or tighter than the default.
+    #[test]
+    fn test_written_files_match_within_the_tolerance() {
+        let dir = std::env::temp_dir().join(format!("nhl_api_golden_{}", std::process::id()));
+        for tolerance in [1e-3, 1e-9] {
+            let golden = Golden::new(&dir).tolerance(tolerance);
+            golden
+                .clone()
+                .update(true)
+                .check("value", &json!([31.234567890123]))
+                .unwrap();
+
+            let golden = golden.update(false);
+            golden
+                .check("value", &json!([31.234567890123 + tolerance / 4.0]))
+                .unwrap();
+            assert!(golden
+                .check("value", &json!([31.234567890123 + tolerance * 50.0]))
+                .is_err());
+        }
+        std::fs::remove_dir_all(&dir).unwrap();
+    }
+
+    #[test]
+    fn test_missing_file_names_the_update_variable() {
+        let golden = Golden::new("tests/golden/does-not-exist").update(false);
+        let err = golden.check("nothing", &json!(1)).unwrap_err();
+        assert!(matches!(err, GoldenError::Missing(_)));
+        assert!(err.to_string().contains("UPDATE_GOLDEN=1"));
+    }
+}
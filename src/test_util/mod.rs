@@ -0,0 +1,577 @@
+//! Test helpers for catching serde field-mapping bugs.
+//!
+//! The API is camelCase and the structs are snake_case with manual renames,
+//! so a rename typo silently leaves a field at its default. These helpers
+//! deserialize a fixture, serialize it back, and report every key the
+//! fixture had that the round trip lost.
+//!
+//! ```ignore
+//! use nhl_api::test_util::assert_no_unmapped_fields;
+//!
+//! assert_no_unmapped_fields::<nhl_api::Boxscore>(BOXSCORE_JSON);
+//! ```
+//!
+//! Paths are JSON pointers with array indices replaced by `*`
+//! (`/playerByGameStats/awayTeam/goalies/*/starter`). Fixture values that are
+//! `null`, `""`, `[]` or `{}` may legitimately be skipped on serialization and
+//! are never reported.
+//!
+//! [`fixture_trim`] shrinks a full payload into a fixture worth committing:
+//!
+//! ```ignore
+//! use nhl_api::test_util::{fixture_trim, TrimRules};
+//!
+//! let rules = TrimRules::new()
+//!     .truncate("/plays", 3)
+//!     .truncate("/rosterSpots", 4);
+//! let fixture = fixture_trim::<nhl_api::PlayByPlay>(&full_payload, &rules)?;
+//! ```
+//!
+//! [`ResponseSequence`] serves successive bodies from one mocked URL, for
+//! tests that follow a game from scheduled to final:
+//!
+//! ```ignore
+//! use nhl_api::test_util::ResponseSequence;
+//!
+//! let play_by_play = ResponseSequence::new([live_1, live_2, final_json]);
+//! let responses = play_by_play.clone();
+//! server
+//!     .mock("GET", "/gamecenter/2024020444/play-by-play")
+//!     .with_body_from_request(move |_| responses.next_body().into_bytes())
+//!     .expect_at_least(1)
+//!     .create();
+//! // ... drive the client ...
+//! assert_eq!(play_by_play.served(), 3);
+//! ```
+//!
+//! [`PollController`] stands in for the sleep between polls of a
+//! [`Poller`](crate::poll::Poller), so a test steps through polls without
+//! waiting:
+//!
+//! ```ignore
+//! use nhl_api::test_util::PollController;
+//!
+//! let controller = PollController::new();
+//! let mut scores = client
+//!     .watch_scores(None, Duration::from_secs(30))
+//!     .with_sleeper(controller.sleeper());
+//! let first = scores.next().await;
+//! controller.advance();
+//! let second = scores.next().await;
+//! ```
+//!
+//! [`golden::assert_matches`] pins a derived value to a committed JSON file
+//! under `tests/golden/`; run with `UPDATE_GOLDEN=1` to rewrite it.
+
+pub mod golden;
+
+use futures_util::future::{BoxFuture, FutureExt};
+use serde::de::DeserializeOwned;
+use serde::Serialize;
+use serde_json::Value;
+use std::sync::atomic::{AtomicUsize, Ordering};
+use std::sync::{Arc, Mutex};
+use std::time::Duration;
+use tokio::sync::Semaphore;
+
+use crate::poll::Sleeper;
+
+/// Keys present in `fixture_json` but missing after a deserialize/serialize
+/// round trip through `T`, as sorted, de-duplicated paths.
+///
+/// An `allowlist` entry starting with `/` ignores that exact path (and
+/// everything under it); any other entry ignores that key name at every
+/// level.
+///
+/// # Panics
+/// If `fixture_json` isn't valid JSON or doesn't deserialize as `T`.
+pub fn unmapped_fields<T>(fixture_json: &str, allowlist: &[&str]) -> Vec<String>
+where
+    T: DeserializeOwned + Serialize,
+{
+    let fixture: Value = serde_json::from_str(fixture_json)
+        .unwrap_or_else(|e| panic!("fixture is not valid JSON: {}", e));
+    let parsed: T = serde_json::from_value(fixture.clone()).unwrap_or_else(|e| {
+        panic!(
+            "fixture does not deserialize as {}: {}",
+            std::any::type_name::<T>(),
+            e
+        )
+    });
+    let round_trip = serde_json::to_value(&parsed).unwrap_or_else(|e| {
+        panic!(
+            "{} does not serialize back to JSON: {}",
+            std::any::type_name::<T>(),
+            e
+        )
+    });
+
+    let mut missing = Vec::new();
+    collect_missing(&fixture, &round_trip, "", allowlist, &mut missing);
+    missing.sort();
+    missing.dedup();
+    missing
+}
+
+/// Panics listing every fixture key `T` drops on a round trip.
+pub fn assert_no_unmapped_fields<T>(fixture_json: &str)
+where
+    T: DeserializeOwned + Serialize,
+{
+    assert_no_unmapped_fields_except::<T>(fixture_json, &[]);
+}
+
+/// [`assert_no_unmapped_fields`], ignoring the keys or paths in `allowlist`
+/// (see [`unmapped_fields`]).
+pub fn assert_no_unmapped_fields_except<T>(fixture_json: &str, allowlist: &[&str])
+where
+    T: DeserializeOwned + Serialize,
+{
+    let missing = unmapped_fields::<T>(fixture_json, allowlist);
+    assert!(
+        missing.is_empty(),
+        "{} drops fixture fields on a round trip (missing or misspelled rename?):\n  {}",
+        std::any::type_name::<T>(),
+        missing.join("\n  ")
+    );
+}
+
+/// What [`fixture_trim`] keeps from a payload.
+///
+/// Pointers are JSON pointers where a `*` segment matches any object key or
+/// array index (`/playerByGameStats/*/forwards`). With no `keep` pointers the
+/// whole payload is kept; otherwise only the kept paths, their ancestors and
+/// everything below them survive.
+#[derive(Debug, Clone, Default)]
+pub struct TrimRules {
+    keep: Vec<String>,
+    truncate: Vec<(String, usize)>,
+}
+
+impl TrimRules {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Keep the value at `pointer` (and everything under it).
+    pub fn keep(mut self, pointer: &str) -> Self {
+        self.keep.push(pointer.to_string());
+        self
+    }
+
+    /// Keep only the first `max_len` elements of arrays at `pointer`.
+    pub fn truncate(mut self, pointer: &str, max_len: usize) -> Self {
+        self.truncate.push((pointer.to_string(), max_len));
+        self
+    }
+
+    /// The trimmed payload, without checking it still deserializes.
+    pub fn apply(&self, payload: &Value) -> Value {
+        let keep: Vec<Vec<&str>> = self.keep.iter().map(|p| segments(p)).collect();
+        let truncate: Vec<(Vec<&str>, usize)> = self
+            .truncate
+            .iter()
+            .map(|(pointer, max_len)| (segments(pointer), *max_len))
+            .collect();
+        trim(payload, &mut Vec::new(), !keep.is_empty(), &keep, &truncate)
+    }
+}
+
+/// Trims `payload` with `rules`, then checks the result still deserializes
+/// as `T` so required fields can't be trimmed away.
+pub fn fixture_trim<T>(payload: &Value, rules: &TrimRules) -> Result<Value, serde_json::Error>
+where
+    T: DeserializeOwned,
+{
+    let trimmed = rules.apply(payload);
+    T::deserialize(&trimmed)?;
+    Ok(trimmed)
+}
+
+fn segments(pointer: &str) -> Vec<&str> {
+    pointer.split('/').skip(1).collect()
+}
+
+fn matches(pattern: &[&str], path: &[String]) -> bool {
+    pattern.len() == path.len()
+        && pattern
+            .iter()
+            .zip(path)
+            .all(|(expected, actual)| *expected == "*" || expected == actual)
+}
+
+/// `Some(true)` when `path` is at or under a kept pointer, `Some(false)` when
+/// it is an ancestor of one, `None` when it should be dropped.
+fn keep_state(path: &[String], keep: &[Vec<&str>]) -> Option<bool> {
+    let mut ancestor = false;
+    for pattern in keep {
+        if pattern.len() <= path.len() && matches(pattern, &path[..pattern.len()]) {
+            return Some(true);
+        }
+        ancestor |= pattern.len() > path.len() && matches(&pattern[..path.len()], path);
+    }
+    ancestor.then_some(false)
+}
+
+fn trim(
+    value: &Value,
+    path: &mut Vec<String>,
+    filtering: bool,
+    keep: &[Vec<&str>],
+    truncate: &[(Vec<&str>, usize)],
+) -> Value {
+    let limit = truncate
+        .iter()
+        .filter(|(pattern, _)| matches(pattern, path))
+        .map(|(_, max_len)| *max_len)
+        .min()
+        .unwrap_or(usize::MAX);
+    let mut child = |key: String, value: &Value| -> Option<Value> {
+        path.push(key);
+        let filtering_child = if filtering {
+            match keep_state(path, keep) {
+                Some(fully_kept) => !fully_kept,
+                None => {
+                    path.pop();
+                    return None;
+                }
+            }
+        } else {
+            false
+        };
+        let trimmed = trim(value, path, filtering_child, keep, truncate);
+        path.pop();
+        Some(trimmed)
+    };
+
+    match value {
+        Value::Object(map) => Value::Object(
+            map.iter()
+                .filter_map(|(key, value)| Some((key.clone(), child(key.clone(), value)?)))
+                .collect(),
+        ),
+        Value::Array(items) => Value::Array(
+            items
+                .iter()
+                .take(limit)
+                .enumerate()
+                .filter_map(|(index, item)| child(index.to_string(), item))
+                .collect(),
+        ),
+        other => other.clone(),
+    }
+}
+
+fn is_allowed(path: &str, key: &str, allowlist: &[&str]) -> bool {
+    allowlist.iter().any(|entry| {
+        if entry.starts_with('/') {
+            path == *entry
+        } else {
+            key == *entry
+        }
+    })
+}
+
+fn is_empty(value: &Value) -> bool {
+    match value {
+        Value::Null => true,
+        Value::String(s) => s.is_empty(),
+        Value::Array(items) => items.is_empty(),
+        Value::Object(map) => map.is_empty(),
+        Value::Bool(_) | Value::Number(_) => false,
+    }
+}
+
+fn collect_missing(
+    fixture: &Value,
+    round_trip: &Value,
+    path: &str,
+    allowlist: &[&str],
+    missing: &mut Vec<String>,
+) {
+    match (fixture, round_trip) {
+        (Value::Object(expected), Value::Object(actual)) => {
+            for (key, value) in expected {
+                let child = format!("{}/{}", path, key);
+                if is_allowed(&child, key, allowlist) {
+                    continue;
+                }
+                match actual.get(key) {
+                    Some(actual_value) => {
+                        collect_missing(value, actual_value, &child, allowlist, missing)
+                    }
+                    None if is_empty(value) => {}
+                    None => missing.push(child),
+                }
+            }
+        }
+        (Value::Array(expected), Value::Array(actual)) => {
+            let child = format!("{}/*", path);
+            for (value, actual_value) in expected.iter().zip(actual) {
+                collect_missing(value, actual_value, &child, allowlist, missing);
+            }
+        }
+        _ => {}
+    }
+}
+
+/// Response bodies handed out in order, one per request, for a mock server
+/// route whose payload changes between calls (a game going live, then
+/// final). Once exhausted the last body repeats.
+///
+/// Clones share their position, so one clone can move into the mock's
+/// body callback while the test keeps another to check [`Self::served`].
+#[derive(Debug, Clone)]
+pub struct ResponseSequence {
+    bodies: Arc<Vec<String>>,
+    served: Arc<AtomicUsize>,
+}
+
+impl ResponseSequence {
+    /// # Panics
+    /// If `bodies` is empty.
+    pub fn new<I>(bodies: I) -> Self
+    where
+        I: IntoIterator,
+        I::Item: Into<String>,
+    {
+        let bodies: Vec<String> = bodies.into_iter().map(Into::into).collect();
+        assert!(!bodies.is_empty(), "a response sequence needs a body");
+        Self {
+            bodies: Arc::new(bodies),
+            served: Arc::new(AtomicUsize::new(0)),
+        }
+    }
+
+    /// The body for the next request.
+    pub fn next_body(&self) -> String {
+        let index = self.served.fetch_add(1, Ordering::SeqCst);
+        self.bodies[index.min(self.bodies.len() - 1)].clone()
+    }
+
+    /// How many bodies have been handed out.
+    pub fn served(&self) -> usize {
+        self.served.load(Ordering::SeqCst)
+    }
+}
+
+/// A [`Sleeper`] whose sleeps only finish when the test calls
+/// [`Self::advance`], for stepping a [`Poller`](crate::poll::Poller)
+/// through its polls deterministically.
+///
+/// Clones share their state; pass [`Self::sleeper`] to
+/// [`Poller::with_sleeper`](crate::poll::Poller::with_sleeper) and keep the
+/// controller.
+#[derive(Debug, Clone)]
+pub struct PollController {
+    wakeups: Arc<Semaphore>,
+    requested: Arc<Mutex<Vec<Duration>>>,
+}
+
+impl PollController {
+    pub fn new() -> Self {
+        Self {
+            wakeups: Arc::new(Semaphore::new(0)),
+            requested: Arc::new(Mutex::new(Vec::new())),
+        }
+    }
+
+    /// This controller as a [`Sleeper`].
+    pub fn sleeper(&self) -> Arc<dyn Sleeper> {
+        Arc::new(self.clone())
+    }
+
+    /// Finishes one sleep: the one in progress, or else the next to start.
+    pub fn advance(&self) {
+        self.wakeups.add_permits(1);
+    }
+
+    /// The duration of every sleep requested so far, in order.
+    pub fn requested_sleeps(&self) -> Vec<Duration> {
+        self.requested.lock().unwrap().clone()
+    }
+}
+
+impl Default for PollController {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl Sleeper for PollController {
+    fn sleep(&self, duration: Duration) -> BoxFuture<'static, ()> {
+        self.requested.lock().unwrap().push(duration);
+        let wakeups = Arc::clone(&self.wakeups);
+        async move {
+            wakeups
+                .acquire()
+                .await
+                .expect("the semaphore is never closed")
+                .forget();
+        }
+        .boxed()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use serde::Deserialize;
+
+    #[derive(Serialize, Deserialize)]
+    struct Inner {
+        #[serde(rename = "savePctg")]
+        save_pctg: f64,
+    }
+
+    #[derive(Serialize, Deserialize)]
+    struct Outer {
+        id: i64,
+        // Misspelled: the API sends `gameDate`.
+        #[serde(rename = "gamedate", default)]
+        game_date: Option<String>,
+        #[serde(default)]
+        goalies: Vec<Inner>,
+        #[serde(rename = "venue", skip_serializing_if = "Option::is_none")]
+        venue: Option<String>,
+    }
+
+    const JSON: &str = r#"{
+        "id": 1,
+        "gameDate": "2024-11-09",
+        "goalies": [{"savePctg": 0.9, "decision": "W"}],
+        "venue": null,
+        "tvBroadcasts": []
+    }"#;
+
+    #[test]
+    fn test_unmapped_fields_reports_nested_paths() {
+        assert_eq!(
+            unmapped_fields::<Outer>(JSON, &[]),
+            vec!["/gameDate", "/goalies/*/decision"]
+        );
+    }
+
+    #[test]
+    fn test_unmapped_fields_allowlist_by_key_and_path() {
+        assert_eq!(
+            unmapped_fields::<Outer>(JSON, &["decision"]),
+            vec!["/gameDate"]
+        );
+        assert!(unmapped_fields::<Outer>(JSON, &["/gameDate", "/goalies/*/decision"]).is_empty());
+    }
+
+    #[test]
+    fn test_unmapped_fields_ignores_empty_values() {
+        assert!(unmapped_fields::<Outer>(r#"{"id": 1, "venue": null, "x": ""}"#, &[]).is_empty());
+    }
+
+    #[test]
+    #[should_panic(expected = "/goalies/*/decision")]
+    fn test_assert_no_unmapped_fields_panics_with_paths() {
+        assert_no_unmapped_fields::<Outer>(JSON);
+    }
+
+    #[test]
+    #[should_panic(expected = "does not deserialize")]
+    fn test_unmapped_fields_rejects_bad_fixture() {
+        unmapped_fields::<Outer>(r#"{"id": "one"}"#, &[]);
+    }
+
+    fn payload() -> Value {
+        serde_json::json!({
+            "id": 1,
+            "gameDate": "2024-11-09",
+            "goalies": [{"savePctg": 0.9}, {"savePctg": 0.8}, {"savePctg": 0.7}],
+            "teams": {
+                "away": {"skaters": [1, 2, 3, 4], "coach": "A"},
+                "home": {"skaters": [5, 6, 7], "coach": "B"}
+            }
+        })
+    }
+
+    #[test]
+    fn test_trim_rules_truncate_with_wildcards() {
+        let trimmed = TrimRules::new()
+            .truncate("/goalies", 1)
+            .truncate("/teams/*/skaters", 2)
+            .apply(&payload());
+
+        assert_eq!(trimmed["goalies"], serde_json::json!([{"savePctg": 0.9}]));
+        assert_eq!(
+            trimmed["teams"]["away"]["skaters"],
+            serde_json::json!([1, 2])
+        );
+        assert_eq!(
+            trimmed["teams"]["home"]["skaters"],
+            serde_json::json!([5, 6])
+        );
+        assert_eq!(trimmed["teams"]["home"]["coach"], "B");
+        assert_eq!(trimmed["id"], 1);
+    }
+
+    #[test]
+    fn test_trim_rules_keep_only_listed_paths() {
+        let trimmed = TrimRules::new()
+            .keep("/id")
+            .keep("/teams/*/coach")
+            .apply(&payload());
+
+        assert_eq!(
+            trimmed,
+            serde_json::json!({
+                "id": 1,
+                "teams": {"away": {"coach": "A"}, "home": {"coach": "B"}}
+            })
+        );
+    }
+
+    #[test]
+    fn test_fixture_trim_boxscore_still_deserializes() {
+        let payload: Value =
+            serde_json::from_str(include_str!("../../tests/fixtures/boxscore.json")).unwrap();
+        let rules = TrimRules::new()
+            .truncate("/playerByGameStats/*/forwards", 1)
+            .truncate("/playerByGameStats/*/defense", 0)
+            .truncate("/tvBroadcasts", 1);
+
+        let trimmed = fixture_trim::<crate::Boxscore>(&payload, &rules).unwrap();
+        let boxscore: crate::Boxscore = serde_json::from_value(trimmed).unwrap();
+
+        for team in [
+            &boxscore.player_by_game_stats.away_team,
+            &boxscore.player_by_game_stats.home_team,
+        ] {
+            assert_eq!(team.forwards.len(), 1);
+            assert!(team.defense.is_empty());
+            assert_eq!(team.goalies.len(), 1);
+        }
+        assert_eq!(boxscore.tv_broadcasts.len(), 1);
+        assert_eq!(boxscore.away_team.abbrev, "NJD");
+    }
+
+    #[test]
+    fn test_fixture_trim_validates_required_fields() {
+        let kept = TrimRules::new()
+            .keep("/id")
+            .keep("/goalies")
+            .truncate("/goalies", 2);
+        let trimmed = fixture_trim::<Outer>(&payload(), &kept).unwrap();
+        assert_eq!(trimmed["goalies"].as_array().unwrap().len(), 2);
+
+        // `id` is required by `Outer`.
+        let missing_id = TrimRules::new().keep("/goalies");
+        assert!(fixture_trim::<Outer>(&payload(), &missing_id).is_err());
+    }
+
+    #[test]
+    fn test_response_sequence_repeats_last_body() {
+        let sequence = ResponseSequence::new(["FUT", "LIVE", "OFF"]);
+        let shared = sequence.clone();
+
+        let bodies: Vec<String> = (0..4).map(|_| shared.next_body()).collect();
+
+        assert_eq!(bodies, ["FUT", "LIVE", "OFF", "OFF"]);
+        assert_eq!(sequence.served(), 4);
+    }
+}
@@ -0,0 +1,84 @@
+//! Pluggable request transport, for serving responses without HTTP.
+//!
+//! By default a [`Client`](crate::Client) sends every request with
+//! `reqwest`. A [`Transport`] set with
+//! [`ClientConfig::with_transport`](crate::ClientConfig::with_transport) (or
+//! [`Client::with_transport`](crate::Client::with_transport)) answers them
+//! instead: it gets the host, the resource path and the query parameters and
+//! hands back the JSON body. Everything above the transport still applies —
+//! decoding, the response cache, retries and the request observer — and
+//! errors it returns are treated like the HTTP ones: a
+//! [`ServerError`](NHLApiError::ServerError) or
+//! [`RateLimitExceeded`](NHLApiError::RateLimitExceeded) is retried when
+//! retries are on, a [`ResourceNotFound`](NHLApiError::ResourceNotFound)
+//! isn't.
+//!
+//! The `fixtures` feature ships `fixtures::StaticTransport`, which serves
+//! canned bodies from a map keyed by resource.
+//!
+//! ```
+//! use std::collections::HashMap;
+//! use std::sync::Arc;
+//! use futures_util::future::{BoxFuture, FutureExt};
+//! use nhl_api::transport::{Endpoint, Transport};
+//! use nhl_api::{Client, NHLApiError};
+//!
+//! /// Every request gets the same standings.
+//! struct Standings;
+//!
+//! impl Transport for Standings {
+//!     fn get<'a>(
+//!         &'a self,
+//!         _endpoint: &'a Endpoint,
+//!         _resource: &'a str,
+//!         _params: Option<&'a HashMap<String, String>>,
+//!     ) -> BoxFuture<'a, Result<String, NHLApiError>> {
+//!         async { Ok(r#"{"standings": []}"#.to_string()) }.boxed()
+//!     }
+//! }
+//!
+//! # async fn example() -> Result<(), NHLApiError> {
+//! let client = Client::with_transport(Arc::new(Standings))?;
+//! assert!(client.current_league_standings().await?.is_empty());
+//! # Ok(())
+//! # }
+//! ```
+
+use futures_util::future::BoxFuture;
+use std::collections::HashMap;
+use std::fmt;
+use std::sync::Arc;
+
+use crate::error::NHLApiError;
+
+pub use crate::http_client::Endpoint;
+
+/// Answers the client's requests. One call per attempt, so a retried
+/// request calls `get` again.
+pub trait Transport: Send + Sync {
+    /// The response body for `resource` on `endpoint`'s host, with
+    /// `params` as the query string.
+    ///
+    /// `resource` is the path relative to the host's base URL, e.g.
+    /// `"gamecenter/2023020001/boxscore"`. Stats-host resources carry their
+    /// language segment (`"en/franchise"`).
+    fn get<'a>(
+        &'a self,
+        endpoint: &'a Endpoint,
+        resource: &'a str,
+        params: Option<&'a HashMap<String, String>>,
+    ) -> BoxFuture<'a, Result<String, NHLApiError>>;
+}
+
+/// The configured transport, with a `Debug` impl so [`ClientConfig`]
+/// can keep deriving it.
+///
+/// [`ClientConfig`]: crate::ClientConfig
+#[derive(Clone)]
+pub(crate) struct SharedTransport(pub(crate) Arc<dyn Transport>);
+
+impl fmt::Debug for SharedTransport {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str("Transport")
+    }
+}
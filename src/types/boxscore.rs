@@ -1,14 +1,36 @@
-use serde::{Deserialize, Serialize};
+use serde::de::Error as DeError;
+use serde::{Deserialize, Deserializer, Serialize, Serializer};
+use std::cmp::{Ordering, Reverse};
+use std::collections::{HashMap, HashSet};
+use std::fmt;
+use std::ops::Add;
+use std::str::FromStr;
+use std::time::Duration;
+use thiserror::Error;
 
 use crate::date::Season;
+use crate::ice_time::IceTime;
 use crate::ids::{GameId, PlayerId, TeamId};
 
 use super::common::LocalizedString;
-use super::enums::{empty_string_as_none, GameScheduleState, GoalieDecision, PeriodType, Position};
+use super::enums::{
+    empty_string_as_none, BroadcastMarket, GameScheduleState, GoalieDecision, HomeRoad, PeriodType,
+    Position,
+};
+use super::game_center::{
+    GameSituation, Officials, OnIceSituation, PlayByPlay, PlayEventType, SeasonSeriesMatchup,
+};
 use super::game_state::GameState;
 use super::game_type::GameType;
+use super::venue_time::GameVenueTime;
 
 /// Boxscore response with detailed game and player statistics
+///
+/// Games from the early decades are flagged `limitedScoring` (see
+/// [`Self::is_limited`]): their payloads carry the final score but no
+/// clock, shot totals or player lines. For those, `clock` is the default
+/// (zeroed) reading, `player_by_game_stats` lists nobody and the teams'
+/// `sog` is `None`.
 #[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
 pub struct Boxscore {
     pub id: GameId,
@@ -43,16 +65,188 @@ pub struct Boxscore {
     pub away_team: BoxscoreTeam,
     #[serde(rename = "homeTeam")]
     pub home_team: BoxscoreTeam,
+    /// Zeroed when the payload has no clock (limited-scoring games).
+    #[serde(default)]
     pub clock: GameClock,
-    #[serde(rename = "playerByGameStats")]
+    /// Empty when the payload has no player lines (limited-scoring games).
+    #[serde(rename = "playerByGameStats", default)]
     pub player_by_game_stats: PlayerByGameStats,
+    /// Top-level officials block, only present in some payload versions.
+    #[serde(rename = "gameInfo", default)]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub game_info: Option<Officials>,
+    #[serde(default)]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub summary: Option<BoxscoreSummary>,
+    /// Present only while the game is live.
+    #[serde(default)]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub situation: Option<OnIceSituation>,
+}
+
+impl Boxscore {
+    /// Whether the NHL only has limited scoring data for this game: no
+    /// clock, shots or player stats, just the result.
+    pub fn is_limited(&self) -> bool {
+        self.limited_scoring
+    }
+
+    /// Whether the game was postponed (`gameScheduleState` `"PPD"`).
+    pub fn is_postponed(&self) -> bool {
+        self.game_schedule_state.is_postponed()
+    }
+
+    /// Start time and UTC offsets, for the start in UTC, venue or Eastern
+    /// time.
+    pub fn venue_time(&self) -> GameVenueTime<'_> {
+        GameVenueTime {
+            start_time_utc: &self.start_time_utc,
+            venue_utc_offset: &self.venue_utc_offset,
+            eastern_utc_offset: &self.eastern_utc_offset,
+        }
+    }
+
+    /// Officials from whichever `gameInfo` block the payload carries
+    /// (top-level first, then `summary.gameInfo`), skipping empty blocks.
+    pub fn officials(&self) -> Option<&Officials> {
+        self.game_info
+            .iter()
+            .chain(self.summary.iter().filter_map(|s| s.game_info.as_ref()))
+            .find(|officials| !officials.is_empty())
+    }
+
+    /// Total game seconds elapsed, e.g. for per-60 rates during a live game
+    ///
+    /// Adds the lengths of the periods already played (20 minutes; 5 for
+    /// regular-season overtime) to the time played in the current one. A
+    /// shootout adds nothing past the end of overtime. `0` before the game
+    /// starts. Final games use the clock as reported, normally `00:00`.
+    pub fn game_elapsed_seconds(&self) -> u32 {
+        game_elapsed_seconds(
+            self.game_type,
+            &self.game_state,
+            &self.period_descriptor,
+            self.period_descriptor.regulation_periods(),
+            &self.clock,
+        )
+    }
+
+    /// The team with more goals; `None` until the game is final.
+    pub fn winner(&self) -> Option<&BoxscoreTeam> {
+        Some(self.final_result()?.0)
+    }
+
+    /// The team with fewer goals; `None` until the game is final.
+    pub fn loser(&self) -> Option<&BoxscoreTeam> {
+        Some(self.final_result()?.1)
+    }
+
+    /// `(winner, loser)` of a final game.
+    fn final_result(&self) -> Option<(&BoxscoreTeam, &BoxscoreTeam)> {
+        if !self.game_state.is_final() {
+            return None;
+        }
+        match self.away_team.score.cmp(&self.home_team.score) {
+            Ordering::Greater => Some((&self.away_team, &self.home_team)),
+            Ordering::Less => Some((&self.home_team, &self.away_team)),
+            Ordering::Equal => None,
+        }
+    }
+
+    /// Whether the game is past regulation: in (or decided in) overtime or
+    /// a shootout.
+    pub fn went_to_overtime(&self) -> bool {
+        self.period_descriptor.is_past_regulation()
+    }
+
+    /// Away team first, e.g. `"NJD 2 – BUF 1"`, with the period it ended
+    /// in (or is in) past regulation: `"NJD 2 – BUF 1 (OT)"`, `"(2OT)"`,
+    /// `"(SO)"`.
+    pub fn score_string(&self) -> String {
+        let mut score = format!(
+            "{} {} – {} {}",
+            self.away_team.abbrev,
+            self.away_team.score,
+            self.home_team.abbrev,
+            self.home_team.score
+        );
+        if self.went_to_overtime() {
+            score.push_str(&format!(" ({})", self.period_descriptor.label()));
+        }
+        score
+    }
+
+    /// Home forwards, then defensemen.
+    pub fn home_skaters(&self) -> impl Iterator<Item = &SkaterStats> {
+        self.player_by_game_stats.home_team.skaters()
+    }
+
+    /// Away forwards, then defensemen.
+    pub fn away_skaters(&self) -> impl Iterator<Item = &SkaterStats> {
+        self.player_by_game_stats.away_team.skaters()
+    }
+
+    /// `player_id`'s line, from either team's skaters or goalies.
+    pub fn player_stats(&self, player_id: PlayerId) -> Option<BoxscorePlayer<'_>> {
+        let stats = &self.player_by_game_stats;
+        [&stats.away_team, &stats.home_team]
+            .into_iter()
+            .find_map(|team| team.player(player_id))
+    }
+
+    /// National broadcasts in `sequence_number` order, one per network.
+    pub fn national_broadcasts(&self) -> Vec<&TvBroadcast> {
+        self.broadcasts_in(BroadcastMarket::National)
+    }
+
+    /// Home-market broadcasts in `sequence_number` order, one per network,
+    /// leaving out networks that also carry the game nationally.
+    pub fn home_broadcasts(&self) -> Vec<&TvBroadcast> {
+        self.broadcasts_in(BroadcastMarket::Home)
+    }
+
+    /// Away-market broadcasts in `sequence_number` order, one per network,
+    /// leaving out networks that also carry the game nationally.
+    pub fn away_broadcasts(&self) -> Vec<&TvBroadcast> {
+        self.broadcasts_in(BroadcastMarket::Away)
+    }
+
+    fn broadcasts_in(&self, market: BroadcastMarket) -> Vec<&TvBroadcast> {
+        // The API occasionally lists a network once per market; a national
+        // listing wins over a regional one.
+        let mut seen: HashSet<&str> = HashSet::new();
+        if market != BroadcastMarket::National {
+            seen.extend(
+                self.tv_broadcasts
+                    .iter()
+                    .filter(|broadcast| broadcast.market == BroadcastMarket::National)
+                    .map(|broadcast| broadcast.network.as_str()),
+            );
+        }
+        let mut broadcasts: Vec<&TvBroadcast> = self
+            .tv_broadcasts
+            .iter()
+            .filter(|broadcast| broadcast.market == market)
+            .collect();
+        broadcasts.sort_by_key(|broadcast| broadcast.sequence_number);
+        broadcasts.retain(|broadcast| seen.insert(broadcast.network.as_str()));
+        broadcasts
+    }
+}
+
+/// Summary block carried by some boxscore payload versions
+#[derive(Debug, Clone, Default, Serialize, Deserialize, PartialEq)]
+pub struct BoxscoreSummary {
+    #[serde(rename = "gameInfo", default)]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub game_info: Option<Officials>,
 }
 
 /// TV broadcast information
 #[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
 pub struct TvBroadcast {
     pub id: i64,
-    pub market: String,
+    pub market: BroadcastMarket,
     #[serde(rename = "countryCode")]
     pub country_code: String,
     pub network: String,
@@ -89,6 +283,50 @@ pub struct PeriodDescriptor {
     pub max_regulation_periods: i32,
 }
 
+impl PeriodDescriptor {
+    /// `max_regulation_periods`, or 3 when the payload leaves it out.
+    pub(crate) fn regulation_periods(&self) -> i32 {
+        if self.max_regulation_periods > 0 {
+            self.max_regulation_periods
+        } else {
+            3
+        }
+    }
+
+    /// Whether this is an overtime period or the shootout. Without a
+    /// period type, whether the number is past regulation.
+    pub fn is_past_regulation(&self) -> bool {
+        match &self.period_type {
+            Some(period_type) => period_type.is_overtime(),
+            None => self.number > self.regulation_periods(),
+        }
+    }
+
+    /// Scoreboard name of the period: `"1st"`, `"2nd"`, `"3rd"`, then `"OT"`,
+    /// `"2OT"`, ... for overtime periods and `"SO"` for the shootout.
+    pub fn label(&self) -> String {
+        let regulation = self.regulation_periods();
+        match self.period_type {
+            Some(PeriodType::Shootout) => "SO".to_string(),
+            Some(PeriodType::Overtime) | None if self.number > regulation => {
+                match self.number - regulation {
+                    1 => "OT".to_string(),
+                    overtime => format!("{}OT", overtime),
+                }
+            }
+            _ => {
+                let suffix = match self.number {
+                    1 => "st",
+                    2 => "nd",
+                    3 => "rd",
+                    _ => "th",
+                };
+                format!("{}{}", self.number, suffix)
+            }
+        }
+    }
+}
+
 /// Team information in boxscore
 #[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
 pub struct BoxscoreTeam {
@@ -97,7 +335,9 @@ pub struct BoxscoreTeam {
     pub common_name: LocalizedString,
     pub abbrev: String,
     pub score: i32,
-    pub sog: i32,
+    /// `None` in limited-scoring games, which have no shot totals.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub sog: Option<i32>,
     pub logo: String,
     #[serde(rename = "darkLogo")]
     pub dark_logo: String,
@@ -108,10 +348,13 @@ pub struct BoxscoreTeam {
 }
 
 /// Game clock information
-#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+///
+/// Readings within one period order from earliest to latest: more time
+/// remaining sorts first and the intermission after the period sorts last.
+#[derive(Debug, Clone, Default, Serialize, Deserialize, PartialEq, Eq)]
 pub struct GameClock {
     #[serde(rename = "timeRemaining")]
-    pub time_remaining: String,
+    pub time_remaining: IceTime,
     #[serde(rename = "secondsRemaining")]
     pub seconds_remaining: i32,
     pub running: bool,
@@ -119,8 +362,92 @@ pub struct GameClock {
     pub in_intermission: bool,
 }
 
+impl GameClock {
+    /// Time left on the clock. During an intermission this is the time left
+    /// in the break, not in a period.
+    pub fn remaining(&self) -> Duration {
+        Duration::from_secs(self.seconds_remaining.max(0) as u64)
+    }
+
+    /// Time played in a period of `period_length`; all of it during the
+    /// intermission that follows the period.
+    pub fn elapsed(&self, period_length: Duration) -> Duration {
+        if self.in_intermission {
+            return period_length;
+        }
+        period_length.saturating_sub(self.remaining())
+    }
+
+    /// Whether the period's clock has run out, including during the
+    /// intermission after it
+    pub fn is_expired(&self) -> bool {
+        self.in_intermission || self.seconds_remaining <= 0
+    }
+
+    fn sort_key(&self) -> (bool, Reverse<i32>, Reverse<&IceTime>, bool) {
+        (
+            self.in_intermission,
+            Reverse(self.seconds_remaining),
+            Reverse(&self.time_remaining),
+            self.running,
+        )
+    }
+}
+
+impl PartialOrd for GameClock {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl Ord for GameClock {
+    fn cmp(&self, other: &Self) -> Ordering {
+        self.sort_key().cmp(&other.sort_key())
+    }
+}
+
+/// Length of a regulation (and playoff overtime) period.
+pub(crate) const PERIOD_SECONDS: i32 = 20 * 60;
+
+/// Length of regular-season (3-on-3) overtime.
+pub(crate) const REGULAR_SEASON_OT_SECONDS: i32 = 5 * 60;
+
+/// Length of period `number` in a game with `regulation` regulation
+/// periods: 20 minutes, except 5-minute overtime outside the playoffs.
+pub(crate) fn period_length_seconds(game_type: GameType, number: i32, regulation: i32) -> u32 {
+    if number > regulation && !game_type.is_playoffs() {
+        REGULAR_SEASON_OT_SECONDS as u32
+    } else {
+        PERIOD_SECONDS as u32
+    }
+}
+
+/// Game seconds elapsed at `clock` during `period`; see
+/// [`Boxscore::game_elapsed_seconds`].
+pub(crate) fn game_elapsed_seconds(
+    game_type: GameType,
+    game_state: &GameState,
+    period: &PeriodDescriptor,
+    regulation: i32,
+    clock: &GameClock,
+) -> u32 {
+    if !game_state.has_started() || period.number < 1 {
+        return 0;
+    }
+    let before: u32 = (1..period.number)
+        .map(|earlier| period_length_seconds(game_type, earlier, regulation))
+        .sum();
+    let shootout = period.period_type == Some(PeriodType::Shootout)
+        || (!game_type.is_playoffs() && period.number > regulation + 1);
+    if shootout {
+        return before;
+    }
+    let length = period_length_seconds(game_type, period.number, regulation);
+    before + clock.elapsed(Duration::from_secs(length.into())).as_secs() as u32
+}
+
 /// Player statistics organized by team
-#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+#[derive(Debug, Clone, Default, Serialize, Deserialize, PartialEq)]
 pub struct PlayerByGameStats {
     #[serde(rename = "awayTeam")]
     pub away_team: TeamPlayerStats,
@@ -129,7 +456,7 @@ pub struct PlayerByGameStats {
 }
 
 /// Team's player statistics grouped by position
-#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+#[derive(Debug, Clone, Default, Serialize, Deserialize, PartialEq)]
 pub struct TeamPlayerStats {
     #[serde(default)]
     pub forwards: Vec<SkaterStats>,
@@ -139,13 +466,54 @@ pub struct TeamPlayerStats {
     pub goalies: Vec<GoalieStats>,
 }
 
+impl TeamPlayerStats {
+    /// Forwards, then defensemen.
+    pub fn skaters(&self) -> impl Iterator<Item = &SkaterStats> {
+        self.forwards.iter().chain(&self.defense)
+    }
+
+    /// `player_id`'s line among these skaters and goalies.
+    pub fn player(&self, player_id: PlayerId) -> Option<BoxscorePlayer<'_>> {
+        self.skaters()
+            .find(|skater| skater.player_id == player_id)
+            .map(BoxscorePlayer::Skater)
+            .or_else(|| {
+                self.goalies
+                    .iter()
+                    .find(|goalie| goalie.player_id == player_id)
+                    .map(BoxscorePlayer::Goalie)
+            })
+    }
+}
+
+/// One player's boxscore line, from [`Boxscore::player_stats`].
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum BoxscorePlayer<'a> {
+    Skater(&'a SkaterStats),
+    Goalie(&'a GoalieStats),
+}
+
 /// Aggregated team statistics for game comparison
-#[derive(Debug, Clone, PartialEq, Default)]
+///
+/// Built from boxscore player stats ([`Self::from_team_player_stats`]), the
+/// official right-rail comparison ([`Self::from_right_rail`]) or
+/// play-by-play events ([`Self::from_play_by_play`]). Faceoff counts only
+/// come from play-by-play, power-play opportunities from the right rail or
+/// play-by-play: boxscore player stats carry a faceoff percentage without
+/// counts, and nothing that identifies a power play.
+#[derive(Debug, Clone, PartialEq, Default, Serialize)]
 pub struct TeamGameStats {
     pub shots_on_goal: i32,
-    pub faceoff_wins: i32,
-    pub faceoff_total: i32,
+    /// Faceoffs won. `None` when built from boxscore player stats or the
+    /// right rail.
+    pub faceoff_wins: Option<i32>,
+    /// Faceoffs taken by either team. `None` when built from boxscore player
+    /// stats or the right rail.
+    pub faceoff_total: Option<i32>,
     pub power_play_goals: i32,
+    /// Opponent penalties that put this team on a power play (coincidental
+    /// penalties cancel). `None` when built from boxscore player stats.
+    pub power_play_opportunities: Option<i32>,
     pub penalty_minutes: i32,
     pub hits: i32,
     pub blocked_shots: i32,
@@ -153,8 +521,48 @@ pub struct TeamGameStats {
     pub takeaways: i32,
 }
 
+/// Where a [`TeamGameStats`] field came from (see
+/// [`TeamGameStats::from_sources`])
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize)]
+pub enum StatSource {
+    /// Summed from the boxscore's `playerByGameStats`
+    PlayerStats,
+    /// The right-rail `teamGameStats` comparison
+    RightRail,
+    /// Counted from play-by-play events
+    PlayByPlay,
+}
+
+/// Which source supplied each [`TeamGameStats`] field; `None` when no
+/// source had it and the field was left at its default
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default, Serialize)]
+pub struct StatProvenance {
+    pub shots_on_goal: Option<StatSource>,
+    pub faceoff_wins: Option<StatSource>,
+    pub faceoff_total: Option<StatSource>,
+    pub power_play_goals: Option<StatSource>,
+    pub power_play_opportunities: Option<StatSource>,
+    pub penalty_minutes: Option<StatSource>,
+    pub hits: Option<StatSource>,
+    pub blocked_shots: Option<StatSource>,
+    pub giveaways: Option<StatSource>,
+    pub takeaways: Option<StatSource>,
+}
+
+/// The first candidate with a value, in precedence order
+fn first_source<const N: usize>(
+    candidates: [(StatSource, Option<i32>); N],
+) -> Option<(i32, StatSource)> {
+    candidates
+        .into_iter()
+        .find_map(|(source, value)| value.map(|value| (value, source)))
+}
+
 impl TeamGameStats {
     /// Calculate aggregated team statistics from individual player stats
+    ///
+    /// Leaves the faceoff counts and power-play opportunities `None`; use
+    /// [`Self::from_play_by_play`] for those.
     pub fn from_team_player_stats(stats: &TeamPlayerStats) -> Self {
         let mut team_stats = Self::default();
 
@@ -164,6 +572,158 @@ impl TeamGameStats {
         team_stats
     }
 
+    /// One side's statistics from the right-rail `teamGameStats`, the NHL's
+    /// official team comparison
+    ///
+    /// `None` unless the rail lists every count category (`sog`,
+    /// `powerPlay`, `pim`, `hits`, `blockedShots`, `giveaways`,
+    /// `takeaways`), which it only does once the game has started. The
+    /// rail gives a faceoff percentage but no counts, so the faceoff fields
+    /// stay `None`.
+    pub fn from_right_rail(right_rail: &SeasonSeriesMatchup, side: HomeRoad) -> Option<Self> {
+        let value = |category: &str| right_rail.team_stat(category).map(|stat| stat.value(side));
+        let count = |category: &str| value(category).and_then(|value| value.as_i32());
+        let (power_play_goals, power_play_opportunities) = value("powerPlay")?.as_fraction()?;
+
+        Some(Self {
+            shots_on_goal: count("sog")?,
+            faceoff_wins: None,
+            faceoff_total: None,
+            power_play_goals,
+            power_play_opportunities: Some(power_play_opportunities),
+            penalty_minutes: count("pim")?,
+            hits: count("hits")?,
+            blocked_shots: count("blockedShots")?,
+            giveaways: count("giveaways")?,
+            takeaways: count("takeaways")?,
+        })
+    }
+
+    /// One side's statistics from whichever sources have them, with the
+    /// source used for each field
+    ///
+    /// Some final games (older ones, some international games) come with
+    /// empty `playerByGameStats`, which [`Self::from_team_player_stats`]
+    /// turns into all zeros. Each field takes the first source that has it:
+    ///
+    /// 1. boxscore player stats, when the side lists any skaters
+    /// 2. the right-rail `teamGameStats` (`sog`, `powerPlay`, `pim`, `hits`,
+    ///    `blockedShots`, `giveaways`, `takeaways`)
+    /// 3. play-by-play events
+    ///
+    /// Faceoff counts only come from play-by-play, and power-play
+    /// opportunities from the right rail or play-by-play.
+    pub fn from_sources(
+        boxscore: &Boxscore,
+        right_rail: Option<&SeasonSeriesMatchup>,
+        play_by_play: Option<&PlayByPlay>,
+        side: HomeRoad,
+    ) -> (Self, StatProvenance) {
+        let players = match side {
+            HomeRoad::Home => &boxscore.player_by_game_stats.home_team,
+            HomeRoad::Road => &boxscore.player_by_game_stats.away_team,
+        };
+        let from_players = (!players.forwards.is_empty() || !players.defense.is_empty())
+            .then(|| Self::from_team_player_stats(players));
+        let from_events = play_by_play.map(|pbp| Self::from_play_by_play(pbp, side));
+        let rail = |category: &str| {
+            right_rail
+                .and_then(|rail| rail.team_stat(category))
+                .map(|stat| stat.value(side))
+        };
+        let rail_power_play = rail("powerPlay").and_then(|value| value.as_fraction());
+
+        let counted = |field: fn(&Self) -> i32, category: &str| {
+            first_source([
+                (StatSource::PlayerStats, from_players.as_ref().map(field)),
+                (
+                    StatSource::RightRail,
+                    rail(category).and_then(|value| value.as_i32()),
+                ),
+                (StatSource::PlayByPlay, from_events.as_ref().map(field)),
+            ])
+        };
+
+        let mut stats = Self::default();
+        let mut provenance = StatProvenance::default();
+        for (picked, value, source) in [
+            (
+                counted(|s| s.shots_on_goal, "sog"),
+                &mut stats.shots_on_goal,
+                &mut provenance.shots_on_goal,
+            ),
+            (
+                first_source([
+                    (
+                        StatSource::PlayerStats,
+                        from_players.as_ref().map(|s| s.power_play_goals),
+                    ),
+                    (
+                        StatSource::RightRail,
+                        rail_power_play.map(|(goals, _)| goals),
+                    ),
+                    (
+                        StatSource::PlayByPlay,
+                        from_events.as_ref().map(|s| s.power_play_goals),
+                    ),
+                ]),
+                &mut stats.power_play_goals,
+                &mut provenance.power_play_goals,
+            ),
+            (
+                counted(|s| s.penalty_minutes, "pim"),
+                &mut stats.penalty_minutes,
+                &mut provenance.penalty_minutes,
+            ),
+            (
+                counted(|s| s.hits, "hits"),
+                &mut stats.hits,
+                &mut provenance.hits,
+            ),
+            (
+                counted(|s| s.blocked_shots, "blockedShots"),
+                &mut stats.blocked_shots,
+                &mut provenance.blocked_shots,
+            ),
+            (
+                counted(|s| s.giveaways, "giveaways"),
+                &mut stats.giveaways,
+                &mut provenance.giveaways,
+            ),
+            (
+                counted(|s| s.takeaways, "takeaways"),
+                &mut stats.takeaways,
+                &mut provenance.takeaways,
+            ),
+        ] {
+            if let Some((picked, picked_source)) = picked {
+                *value = picked;
+                *source = Some(picked_source);
+            }
+        }
+
+        if let Some(events) = &from_events {
+            stats.faceoff_wins = events.faceoff_wins;
+            stats.faceoff_total = events.faceoff_total;
+            provenance.faceoff_wins = Some(StatSource::PlayByPlay);
+            provenance.faceoff_total = Some(StatSource::PlayByPlay);
+        }
+        if let Some((opportunities, source)) = first_source([
+            (StatSource::RightRail, rail_power_play.map(|(_, opps)| opps)),
+            (
+                StatSource::PlayByPlay,
+                from_events
+                    .as_ref()
+                    .and_then(|s| s.power_play_opportunities),
+            ),
+        ]) {
+            stats.power_play_opportunities = Some(opportunities);
+            provenance.power_play_opportunities = Some(source);
+        }
+
+        (stats, provenance)
+    }
+
     fn aggregate_skater_stats(team_stats: &mut TeamGameStats, stats: &TeamPlayerStats) {
         for skater in stats.forwards.iter().chain(stats.defense.iter()) {
             team_stats.shots_on_goal += skater.sog;
@@ -173,20 +733,6 @@ impl TeamGameStats {
             team_stats.blocked_shots += skater.blocked_shots;
             team_stats.giveaways += skater.giveaways;
             team_stats.takeaways += skater.takeaways;
-
-            Self::add_faceoff_stats(team_stats, skater);
-        }
-    }
-
-    fn add_faceoff_stats(team_stats: &mut TeamGameStats, skater: &SkaterStats) {
-        // TODO: Revisit this logic - not sure only counting centers for faceoffs is correct.
-        // Wings can also take faceoffs in certain situations.
-        if skater.position == Some(Position::Center) && skater.faceoff_winning_pctg > 0.0 {
-            // Estimate total faceoffs using shifts as a proxy for faceoff participation
-            let estimated_faceoffs = skater.shifts;
-            team_stats.faceoff_total += estimated_faceoffs;
-            team_stats.faceoff_wins +=
-                (estimated_faceoffs as f64 * skater.faceoff_winning_pctg).round() as i32;
         }
     }
 
@@ -198,11 +744,113 @@ impl TeamGameStats {
         }
     }
 
-    pub fn faceoff_percentage(&self) -> f64 {
-        if self.faceoff_total > 0 {
-            (self.faceoff_wins as f64 / self.faceoff_total as f64) * 100.0
-        } else {
-            0.0
+    /// Calculate one side's statistics from play-by-play events
+    ///
+    /// Faceoffs, shots, hits, giveaways, takeaways and penalties are credited
+    /// to the event's owning team; blocked shots to the team of the blocking
+    /// player. Shootout attempts are ignored. A power-play opportunity is
+    /// counted for each opponent minor, major, bench minor or match penalty
+    /// not cancelled by one of this team's penalties at the same stoppage.
+    pub fn from_play_by_play(play_by_play: &PlayByPlay, side: HomeRoad) -> Self {
+        let (team_id, is_power_play): (TeamId, fn(&GameSituation) -> bool) = match side {
+            HomeRoad::Home => (play_by_play.home_team.id, GameSituation::is_home_power_play),
+            HomeRoad::Road => (play_by_play.away_team.id, GameSituation::is_away_power_play),
+        };
+        let roster: HashSet<PlayerId> = play_by_play
+            .roster_spots
+            .iter()
+            .filter(|spot| spot.team_id == team_id)
+            .map(|spot| spot.player_id)
+            .collect();
+
+        let mut stats = Self::default();
+        let mut faceoff_wins = 0;
+        let mut faceoff_total = 0;
+        // (own, opponent) power-play penalties per stoppage.
+        let mut penalties: HashMap<(i32, &str), (i32, i32)> = HashMap::new();
+
+        for play in &play_by_play.plays {
+            if play.period_descriptor.period_type == Some(PeriodType::Shootout) {
+                continue;
+            }
+            let Some(details) = &play.details else {
+                continue;
+            };
+            let owned = details.event_owner_team_id == Some(team_id);
+
+            match play.event_type() {
+                PlayEventType::Faceoff => {
+                    faceoff_total += 1;
+                    faceoff_wins += i32::from(owned);
+                }
+                PlayEventType::ShotOnGoal if owned => stats.shots_on_goal += 1,
+                PlayEventType::Goal if owned => {
+                    stats.shots_on_goal += 1;
+                    if play.situation().is_some_and(|s| is_power_play(&s)) {
+                        stats.power_play_goals += 1;
+                    }
+                }
+                PlayEventType::Hit if owned => stats.hits += 1,
+                PlayEventType::Giveaway if owned => stats.giveaways += 1,
+                PlayEventType::Takeaway if owned => stats.takeaways += 1,
+                PlayEventType::BlockedShot
+                    if details
+                        .blocking_player_id
+                        .is_some_and(|id| roster.contains(&id)) =>
+                {
+                    stats.blocked_shots += 1;
+                }
+                PlayEventType::Penalty => {
+                    if owned {
+                        stats.penalty_minutes += details.duration.unwrap_or(0);
+                    }
+                    let creates_power_play = details
+                        .type_code
+                        .as_ref()
+                        .is_some_and(|severity| severity.creates_power_play());
+                    if creates_power_play && details.event_owner_team_id.is_some() {
+                        let stoppage = penalties
+                            .entry((play.period_descriptor.number, play.time_in_period.as_str()))
+                            .or_default();
+                        if owned {
+                            stoppage.0 += 1;
+                        } else {
+                            stoppage.1 += 1;
+                        }
+                    }
+                }
+                _ => {}
+            }
+        }
+
+        stats.faceoff_wins = Some(faceoff_wins);
+        stats.faceoff_total = Some(faceoff_total);
+        stats.power_play_opportunities = Some(
+            penalties
+                .values()
+                .map(|(own, opponent)| (opponent - own).max(0))
+                .sum(),
+        );
+        stats
+    }
+
+    /// Faceoff win percentage (0-100). `None` without faceoff counts or when
+    /// no faceoffs were taken.
+    pub fn faceoff_percentage(&self) -> Option<f64> {
+        match (self.faceoff_wins, self.faceoff_total) {
+            (Some(wins), Some(total)) if total > 0 => Some(wins as f64 / total as f64 * 100.0),
+            _ => None,
+        }
+    }
+
+    /// Power-play conversion percentage (0-100). `None` without opportunity
+    /// counts or when there were no opportunities.
+    pub fn power_play_percentage(&self) -> Option<f64> {
+        match self.power_play_opportunities {
+            Some(opportunities) if opportunities > 0 => {
+                Some(self.power_play_goals as f64 / opportunities as f64 * 100.0)
+            }
+            _ => None,
         }
     }
 }
@@ -212,8 +860,11 @@ impl TeamGameStats {
 pub struct SkaterStats {
     #[serde(rename = "playerId")]
     pub player_id: PlayerId,
-    #[serde(rename = "sweaterNumber")]
-    pub sweater_number: i32,
+    /// `None` when the API omits it or sends `null`, as for emergency backup
+    /// goalies and players signed mid-game.
+    #[serde(rename = "sweaterNumber", default)]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub sweater_number: Option<i32>,
     pub name: LocalizedString,
     /// `None` for historical data where the API returns an empty position code.
     #[serde(deserialize_with = "empty_string_as_none", default)]
@@ -231,7 +882,7 @@ pub struct SkaterStats {
     pub sog: i32,
     #[serde(rename = "faceoffWinningPctg")]
     pub faceoff_winning_pctg: f64,
-    pub toi: String,
+    pub toi: IceTime,
     #[serde(rename = "blockedShots")]
     pub blocked_shots: i32,
     pub shifts: i32,
@@ -244,21 +895,24 @@ pub struct SkaterStats {
 pub struct GoalieStats {
     #[serde(rename = "playerId")]
     pub player_id: PlayerId,
-    #[serde(rename = "sweaterNumber")]
-    pub sweater_number: i32,
+    /// `None` when the API omits it or sends `null`, as for emergency backup
+    /// goalies and players signed mid-game.
+    #[serde(rename = "sweaterNumber", default)]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub sweater_number: Option<i32>,
     pub name: LocalizedString,
     /// `None` for historical data where the API returns an empty position code.
     #[serde(deserialize_with = "empty_string_as_none", default)]
     #[serde(skip_serializing_if = "Option::is_none")]
     pub position: Option<Position>,
     #[serde(rename = "evenStrengthShotsAgainst")]
-    pub even_strength_shots_against: String,
+    pub even_strength_shots_against: SavesShots,
     #[serde(rename = "powerPlayShotsAgainst")]
-    pub power_play_shots_against: String,
+    pub power_play_shots_against: SavesShots,
     #[serde(rename = "shorthandedShotsAgainst")]
-    pub shorthanded_shots_against: String,
+    pub shorthanded_shots_against: SavesShots,
     #[serde(rename = "saveShotsAgainst")]
-    pub save_shots_against: String,
+    pub save_shots_against: SavesShots,
     #[serde(skip_serializing_if = "Option::is_none")]
     #[serde(rename = "savePctg")]
     pub save_pctg: Option<f64>,
@@ -272,7 +926,7 @@ pub struct GoalieStats {
     pub pim: Option<i32>,
     #[serde(rename = "goalsAgainst")]
     pub goals_against: i32,
-    pub toi: String,
+    pub toi: IceTime,
     #[serde(skip_serializing_if = "Option::is_none")]
     pub starter: Option<bool>,
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -282,74 +936,119 @@ pub struct GoalieStats {
     pub saves: i32,
 }
 
+/// Error returned when a string is not a `"saves/shots"` fraction.
+#[derive(Debug, Clone, PartialEq, Eq, Error)]
+#[error("invalid saves/shots value: {0:?} (expected \"S/T\")")]
+pub struct ParseSavesShotsError(String);
+
+/// A goalie's saves out of shots faced, sent by the API as `"25/26"`
+///
+/// Serializes back to the same string. Splits add up (`even_strength +
+/// power_play + shorthanded` matches `save_shots_against`).
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct SavesShots {
+    pub saves: i32,
+    pub shots: i32,
+}
+
+impl SavesShots {
+    /// Save percentage as a fraction (`0.9615` for 25/26). `None` with no
+    /// shots faced.
+    pub fn save_pctg(&self) -> Option<f64> {
+        (self.shots > 0).then(|| f64::from(self.saves) / f64::from(self.shots))
+    }
+
+    /// Shots that went in.
+    pub fn goals_against(&self) -> i32 {
+        self.shots - self.saves
+    }
+}
+
+impl fmt::Display for SavesShots {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}/{}", self.saves, self.shots)
+    }
+}
+
+impl FromStr for SavesShots {
+    type Err = ParseSavesShotsError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let invalid = || ParseSavesShotsError(s.to_string());
+        let (saves, shots) = s.split_once('/').ok_or_else(invalid)?;
+        let count = |part: &str| -> Result<i32, ParseSavesShotsError> {
+            if part.is_empty() || !part.bytes().all(|b| b.is_ascii_digit()) {
+                return Err(invalid());
+            }
+            part.parse().map_err(|_| invalid())
+        };
+        Ok(Self {
+            saves: count(saves)?,
+            shots: count(shots)?,
+        })
+    }
+}
+
+impl Add for SavesShots {
+    type Output = Self;
+
+    fn add(self, rhs: Self) -> Self {
+        Self {
+            saves: self.saves + rhs.saves,
+            shots: self.shots + rhs.shots,
+        }
+    }
+}
+
+impl Serialize for SavesShots {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        serializer.collect_str(self)
+    }
+}
+
+impl<'de> Deserialize<'de> for SavesShots {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        let value = String::deserialize(deserializer)?;
+        value.parse().map_err(D::Error::custom)
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::test_util::assert_no_unmapped_fields;
+    use crate::types::enums::HomeRoad;
+
+    /// A live boxscore trimmed with `test_util::fixture_trim` to two forwards,
+    /// one defenseman and one goalie per team.
+    const BOXSCORE_FIXTURE: &str = include_str!("../../tests/fixtures/boxscore.json");
+    const LIMITED_BOXSCORE_FIXTURE: &str = include_str!("../../tests/fixtures/boxscore_1955.json");
+    const POSTPONED_BOXSCORE_FIXTURE: &str = include_str!("../../tests/fixtures/boxscore_ppd.json");
 
     #[test]
-    fn test_boxscore_deserialization() {
-        let json = r#"{
-            "id": 2024020001,
-            "season": 20242025,
-            "gameType": 2,
-            "limitedScoring": false,
-            "gameDate": "2024-10-04",
-            "venue": {"default": "Test Arena"},
-            "venueLocation": {"default": "Test City"},
-            "startTimeUTC": "2024-10-04T19:00:00Z",
-            "easternUTCOffset": "-04:00",
-            "venueUTCOffset": "-04:00",
-            "tvBroadcasts": [],
-            "gameState": "LIVE",
-            "gameScheduleState": "OK",
-            "periodDescriptor": {
-                "number": 2,
-                "periodType": "REG",
-                "maxRegulationPeriods": 3
-            },
-            "awayTeam": {
-                "id": 1,
-                "commonName": {"default": "Devils"},
-                "abbrev": "NJD",
-                "score": 2,
-                "sog": 15,
-                "logo": "https://assets.nhle.com/logos/nhl/svg/NJD_light.svg",
-                "darkLogo": "https://assets.nhle.com/logos/nhl/svg/NJD_dark.svg",
-                "placeName": {"default": "New Jersey"},
-                "placeNameWithPreposition": {"default": "New Jersey"}
-            },
-            "homeTeam": {
-                "id": 7,
-                "commonName": {"default": "Sabres"},
-                "abbrev": "BUF",
-                "score": 1,
-                "sog": 12,
-                "logo": "https://assets.nhle.com/logos/nhl/svg/BUF_light.svg",
-                "darkLogo": "https://assets.nhle.com/logos/nhl/svg/BUF_dark.svg",
-                "placeName": {"default": "Buffalo"},
-                "placeNameWithPreposition": {"default": "Buffalo"}
-            },
-            "clock": {
-                "timeRemaining": "10:15",
-                "secondsRemaining": 615,
-                "running": true,
-                "inIntermission": false
-            },
-            "playerByGameStats": {
-                "awayTeam": {
-                    "forwards": [],
-                    "defense": [],
-                    "goalies": []
-                },
-                "homeTeam": {
-                    "forwards": [],
-                    "defense": [],
-                    "goalies": []
-                }
-            }
-        }"#;
+    fn test_period_descriptor_label() {
+        let period = |number, period_type| PeriodDescriptor {
+            number,
+            period_type: Some(period_type),
+            max_regulation_periods: 3,
+        };
+        assert_eq!(period(1, PeriodType::Regulation).label(), "1st");
+        assert_eq!(period(3, PeriodType::Regulation).label(), "3rd");
+        assert_eq!(period(4, PeriodType::Overtime).label(), "OT");
+        assert_eq!(period(6, PeriodType::Overtime).label(), "3OT");
+        assert_eq!(period(5, PeriodType::Shootout).label(), "SO");
+    }
 
-        let boxscore: Boxscore = serde_json::from_str(json).unwrap();
+    #[test]
+    fn test_boxscore_deserialization() {
+        let boxscore: Boxscore = serde_json::from_str(BOXSCORE_FIXTURE).unwrap();
+        assert_no_unmapped_fields::<Boxscore>(BOXSCORE_FIXTURE);
         assert_eq!(boxscore.id, GameId::new(2024020001));
         assert_eq!(boxscore.season, Season::new(2024));
         assert_eq!(boxscore.game_type, GameType::RegularSeason);
@@ -359,10 +1058,24 @@ mod tests {
         assert_eq!(boxscore.home_team.abbrev, "BUF");
         assert_eq!(boxscore.away_team.score, 2);
         assert_eq!(boxscore.home_team.score, 1);
-        assert_eq!(boxscore.clock.time_remaining, "10:15");
+        assert_eq!(
+            boxscore.clock.time_remaining,
+            "10:15".parse::<IceTime>().unwrap()
+        );
         assert_eq!(boxscore.clock.seconds_remaining, 615);
         assert!(boxscore.clock.running);
         assert_eq!(boxscore.period_descriptor.number, 2);
+
+        let away = &boxscore.player_by_game_stats.away_team;
+        assert_eq!(away.forwards.len(), 2);
+        assert_eq!(away.defense.len(), 1);
+        assert_eq!(away.forwards[1].power_play_goals, 1);
+        let goalie = &away.goalies[0];
+        assert_eq!(goalie.starter, Some(true));
+        assert_eq!(
+            goalie.even_strength_shots_against + goalie.power_play_shots_against,
+            goalie.save_shots_against
+        );
     }
 
     /// `gameScheduleState` is typed `GameScheduleState`, matching the sibling
@@ -370,59 +1083,165 @@ mod tests {
     /// `"CNCL"`.
     #[test]
     fn test_boxscore_deserialization_cancelled_game() {
-        let json = r#"{
-            "id": 2024020002,
-            "season": 20242025,
-            "gameType": 2,
-            "limitedScoring": false,
-            "gameDate": "2024-10-05",
-            "venue": {"default": "Test Arena"},
-            "venueLocation": {"default": "Test City"},
-            "startTimeUTC": "2024-10-05T19:00:00Z",
-            "easternUTCOffset": "-04:00",
-            "venueUTCOffset": "-04:00",
-            "tvBroadcasts": [],
-            "gameState": "OFF",
-            "gameScheduleState": "CNCL",
-            "periodDescriptor": {},
-            "awayTeam": {
-                "id": 1,
-                "commonName": {"default": "Devils"},
-                "abbrev": "NJD",
-                "score": 0,
-                "sog": 0,
-                "logo": "https://assets.nhle.com/logos/nhl/svg/NJD_light.svg",
-                "darkLogo": "https://assets.nhle.com/logos/nhl/svg/NJD_dark.svg",
-                "placeName": {"default": "New Jersey"},
-                "placeNameWithPreposition": {"default": "New Jersey"}
-            },
-            "homeTeam": {
-                "id": 7,
-                "commonName": {"default": "Sabres"},
-                "abbrev": "BUF",
-                "score": 0,
-                "sog": 0,
-                "logo": "https://assets.nhle.com/logos/nhl/svg/BUF_light.svg",
-                "darkLogo": "https://assets.nhle.com/logos/nhl/svg/BUF_dark.svg",
-                "placeName": {"default": "Buffalo"},
-                "placeNameWithPreposition": {"default": "Buffalo"}
-            },
-            "clock": {
-                "timeRemaining": "20:00",
-                "secondsRemaining": 1200,
-                "running": false,
-                "inIntermission": false
-            },
-            "playerByGameStats": {
-                "awayTeam": {"forwards": [], "defense": [], "goalies": []},
-                "homeTeam": {"forwards": [], "defense": [], "goalies": []}
-            }
-        }"#;
-
-        let boxscore: Boxscore = serde_json::from_str(json).unwrap();
+        let json = boxscore_json(
+            r#", "gameState": "OFF", "gameScheduleState": "CNCL", "periodDescriptor": {}"#,
+        );
+        let boxscore: Boxscore = serde_json::from_str(&json).unwrap();
         assert_eq!(boxscore.game_schedule_state, GameScheduleState::Cancelled);
     }
 
+    /// [`BOXSCORE_FIXTURE`] with the top-level fields in `extra_fragment`
+    /// (`, "key": value, ...`) added or replaced.
+    fn boxscore_json(extra_fragment: &str) -> String {
+        let mut json: serde_json::Value = serde_json::from_str(BOXSCORE_FIXTURE).unwrap();
+        let extra: serde_json::Value = serde_json::from_str(&format!(
+            "{{{}}}",
+            extra_fragment.trim_start().trim_start_matches(',')
+        ))
+        .unwrap();
+        json.as_object_mut()
+            .unwrap()
+            .extend(extra.as_object().unwrap().clone());
+        json.to_string()
+    }
+
+    #[test]
+    fn test_boxscore_winner_and_score_string() {
+        let live: Boxscore = serde_json::from_str(BOXSCORE_FIXTURE).unwrap();
+        assert_eq!(live.winner(), None);
+        assert_eq!(live.loser(), None);
+        assert!(!live.went_to_overtime());
+        assert_eq!(live.score_string(), "NJD 2 – BUF 1");
+
+        let json = boxscore_json(r#", "gameState": "OFF""#);
+        let regulation: Boxscore = serde_json::from_str(&json).unwrap();
+        assert_eq!(regulation.winner().unwrap().abbrev, "NJD");
+        assert_eq!(regulation.loser().unwrap().abbrev, "BUF");
+
+        let json = boxscore_json(
+            r#", "gameState": "FINAL",
+            "periodDescriptor": {"number": 4, "periodType": "OT", "maxRegulationPeriods": 3}"#,
+        );
+        let mut overtime: Boxscore = serde_json::from_str(&json).unwrap();
+        overtime.home_team.score = 3;
+        assert!(overtime.went_to_overtime());
+        assert_eq!(overtime.winner().unwrap().abbrev, "BUF");
+        assert_eq!(overtime.score_string(), "NJD 2 – BUF 3 (OT)");
+
+        overtime.period_descriptor = PeriodDescriptor {
+            number: 5,
+            period_type: Some(PeriodType::Shootout),
+            max_regulation_periods: 3,
+        };
+        assert_eq!(overtime.score_string(), "NJD 2 – BUF 3 (SO)");
+    }
+
+    #[test]
+    fn test_boxscore_tied_live_game_has_no_winner() {
+        let json = boxscore_json(
+            r#", "gameState": "CRIT",
+            "periodDescriptor": {"number": 3, "periodType": "REG", "maxRegulationPeriods": 3}"#,
+        );
+        let mut boxscore: Boxscore = serde_json::from_str(&json).unwrap();
+        boxscore.home_team.score = 2;
+
+        assert_eq!(boxscore.winner(), None);
+        assert_eq!(boxscore.loser(), None);
+        assert!(!boxscore.went_to_overtime());
+        assert_eq!(boxscore.score_string(), "NJD 2 – BUF 2");
+    }
+
+    #[test]
+    fn test_boxscore_skaters_and_player_stats() {
+        let boxscore: Boxscore = serde_json::from_str(BOXSCORE_FIXTURE).unwrap();
+
+        let away: Vec<PlayerId> = boxscore
+            .away_skaters()
+            .map(|skater| skater.player_id)
+            .collect();
+        let team = &boxscore.player_by_game_stats.away_team;
+        assert_eq!(
+            away,
+            [
+                team.forwards[0].player_id,
+                team.forwards[1].player_id,
+                team.defense[0].player_id
+            ]
+        );
+        assert_eq!(boxscore.home_skaters().count(), 3);
+
+        match boxscore.player_stats(PlayerId::new(8480002)) {
+            Some(BoxscorePlayer::Skater(skater)) => assert_eq!(skater.name.default, "D. Hamilton"),
+            other => panic!("expected Hamilton's skater line, got {:?}", other),
+        }
+        let home_goalie = &boxscore.player_by_game_stats.home_team.goalies[0];
+        assert_eq!(
+            boxscore.player_stats(home_goalie.player_id),
+            Some(BoxscorePlayer::Goalie(home_goalie))
+        );
+        assert_eq!(boxscore.player_stats(PlayerId::new(1)), None);
+    }
+
+    #[test]
+    fn test_boxscore_live_situation() {
+        let json = boxscore_json(
+            r#", "situation": {
+                "homeTeam": {"abbrev": "BUF", "strength": 4,
+                    "penaltyBox": [{"playerId": 8480839, "name": {"default": "R. Dahlin"},
+                        "sweaterNumber": 26, "secondsRemaining": 45}]},
+                "awayTeam": {"abbrev": "NJD", "strength": 5, "situationDescriptions": ["PP"]},
+                "situationCode": "1541"
+            }"#,
+        );
+        let boxscore: Boxscore = serde_json::from_str(&json).unwrap();
+        assert_no_unmapped_fields::<Boxscore>(&json);
+        let situation = boxscore.situation.unwrap();
+        assert_eq!(situation.power_play_team(), Some(HomeRoad::Road));
+        assert_eq!(situation.seconds_until_even_strength(), Some(45));
+    }
+
+    #[test]
+    fn test_boxscore_without_situation() {
+        let boxscore: Boxscore = serde_json::from_str(&boxscore_json("")).unwrap();
+        assert!(boxscore.situation.is_none());
+    }
+
+    #[test]
+    fn test_boxscore_officials_absent() {
+        let boxscore: Boxscore = serde_json::from_str(&boxscore_json("")).unwrap();
+        assert!(boxscore.game_info.is_none());
+        assert!(boxscore.summary.is_none());
+        assert!(boxscore.officials().is_none());
+    }
+
+    #[test]
+    fn test_boxscore_officials_top_level_game_info() {
+        let json = boxscore_json(
+            r#", "gameInfo": {
+                "referees": [{"default": "Wes McCauley"}],
+                "linesmen": [{"default": "Steve Barton"}]
+            }"#,
+        );
+        let boxscore: Boxscore = serde_json::from_str(&json).unwrap();
+        let officials = boxscore.officials().unwrap();
+        assert_eq!(officials.referees[0].default, "Wes McCauley");
+        assert_eq!(officials.linesmen[0].default, "Steve Barton");
+    }
+
+    #[test]
+    fn test_boxscore_officials_summary_game_info() {
+        let json = boxscore_json(
+            r#", "gameInfo": {"referees": [], "linesmen": []},
+            "summary": {"gameInfo": {"referees": [{"default": "Chris Rooney"}]}}"#,
+        );
+        let boxscore: Boxscore = serde_json::from_str(&json).unwrap();
+        assert_no_unmapped_fields::<Boxscore>(&json);
+        // The empty top-level block is skipped in favour of the summary one.
+        let officials = boxscore.officials().unwrap();
+        assert_eq!(officials.referees[0].default, "Chris Rooney");
+        assert!(officials.linesmen.is_empty());
+    }
+
     #[test]
     fn test_skater_stats_deserialization() {
         let json = r#"{
@@ -447,8 +1266,9 @@ mod tests {
         }"#;
 
         let stats: SkaterStats = serde_json::from_str(json).unwrap();
+        assert_no_unmapped_fields::<SkaterStats>(json);
         assert_eq!(stats.player_id, PlayerId::new(8480002));
-        assert_eq!(stats.sweater_number, 13);
+        assert_eq!(stats.sweater_number, Some(13));
         assert_eq!(stats.name.default, "N. Hischier");
         assert_eq!(stats.position, Some(Position::Center));
         assert_eq!(stats.goals, 1);
@@ -458,6 +1278,44 @@ mod tests {
         assert_eq!(stats.faceoff_winning_pctg, 0.55);
     }
 
+    /// Emergency backup goalies and players signed mid-game can come
+    /// without a number, the field either missing or `null`.
+    #[test]
+    fn test_player_stats_without_sweater_number() {
+        let skater = |sweater_number: &str| {
+            format!(
+                r#"{{"playerId": 8480002, {}"name": {{"default": "N. Hischier"}},
+                    "position": "C", "goals": 0, "assists": 0, "points": 0, "plusMinus": 0,
+                    "pim": 0, "hits": 0, "powerPlayGoals": 0, "sog": 0,
+                    "faceoffWinningPctg": 0.0, "toi": "00:00", "blockedShots": 0,
+                    "shifts": 0, "giveaways": 0, "takeaways": 0}}"#,
+                sweater_number
+            )
+        };
+        let goalie = |sweater_number: &str| {
+            format!(
+                r#"{{"playerId": 8482823, {}"name": {{"default": "S. Ebug"}},
+                    "position": "G", "evenStrengthShotsAgainst": "0/0",
+                    "powerPlayShotsAgainst": "0/0", "shorthandedShotsAgainst": "0/0",
+                    "saveShotsAgainst": "0/0", "evenStrengthGoalsAgainst": 0,
+                    "powerPlayGoalsAgainst": 0, "shorthandedGoalsAgainst": 0, "pim": 0,
+                    "goalsAgainst": 0, "toi": "00:00", "starter": false,
+                    "shotsAgainst": 0, "saves": 0}}"#,
+                sweater_number
+            )
+        };
+
+        for field in ["", r#""sweaterNumber": null, "#] {
+            let stats: SkaterStats = serde_json::from_str(&skater(field)).unwrap();
+            assert_eq!(stats.sweater_number, None);
+            let stats: GoalieStats = serde_json::from_str(&goalie(field)).unwrap();
+            assert_eq!(stats.sweater_number, None);
+            assert!(!serde_json::to_string(&stats)
+                .unwrap()
+                .contains("sweaterNumber"));
+        }
+    }
+
     #[test]
     fn test_goalie_stats_deserialization() {
         let json = r#"{
@@ -483,8 +1341,9 @@ mod tests {
         }"#;
 
         let stats: GoalieStats = serde_json::from_str(json).unwrap();
+        assert_no_unmapped_fields::<GoalieStats>(json);
         assert_eq!(stats.player_id, PlayerId::new(8474593));
-        assert_eq!(stats.sweater_number, 25);
+        assert_eq!(stats.sweater_number, Some(25));
         assert_eq!(stats.name.default, "J. Markstrom");
         assert_eq!(stats.position, Some(Position::Goalie));
         assert_eq!(stats.save_pctg, Some(0.967));
@@ -493,6 +1352,63 @@ mod tests {
         assert_eq!(stats.shots_against, 31);
         assert_eq!(stats.starter, Some(true));
         assert_eq!(stats.decision, Some(GoalieDecision::Win));
+        assert_eq!(
+            stats.even_strength_shots_against,
+            SavesShots {
+                saves: 25,
+                shots: 26
+            }
+        );
+        assert_eq!(
+            stats.even_strength_shots_against
+                + stats.power_play_shots_against
+                + stats.shorthanded_shots_against,
+            stats.save_shots_against
+        );
+    }
+
+    #[test]
+    fn test_saves_shots_zero_shots() {
+        let none: SavesShots = "0/0".parse().unwrap();
+        assert_eq!(none, SavesShots::default());
+        assert_eq!(none.save_pctg(), None);
+        assert_eq!(none.goals_against(), 0);
+    }
+
+    #[test]
+    fn test_saves_shots_asymmetric_values() {
+        let split: SavesShots = "3/5".parse().unwrap();
+        assert_eq!(split.saves, 3);
+        assert_eq!(split.shots, 5);
+        assert_eq!(split.save_pctg(), Some(0.6));
+        assert_eq!(split.goals_against(), 2);
+
+        let long: SavesShots = "112/118".parse().unwrap();
+        assert_eq!((long.saves, long.shots), (112, 118));
+    }
+
+    #[test]
+    fn test_saves_shots_serializes_to_original_string() {
+        for text in [r#""0/0""#, r#""25/26""#, r#""3/5""#, r#""112/118""#] {
+            let value: SavesShots = serde_json::from_str(text).unwrap();
+            assert_eq!(serde_json::to_string(&value).unwrap(), text);
+        }
+    }
+
+    #[test]
+    fn test_saves_shots_add() {
+        let total = "20/22".parse::<SavesShots>().unwrap()
+            + "3/5".parse().unwrap()
+            + "0/0".parse().unwrap();
+        assert_eq!(total.to_string(), "23/27");
+    }
+
+    #[test]
+    fn test_saves_shots_rejects_malformed() {
+        for input in ["", "25", "25/", "/26", "25-26", "a/b", "-1/2", "1/2/3"] {
+            assert!(input.parse::<SavesShots>().is_err(), "{:?}", input);
+        }
+        assert!(serde_json::from_str::<SavesShots>("26").is_err());
     }
 
     /// Historical player stats sometimes carry an empty position code.
@@ -614,7 +1530,7 @@ mod tests {
 
         let broadcast: TvBroadcast = serde_json::from_str(json).unwrap();
         assert_eq!(broadcast.id, 123);
-        assert_eq!(broadcast.market, "NATIONAL");
+        assert_eq!(broadcast.market, BroadcastMarket::National);
         assert_eq!(broadcast.country_code, "US");
         assert_eq!(broadcast.network, "ESPN");
         assert_eq!(broadcast.sequence_number, 1);
@@ -643,6 +1559,7 @@ mod tests {
         }"#;
 
         let period: PeriodDescriptor = serde_json::from_str(json).unwrap();
+        assert_no_unmapped_fields::<PeriodDescriptor>(json);
         assert_eq!(period.number, 3);
         assert_eq!(period.period_type, Some(PeriodType::Regulation));
         assert_eq!(period.max_regulation_periods, 3);
@@ -726,67 +1643,25 @@ mod tests {
         }"#;
 
         let team: BoxscoreTeam = serde_json::from_str(json).unwrap();
+        assert_no_unmapped_fields::<BoxscoreTeam>(json);
         assert_eq!(team.id, TeamId::new(8));
         assert_eq!(team.common_name.default, "Canadiens");
         assert_eq!(team.abbrev, "MTL");
         assert_eq!(team.score, 3);
-        assert_eq!(team.sog, 28);
+        assert_eq!(team.sog, Some(28));
     }
 
     /// The NHL API sometimes returns numeric ID fields as strings; `GameId`,
     /// `TeamId`, `PlayerId`, and `Season` all accept both forms (1.1/1.3).
     #[test]
     fn test_boxscore_ids_and_season_deserialize_from_numeric_strings() {
-        let json = r#"{
-            "id": "2024020001",
-            "season": "20242025",
-            "gameType": 2,
-            "limitedScoring": false,
-            "gameDate": "2024-10-04",
-            "venue": {"default": "Test Arena"},
-            "venueLocation": {"default": "Test City"},
-            "startTimeUTC": "2024-10-04T19:00:00Z",
-            "easternUTCOffset": "-04:00",
-            "venueUTCOffset": "-04:00",
-            "tvBroadcasts": [],
-            "gameState": "LIVE",
-            "gameScheduleState": "OK",
-            "periodDescriptor": {},
-            "awayTeam": {
-                "id": "1",
-                "commonName": {"default": "Devils"},
-                "abbrev": "NJD",
-                "score": 0,
-                "sog": 0,
-                "logo": "https://assets.nhle.com/logos/nhl/svg/NJD_light.svg",
-                "darkLogo": "https://assets.nhle.com/logos/nhl/svg/NJD_dark.svg",
-                "placeName": {"default": "New Jersey"},
-                "placeNameWithPreposition": {"default": "New Jersey"}
-            },
-            "homeTeam": {
-                "id": "7",
-                "commonName": {"default": "Sabres"},
-                "abbrev": "BUF",
-                "score": 0,
-                "sog": 0,
-                "logo": "https://assets.nhle.com/logos/nhl/svg/BUF_light.svg",
-                "darkLogo": "https://assets.nhle.com/logos/nhl/svg/BUF_dark.svg",
-                "placeName": {"default": "Buffalo"},
-                "placeNameWithPreposition": {"default": "Buffalo"}
-            },
-            "clock": {
-                "timeRemaining": "20:00",
-                "secondsRemaining": 1200,
-                "running": false,
-                "inIntermission": false
-            },
-            "playerByGameStats": {
-                "awayTeam": {"forwards": [], "defense": [], "goalies": []},
-                "homeTeam": {"forwards": [], "defense": [], "goalies": []}
-            }
-        }"#;
+        let mut json: serde_json::Value = serde_json::from_str(BOXSCORE_FIXTURE).unwrap();
+        json["id"] = serde_json::json!("2024020001");
+        json["season"] = serde_json::json!("20242025");
+        json["awayTeam"]["id"] = serde_json::json!("1");
+        json["homeTeam"]["id"] = serde_json::json!("7");
 
-        let boxscore: Boxscore = serde_json::from_str(json).unwrap();
+        let boxscore: Boxscore = serde_json::from_value(json).unwrap();
         assert_eq!(boxscore.id, GameId::new(2024020001));
         assert_eq!(boxscore.season, Season::new(2024));
         assert_eq!(boxscore.away_team.id, TeamId::new(1));
@@ -851,7 +1726,8 @@ mod tests {
         }"#;
 
         let clock: GameClock = serde_json::from_str(json).unwrap();
-        assert_eq!(clock.time_remaining, "05:30");
+        assert_no_unmapped_fields::<GameClock>(json);
+        assert_eq!(clock.time_remaining, "05:30".parse::<IceTime>().unwrap());
         assert_eq!(clock.seconds_remaining, 330);
         assert!(!clock.running);
         assert!(clock.in_intermission);
@@ -867,7 +1743,7 @@ mod tests {
         }"#;
 
         let clock: GameClock = serde_json::from_str(json).unwrap();
-        assert_eq!(clock.time_remaining, "00:00");
+        assert_eq!(clock.time_remaining, "00:00".parse::<IceTime>().unwrap());
         assert_eq!(clock.seconds_remaining, 0);
         assert!(!clock.running);
         assert!(clock.in_intermission);
@@ -875,73 +1751,16 @@ mod tests {
 
     #[test]
     fn test_boxscore_with_special_event() {
-        let json = r#"{
-            "id": 2024020001,
-            "season": 20242025,
-            "gameType": 2,
-            "limitedScoring": false,
-            "gameDate": "2024-10-04",
-            "venue": {"default": "Test Arena"},
-            "venueLocation": {"default": "Test City"},
-            "startTimeUTC": "2024-10-04T19:00:00Z",
-            "easternUTCOffset": "-04:00",
-            "venueUTCOffset": "-04:00",
-            "tvBroadcasts": [],
-            "gameState": "LIVE",
-            "gameScheduleState": "OK",
-            "periodDescriptor": {
-                "number": 1,
-                "periodType": "REG",
-                "maxRegulationPeriods": 3
-            },
-            "specialEvent": {
+        let json = boxscore_json(
+            r#", "specialEvent": {
                 "parentId": 1000,
                 "name": {"default": "Stadium Series"},
                 "lightLogoUrl": {"default": "https://example.com/stadium.png"}
-            },
-            "awayTeam": {
-                "id": 1,
-                "commonName": {"default": "Devils"},
-                "abbrev": "NJD",
-                "score": 0,
-                "sog": 0,
-                "logo": "https://assets.nhle.com/logos/nhl/svg/NJD_light.svg",
-                "darkLogo": "https://assets.nhle.com/logos/nhl/svg/NJD_dark.svg",
-                "placeName": {"default": "New Jersey"},
-                "placeNameWithPreposition": {"default": "New Jersey"}
-            },
-            "homeTeam": {
-                "id": 7,
-                "commonName": {"default": "Sabres"},
-                "abbrev": "BUF",
-                "score": 0,
-                "sog": 0,
-                "logo": "https://assets.nhle.com/logos/nhl/svg/BUF_light.svg",
-                "darkLogo": "https://assets.nhle.com/logos/nhl/svg/BUF_dark.svg",
-                "placeName": {"default": "Buffalo"},
-                "placeNameWithPreposition": {"default": "Buffalo"}
-            },
-            "clock": {
-                "timeRemaining": "20:00",
-                "secondsRemaining": 1200,
-                "running": false,
-                "inIntermission": false
-            },
-            "playerByGameStats": {
-                "awayTeam": {
-                    "forwards": [],
-                    "defense": [],
-                    "goalies": []
-                },
-                "homeTeam": {
-                    "forwards": [],
-                    "defense": [],
-                    "goalies": []
-                }
-            }
-        }"#;
+            }"#,
+        );
 
-        let boxscore: Boxscore = serde_json::from_str(json).unwrap();
+        let boxscore: Boxscore = serde_json::from_str(&json).unwrap();
+        assert_no_unmapped_fields::<Boxscore>(&json);
         assert!(boxscore.special_event.is_some());
         let event = boxscore.special_event.unwrap();
         assert_eq!(event.name.default, "Stadium Series");
@@ -949,86 +1768,334 @@ mod tests {
 
     #[test]
     fn test_boxscore_with_tv_broadcasts() {
-        let json = r#"{
-            "id": 2024020001,
-            "season": 20242025,
-            "gameType": 2,
-            "limitedScoring": false,
-            "gameDate": "2024-10-04",
-            "venue": {"default": "Test Arena"},
-            "venueLocation": {"default": "Test City"},
-            "startTimeUTC": "2024-10-04T19:00:00Z",
-            "easternUTCOffset": "-04:00",
-            "venueUTCOffset": "-04:00",
-            "tvBroadcasts": [
-                {
-                    "id": 1,
-                    "market": "NATIONAL",
-                    "countryCode": "US",
-                    "network": "ESPN",
-                    "sequenceNumber": 1
-                },
-                {
-                    "id": 2,
-                    "market": "AWAY",
-                    "countryCode": "US",
-                    "network": "MSG",
-                    "sequenceNumber": 2
-                }
-            ],
-            "gameState": "LIVE",
-            "gameScheduleState": "OK",
-            "periodDescriptor": {
-                "number": 1,
-                "periodType": "REG",
-                "maxRegulationPeriods": 3
-            },
-            "awayTeam": {
-                "id": 1,
-                "commonName": {"default": "Devils"},
-                "abbrev": "NJD",
-                "score": 0,
-                "sog": 0,
-                "logo": "https://assets.nhle.com/logos/nhl/svg/NJD_light.svg",
-                "darkLogo": "https://assets.nhle.com/logos/nhl/svg/NJD_dark.svg",
-                "placeName": {"default": "New Jersey"},
-                "placeNameWithPreposition": {"default": "New Jersey"}
-            },
-            "homeTeam": {
-                "id": 7,
-                "commonName": {"default": "Sabres"},
-                "abbrev": "BUF",
-                "score": 0,
-                "sog": 0,
-                "logo": "https://assets.nhle.com/logos/nhl/svg/BUF_light.svg",
-                "darkLogo": "https://assets.nhle.com/logos/nhl/svg/BUF_dark.svg",
-                "placeName": {"default": "Buffalo"},
-                "placeNameWithPreposition": {"default": "Buffalo"}
-            },
-            "clock": {
-                "timeRemaining": "20:00",
-                "secondsRemaining": 1200,
-                "running": false,
-                "inIntermission": false
-            },
-            "playerByGameStats": {
-                "awayTeam": {
-                    "forwards": [],
-                    "defense": [],
-                    "goalies": []
-                },
-                "homeTeam": {
-                    "forwards": [],
-                    "defense": [],
-                    "goalies": []
-                }
-            }
-        }"#;
-
-        let boxscore: Boxscore = serde_json::from_str(json).unwrap();
+        let boxscore: Boxscore = serde_json::from_str(BOXSCORE_FIXTURE).unwrap();
         assert_eq!(boxscore.tv_broadcasts.len(), 2);
         assert_eq!(boxscore.tv_broadcasts[0].network, "ESPN");
         assert_eq!(boxscore.tv_broadcasts[1].network, "MSG");
+
+        // Boxscores spell the market out.
+        let networks = |broadcasts: Vec<&TvBroadcast>| -> Vec<String> {
+            broadcasts.iter().map(|b| b.network.clone()).collect()
+        };
+        assert_eq!(networks(boxscore.national_broadcasts()), vec!["ESPN"]);
+        assert_eq!(networks(boxscore.away_broadcasts()), vec!["MSG"]);
+        assert!(boxscore.home_broadcasts().is_empty());
+    }
+
+    fn clock(seconds_remaining: i32, in_intermission: bool) -> GameClock {
+        GameClock {
+            time_remaining: IceTime::from_seconds(seconds_remaining as u32),
+            seconds_remaining,
+            running: !in_intermission,
+            in_intermission,
+        }
+    }
+
+    #[test]
+    fn test_game_clock_durations() {
+        let period = Duration::from_secs(1200);
+
+        let mid_period = clock(615, false);
+        assert_eq!(mid_period.remaining(), Duration::from_secs(615));
+        assert_eq!(mid_period.elapsed(period), Duration::from_secs(585));
+        assert!(!mid_period.is_expired());
+
+        // The intermission clock counts down the break; the period is over.
+        let intermission = clock(861, true);
+        assert_eq!(intermission.remaining(), Duration::from_secs(861));
+        assert_eq!(intermission.elapsed(period), period);
+        assert!(intermission.is_expired());
+
+        let expired = clock(0, false);
+        assert_eq!(expired.elapsed(period), period);
+        assert!(expired.is_expired());
+    }
+
+    #[test]
+    fn test_game_clock_orders_earliest_first() {
+        let mut readings = [
+            clock(861, true),
+            clock(0, false),
+            clock(1200, false),
+            clock(615, false),
+        ];
+        readings.sort();
+        let order: Vec<(i32, bool)> = readings
+            .iter()
+            .map(|c| (c.seconds_remaining, c.in_intermission))
+            .collect();
+        assert_eq!(
+            order,
+            vec![(1200, false), (615, false), (0, false), (861, true)]
+        );
+    }
+
+    #[test]
+    fn test_boxscore_game_elapsed_seconds() {
+        // Second period, 10:15 left.
+        let boxscore: Boxscore = serde_json::from_str(BOXSCORE_FIXTURE).unwrap();
+        assert_eq!(boxscore.game_elapsed_seconds(), 1200 + 585);
+    }
+
+    /// Right-rail payload with a team comparison: away 24 shots, 1/3 on the
+    /// power play; home 31 shots, 0/2.
+    const RIGHT_RAIL_WITH_TEAM_STATS: &str = r#"{
+        "seasonSeries": [],
+        "seasonSeriesWins": {"awayTeamWins": 0, "homeTeamWins": 0},
+        "gameInfo": {
+            "referees": [], "linesmen": [],
+            "awayTeam": {"headCoach": {"default": "A"}, "scratches": []},
+            "homeTeam": {"headCoach": {"default": "B"}, "scratches": []}
+        },
+        "teamGameStats": [
+            {"category": "sog", "awayValue": "24", "homeValue": "31"},
+            {"category": "faceoffWinningPctg", "awayValue": 0.482, "homeValue": 0.518},
+            {"category": "powerPlay", "awayValue": "1/3", "homeValue": "0/2"},
+            {"category": "powerPlayPctg", "awayValue": 0.333, "homeValue": 0.0},
+            {"category": "pim", "awayValue": "8", "homeValue": "12"},
+            {"category": "hits", "awayValue": "19", "homeValue": "22"},
+            {"category": "blockedShots", "awayValue": "14", "homeValue": "9"},
+            {"category": "giveaways", "awayValue": "6", "homeValue": "11"},
+            {"category": "takeaways", "awayValue": "5", "homeValue": "7"}
+        ],
+        "shotsByPeriod": [
+            {"periodDescriptor": {"number": 1, "periodType": "REG", "maxRegulationPeriods": 3}, "away": 8, "home": 10},
+            {"periodDescriptor": {"number": 2, "periodType": "REG", "maxRegulationPeriods": 3}, "away": 9, "home": 11},
+            {"periodDescriptor": {"number": 3, "periodType": "REG", "maxRegulationPeriods": 3}, "away": 7, "home": 10}
+        ]
+    }"#;
+
+    #[test]
+    fn test_boxscore_venue_time() {
+        let boxscore: Boxscore = serde_json::from_str(BOXSCORE_FIXTURE).unwrap();
+        let time = boxscore.venue_time();
+
+        assert_eq!(
+            time.start_time_local().unwrap().to_rfc3339(),
+            "2024-10-04T19:00:00+02:00"
+        );
+        assert_eq!(
+            time.start_time_eastern().unwrap().to_rfc3339(),
+            "2024-10-04T13:00:00-04:00"
+        );
+        assert_eq!(time.local_date().unwrap().to_string(), boxscore.game_date);
+    }
+
+    #[test]
+    fn test_limited_scoring_boxscore() {
+        let boxscore: Boxscore = serde_json::from_str(LIMITED_BOXSCORE_FIXTURE).unwrap();
+        assert_no_unmapped_fields::<Boxscore>(LIMITED_BOXSCORE_FIXTURE);
+
+        assert!(boxscore.is_limited());
+        assert_eq!(boxscore.away_team.sog, None);
+        assert_eq!(boxscore.home_team.sog, None);
+        assert_eq!(boxscore.clock, GameClock::default());
+        assert_eq!(boxscore.player_by_game_stats, PlayerByGameStats::default());
+        assert_eq!(
+            boxscore.winner().map(|team| team.abbrev.as_str()),
+            Some("MTL")
+        );
+        assert_eq!(boxscore.score_string(), "TOR 1 – MTL 2");
+        assert_eq!(boxscore.game_elapsed_seconds(), 3 * 1200);
+
+        let serialized = serde_json::to_value(&boxscore).unwrap();
+        assert!(serialized["awayTeam"].get("sog").is_none());
+
+        let full: Boxscore = serde_json::from_str(BOXSCORE_FIXTURE).unwrap();
+        assert!(!full.is_limited());
+    }
+
+    #[test]
+    fn test_postponed_boxscore() {
+        let boxscore: Boxscore = serde_json::from_str(POSTPONED_BOXSCORE_FIXTURE).unwrap();
+        assert_no_unmapped_fields::<Boxscore>(POSTPONED_BOXSCORE_FIXTURE);
+
+        assert!(boxscore.is_postponed());
+        assert_eq!(boxscore.game_schedule_state, GameScheduleState::Postponed);
+        assert!(!boxscore.game_schedule_state.is_playable());
+        assert_eq!(boxscore.winner(), None);
+        assert_eq!(boxscore.game_elapsed_seconds(), 0);
+
+        let serialized = serde_json::to_value(&boxscore).unwrap();
+        assert_eq!(serialized["gameScheduleState"], "PPD");
+        let round_trip: Boxscore = serde_json::from_value(serialized).unwrap();
+        assert!(round_trip.is_postponed());
+
+        let full: Boxscore = serde_json::from_str(BOXSCORE_FIXTURE).unwrap();
+        assert!(!full.is_postponed());
+    }
+
+    #[test]
+    fn test_from_right_rail_finished_game() {
+        let right_rail: SeasonSeriesMatchup =
+            serde_json::from_str(RIGHT_RAIL_WITH_TEAM_STATS).unwrap();
+
+        let away = TeamGameStats::from_right_rail(&right_rail, HomeRoad::Road).unwrap();
+        assert_eq!(
+            away,
+            TeamGameStats {
+                shots_on_goal: 24,
+                faceoff_wins: None,
+                faceoff_total: None,
+                power_play_goals: 1,
+                power_play_opportunities: Some(3),
+                penalty_minutes: 8,
+                hits: 19,
+                blocked_shots: 14,
+                giveaways: 6,
+                takeaways: 5,
+            }
+        );
+        let home = TeamGameStats::from_right_rail(&right_rail, HomeRoad::Home).unwrap();
+        assert_eq!(
+            home,
+            TeamGameStats {
+                shots_on_goal: 31,
+                faceoff_wins: None,
+                faceoff_total: None,
+                power_play_goals: 0,
+                power_play_opportunities: Some(2),
+                penalty_minutes: 12,
+                hits: 22,
+                blocked_shots: 9,
+                giveaways: 11,
+                takeaways: 7,
+            }
+        );
+
+        for (side, stats) in [(HomeRoad::Road, &away), (HomeRoad::Home, &home)] {
+            let by_period: i32 = right_rail
+                .shots_by_period
+                .iter()
+                .map(|period| period.for_side(side))
+                .sum();
+            assert_eq!(by_period, stats.shots_on_goal);
+        }
+        assert_eq!(right_rail.shots_by_period[2].period_descriptor.number, 3);
+    }
+
+    #[test]
+    fn test_from_right_rail_needs_every_category() {
+        let mut right_rail: SeasonSeriesMatchup =
+            serde_json::from_str(RIGHT_RAIL_WITH_TEAM_STATS).unwrap();
+        right_rail
+            .team_game_stats
+            .retain(|stat| stat.category != "hits");
+        assert_eq!(
+            TeamGameStats::from_right_rail(&right_rail, HomeRoad::Home),
+            None
+        );
+
+        right_rail.team_game_stats.clear();
+        assert_eq!(
+            TeamGameStats::from_right_rail(&right_rail, HomeRoad::Road),
+            None
+        );
+    }
+
+    #[test]
+    fn test_from_sources_fills_empty_player_stats_from_right_rail() {
+        let mut json: serde_json::Value = serde_json::from_str(BOXSCORE_FIXTURE).unwrap();
+        let empty = serde_json::json!({"forwards": [], "defense": [], "goalies": []});
+        json["playerByGameStats"]["awayTeam"] = empty.clone();
+        json["playerByGameStats"]["homeTeam"] = empty;
+        let boxscore: Boxscore = serde_json::from_value(json).unwrap();
+        let right_rail: SeasonSeriesMatchup =
+            serde_json::from_str(RIGHT_RAIL_WITH_TEAM_STATS).unwrap();
+        assert_no_unmapped_fields::<SeasonSeriesMatchup>(RIGHT_RAIL_WITH_TEAM_STATS);
+
+        let (away, provenance) =
+            TeamGameStats::from_sources(&boxscore, Some(&right_rail), None, HomeRoad::Road);
+
+        assert_eq!(
+            away,
+            TeamGameStats {
+                shots_on_goal: 24,
+                faceoff_wins: None,
+                faceoff_total: None,
+                power_play_goals: 1,
+                power_play_opportunities: Some(3),
+                penalty_minutes: 8,
+                hits: 19,
+                blocked_shots: 14,
+                giveaways: 6,
+                takeaways: 5,
+            }
+        );
+        let rail = Some(StatSource::RightRail);
+        assert_eq!(
+            provenance,
+            StatProvenance {
+                shots_on_goal: rail,
+                faceoff_wins: None,
+                faceoff_total: None,
+                power_play_goals: rail,
+                power_play_opportunities: rail,
+                penalty_minutes: rail,
+                hits: rail,
+                blocked_shots: rail,
+                giveaways: rail,
+                takeaways: rail,
+            }
+        );
+
+        let (home, _) =
+            TeamGameStats::from_sources(&boxscore, Some(&right_rail), None, HomeRoad::Home);
+        assert_eq!(home.shots_on_goal, 31);
+        assert_eq!(home.power_play_percentage(), Some(0.0));
+    }
+
+    #[test]
+    fn test_from_sources_prefers_player_stats() {
+        let boxscore: Boxscore = serde_json::from_str(BOXSCORE_FIXTURE).unwrap();
+        let right_rail: SeasonSeriesMatchup =
+            serde_json::from_str(RIGHT_RAIL_WITH_TEAM_STATS).unwrap();
+
+        let (home, provenance) =
+            TeamGameStats::from_sources(&boxscore, Some(&right_rail), None, HomeRoad::Home);
+
+        let mut expected =
+            TeamGameStats::from_team_player_stats(&boxscore.player_by_game_stats.home_team);
+        expected.power_play_opportunities = Some(2);
+        assert_eq!(home, expected);
+        assert_eq!(provenance.hits, Some(StatSource::PlayerStats));
+        assert_eq!(provenance.power_play_goals, Some(StatSource::PlayerStats));
+        assert_eq!(
+            provenance.power_play_opportunities,
+            Some(StatSource::RightRail)
+        );
+
+        // Nothing to fall back on: fields stay at their defaults, unsourced.
+        let mut json: serde_json::Value = serde_json::from_str(BOXSCORE_FIXTURE).unwrap();
+        json["playerByGameStats"]["homeTeam"] = serde_json::json!({});
+        let bare: Boxscore = serde_json::from_value(json).unwrap();
+        let (stats, provenance) = TeamGameStats::from_sources(&bare, None, None, HomeRoad::Home);
+        assert_eq!(stats, TeamGameStats::default());
+        assert_eq!(provenance, StatProvenance::default());
+    }
+
+    #[test]
+    fn test_broadcast_helpers_sort_and_dedupe_short_market_codes() {
+        // Schedule-style one-letter markets, out of order, with SN listed
+        // both nationally and for the away market and TSN4 listed twice.
+        let mut json: serde_json::Value = serde_json::from_str(BOXSCORE_FIXTURE).unwrap();
+        json["tvBroadcasts"] = serde_json::json!([
+            {"id": 5, "market": "H", "countryCode": "CA", "network": "TSN4", "sequenceNumber": 40},
+            {"id": 4, "market": "A", "countryCode": "CA", "network": "SN", "sequenceNumber": 30},
+            {"id": 3, "market": "A", "countryCode": "CA", "network": "RDS", "sequenceNumber": 20},
+            {"id": 2, "market": "N", "countryCode": "CA", "network": "SN", "sequenceNumber": 10},
+            {"id": 6, "market": "H", "countryCode": "CA", "network": "TSN4", "sequenceNumber": 50},
+            {"id": 1, "market": "N", "countryCode": "US", "network": "TNT", "sequenceNumber": 5}
+        ]);
+        let boxscore: Boxscore = serde_json::from_value(json).unwrap();
+
+        let ids = |broadcasts: Vec<&TvBroadcast>| -> Vec<i64> {
+            broadcasts.iter().map(|b| b.id).collect()
+        };
+        assert_eq!(ids(boxscore.national_broadcasts()), vec![1, 2]);
+        assert_eq!(ids(boxscore.away_broadcasts()), vec![3]);
+        assert_eq!(ids(boxscore.home_broadcasts()), vec![5]);
+        assert_eq!(boxscore.tv_broadcasts[0].market, BroadcastMarket::Home);
     }
 
     #[test]
@@ -1089,6 +2156,7 @@ mod tests {
         }"#;
 
         let stats: TeamPlayerStats = serde_json::from_str(json).unwrap();
+        assert_no_unmapped_fields::<TeamPlayerStats>(json);
         assert_eq!(stats.forwards.len(), 1);
         assert_eq!(stats.defense.len(), 0);
         assert_eq!(stats.goalies.len(), 0);
@@ -1138,10 +2206,8 @@ mod tests {
         let team_stats = TeamPlayerStats {
             forwards: vec![SkaterStats {
                 player_id: PlayerId::new(1),
-                sweater_number: 13,
-                name: LocalizedString {
-                    default: "Player 1".to_string(),
-                },
+                sweater_number: Some(13),
+                name: LocalizedString::new("Player 1"),
                 position: Some(Position::Center),
                 goals: 1,
                 assists: 2,
@@ -1152,7 +2218,7 @@ mod tests {
                 power_play_goals: 1,
                 sog: 4,
                 faceoff_winning_pctg: 0.6,
-                toi: "18:00".to_string(),
+                toi: "18:00".parse().unwrap(),
                 blocked_shots: 2,
                 shifts: 25,
                 giveaways: 1,
@@ -1160,10 +2226,8 @@ mod tests {
             }],
             defense: vec![SkaterStats {
                 player_id: PlayerId::new(2),
-                sweater_number: 44,
-                name: LocalizedString {
-                    default: "Player 2".to_string(),
-                },
+                sweater_number: Some(44),
+                name: LocalizedString::new("Player 2"),
                 position: Some(Position::Defense),
                 goals: 0,
                 assists: 1,
@@ -1174,7 +2238,7 @@ mod tests {
                 power_play_goals: 0,
                 sog: 3,
                 faceoff_winning_pctg: 0.0,
-                toi: "22:00".to_string(),
+                toi: "22:00".parse().unwrap(),
                 blocked_shots: 5,
                 shifts: 30,
                 giveaways: 2,
@@ -1191,6 +2255,42 @@ mod tests {
         assert_eq!(game_stats.blocked_shots, 7); // 2 + 5
         assert_eq!(game_stats.giveaways, 3); // 1 + 2
         assert_eq!(game_stats.takeaways, 4); // 3 + 1
+
+        // Boxscore player stats have no faceoff counts or power-play data.
+        assert_eq!(game_stats.faceoff_total, None);
+        assert_eq!(game_stats.power_play_opportunities, None);
+    }
+
+    #[test]
+    fn test_forward_toi_sums_past_sixty_minutes() {
+        let forward = |toi: &str| -> SkaterStats {
+            serde_json::from_str(&format!(
+                r#"{{
+                    "playerId": 1, "sweaterNumber": 13, "name": {{"default": "F"}},
+                    "position": "C", "goals": 0, "assists": 0, "points": 0,
+                    "plusMinus": 0, "pim": 0, "hits": 0, "powerPlayGoals": 0, "sog": 0,
+                    "faceoffWinningPctg": 0.0, "toi": "{}", "blockedShots": 0,
+                    "shifts": 20, "giveaways": 0, "takeaways": 0
+                }}"#,
+                toi
+            ))
+            .unwrap()
+        };
+        let forwards = [
+            forward("18:15"),
+            forward("17:50"),
+            forward("15:02"),
+            forward("12:33"),
+        ];
+
+        let total: IceTime = forwards.iter().map(|skater| skater.toi).sum();
+
+        assert_eq!(total.as_seconds(), 1095 + 1070 + 902 + 753);
+        assert_eq!(total.to_string(), "63:40");
+        assert_eq!(
+            serde_json::to_value(&forwards[0]).unwrap()["toi"],
+            serde_json::json!("18:15")
+        );
     }
 
     #[test]
@@ -1200,22 +2300,20 @@ mod tests {
             defense: vec![],
             goalies: vec![GoalieStats {
                 player_id: PlayerId::new(1),
-                sweater_number: 35,
-                name: LocalizedString {
-                    default: "Goalie 1".to_string(),
-                },
+                sweater_number: Some(35),
+                name: LocalizedString::new("Goalie 1"),
                 position: Some(Position::Goalie),
-                even_strength_shots_against: "20/22".to_string(),
-                power_play_shots_against: "3/5".to_string(),
-                shorthanded_shots_against: "0/0".to_string(),
-                save_shots_against: "23/27".to_string(),
+                even_strength_shots_against: "20/22".parse().unwrap(),
+                power_play_shots_against: "3/5".parse().unwrap(),
+                shorthanded_shots_against: "0/0".parse().unwrap(),
+                save_shots_against: "23/27".parse().unwrap(),
                 save_pctg: Some(0.852),
                 even_strength_goals_against: 2,
                 power_play_goals_against: 2,
                 shorthanded_goals_against: 0,
                 pim: Some(2),
                 goals_against: 4,
-                toi: "60:00".to_string(),
+                toi: "60:00".parse().unwrap(),
                 starter: Some(true),
                 decision: Some(GoalieDecision::Loss),
                 shots_against: 27,
@@ -1230,35 +2328,183 @@ mod tests {
     #[test]
     fn test_team_game_stats_faceoff_percentage_zero_faceoffs() {
         let game_stats = TeamGameStats {
-            shots_on_goal: 30,
-            faceoff_wins: 0,
-            faceoff_total: 0,
-            power_play_goals: 1,
-            penalty_minutes: 8,
-            hits: 25,
-            blocked_shots: 15,
-            giveaways: 5,
-            takeaways: 7,
+            faceoff_wins: Some(0),
+            faceoff_total: Some(0),
+            ..TeamGameStats::default()
         };
 
-        assert_eq!(game_stats.faceoff_percentage(), 0.0);
+        assert_eq!(game_stats.faceoff_percentage(), None);
     }
 
     #[test]
     fn test_team_game_stats_faceoff_percentage() {
         let game_stats = TeamGameStats {
-            shots_on_goal: 30,
-            faceoff_wins: 30,
-            faceoff_total: 60,
+            faceoff_wins: Some(30),
+            faceoff_total: Some(60),
+            ..TeamGameStats::default()
+        };
+
+        assert_eq!(game_stats.faceoff_percentage(), Some(50.0));
+    }
+
+    #[test]
+    fn test_team_game_stats_power_play_percentage() {
+        let game_stats = TeamGameStats {
             power_play_goals: 1,
-            penalty_minutes: 8,
-            hits: 25,
-            blocked_shots: 15,
-            giveaways: 5,
-            takeaways: 7,
+            power_play_opportunities: Some(4),
+            ..TeamGameStats::default()
+        };
+        assert_eq!(game_stats.power_play_percentage(), Some(25.0));
+
+        let no_chances = TeamGameStats {
+            power_play_opportunities: Some(0),
+            ..TeamGameStats::default()
         };
+        assert_eq!(no_chances.power_play_percentage(), None);
+    }
+
+    const PBP_AWAY_ID: i64 = 1;
+    const PBP_HOME_ID: i64 = 7;
+    const AWAY_CENTER: i64 = 8479407;
+    const HOME_CENTER: i64 = 8479420;
+    const HOME_DEFENSEMAN: i64 = 8480839;
+
+    /// One play-by-play event; `details` is spliced into the details object
+    /// after `eventOwnerTeamId`.
+    fn pbp_event(
+        event_type: &str,
+        period: i32,
+        time: &str,
+        situation: &str,
+        owner: i64,
+        details: &str,
+    ) -> crate::types::PlayEvent {
+        let period_type = if period == 5 { "SO" } else { "REG" };
+        serde_json::from_str(&format!(
+            r#"{{
+                "eventId": 1, "periodDescriptor": {{"number": {}, "periodType": "{}"}},
+                "timeInPeriod": "{}", "timeRemaining": "00:00", "situationCode": "{}",
+                "typeCode": 0, "typeDescKey": "{}", "sortOrder": 0,
+                "details": {{"eventOwnerTeamId": {}{}}}
+            }}"#,
+            period, period_type, time, situation, event_type, owner, details
+        ))
+        .unwrap()
+    }
 
-        assert_eq!(game_stats.faceoff_percentage(), 50.0);
+    fn penalty(
+        period: i32,
+        time: &str,
+        owner: i64,
+        code: &str,
+        minutes: i32,
+    ) -> crate::types::PlayEvent {
+        pbp_event(
+            "penalty",
+            period,
+            time,
+            "1551",
+            owner,
+            &format!(r#", "typeCode": "{}", "duration": {}"#, code, minutes),
+        )
+    }
+
+    fn roster_spot(team_id: i64, player_id: i64) -> crate::types::RosterSpot {
+        serde_json::from_str(&format!(
+            r#"{{"teamId": {}, "playerId": {}, "firstName": {{"default": "A"}},
+                "lastName": {{"default": "B"}}, "sweaterNumber": 1, "positionCode": "C",
+                "headshot": ""}}"#,
+            team_id, player_id
+        ))
+        .unwrap()
+    }
+
+    /// A game with hand-counted totals. Home: 3 of 5 faceoffs, 3 shots
+    /// (one a power-play goal), 2 PIM, 1 block. Away: 2 faceoffs, 2 shots,
+    /// 12 PIM. Power plays: home gets 2 (a lone away minor at 5:00 and the
+    /// away major at 15:00), away gets 0 (the 10:00 minors are coincidental;
+    /// the misconduct doesn't count). The shootout goal is ignored.
+    fn known_game() -> PlayByPlay {
+        let faceoff = |owner, winner, loser| {
+            pbp_event(
+                "faceoff",
+                1,
+                "00:00",
+                "1551",
+                owner,
+                &format!(
+                    r#", "winningPlayerId": {}, "losingPlayerId": {}"#,
+                    winner, loser
+                ),
+            )
+        };
+        let mut pbp = crate::fixtures::play_by_play();
+        pbp.away_team.id = TeamId::new(PBP_AWAY_ID);
+        pbp.home_team.id = TeamId::new(PBP_HOME_ID);
+        pbp.roster_spots = vec![
+            roster_spot(PBP_AWAY_ID, AWAY_CENTER),
+            roster_spot(PBP_HOME_ID, HOME_CENTER),
+            roster_spot(PBP_HOME_ID, HOME_DEFENSEMAN),
+        ];
+        pbp.plays = vec![
+            faceoff(PBP_HOME_ID, HOME_CENTER, AWAY_CENTER),
+            faceoff(PBP_HOME_ID, HOME_CENTER, AWAY_CENTER),
+            faceoff(PBP_AWAY_ID, AWAY_CENTER, HOME_CENTER),
+            pbp_event("shot-on-goal", 1, "02:00", "1551", PBP_HOME_ID, ""),
+            pbp_event("shot-on-goal", 1, "03:00", "1551", PBP_AWAY_ID, ""),
+            penalty(1, "05:00", PBP_AWAY_ID, "MIN", 2),
+            // Home scores on the ensuing 5-on-4 (away 4 skaters, home 5).
+            pbp_event("goal", 1, "05:30", "1451", PBP_HOME_ID, ""),
+            penalty(2, "10:00", PBP_AWAY_ID, "MIN", 2),
+            penalty(2, "10:00", PBP_HOME_ID, "MIN", 2),
+            penalty(2, "10:00", PBP_AWAY_ID, "MIS", 10),
+            faceoff(PBP_AWAY_ID, AWAY_CENTER, HOME_CENTER),
+            faceoff(PBP_HOME_ID, HOME_CENTER, AWAY_CENTER),
+            pbp_event(
+                "blocked-shot",
+                2,
+                "12:00",
+                "1551",
+                PBP_HOME_ID,
+                &format!(r#", "blockingPlayerId": {}"#, HOME_DEFENSEMAN),
+            ),
+            pbp_event("shot-on-goal", 3, "01:00", "1551", PBP_HOME_ID, ""),
+            pbp_event("hit", 3, "02:00", "1551", PBP_AWAY_ID, ""),
+            penalty(3, "15:00", PBP_AWAY_ID, "MAJ", 5),
+            pbp_event("goal", 3, "16:00", "1551", PBP_AWAY_ID, ""),
+            pbp_event("goal", 5, "00:00", "0101", PBP_HOME_ID, ""),
+        ];
+        pbp
+    }
+
+    #[test]
+    fn test_team_game_stats_from_play_by_play_home() {
+        let stats = TeamGameStats::from_play_by_play(&known_game(), HomeRoad::Home);
+
+        assert_eq!(stats.faceoff_wins, Some(3));
+        assert_eq!(stats.faceoff_total, Some(5));
+        assert_eq!(stats.faceoff_percentage(), Some(60.0));
+        assert_eq!(stats.shots_on_goal, 3);
+        assert_eq!(stats.power_play_goals, 1);
+        assert_eq!(stats.power_play_opportunities, Some(2));
+        assert_eq!(stats.power_play_percentage(), Some(50.0));
+        assert_eq!(stats.penalty_minutes, 2);
+        assert_eq!(stats.blocked_shots, 1);
+        assert_eq!(stats.hits, 0);
+    }
+
+    #[test]
+    fn test_team_game_stats_from_play_by_play_away() {
+        let stats = TeamGameStats::from_play_by_play(&known_game(), HomeRoad::Road);
+
+        assert_eq!(stats.faceoff_wins, Some(2));
+        assert_eq!(stats.faceoff_total, Some(5));
+        assert_eq!(stats.shots_on_goal, 2);
+        assert_eq!(stats.power_play_goals, 0);
+        assert_eq!(stats.power_play_opportunities, Some(0));
+        assert_eq!(stats.penalty_minutes, 19); // 2 + 2 + 10 + 5
+        assert_eq!(stats.blocked_shots, 0);
+        assert_eq!(stats.hits, 1);
     }
 
     #[test]
@@ -1,11 +1,14 @@
 use serde::{Deserialize, Serialize};
+use std::cmp::Ordering;
+use std::collections::HashMap;
 use std::fmt;
 
+use crate::analytics::{reconcile_position, PositionSource};
 use crate::date::Season;
 use crate::ids::PlayerId;
 
 use super::common::LocalizedString;
-use super::enums::{empty_string_as_none, Position};
+use super::enums::{empty_string_as_none, HomeRoad, Position};
 use super::game_type::GameType;
 
 /// Skater season statistics for a team
@@ -134,6 +137,157 @@ pub struct ClubStats {
     pub goalies: Vec<ClubGoalieStats>,
 }
 
+/// Skater and goalie counting stats summed over a club, from
+/// [`ClubStats::team_totals`].
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct ClubStatsTotals {
+    pub goals: i32,
+    pub assists: i32,
+    pub penalty_minutes: i32,
+    /// Skaters only: the goalie rows have no shot count.
+    pub shots: i32,
+}
+
+/// Points, then goals, then fewer games played (the better rate).
+fn by_points(a: &ClubSkaterStats, b: &ClubSkaterStats) -> Ordering {
+    b.points
+        .cmp(&a.points)
+        .then(b.goals.cmp(&a.goals))
+        .then(a.games_played.cmp(&b.games_played))
+}
+
+impl ClubStats {
+    /// The `n` skaters with the most points, best first. Ties go to more
+    /// goals, then fewer games played; skaters still tied keep the API's
+    /// order.
+    pub fn top_scorers(&self, n: usize) -> Vec<&ClubSkaterStats> {
+        let mut skaters: Vec<&ClubSkaterStats> = self.skaters.iter().collect();
+        skaters.sort_by(|a, b| by_points(a, b));
+        skaters.truncate(n);
+        skaters
+    }
+
+    /// The `n` skaters with the most goals, best first. Ties go to more
+    /// points, then fewer games played, then the API's order.
+    pub fn top_goal_scorers(&self, n: usize) -> Vec<&ClubSkaterStats> {
+        let mut skaters: Vec<&ClubSkaterStats> = self.skaters.iter().collect();
+        skaters.sort_by(|a, b| b.goals.cmp(&a.goals).then(by_points(a, b)));
+        skaters.truncate(n);
+        skaters
+    }
+
+    /// Skaters listed at `position`, in [`Self::top_scorers`] order. Skaters
+    /// without a position are never included.
+    pub fn skaters_by_position(&self, position: Position) -> Vec<&ClubSkaterStats> {
+        self.skaters_by_reconciled_position(position, &HashMap::new())
+    }
+
+    /// [`Self::skaters_by_position`], with each skater's position resolved
+    /// by [`reconcile_position`] from their `claims` plus the club stats
+    /// listing, which counts as a [`PositionSource::ClubRoster`] claim.
+    ///
+    /// Pass [`game_roster_claims`](crate::analytics::game_roster_claims)
+    /// to bucket skaters by where they lined up in a game.
+    pub fn skaters_by_reconciled_position(
+        &self,
+        position: Position,
+        claims: &HashMap<PlayerId, Vec<PositionSource>>,
+    ) -> Vec<&ClubSkaterStats> {
+        let mut skaters: Vec<&ClubSkaterStats> = self
+            .skaters
+            .iter()
+            .filter(|skater| {
+                let mut sources = claims.get(&skater.player_id).cloned().unwrap_or_default();
+                sources.extend(skater.position.clone().map(PositionSource::ClubRoster));
+                reconcile_position(&sources).as_ref() == Some(&position)
+            })
+            .collect();
+        skaters.sort_by(|a, b| by_points(a, b));
+        skaters
+    }
+
+    /// Points per game of every skater who played a game.
+    pub fn points_per_game(&self) -> HashMap<PlayerId, f64> {
+        self.skaters
+            .iter()
+            .filter(|skater| skater.games_played > 0)
+            .map(|skater| {
+                (
+                    skater.player_id,
+                    skater.points as f64 / skater.games_played as f64,
+                )
+            })
+            .collect()
+    }
+
+    /// The goalie with the most starts; games played breaks ties.
+    pub fn starting_goalie(&self) -> Option<&ClubGoalieStats> {
+        self.goalies
+            .iter()
+            .max_by_key(|goalie| (goalie.games_started, goalie.games_played))
+    }
+
+    /// Same as [`Self::starting_goalie`].
+    pub fn most_used_goalie(&self) -> Option<&ClubGoalieStats> {
+        self.starting_goalie()
+    }
+
+    /// Goals, assists, penalty minutes and shots summed over every skater
+    /// and goalie.
+    pub fn team_totals(&self) -> ClubStatsTotals {
+        let mut totals = ClubStatsTotals::default();
+        for skater in &self.skaters {
+            totals.goals += skater.goals;
+            totals.assists += skater.assists;
+            totals.penalty_minutes += skater.penalty_minutes;
+            totals.shots += skater.shots;
+        }
+        for goalie in &self.goalies {
+            totals.goals += goalie.goals;
+            totals.assists += goalie.assists;
+            totals.penalty_minutes += goalie.penalty_minutes;
+        }
+        totals
+    }
+}
+
+/// Both clubs' season stats for one game, for side-by-side previews.
+#[derive(Debug, Clone, PartialEq)]
+pub struct MatchupClubStats {
+    pub away: ClubStats,
+    pub home: ClubStats,
+}
+
+/// Each side's most-used goalie, from [`MatchupClubStats::goalie_comparison`].
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct GoalieComparison<'a> {
+    pub away: Option<&'a ClubGoalieStats>,
+    pub home: Option<&'a ClubGoalieStats>,
+}
+
+impl MatchupClubStats {
+    /// Stats for one side of the matchup.
+    pub fn side(&self, side: HomeRoad) -> &ClubStats {
+        match side {
+            HomeRoad::Home => &self.home,
+            HomeRoad::Road => &self.away,
+        }
+    }
+
+    /// [`ClubStats::top_scorers`] for one side.
+    pub fn top_scorers(&self, side: HomeRoad, n: usize) -> Vec<&ClubSkaterStats> {
+        self.side(side).top_scorers(n)
+    }
+
+    /// Pairs each team's most-used goalie.
+    pub fn goalie_comparison(&self) -> GoalieComparison<'_> {
+        GoalieComparison {
+            away: self.away.most_used_goalie(),
+            home: self.home.most_used_goalie(),
+        }
+    }
+}
+
 /// Season game type availability for a team
 #[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Eq, Hash)]
 pub struct SeasonGameTypes {
@@ -188,6 +342,7 @@ impl fmt::Display for SeasonGameTypes {
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::test_util::assert_no_unmapped_fields;
 
     #[test]
     fn test_skater_stats_deserialization() {
@@ -219,6 +374,7 @@ mod tests {
         }"#;
 
         let stats: ClubSkaterStats = serde_json::from_str(json).unwrap();
+        assert_no_unmapped_fields::<ClubSkaterStats>(json);
         assert_eq!(stats.player_id, PlayerId::new(8475233));
         assert_eq!(stats.first_name.default, "David");
         assert_eq!(stats.last_name.default, "Savard");
@@ -320,6 +476,7 @@ mod tests {
         }"#;
 
         let stats: ClubGoalieStats = serde_json::from_str(json).unwrap();
+        assert_no_unmapped_fields::<ClubGoalieStats>(json);
         assert_eq!(stats.player_id, PlayerId::new(8478470));
         assert_eq!(stats.first_name.default, "Sam");
         assert_eq!(stats.last_name.default, "Montembeault");
@@ -441,6 +598,7 @@ mod tests {
         }"#;
 
         let stats: ClubStats = serde_json::from_str(json).unwrap();
+        assert_no_unmapped_fields::<ClubStats>(json);
         assert_eq!(stats.season, Season::new(2024));
         assert_eq!(stats.game_type, GameType::RegularSeason);
         assert_eq!(stats.skaters.len(), 1);
@@ -473,6 +631,7 @@ mod tests {
         }"#;
 
         let season: SeasonGameTypes = serde_json::from_str(json).unwrap();
+        assert_no_unmapped_fields::<SeasonGameTypes>(json);
         assert_eq!(season.season, Season::new(2024));
         assert_eq!(
             season.game_types,
@@ -513,12 +672,8 @@ mod tests {
         let stats = ClubSkaterStats {
             player_id: PlayerId::new(8475233),
             headshot: "test.png".to_string(),
-            first_name: LocalizedString {
-                default: "David".to_string(),
-            },
-            last_name: LocalizedString {
-                default: "Savard".to_string(),
-            },
+            first_name: LocalizedString::new("David"),
+            last_name: LocalizedString::new("Savard"),
             position: Some(Position::Defense),
             games_played: 75,
             goals: 1,
@@ -548,12 +703,8 @@ mod tests {
         let stats = ClubGoalieStats {
             player_id: PlayerId::new(8478470),
             headshot: "test.png".to_string(),
-            first_name: LocalizedString {
-                default: "Sam".to_string(),
-            },
-            last_name: LocalizedString {
-                default: "Montembeault".to_string(),
-            },
+            first_name: LocalizedString::new("Sam"),
+            last_name: LocalizedString::new("Montembeault"),
             games_played: 62,
             games_started: 60,
             wins: 31,
@@ -648,12 +799,8 @@ mod tests {
         let stats = ClubSkaterStats {
             player_id: PlayerId::new(8475233),
             headshot: "test.png".to_string(),
-            first_name: LocalizedString {
-                default: "David".to_string(),
-            },
-            last_name: LocalizedString {
-                default: "Savard".to_string(),
-            },
+            first_name: LocalizedString::new("David"),
+            last_name: LocalizedString::new("Savard"),
             position: Some(Position::Defense),
             games_played: 75,
             goals: 1,
@@ -682,12 +829,8 @@ mod tests {
         let stats = ClubSkaterStats {
             player_id: PlayerId::new(8475233),
             headshot: "test.png".to_string(),
-            first_name: LocalizedString {
-                default: "David".to_string(),
-            },
-            last_name: LocalizedString {
-                default: "Savard".to_string(),
-            },
+            first_name: LocalizedString::new("David"),
+            last_name: LocalizedString::new("Savard"),
             position: Some(Position::Defense),
             games_played: 75,
             goals: 1,
@@ -717,12 +860,8 @@ mod tests {
         let stats = ClubGoalieStats {
             player_id: PlayerId::new(8478470),
             headshot: "test.png".to_string(),
-            first_name: LocalizedString {
-                default: "Sam".to_string(),
-            },
-            last_name: LocalizedString {
-                default: "Montembeault".to_string(),
-            },
+            first_name: LocalizedString::new("Sam"),
+            last_name: LocalizedString::new("Montembeault"),
             games_played: 62,
             games_started: 60,
             wins: 31,
@@ -751,12 +890,8 @@ mod tests {
         let stats = ClubGoalieStats {
             player_id: PlayerId::new(8478470),
             headshot: "test.png".to_string(),
-            first_name: LocalizedString {
-                default: "Sam".to_string(),
-            },
-            last_name: LocalizedString {
-                default: "Montembeault".to_string(),
-            },
+            first_name: LocalizedString::new("Sam"),
+            last_name: LocalizedString::new("Montembeault"),
             games_played: 62,
             games_started: 60,
             wins: 31,
@@ -877,12 +1012,8 @@ mod tests {
         let stats = ClubSkaterStats {
             player_id: PlayerId::new(8475233),
             headshot: "test.png".to_string(),
-            first_name: LocalizedString {
-                default: "David".to_string(),
-            },
-            last_name: LocalizedString {
-                default: "Savard".to_string(),
-            },
+            first_name: LocalizedString::new("David"),
+            last_name: LocalizedString::new("Savard"),
             position: Some(Position::Defense),
             games_played: 75,
             goals: 1,
@@ -911,12 +1042,8 @@ mod tests {
         let stats = ClubGoalieStats {
             player_id: PlayerId::new(8478470),
             headshot: "test.png".to_string(),
-            first_name: LocalizedString {
-                default: "Sam".to_string(),
-            },
-            last_name: LocalizedString {
-                default: "Montembeault".to_string(),
-            },
+            first_name: LocalizedString::new("Sam"),
+            last_name: LocalizedString::new("Montembeault"),
             games_played: 62,
             games_started: 60,
             wins: 31,
@@ -966,12 +1093,8 @@ mod tests {
         let stats1 = ClubSkaterStats {
             player_id: PlayerId::new(8475233),
             headshot: "test.png".to_string(),
-            first_name: LocalizedString {
-                default: "David".to_string(),
-            },
-            last_name: LocalizedString {
-                default: "Savard".to_string(),
-            },
+            first_name: LocalizedString::new("David"),
+            last_name: LocalizedString::new("Savard"),
             position: Some(Position::Defense),
             games_played: 75,
             goals: 1,
@@ -1003,12 +1126,8 @@ mod tests {
         let stats1 = ClubGoalieStats {
             player_id: PlayerId::new(8478470),
             headshot: "test.png".to_string(),
-            first_name: LocalizedString {
-                default: "Sam".to_string(),
-            },
-            last_name: LocalizedString {
-                default: "Montembeault".to_string(),
-            },
+            first_name: LocalizedString::new("Sam"),
+            last_name: LocalizedString::new("Montembeault"),
             games_played: 62,
             games_started: 60,
             wins: 31,
@@ -1119,4 +1238,286 @@ mod tests {
         let stats: ClubStats = serde_json::from_str(json).unwrap();
         assert_eq!(stats.game_type, GameType::AllStar);
     }
+
+    fn skater(player_id: i64, last: &str, goals: i32, points: i32) -> ClubSkaterStats {
+        ClubSkaterStats {
+            player_id: PlayerId::new(player_id),
+            headshot: String::new(),
+            first_name: LocalizedString::default(),
+            last_name: LocalizedString::new(last.to_string()),
+            position: Some(Position::Center),
+            games_played: 82,
+            goals,
+            assists: points - goals,
+            points,
+            plus_minus: 0,
+            penalty_minutes: 0,
+            power_play_goals: 0,
+            shorthanded_goals: 0,
+            game_winning_goals: 0,
+            overtime_goals: 0,
+            shots: 0,
+            shooting_pctg: 0.0,
+            avg_time_on_ice_per_game: 0.0,
+            avg_shifts_per_game: 0.0,
+            faceoff_win_pctg: 0.0,
+        }
+    }
+
+    fn goalie(player_id: i64, games_played: i32, games_started: i32) -> ClubGoalieStats {
+        ClubGoalieStats {
+            player_id: PlayerId::new(player_id),
+            headshot: String::new(),
+            first_name: LocalizedString::default(),
+            last_name: LocalizedString::default(),
+            games_played,
+            games_started,
+            wins: 0,
+            losses: 0,
+            overtime_losses: 0,
+            goals_against_average: 0.0,
+            save_percentage: 0.0,
+            shots_against: 0,
+            saves: 0,
+            goals_against: 0,
+            shutouts: 0,
+            goals: 0,
+            assists: 0,
+            points: 0,
+            penalty_minutes: 0,
+            time_on_ice: 0,
+        }
+    }
+
+    fn club(skaters: Vec<ClubSkaterStats>, goalies: Vec<ClubGoalieStats>) -> ClubStats {
+        ClubStats {
+            season: Season::new(2024),
+            game_type: GameType::RegularSeason,
+            skaters,
+            goalies,
+        }
+    }
+
+    #[test]
+    fn test_club_stats_top_scorers_orders_by_points_then_goals() {
+        let stats = club(
+            vec![
+                skater(1, "Depth", 5, 12),
+                skater(2, "Playmaker", 20, 90),
+                skater(3, "Sniper", 50, 90),
+                skater(4, "Third", 25, 60),
+            ],
+            Vec::new(),
+        );
+
+        let names: Vec<&str> = stats
+            .top_scorers(3)
+            .iter()
+            .map(|s| s.last_name.default.as_str())
+            .collect();
+        assert_eq!(names, vec!["Sniper", "Playmaker", "Third"]);
+        assert_eq!(stats.top_scorers(10).len(), 4);
+    }
+
+    #[test]
+    fn test_club_stats_most_used_goalie() {
+        let stats = club(
+            Vec::new(),
+            vec![goalie(1, 30, 25), goalie(2, 55, 52), goalie(3, 56, 52)],
+        );
+        assert_eq!(
+            stats.most_used_goalie().unwrap().player_id,
+            PlayerId::new(3)
+        );
+        assert!(club(Vec::new(), Vec::new()).most_used_goalie().is_none());
+    }
+
+    #[test]
+    fn test_matchup_club_stats_sides_and_goalie_comparison() {
+        let matchup = MatchupClubStats {
+            away: club(vec![skater(10, "Away", 30, 70)], vec![goalie(11, 50, 48)]),
+            home: club(vec![skater(20, "Home", 40, 80)], Vec::new()),
+        };
+
+        assert_eq!(
+            matchup.top_scorers(HomeRoad::Road, 1)[0].player_id,
+            PlayerId::new(10)
+        );
+        assert_eq!(
+            matchup.top_scorers(HomeRoad::Home, 1)[0].player_id,
+            PlayerId::new(20)
+        );
+
+        let goalies = matchup.goalie_comparison();
+        assert_eq!(goalies.away.unwrap().player_id, PlayerId::new(11));
+        assert!(goalies.home.is_none());
+    }
+
+    fn mtl_skater_json(
+        player_id: i64,
+        name: (&str, &str),
+        position: &str,
+        games_played: i32,
+        (goals, assists): (i32, i32),
+        penalty_minutes: i32,
+        shots: i32,
+    ) -> String {
+        format!(
+            r#"{{"playerId": {player_id}, "headshot": "",
+                "firstName": {{"default": "{}"}}, "lastName": {{"default": "{}"}},
+                "positionCode": "{position}", "gamesPlayed": {games_played},
+                "goals": {goals}, "assists": {assists}, "points": {},
+                "plusMinus": 0, "penaltyMinutes": {penalty_minutes}, "powerPlayGoals": 0,
+                "shorthandedGoals": 0, "gameWinningGoals": 0, "overtimeGoals": 0,
+                "shots": {shots}, "shootingPctg": 0.0, "avgTimeOnIcePerGame": 0.0,
+                "avgShiftsPerGame": 0.0, "faceoffWinPctg": 0.0}}"#,
+            name.0,
+            name.1,
+            goals + assists
+        )
+    }
+
+    fn mtl_goalie_json(
+        player_id: i64,
+        last_name: &str,
+        (games_played, games_started): (i32, i32),
+        assists: i32,
+        penalty_minutes: i32,
+    ) -> String {
+        format!(
+            r#"{{"playerId": {player_id}, "headshot": "", "firstName": {{"default": ""}},
+                "lastName": {{"default": "{last_name}"}}, "gamesPlayed": {games_played},
+                "gamesStarted": {games_started}, "wins": 0, "losses": 0, "overtimeLosses": 0,
+                "goalsAgainstAverage": 0.0, "savePercentage": 0.0, "shotsAgainst": 0,
+                "saves": 0, "goalsAgainst": 0, "shutouts": 0, "goals": 0,
+                "assists": {assists}, "points": {assists}, "penaltyMinutes": {penalty_minutes},
+                "timeOnIce": 0}}"#
+        )
+    }
+
+    /// A Canadiens-style club: Newhook and Armia tie on points and goals.
+    fn mtl_club_stats() -> ClubStats {
+        let skaters = [
+            mtl_skater_json(8475233, ("David", "Savard"), "D", 75, (1, 14), 36, 48),
+            mtl_skater_json(8480018, ("Nick", "Suzuki"), "C", 82, (33, 44), 22, 220),
+            mtl_skater_json(8481540, ("Cole", "Caufield"), "R", 82, (28, 37), 12, 274),
+            mtl_skater_json(8476875, ("Mike", "Matheson"), "D", 82, (11, 51), 44, 176),
+            mtl_skater_json(8476469, ("Joel", "Armia"), "R", 73, (15, 9), 6, 120),
+            mtl_skater_json(8481618, ("Alex", "Newhook"), "C", 57, (15, 9), 10, 110),
+            mtl_skater_json(8483515, ("Juraj", "Slafkovsky"), "L", 82, (20, 30), 44, 153),
+        ];
+        let goalies = [
+            mtl_goalie_json(8478470, "Montembeault", (41, 40), 1, 0),
+            mtl_goalie_json(8479979, "Allen", (21, 16), 0, 0),
+            mtl_goalie_json(8480008, "Primeau", (23, 20), 0, 2),
+        ];
+        serde_json::from_str(&format!(
+            r#"{{"season": "20232024", "gameType": 2, "skaters": [{}], "goalies": [{}]}}"#,
+            skaters.join(","),
+            goalies.join(",")
+        ))
+        .unwrap()
+    }
+
+    fn last_names<'a>(skaters: &[&'a ClubSkaterStats]) -> Vec<&'a str> {
+        skaters
+            .iter()
+            .map(|skater| skater.last_name.default.as_str())
+            .collect()
+    }
+
+    #[test]
+    fn test_club_stats_sorting_tie_breakers() {
+        let stats = mtl_club_stats();
+
+        assert_eq!(
+            last_names(&stats.top_scorers(10)),
+            [
+                "Suzuki",
+                "Caufield",
+                "Matheson",
+                "Slafkovsky",
+                "Newhook",
+                "Armia",
+                "Savard"
+            ]
+        );
+        assert_eq!(
+            last_names(&stats.top_goal_scorers(4)),
+            ["Suzuki", "Caufield", "Slafkovsky", "Newhook"]
+        );
+        assert_eq!(
+            last_names(&stats.skaters_by_position(Position::Defense)),
+            ["Matheson", "Savard"]
+        );
+        assert_eq!(
+            last_names(&stats.skaters_by_position(Position::RightWing)),
+            ["Caufield", "Armia"]
+        );
+    }
+
+    /// A game roster spot outranks the club stats listing; a landing
+    /// claim doesn't.
+    #[test]
+    fn test_skaters_by_reconciled_position() {
+        let stats = mtl_club_stats();
+        let claims = HashMap::from([
+            (
+                PlayerId::new(8481618),
+                vec![PositionSource::GameRoster(Position::LeftWing)],
+            ),
+            (
+                PlayerId::new(8481540),
+                vec![PositionSource::Landing(Position::Center)],
+            ),
+        ]);
+
+        let bucket =
+            |position| last_names(&stats.skaters_by_reconciled_position(position, &claims));
+        assert_eq!(bucket(Position::Center), ["Suzuki"]);
+        assert_eq!(bucket(Position::LeftWing), ["Slafkovsky", "Newhook"]);
+        assert_eq!(bucket(Position::RightWing), ["Caufield", "Armia"]);
+    }
+
+    #[test]
+    fn test_club_stats_per_game_goalie_and_totals() {
+        let stats = mtl_club_stats();
+
+        let per_game = stats.points_per_game();
+        assert_eq!(per_game.len(), 7);
+        assert_eq!(per_game[&PlayerId::new(8480018)], 77.0 / 82.0);
+        assert_eq!(per_game[&PlayerId::new(8475233)], 15.0 / 75.0);
+
+        let starter = stats.starting_goalie().unwrap();
+        assert_eq!(starter.last_name.default, "Montembeault");
+        assert_eq!(stats.most_used_goalie(), Some(starter));
+
+        assert_eq!(
+            stats.team_totals(),
+            ClubStatsTotals {
+                goals: 123,
+                assists: 195,
+                penalty_minutes: 176,
+                shots: 1101,
+            }
+        );
+    }
+
+    #[test]
+    fn test_club_stats_helpers_on_empty_roster() {
+        let mut stats = club(Vec::new(), Vec::new());
+        stats.skaters.push(skater(1, "Callup", 0, 0));
+        stats.skaters[0].games_played = 0;
+
+        assert!(stats.points_per_game().is_empty());
+        assert_eq!(stats.top_scorers(3).len(), 1);
+
+        let empty = club(Vec::new(), Vec::new());
+        assert!(empty.top_scorers(5).is_empty());
+        assert!(empty.top_goal_scorers(5).is_empty());
+        assert!(empty.skaters_by_position(Position::Center).is_empty());
+        assert!(empty.points_per_game().is_empty());
+        assert!(empty.starting_goalie().is_none());
+        assert_eq!(empty.team_totals(), ClubStatsTotals::default());
+    }
 }
@@ -1,22 +1,207 @@
 use chrono::{Datelike, NaiveDate};
 use serde::{Deserialize, Serialize};
+use std::collections::BTreeMap;
 use std::fmt;
 
 use crate::ids::PlayerId;
 
 use super::enums::{empty_string_as_none, Handedness, Position};
 
-/// Number of inches in a foot, used by [`RosterPlayer::height_feet_inches`].
-const INCHES_PER_FOOT: i32 = 12;
+/// Number of inches in a foot, used by [`RosterPlayer::height_imperial`]
+/// and when parsing search result heights.
+pub(crate) const INCHES_PER_FOOT: i32 = 12;
+
+/// Centimeters in an inch.
+const CENTIMETERS_PER_INCH: f64 = 2.54;
+
+/// Kilograms in a pound.
+const KILOGRAMS_PER_POUND: f64 = 0.453_592_37;
+
+/// Rounded to the nearest inch.
+pub(crate) fn centimeters_to_inches(centimeters: i32) -> i32 {
+    (f64::from(centimeters) / CENTIMETERS_PER_INCH).round() as i32
+}
+
+/// A height or weight sent in either unit, in the unit asked for: `native`
+/// when sent, else `other` converted by `factor` (native units per other
+/// unit) and rounded to the nearest whole unit. `None` when neither is sent
+/// or the value is negative.
+pub(crate) fn measurement_in(native: Option<i32>, other: Option<i32>, factor: f64) -> Option<u32> {
+    let value = native.or_else(|| other.map(|other| (f64::from(other) * factor).round() as i32))?;
+    u32::try_from(value).ok()
+}
+
+/// Height in centimeters from `heightInCentimeters` or `heightInInches`.
+pub(crate) fn height_cm(inches: Option<i32>, centimeters: Option<i32>) -> Option<u32> {
+    measurement_in(centimeters, inches, CENTIMETERS_PER_INCH)
+}
+
+/// Height as `6'2"`, from `heightInInches` or `heightInCentimeters`.
+pub(crate) fn height_imperial(inches: Option<i32>, centimeters: Option<i32>) -> Option<String> {
+    let inches = measurement_in(inches, centimeters, 1.0 / CENTIMETERS_PER_INCH)?;
+    let foot = INCHES_PER_FOOT as u32;
+    Some(format!("{}'{}\"", inches / foot, inches % foot))
+}
+
+/// Weight in kilograms from `weightInKilograms` or `weightInPounds`.
+pub(crate) fn weight_kg(pounds: Option<i32>, kilograms: Option<i32>) -> Option<u32> {
+    measurement_in(kilograms, pounds, KILOGRAMS_PER_POUND)
+}
+
+/// Weight in pounds from `weightInPounds` or `weightInKilograms`.
+pub(crate) fn weight_lbs(pounds: Option<i32>, kilograms: Option<i32>) -> Option<u32> {
+    measurement_in(pounds, kilograms, 1.0 / KILOGRAMS_PER_POUND)
+}
 
 /// `chrono` format string matching the NHL API's `birthDate` field
 /// (e.g. `"1997-01-13"`).
 const BIRTH_DATE_FORMAT: &str = "%Y-%m-%d";
 
 /// Localized string (NHL API returns {default: "value"})
+///
+/// The api-web host sends every translation it has next to `default`
+/// (`{"default": "Montreal", "fr": "Montréal"}`) whatever the request's
+/// language; they are kept in `translations`, keyed by language code.
 #[derive(Debug, Clone, Default, Serialize, Deserialize, PartialEq, Eq, Hash)]
 pub struct LocalizedString {
     pub default: String,
+    #[serde(flatten)]
+    pub translations: BTreeMap<String, String>,
+}
+
+impl LocalizedString {
+    /// A string with only its default value
+    pub fn new(default: impl Into<String>) -> Self {
+        Self {
+            default: default.into(),
+            translations: BTreeMap::new(),
+        }
+    }
+
+    /// The `language` translation (e.g. `"fr"`), or `default` when the API
+    /// sent none
+    pub fn get(&self, language: &str) -> &str {
+        self.translations
+            .get(language)
+            .map_or(&self.default, String::as_str)
+    }
+}
+
+/// Where a player was born. Flattened into the player structs, so the
+/// `birthCity`, `birthStateProvince` and `birthCountry` keys stay where the
+/// API sends them. The search and draft hosts send city and state/province
+/// as plain strings; they are read as the `default` value.
+#[derive(Debug, Clone, Default, Serialize, Deserialize, PartialEq, Eq)]
+pub struct BirthInfo {
+    #[serde(rename = "birthCity", deserialize_with = "localized_or_plain", default)]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub city: Option<LocalizedString>,
+    /// `None` for most players born outside North America.
+    #[serde(
+        rename = "birthStateProvince",
+        deserialize_with = "localized_or_plain",
+        default
+    )]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub state_province: Option<LocalizedString>,
+    /// Three-letter code as sent, e.g. `"CAN"` or `"SWE"`.
+    #[serde(rename = "birthCountry", default)]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub country_code: Option<String>,
+}
+
+impl BirthInfo {
+    /// English name of [`Self::country_code`]; see [`country_name`].
+    pub fn country_name(&self) -> Option<&'static str> {
+        country_name(self.country_code.as_deref()?)
+    }
+
+    /// City, state/province and country code, comma-joined, leaving out
+    /// whichever are missing or empty (e.g. `"Richmond Hill, ON, CAN"` or
+    /// `"Rauma, FIN"`).
+    pub fn place(&self) -> String {
+        [
+            self.city.as_ref().map(|city| city.default.as_str()),
+            self.state_province
+                .as_ref()
+                .map(|state| state.default.as_str()),
+            self.country_code.as_deref(),
+        ]
+        .into_iter()
+        .flatten()
+        .filter(|part| !part.is_empty())
+        .collect::<Vec<_>>()
+        .join(", ")
+    }
+}
+
+/// English name of a three-letter country code as the NHL sends them,
+/// mostly ISO 3166 alpha-3 (`"CAN"`, `"SWE"`) with IOC codes for a few
+/// countries (`"GER"`, `"SUI"`). Case-insensitive. `None` for codes outside
+/// the hockey-playing countries listed here.
+pub fn country_name(code: &str) -> Option<&'static str> {
+    let name = match code.to_ascii_uppercase().as_str() {
+        "AUS" => "Australia",
+        "AUT" => "Austria",
+        "BEL" => "Belgium",
+        "BLR" => "Belarus",
+        "BRA" => "Brazil",
+        "CAN" => "Canada",
+        "CHE" | "SUI" => "Switzerland",
+        "CHN" => "China",
+        "CZE" => "Czechia",
+        "DEU" | "GER" => "Germany",
+        "DNK" | "DEN" => "Denmark",
+        "EST" => "Estonia",
+        "FIN" => "Finland",
+        "FRA" => "France",
+        "GBR" => "United Kingdom",
+        "HRV" | "CRO" => "Croatia",
+        "HUN" => "Hungary",
+        "ITA" => "Italy",
+        "JAM" => "Jamaica",
+        "JPN" => "Japan",
+        "KAZ" => "Kazakhstan",
+        "KOR" => "South Korea",
+        "LTU" => "Lithuania",
+        "LVA" | "LAT" => "Latvia",
+        "NGA" => "Nigeria",
+        "NLD" | "NED" => "Netherlands",
+        "NOR" => "Norway",
+        "POL" => "Poland",
+        "ROU" => "Romania",
+        "RUS" => "Russia",
+        "SRB" => "Serbia",
+        "SVK" => "Slovakia",
+        "SVN" | "SLO" => "Slovenia",
+        "SWE" => "Sweden",
+        "UKR" => "Ukraine",
+        "USA" => "United States",
+        "ZAF" | "RSA" => "South Africa",
+        _ => return None,
+    };
+    Some(name)
+}
+
+/// An optional [`LocalizedString`] sent either as an object or as a plain
+/// string; `null` is `None`.
+fn localized_or_plain<'de, D>(deserializer: D) -> Result<Option<LocalizedString>, D::Error>
+where
+    D: serde::Deserializer<'de>,
+{
+    #[derive(Deserialize)]
+    #[serde(untagged)]
+    enum LocalizedOrPlain {
+        Plain(String),
+        Localized(LocalizedString),
+    }
+
+    Ok(
+        Option::<LocalizedOrPlain>::deserialize(deserializer)?.map(|value| match value {
+            LocalizedOrPlain::Plain(plain) => LocalizedString::new(plain),
+            LocalizedOrPlain::Localized(localized) => localized,
+        }),
+    )
 }
 
 /// Conference information for a team
@@ -94,6 +279,109 @@ pub struct Roster {
     pub goalies: Vec<RosterPlayer>,
 }
 
+impl Roster {
+    /// Every player with the group they're listed in.
+    fn players_by_group(&self) -> impl Iterator<Item = (RosterGroup, &RosterPlayer)> {
+        [
+            (RosterGroup::Forwards, &self.forwards),
+            (RosterGroup::Defensemen, &self.defensemen),
+            (RosterGroup::Goalies, &self.goalies),
+        ]
+        .into_iter()
+        .flat_map(|(group, players)| players.iter().map(move |player| (group, player)))
+    }
+
+    /// What changed from this roster to `other` (e.g. last season's to this
+    /// season's): players only on `other` are added, players only on this
+    /// one removed, and players on both whose sweater number, position
+    /// code or position group differ are changed.
+    pub fn diff(&self, other: &Roster) -> RosterDiff {
+        let before: BTreeMap<PlayerId, (RosterGroup, &RosterPlayer)> = self
+            .players_by_group()
+            .map(|(group, player)| (player.id, (group, player)))
+            .collect();
+        let after: BTreeMap<PlayerId, (RosterGroup, &RosterPlayer)> = other
+            .players_by_group()
+            .map(|(group, player)| (player.id, (group, player)))
+            .collect();
+
+        let mut diff = RosterDiff::default();
+        for (id, (group, player)) in &before {
+            match after.get(id) {
+                None => {
+                    diff.removed.insert(*id, (*player).clone());
+                }
+                Some((other_group, other_player))
+                    if group != other_group
+                        || player.sweater_number != other_player.sweater_number
+                        || player.position != other_player.position =>
+                {
+                    diff.changed.insert(
+                        *id,
+                        RosterChange {
+                            before: (*player).clone(),
+                            after: (*other_player).clone(),
+                        },
+                    );
+                }
+                Some(_) => {}
+            }
+        }
+        for (id, (_, player)) in &after {
+            if !before.contains_key(id) {
+                diff.added.insert(*id, (*player).clone());
+            }
+        }
+        diff
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum RosterGroup {
+    Forwards,
+    Defensemen,
+    Goalies,
+}
+
+/// Differences between two rosters, keyed by player id. From
+/// [`Roster::diff`].
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct RosterDiff {
+    /// Players only on the newer roster.
+    pub added: BTreeMap<PlayerId, RosterPlayer>,
+    /// Players only on the older roster.
+    pub removed: BTreeMap<PlayerId, RosterPlayer>,
+    /// Players on both whose sweater number or position changed.
+    pub changed: BTreeMap<PlayerId, RosterChange>,
+}
+
+impl RosterDiff {
+    /// Whether the two rosters list the same players the same way.
+    pub fn is_empty(&self) -> bool {
+        self.added.is_empty() && self.removed.is_empty() && self.changed.is_empty()
+    }
+}
+
+/// A player's roster entries before and after, in a [`RosterDiff`]
+#[derive(Debug, Clone, PartialEq)]
+pub struct RosterChange {
+    pub before: RosterPlayer,
+    pub after: RosterPlayer,
+}
+
+impl RosterChange {
+    pub fn sweater_number_changed(&self) -> bool {
+        self.before.sweater_number != self.after.sweater_number
+    }
+
+    /// Whether the position code changed; a move between the forward,
+    /// defense and goalie groups always changes it unless the code is
+    /// missing.
+    pub fn position_changed(&self) -> bool {
+        self.before.position != self.after.position
+    }
+}
+
 /// Individual player in a team roster
 #[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
 pub struct RosterPlayer {
@@ -122,23 +410,24 @@ pub struct RosterPlayer {
     )]
     #[serde(skip_serializing_if = "Option::is_none")]
     pub shoots_catches: Option<Handedness>,
-    #[serde(rename = "heightInInches")]
-    pub height_in_inches: i32,
-    #[serde(rename = "weightInPounds")]
-    pub weight_in_pounds: i32,
-    #[serde(rename = "heightInCentimeters")]
-    pub height_in_centimeters: i32,
-    #[serde(rename = "weightInKilograms")]
-    pub weight_in_kilograms: i32,
+    /// Endpoints send height and weight in imperial units, metric units or
+    /// both; see [`Self::height_cm`] and friends for either unit.
+    #[serde(rename = "heightInInches", default)]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub height_in_inches: Option<i32>,
+    #[serde(rename = "weightInPounds", default)]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub weight_in_pounds: Option<i32>,
+    #[serde(rename = "heightInCentimeters", default)]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub height_in_centimeters: Option<i32>,
+    #[serde(rename = "weightInKilograms", default)]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub weight_in_kilograms: Option<i32>,
     #[serde(rename = "birthDate")]
     pub birth_date: String,
-    #[serde(rename = "birthCity")]
-    pub birth_city: LocalizedString,
-    #[serde(rename = "birthCountry")]
-    pub birth_country: String,
-    #[serde(skip_serializing_if = "Option::is_none")]
-    #[serde(rename = "birthStateProvince")]
-    pub birth_state_province: Option<LocalizedString>,
+    #[serde(flatten)]
+    pub birth: BirthInfo,
 }
 
 impl RosterPlayer {
@@ -150,30 +439,31 @@ impl RosterPlayer {
     /// A comma-joined birth place built from whichever of city, state/
     /// province, and country are present and non-empty (e.g.
     /// `"Richmond Hill, ON, CAN"`, or `"Boston, USA"` when there's no
-    /// state/province on file).
+    /// state/province on file). See [`BirthInfo::place`].
     pub fn birth_place(&self) -> String {
-        let state_province = self
-            .birth_state_province
-            .as_ref()
-            .map(|s| s.default.as_str());
+        self.birth.place()
+    }
 
-        [
-            self.birth_city.default.as_str(),
-            state_province.unwrap_or(""),
-            self.birth_country.as_str(),
-        ]
-        .into_iter()
-        .filter(|part| !part.is_empty())
-        .collect::<Vec<_>>()
-        .join(", ")
+    /// Height in centimeters, converted from inches when only those were
+    /// sent. Conversions round to the nearest whole unit.
+    pub fn height_cm(&self) -> Option<u32> {
+        height_cm(self.height_in_inches, self.height_in_centimeters)
+    }
+
+    /// Height as feet and inches (e.g. `6'2"` for 74 inches), converted
+    /// from centimeters when only those were sent.
+    pub fn height_imperial(&self) -> Option<String> {
+        height_imperial(self.height_in_inches, self.height_in_centimeters)
+    }
+
+    /// Weight in kilograms, converted from pounds when only those were sent.
+    pub fn weight_kg(&self) -> Option<u32> {
+        weight_kg(self.weight_in_pounds, self.weight_in_kilograms)
     }
 
-    /// The player's height formatted as feet and inches (e.g. `6'2"` for a
-    /// player who is 74 inches tall).
-    pub fn height_feet_inches(&self) -> String {
-        let feet = self.height_in_inches / INCHES_PER_FOOT;
-        let inches = self.height_in_inches % INCHES_PER_FOOT;
-        format!("{feet}'{inches}\"")
+    /// Weight in pounds, converted from kilograms when only those were sent.
+    pub fn weight_lbs(&self) -> Option<u32> {
+        weight_lbs(self.weight_in_pounds, self.weight_in_kilograms)
     }
 
     /// The player's age in whole years as of `on`, or `None` if `birth_date`
@@ -193,15 +483,14 @@ impl RosterPlayer {
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::test_util::assert_no_unmapped_fields;
 
     #[test]
     fn test_team_serialization() {
         let team = Team {
             name: "Buffalo Sabres".to_string(),
             common_name: "Sabres".to_string(),
-            place_name: LocalizedString {
-                default: "Buffalo".to_string(),
-            },
+            place_name: LocalizedString::new("Buffalo"),
             abbr: "BUF".to_string(),
             logo: "https://assets.nhle.com/logos/nhl/svg/BUF_light.svg".to_string(),
             conference: Conference {
@@ -224,6 +513,35 @@ mod tests {
         assert_eq!(deserialized.place_name.default, "Buffalo");
     }
 
+    #[test]
+    fn test_localized_string_keeps_translations() {
+        let json = r#"{"default": "Montreal", "fr": "Montréal", "cs": "Montreal"}"#;
+        let name: LocalizedString = serde_json::from_str(json).unwrap();
+        assert_no_unmapped_fields::<LocalizedString>(json);
+
+        assert_eq!(name.default, "Montreal");
+        assert_eq!(name.get("fr"), "Montréal");
+        assert_eq!(name.get("de"), "Montreal");
+        assert_eq!(name.translations.len(), 2);
+    }
+
+    /// Re-serializing emits every language that came in, nested or not.
+    #[test]
+    fn test_localized_string_translations_round_trip() {
+        let json = serde_json::json!({"default": "Canadiens", "fr": "Canadiens de Montréal", "sk": "Canadiens"});
+        let name: LocalizedString = serde_json::from_value(json.clone()).unwrap();
+        assert_eq!(name.get("fr"), "Canadiens de Montréal");
+        assert_eq!(serde_json::to_value(&name).unwrap(), json);
+
+        let team = serde_json::json!({
+            "id": 8, "abbrev": "MTL", "logo": "",
+            "placeName": {"default": "Montreal", "fr": "Montréal"}
+        });
+        let parsed: crate::types::ScheduleTeam = serde_json::from_value(team.clone()).unwrap();
+        assert_eq!(parsed.place_name.as_ref().unwrap().get("fr"), "Montréal");
+        assert_eq!(serde_json::to_value(&parsed).unwrap(), team);
+    }
+
     /// Older serialized `Team` JSON predating the `place_name` field must
     /// still deserialize, defaulting to an empty `LocalizedString`.
     #[test]
@@ -238,6 +556,7 @@ mod tests {
         }"#;
 
         let team: Team = serde_json::from_str(json).unwrap();
+        assert_no_unmapped_fields::<Team>(json);
         assert_eq!(team.place_name, LocalizedString::default());
         assert_eq!(team.place_name.default, "");
     }
@@ -252,6 +571,7 @@ mod tests {
         }"#;
 
         let franchise: Franchise = serde_json::from_str(json).unwrap();
+        assert_no_unmapped_fields::<Franchise>(json);
         assert_eq!(franchise.id, 32);
         assert_eq!(franchise.full_name, "Anaheim Ducks");
         assert_eq!(franchise.team_common_name, "Ducks");
@@ -290,6 +610,7 @@ mod tests {
         }"#;
 
         let response: FranchisesResponse = serde_json::from_str(json).unwrap();
+        assert_no_unmapped_fields::<FranchisesResponse>(json);
         assert_eq!(response.data.len(), 2);
         assert_eq!(response.data[0].id, 32);
         assert_eq!(response.data[0].full_name, "Anaheim Ducks");
@@ -360,9 +681,7 @@ mod tests {
         let team = Team {
             name: "Buffalo Sabres".to_string(),
             common_name: "Sabres".to_string(),
-            place_name: LocalizedString {
-                default: "Buffalo".to_string(),
-            },
+            place_name: LocalizedString::new("Buffalo"),
             abbr: "BUF".to_string(),
             logo: "https://assets.nhle.com/logos/nhl/svg/BUF_light.svg".to_string(),
             conference: Conference {
@@ -446,11 +765,59 @@ mod tests {
         }"#;
 
         let player: RosterPlayer = serde_json::from_str(json).unwrap();
+        assert_no_unmapped_fields::<RosterPlayer>(json);
         assert_eq!(player.position, Some(Position::Center));
         assert_eq!(player.shoots_catches, Some(Handedness::Left));
         assert_eq!(player.id, PlayerId::new(1));
     }
 
+    #[test]
+    fn test_roster_maps_every_field() {
+        let player = r#"{
+            "id": 8478402, "headshot": "", "firstName": {"default": "Connor"},
+            "lastName": {"default": "McDavid"}, "sweaterNumber": 97, "positionCode": "C",
+            "shootsCatches": "L", "heightInInches": 73, "weightInPounds": 193,
+            "heightInCentimeters": 185, "weightInKilograms": 88, "birthDate": "1997-01-13",
+            "birthCity": {"default": "Richmond Hill"}, "birthCountry": "CAN"
+        }"#;
+        let json = format!(
+            r#"{{"forwards": [{}], "defensemen": [{}], "goalies": [{}]}}"#,
+            player, player, player
+        );
+        assert_no_unmapped_fields::<Roster>(&json);
+    }
+
+    #[test]
+    fn test_roster_player_birth_info_european_player() {
+        let json = r#"{
+            "id": 8480839, "headshot": "", "firstName": {"default": "Rasmus"},
+            "lastName": {"default": "Dahlin"}, "sweaterNumber": 26, "positionCode": "D",
+            "shootsCatches": "L", "birthDate": "2000-04-13",
+            "birthCity": {"default": "Lidkoping", "sv": "Lidköping"},
+            "birthStateProvince": null, "birthCountry": "SWE"
+        }"#;
+
+        let player: RosterPlayer = serde_json::from_str(json).unwrap();
+        assert_eq!(player.birth.city.as_ref().unwrap().get("sv"), "Lidköping");
+        assert_eq!(player.birth.state_province, None);
+        assert_eq!(player.birth.country_name(), Some("Sweden"));
+        assert_eq!(player.birth_place(), "Lidkoping, SWE");
+
+        let round_tripped: RosterPlayer =
+            serde_json::from_str(&serde_json::to_string(&player).unwrap()).unwrap();
+        assert_eq!(round_tripped, player);
+    }
+
+    #[test]
+    fn test_country_name() {
+        assert_eq!(country_name("CAN"), Some("Canada"));
+        assert_eq!(country_name("swe"), Some("Sweden"));
+        assert_eq!(country_name("GER"), country_name("DEU"));
+        assert_eq!(country_name("SUI"), Some("Switzerland"));
+        assert_eq!(country_name("XYZ"), None);
+        assert_eq!(BirthInfo::default().country_name(), None);
+    }
+
     /// `RosterPlayer.id` accepts a numeric-string form too (1.3).
     #[test]
     fn test_roster_player_id_deserializes_from_numeric_string() {
@@ -510,30 +877,91 @@ mod tests {
         RosterPlayer {
             id: PlayerId::new(8478402),
             headshot: "https://assets.nhle.com/mugs/nhl/default.png".to_string(),
-            first_name: LocalizedString {
-                default: "Connor".to_string(),
-            },
-            last_name: LocalizedString {
-                default: "McDavid".to_string(),
-            },
+            first_name: LocalizedString::new("Connor"),
+            last_name: LocalizedString::new("McDavid"),
             sweater_number: 97,
             position: Some(Position::Center),
             shoots_catches: Some(Handedness::Left),
-            height_in_inches: 73,
-            weight_in_pounds: 193,
-            height_in_centimeters: 185,
-            weight_in_kilograms: 88,
+            height_in_inches: Some(73),
+            weight_in_pounds: Some(193),
+            height_in_centimeters: Some(185),
+            weight_in_kilograms: Some(88),
             birth_date: "1997-01-13".to_string(),
-            birth_city: LocalizedString {
-                default: "Richmond Hill".to_string(),
+            birth: BirthInfo {
+                city: Some(LocalizedString::new("Richmond Hill")),
+                state_province: Some(LocalizedString::new("ON")),
+                country_code: Some("CAN".to_string()),
             },
-            birth_country: "CAN".to_string(),
-            birth_state_province: Some(LocalizedString {
-                default: "ON".to_string(),
-            }),
+        }
+    }
+
+    fn roster_entry(id: i64, sweater_number: i32, position: Position) -> RosterPlayer {
+        RosterPlayer {
+            id: PlayerId::new(id),
+            sweater_number,
+            position: Some(position),
+            ..sample_roster_player()
         }
     }
 
+    #[test]
+    fn test_roster_diff_added_removed_and_changed() {
+        let before = Roster {
+            forwards: vec![
+                roster_entry(1, 11, Position::Center),
+                roster_entry(2, 12, Position::LeftWing),
+                roster_entry(3, 13, Position::RightWing),
+            ],
+            defensemen: vec![roster_entry(4, 44, Position::Defense)],
+            goalies: vec![roster_entry(5, 31, Position::Goalie)],
+        };
+        let after = Roster {
+            forwards: vec![
+                roster_entry(1, 11, Position::Center),
+                roster_entry(2, 22, Position::LeftWing),
+                roster_entry(6, 16, Position::Center),
+            ],
+            // Player 3 moved from the wing to the blue line.
+            defensemen: vec![
+                roster_entry(4, 44, Position::Defense),
+                roster_entry(3, 13, Position::Defense),
+            ],
+            goalies: vec![roster_entry(5, 31, Position::Goalie)],
+        };
+
+        let diff = before.diff(&after);
+
+        assert_eq!(diff.added.keys().collect::<Vec<_>>(), [&PlayerId::new(6)]);
+        assert!(diff.removed.is_empty());
+        assert_eq!(
+            diff.changed.keys().collect::<Vec<_>>(),
+            [&PlayerId::new(2), &PlayerId::new(3)]
+        );
+        let renumbered = &diff.changed[&PlayerId::new(2)];
+        assert!(renumbered.sweater_number_changed());
+        assert!(!renumbered.position_changed());
+        let moved = &diff.changed[&PlayerId::new(3)];
+        assert!(moved.position_changed());
+        assert_eq!(moved.after.position, Some(Position::Defense));
+
+        let reverse = after.diff(&before);
+        assert_eq!(
+            reverse.removed.keys().collect::<Vec<_>>(),
+            [&PlayerId::new(6)]
+        );
+        assert_eq!(reverse.changed.len(), 2);
+    }
+
+    #[test]
+    fn test_roster_diff_identical_rosters_is_empty() {
+        let roster = Roster {
+            forwards: vec![roster_entry(1, 11, Position::Center)],
+            defensemen: vec![],
+            goalies: vec![roster_entry(5, 31, Position::Goalie)],
+        };
+        assert!(roster.diff(&roster.clone()).is_empty());
+    }
+
     #[test]
     fn test_roster_player_full_name() {
         let player = sample_roster_player();
@@ -548,20 +976,16 @@ mod tests {
 
     #[test]
     fn test_roster_player_birth_place_missing_state() {
-        let player = RosterPlayer {
-            birth_state_province: None,
-            ..sample_roster_player()
-        };
+        let mut player = sample_roster_player();
+        player.birth.state_province = None;
         assert_eq!(player.birth_place(), "Richmond Hill, CAN");
     }
 
     #[test]
     fn test_roster_player_birth_place_city_only() {
-        let player = RosterPlayer {
-            birth_state_province: None,
-            birth_country: String::new(),
-            ..sample_roster_player()
-        };
+        let mut player = sample_roster_player();
+        player.birth.state_province = None;
+        player.birth.country_code = Some(String::new());
         assert_eq!(player.birth_place(), "Richmond Hill");
     }
 
@@ -569,40 +993,94 @@ mod tests {
     /// also treated as absent, not rendered as a bare `", "`.
     #[test]
     fn test_roster_player_birth_place_empty_state_string_treated_as_absent() {
-        let player = RosterPlayer {
-            birth_state_province: Some(LocalizedString::default()),
-            ..sample_roster_player()
-        };
+        let mut player = sample_roster_player();
+        player.birth.state_province = Some(LocalizedString::default());
         assert_eq!(player.birth_place(), "Richmond Hill, CAN");
     }
 
     #[test]
     fn test_roster_player_birth_place_none() {
         let player = RosterPlayer {
-            birth_city: LocalizedString::default(),
-            birth_state_province: None,
-            birth_country: String::new(),
+            birth: BirthInfo::default(),
             ..sample_roster_player()
         };
         assert_eq!(player.birth_place(), "");
     }
 
     #[test]
-    fn test_roster_player_height_feet_inches() {
+    fn test_roster_player_height_imperial() {
         let player = sample_roster_player();
-        assert_eq!(player.height_feet_inches(), "6'1\"");
+        assert_eq!(player.height_imperial().as_deref(), Some("6'1\""));
 
         let short_player = RosterPlayer {
-            height_in_inches: 72,
+            height_in_inches: Some(72),
             ..sample_roster_player()
         };
-        assert_eq!(short_player.height_feet_inches(), "6'0\"");
+        assert_eq!(short_player.height_imperial().as_deref(), Some("6'0\""));
 
         let exact_foot = RosterPlayer {
-            height_in_inches: 84,
+            height_in_inches: Some(84),
             ..sample_roster_player()
         };
-        assert_eq!(exact_foot.height_feet_inches(), "7'0\"");
+        assert_eq!(exact_foot.height_imperial().as_deref(), Some("7'0\""));
+    }
+
+    fn roster_player_json(measurements: &str) -> RosterPlayer {
+        serde_json::from_str(&format!(
+            r#"{{"id": 8478402, "headshot": "", "firstName": {{"default": "Connor"}},
+                "lastName": {{"default": "McDavid"}}, "sweaterNumber": 97,
+                "positionCode": "C", "shootsCatches": "L", "birthDate": "1997-01-13",
+                "birthCity": {{"default": "Richmond Hill"}}, "birthCountry": "CAN"{}}}"#,
+            measurements
+        ))
+        .unwrap()
+    }
+
+    #[test]
+    fn test_roster_player_measurements_from_either_unit() {
+        let imperial = roster_player_json(r#", "heightInInches": 73, "weightInPounds": 193"#);
+        assert_eq!(imperial.height_in_centimeters, None);
+        assert_eq!(imperial.height_cm(), Some(185));
+        assert_eq!(imperial.height_imperial().as_deref(), Some("6'1\""));
+        assert_eq!(imperial.weight_kg(), Some(88));
+        assert_eq!(imperial.weight_lbs(), Some(193));
+
+        let metric = roster_player_json(r#", "heightInCentimeters": 185, "weightInKilograms": 88"#);
+        assert_eq!(metric.height_in_inches, None);
+        assert_eq!(metric.height_cm(), Some(185));
+        assert_eq!(metric.height_imperial().as_deref(), Some("6'1\""));
+        assert_eq!(metric.weight_kg(), Some(88));
+        assert_eq!(metric.weight_lbs(), Some(194));
+
+        let neither = roster_player_json("");
+        assert_eq!(neither.height_cm(), None);
+        assert_eq!(neither.height_imperial(), None);
+        assert_eq!(neither.weight_kg(), None);
+        assert_eq!(neither.weight_lbs(), None);
+    }
+
+    #[test]
+    fn test_roster_player_sent_units_win_over_conversion() {
+        // 73 in is 185.42 cm; the API's own 186 is kept.
+        let player = RosterPlayer {
+            height_in_centimeters: Some(186),
+            ..sample_roster_player()
+        };
+        assert_eq!(player.height_cm(), Some(186));
+        assert_eq!(player.height_imperial().as_deref(), Some("6'1\""));
+    }
+
+    #[test]
+    fn test_measurement_conversions_round_to_nearest() {
+        // 74 in = 187.96 cm; 187 cm = 73.62 in; 190 lb = 86.18 kg;
+        // 86 kg = 189.6 lb; 72.5 in is the nearest-inch boundary at 184.15 cm.
+        assert_eq!(height_cm(Some(74), None), Some(188));
+        assert_eq!(height_imperial(None, Some(187)).as_deref(), Some("6'2\""));
+        assert_eq!(height_imperial(None, Some(184)).as_deref(), Some("6'0\""));
+        assert_eq!(height_imperial(None, Some(185)).as_deref(), Some("6'1\""));
+        assert_eq!(weight_kg(Some(190), None), Some(86));
+        assert_eq!(weight_lbs(None, Some(86)), Some(190));
+        assert_eq!(weight_lbs(Some(-1), None), None);
     }
 
     #[test]
@@ -0,0 +1,239 @@
+//! NHL Entry Draft prospect rankings (`draft/rankings`) and selections
+//! (`draft/picks`).
+
+use serde::{Deserialize, Serialize};
+
+use crate::ids::{PlayerId, TeamId};
+
+use super::common::{BirthInfo, LocalizedString};
+use super::enums::{empty_string_as_none, Handedness, Position};
+
+/// Central Scouting prospect categories, numbered as in the
+/// `draft/rankings/{season}/{category}` path
+pub mod prospect_category {
+    /// North American skaters
+    pub const NORTH_AMERICAN_SKATERS: u8 = 1;
+    /// International skaters
+    pub const INTERNATIONAL_SKATERS: u8 = 2;
+    /// North American goalies
+    pub const NORTH_AMERICAN_GOALIES: u8 = 3;
+    /// International goalies
+    pub const INTERNATIONAL_GOALIES: u8 = 4;
+}
+
+/// One of the prospect categories a ranking list can be requested for
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+#[serde(rename_all = "camelCase")]
+pub struct ProspectCategory {
+    pub id: i32,
+    pub name: String,
+    pub consumer_key: String,
+}
+
+/// Central Scouting's ranked prospects in one category for one draft
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+#[serde(rename_all = "camelCase")]
+pub struct DraftRankings {
+    pub draft_year: i32,
+    pub category_id: i32,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub category_key: Option<String>,
+    /// Draft years that have rankings
+    #[serde(default)]
+    pub draft_years: Vec<i32>,
+    #[serde(default)]
+    pub categories: Vec<ProspectCategory>,
+    #[serde(default)]
+    pub rankings: Vec<RankedProspect>,
+}
+
+/// A ranked draft prospect
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+#[serde(rename_all = "camelCase")]
+pub struct RankedProspect {
+    pub first_name: String,
+    pub last_name: String,
+    /// `None` when the API returns an empty position code.
+    #[serde(deserialize_with = "empty_string_as_none", default)]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub position_code: Option<Position>,
+    #[serde(deserialize_with = "empty_string_as_none", default)]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub shoots_catches: Option<Handedness>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub height_in_inches: Option<i32>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub weight_in_pounds: Option<i32>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub last_amateur_club: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub last_amateur_league: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub birth_date: Option<String>,
+    #[serde(flatten)]
+    pub birth: BirthInfo,
+    /// Rank in the midterm list; `None` for prospects added afterwards
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub midterm_rank: Option<i32>,
+    /// Rank in the final list; `None` until it is published
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub final_rank: Option<i32>,
+}
+
+/// Selections in one draft, for every round or just one
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+#[serde(rename_all = "camelCase")]
+pub struct DraftPicks {
+    pub draft_year: i32,
+    #[serde(default)]
+    pub draft_years: Vec<i32>,
+    #[serde(default)]
+    pub selectable_rounds: Vec<i32>,
+    /// Draft status, e.g. `"over"`
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub state: Option<String>,
+    #[serde(
+        rename = "broadcastStartTimeUTC",
+        skip_serializing_if = "Option::is_none"
+    )]
+    pub broadcast_start_time_utc: Option<String>,
+    #[serde(default)]
+    pub picks: Vec<DraftPick>,
+}
+
+/// One draft selection. Player details are often missing for late-round
+/// historical picks, and for picks not yet made.
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+#[serde(rename_all = "camelCase")]
+pub struct DraftPick {
+    pub round: i32,
+    pub pick_in_round: i32,
+    pub overall_pick: i32,
+    pub team_id: TeamId,
+    pub team_abbrev: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub team_name: Option<LocalizedString>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub team_common_name: Option<LocalizedString>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub team_place_name_with_preposition: Option<LocalizedString>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub display_abbrev: Option<LocalizedString>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub team_logo_light: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub team_logo_dark: Option<String>,
+    /// Teams that held the pick, oldest first, e.g. `"TOR-CHI"`
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub team_pick_history: Option<String>,
+    /// Only present once the drafted player has an NHL player record
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub player_id: Option<PlayerId>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub first_name: Option<LocalizedString>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub last_name: Option<LocalizedString>,
+    /// `None` when the API returns an empty position code.
+    #[serde(deserialize_with = "empty_string_as_none", default)]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub position_code: Option<Position>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub country_code: Option<String>,
+    /// Height in inches
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub height: Option<i32>,
+    /// Weight in pounds
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub weight: Option<i32>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub amateur_league: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub amateur_club_name: Option<String>,
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_util::assert_no_unmapped_fields;
+
+    #[test]
+    fn test_draft_rankings_deserialization() {
+        let json = r#"{
+            "draftYear": 2025,
+            "categoryId": 1,
+            "categoryKey": "north-american-skater",
+            "draftYears": [2025, 2024],
+            "categories": [{"id": 1, "name": "North American Skater",
+                            "consumerKey": "north-american-skater"}],
+            "rankings": [
+                {"lastName": "Schaefer", "firstName": "Matthew", "positionCode": "D",
+                 "shootsCatches": "L", "heightInInches": 74, "weightInPounds": 183,
+                 "lastAmateurClub": "Erie", "lastAmateurLeague": "OHL",
+                 "birthDate": "2007-09-05", "birthCity": "Hamilton",
+                 "birthStateProvince": "ON", "birthCountry": "CAN",
+                 "midtermRank": 1, "finalRank": 1},
+                {"lastName": "Late", "firstName": "Addition", "positionCode": "",
+                 "finalRank": 224}
+            ]
+        }"#;
+
+        let rankings: DraftRankings = serde_json::from_str(json).unwrap();
+        assert_no_unmapped_fields::<DraftRankings>(json);
+        assert_eq!(
+            rankings.category_id,
+            prospect_category::NORTH_AMERICAN_SKATERS as i32
+        );
+
+        let first = &rankings.rankings[0];
+        assert_eq!(first.position_code, Some(Position::Defense));
+        assert_eq!(first.shoots_catches, Some(Handedness::Left));
+        assert_eq!(first.last_amateur_league.as_deref(), Some("OHL"));
+        assert_eq!(first.birth.place(), "Hamilton, ON, CAN");
+
+        let late = &rankings.rankings[1];
+        assert_eq!(late.position_code, None);
+        assert_eq!(late.midterm_rank, None);
+        assert_eq!(late.final_rank, Some(224));
+        assert_eq!(late.birth, BirthInfo::default());
+    }
+
+    #[test]
+    fn test_draft_picks_deserialization() {
+        let json = r#"{
+            "broadcastStartTimeUTC": "2024-06-28T23:00:00Z",
+            "draftYear": 2024,
+            "draftYears": [2024, 2023],
+            "selectableRounds": [1, 2, 3, 4, 5, 6, 7],
+            "state": "over",
+            "picks": [
+                {"round": 1, "pickInRound": 1, "overallPick": 1, "teamId": 28,
+                 "teamAbbrev": "SJS", "teamName": {"default": "San Jose Sharks"},
+                 "teamCommonName": {"default": "Sharks"},
+                 "teamPlaceNameWithPreposition": {"default": "San Jose"},
+                 "displayAbbrev": {"default": "SJS"},
+                 "teamLogoLight": "https://assets.nhle.com/logos/nhl/svg/SJS_light.svg",
+                 "teamLogoDark": "https://assets.nhle.com/logos/nhl/svg/SJS_dark.svg",
+                 "teamPickHistory": "SJS", "playerId": 8484801,
+                 "firstName": {"default": "Macklin"}, "lastName": {"default": "Celebrini"},
+                 "positionCode": "C", "countryCode": "CAN", "height": 72, "weight": 190,
+                 "amateurLeague": "H-East", "amateurClubName": "Boston University"},
+                {"round": 7, "pickInRound": 32, "overallPick": 224, "teamId": 13,
+                 "teamAbbrev": "FLA", "teamPickHistory": "CHI-FLA"}
+            ]
+        }"#;
+
+        let picks: DraftPicks = serde_json::from_str(json).unwrap();
+        assert_no_unmapped_fields::<DraftPicks>(json);
+        assert_eq!(picks.selectable_rounds.len(), 7);
+
+        let first = &picks.picks[0];
+        assert_eq!(first.player_id, Some(PlayerId::new(8484801)));
+        assert_eq!(first.position_code, Some(Position::Center));
+        assert_eq!(first.team_id, TeamId::new(28));
+
+        let last = &picks.picks[1];
+        assert_eq!(last.overall_pick, 224);
+        assert_eq!(last.player_id, None);
+        assert_eq!(last.last_name, None);
+    }
+}
@@ -364,12 +364,8 @@ mod tests {
     fn sample_overlay() -> EdgeOverlay {
         EdgeOverlay {
             player: EdgeOverlayPlayer {
-                first_name: LocalizedString {
-                    default: "Connor".to_string(),
-                },
-                last_name: LocalizedString {
-                    default: "McDavid".to_string(),
-                },
+                first_name: LocalizedString::new("Connor"),
+                last_name: LocalizedString::new("McDavid"),
             },
             game_date: "2024-04-15".to_string(),
             away_team: EdgeOverlayTeam {
@@ -390,12 +386,8 @@ mod tests {
     fn sample_team_info() -> EdgeTeamInfo {
         EdgeTeamInfo {
             id: 22,
-            common_name: LocalizedString {
-                default: "Oilers".to_string(),
-            },
-            place_name_with_preposition: LocalizedString {
-                default: "Edmonton".to_string(),
-            },
+            common_name: LocalizedString::new("Oilers"),
+            place_name_with_preposition: LocalizedString::new("Edmonton"),
             abbrev: "EDM".to_string(),
             team_logo: EdgeTeamLogo {
                 light: "l".to_string(),
@@ -630,12 +622,8 @@ mod tests {
     #[test]
     fn test_edge_overlay_player_round_trip() {
         assert_round_trip(&EdgeOverlayPlayer {
-            first_name: LocalizedString {
-                default: "Connor".to_string(),
-            },
-            last_name: LocalizedString {
-                default: "McDavid".to_string(),
-            },
+            first_name: LocalizedString::new("Connor"),
+            last_name: LocalizedString::new("McDavid"),
         });
     }
 
@@ -672,12 +660,8 @@ mod tests {
     fn test_edge_skater_player_round_trip() {
         assert_round_trip(&EdgeSkaterPlayer {
             id: 8478402,
-            first_name: LocalizedString {
-                default: "Connor".to_string(),
-            },
-            last_name: LocalizedString {
-                default: "McDavid".to_string(),
-            },
+            first_name: LocalizedString::new("Connor"),
+            last_name: LocalizedString::new("McDavid"),
             birth_date: "1997-01-13".to_string(),
             shoots_catches: "L".to_string(),
             sweater_number: 97,
@@ -696,12 +680,8 @@ mod tests {
     fn test_edge_goalie_player_round_trip() {
         assert_round_trip(&EdgeGoaliePlayer {
             id: 8479979,
-            first_name: LocalizedString {
-                default: "Stuart".to_string(),
-            },
-            last_name: LocalizedString {
-                default: "Skinner".to_string(),
-            },
+            first_name: LocalizedString::new("Stuart"),
+            last_name: LocalizedString::new("Skinner"),
             birth_date: "1998-11-01".to_string(),
             shoots_catches: "L".to_string(),
             sweater_number: 74,
@@ -271,6 +271,7 @@ pub struct EdgeGoalieLanding {
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::test_util::assert_no_unmapped_fields;
 
     /// Asserts a type deserializes from an empty JSON object into its
     /// `Default` value (mirrors the guard in `edge::common`'s tests).
@@ -450,6 +451,7 @@ mod tests {
         }"#;
 
         let detail: EdgeGoalieDetail = serde_json::from_str(json).expect("must deserialize");
+        assert_no_unmapped_fields::<EdgeGoalieDetail>(json);
 
         assert_eq!(detail.player.id, 8479318);
         assert_eq!(detail.player.save_pctg, 0.928);
@@ -490,6 +492,7 @@ mod tests {
         }"#;
 
         let detail: EdgeGoalie5v5Detail = serde_json::from_str(json).expect("must deserialize");
+        assert_no_unmapped_fields::<EdgeGoalie5v5Detail>(json);
 
         assert_eq!(detail.save_pctg_5v5_last10.len(), 2);
         assert_eq!(detail.save_pctg_5v5_last10[0].save_pctg, 0.950);
@@ -512,6 +515,7 @@ mod tests {
 
         let detail: EdgeGoalieShotLocationDetail =
             serde_json::from_str(json).expect("must deserialize");
+        assert_no_unmapped_fields::<EdgeGoalieShotLocationDetail>(json);
 
         assert_eq!(detail.shot_location_details.len(), 1);
         assert_eq!(detail.shot_location_details[0].area, "Crease");
@@ -570,6 +574,7 @@ mod tests {
 
         let detail: EdgeGoalieSavePctgDetail =
             serde_json::from_str(json).expect("must deserialize");
+        assert_no_unmapped_fields::<EdgeGoalieSavePctgDetail>(json);
 
         assert_eq!(detail.player.id, 8480382);
         assert_eq!(detail.save_pctg_last10.len(), 1);
@@ -661,6 +666,7 @@ mod tests {
 
         let comparison: EdgeGoalieComparison =
             serde_json::from_str(json).expect("must deserialize");
+        assert_no_unmapped_fields::<EdgeGoalieComparison>(json);
 
         assert_eq!(comparison.player.id, 8480382);
         assert_eq!(comparison.shot_location_summary.len(), 1);
@@ -724,6 +730,7 @@ mod tests {
         }"#;
 
         let landing: EdgeGoalieLanding = serde_json::from_str(json).expect("must deserialize");
+        assert_no_unmapped_fields::<EdgeGoalieLanding>(json);
 
         assert_eq!(landing.seasons_with_edge_stats.len(), 1);
         assert_eq!(landing.leaders.len(), 5);
@@ -258,6 +258,7 @@ pub struct EdgeSkaterLanding {
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::test_util::assert_no_unmapped_fields;
     use crate::types::LocalizedString;
 
     /// Asserts a type deserializes from an empty JSON object into its
@@ -474,6 +475,7 @@ mod tests {
         }"#;
 
         let detail: EdgeSkaterDetail = serde_json::from_str(json).expect("must deserialize");
+        assert_no_unmapped_fields::<EdgeSkaterDetail>(json);
 
         assert_eq!(detail.player.id, 8478402);
         assert_eq!(detail.player.first_name.default, "Connor");
@@ -512,6 +514,7 @@ mod tests {
         }"#;
 
         let detail: EdgeSkaterSpeedDetail = serde_json::from_str(json).expect("must deserialize");
+        assert_no_unmapped_fields::<EdgeSkaterSpeedDetail>(json);
 
         assert_eq!(detail.top_skating_speeds.len(), 2);
         assert_eq!(detail.top_skating_speeds[0].speed.imperial, 23.1);
@@ -532,6 +535,7 @@ mod tests {
 
         let detail: EdgeSkaterDistanceDetail =
             serde_json::from_str(json).expect("must deserialize");
+        assert_no_unmapped_fields::<EdgeSkaterDistanceDetail>(json);
 
         assert_eq!(detail.skating_distance_last10.len(), 1);
         assert_eq!(detail.skating_distance_last10[0].distance.imperial, 5.2);
@@ -552,6 +556,7 @@ mod tests {
 
         let detail: EdgeSkaterShotSpeedDetail =
             serde_json::from_str(json).expect("must deserialize");
+        assert_no_unmapped_fields::<EdgeSkaterShotSpeedDetail>(json);
 
         assert_eq!(detail.hardest_shots.len(), 1);
         assert_eq!(detail.hardest_shots[0].speed.imperial, 100.2);
@@ -572,6 +577,7 @@ mod tests {
 
         let detail: EdgeSkaterShotLocationDetail =
             serde_json::from_str(json).expect("must deserialize");
+        assert_no_unmapped_fields::<EdgeSkaterShotLocationDetail>(json);
 
         assert_eq!(detail.shot_location_details.len(), 1);
         assert_eq!(detail.shot_location_details[0].shots, 40);
@@ -592,6 +598,7 @@ mod tests {
 
         let detail: EdgeSkaterZoneTimeDetail =
             serde_json::from_str(json).expect("must deserialize");
+        assert_no_unmapped_fields::<EdgeSkaterZoneTimeDetail>(json);
 
         assert_eq!(detail.zone_time_details.len(), 1);
         assert_eq!(detail.zone_time_details[0].strength_code, "5v5");
@@ -667,6 +674,7 @@ mod tests {
 
         let comparison: EdgeSkaterComparison =
             serde_json::from_str(json).expect("must deserialize");
+        assert_no_unmapped_fields::<EdgeSkaterComparison>(json);
 
         assert_eq!(comparison.player.id, 8478402);
 
@@ -752,6 +760,7 @@ mod tests {
         }"#;
 
         let landing: EdgeSkaterLanding = serde_json::from_str(json).expect("must deserialize");
+        assert_no_unmapped_fields::<EdgeSkaterLanding>(json);
 
         assert_eq!(landing.seasons_with_edge_stats.len(), 1);
         assert_eq!(landing.leaders.len(), 2);
@@ -790,9 +799,7 @@ mod tests {
     fn test_edge_skater_player_localized_name_round_trip() {
         let player = EdgeSkaterPlayer {
             id: 8478402,
-            first_name: LocalizedString {
-                default: "Connor".to_string(),
-            },
+            first_name: LocalizedString::new("Connor"),
             ..Default::default()
         };
         let json = serde_json::to_string(&player).unwrap();
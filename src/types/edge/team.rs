@@ -320,6 +320,7 @@ pub struct EdgeTeamLanding {
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::test_util::assert_no_unmapped_fields;
 
     /// Asserts a type deserializes from an empty JSON object into its
     /// `Default` value (mirrors the guard in `edge::common`'s tests).
@@ -516,6 +517,7 @@ mod tests {
         }"#;
 
         let detail: EdgeTeamDetail = serde_json::from_str(json).expect("must deserialize");
+        assert_no_unmapped_fields::<EdgeTeamDetail>(json);
 
         assert_eq!(detail.team.id, 22);
         assert_eq!(detail.team.abbrev, "EDM");
@@ -549,6 +551,7 @@ mod tests {
         }"#;
 
         let detail: EdgeTeamSpeedDetail = serde_json::from_str(json).expect("must deserialize");
+        assert_no_unmapped_fields::<EdgeTeamSpeedDetail>(json);
 
         assert_eq!(detail.top_skating_speeds.len(), 1);
         assert_eq!(
@@ -571,6 +574,7 @@ mod tests {
         }"#;
 
         let detail: EdgeTeamDistanceDetail = serde_json::from_str(json).expect("must deserialize");
+        assert_no_unmapped_fields::<EdgeTeamDistanceDetail>(json);
 
         assert_eq!(detail.skating_distance_last10.len(), 1);
         assert_eq!(detail.skating_distance_last10[0].distance.imperial, 350.0);
@@ -590,6 +594,7 @@ mod tests {
         }"#;
 
         let detail: EdgeTeamShotSpeedDetail = serde_json::from_str(json).expect("must deserialize");
+        assert_no_unmapped_fields::<EdgeTeamShotSpeedDetail>(json);
 
         assert_eq!(detail.hardest_shots.len(), 1);
         assert_eq!(
@@ -615,6 +620,7 @@ mod tests {
 
         let detail: EdgeTeamShotLocationDetail =
             serde_json::from_str(json).expect("must deserialize");
+        assert_no_unmapped_fields::<EdgeTeamShotLocationDetail>(json);
 
         assert_eq!(detail.shot_location_details.len(), 1);
         assert_eq!(detail.shot_location_details[0].shots, 400);
@@ -646,6 +652,7 @@ mod tests {
         }"#;
 
         let detail: EdgeTeamZoneTimeDetails = serde_json::from_str(json).expect("must deserialize");
+        assert_no_unmapped_fields::<EdgeTeamZoneTimeDetails>(json);
 
         // Genuinely nullable and absent from this real fixture.
         assert_eq!(detail.team, None);
@@ -776,6 +783,7 @@ mod tests {
         }"#;
 
         let comparison: EdgeTeamComparison = serde_json::from_str(json).expect("must deserialize");
+        assert_no_unmapped_fields::<EdgeTeamComparison>(json);
 
         assert_eq!(comparison.team.abbrev, "EDM");
 
@@ -888,6 +896,7 @@ mod tests {
         }"#;
 
         let landing: EdgeTeamLanding = serde_json::from_str(json).expect("must deserialize");
+        assert_no_unmapped_fields::<EdgeTeamLanding>(json);
 
         assert_eq!(landing.seasons_with_edge_stats.len(), 1);
         assert_eq!(landing.leaders.len(), 5);
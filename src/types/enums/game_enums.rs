@@ -10,8 +10,10 @@ use super::macros::nhl_string_enum;
 
 nhl_string_enum! {
     error_name = "period type",
+    unknown = Unknown,
     display = code,
     /// NHL period type
+    #[non_exhaustive]
     pub enum PeriodType {
         /// Regulation period
         Regulation = "REG", name = "Regulation";
@@ -51,8 +53,10 @@ nhl_string_enum! {
 
 nhl_string_enum! {
     error_name = "zone code",
+    unknown = Unknown,
     display = code,
     /// Ice zone where play event occurred
+    #[non_exhaustive]
     pub enum ZoneCode {
         /// Offensive zone
         Offensive = "O", name = "Offensive";
@@ -69,8 +73,10 @@ nhl_string_enum! {
 
 nhl_string_enum! {
     error_name = "defending side",
+    unknown = Unknown,
     display = code,
     /// Which side of the ice the home team is defending
+    #[non_exhaustive]
     pub enum DefendingSide {
         /// Defending left side
         Left = "left", name = "left", aliases = ["LEFT", "Left"];
@@ -85,8 +91,10 @@ nhl_string_enum! {
 
 nhl_string_enum! {
     error_name = "game schedule state",
+    unknown = Unknown,
     display = code,
     /// Game schedule state (OK, postponed, etc.)
+    #[non_exhaustive]
     pub enum GameScheduleState {
         /// Game is scheduled as planned
         Ok = "OK", name = "OK";
@@ -110,6 +118,83 @@ impl GameScheduleState {
     pub const fn is_playable(&self) -> bool {
         matches!(self, GameScheduleState::Ok)
     }
+
+    /// Returns true if the game is postponed to a later date
+    pub const fn is_postponed(&self) -> bool {
+        matches!(self, GameScheduleState::Postponed)
+    }
+
+    /// Returns true if the game was stopped partway and will be resumed
+    pub const fn is_suspended(&self) -> bool {
+        matches!(self, GameScheduleState::Suspended)
+    }
+
+    /// Returns true if the game is cancelled and won't be played
+    pub const fn is_cancelled(&self) -> bool {
+        matches!(self, GameScheduleState::Cancelled)
+    }
+}
+
+// =============================================================================
+// BroadcastMarket
+// =============================================================================
+
+nhl_string_enum! {
+    error_name = "broadcast market",
+    display = code,
+    /// Market a TV broadcast is carried in. The schedule endpoints send the
+    /// one-letter codes, boxscores sometimes spell them out.
+    pub enum BroadcastMarket {
+        /// Carried nationally
+        National = "N", name = "National", aliases = ["NATIONAL"];
+        /// Home team's regional broadcast
+        Home = "H", name = "Home", aliases = ["HOME"];
+        /// Away team's regional broadcast
+        Away = "A", name = "Away", aliases = ["AWAY"];
+    }
+}
+
+// =============================================================================
+// PenaltySeverity
+// =============================================================================
+
+nhl_string_enum! {
+    error_name = "penalty severity",
+    unknown = Unknown,
+    display = code,
+    /// Penalty type code on landing summaries (`type`) and play-by-play
+    /// penalty events (`typeCode`)
+    #[non_exhaustive]
+    pub enum PenaltySeverity {
+        /// Two-minute minor, or a four-minute double minor
+        Minor = "MIN", name = "Minor";
+        /// Minor assessed to the bench, served by a player of the coach's choosing
+        BenchMinor = "BEN", name = "Bench Minor";
+        /// Five-minute major
+        Major = "MAJ", name = "Major";
+        /// Ten-minute misconduct
+        Misconduct = "MIS", name = "Misconduct";
+        /// Game misconduct
+        GameMisconduct = "GMIS", name = "Game Misconduct", aliases = ["GAM"];
+        /// Match penalty
+        Match = "MAT", name = "Match";
+        /// Infraction that awarded a penalty shot instead of time in the box
+        PenaltyShot = "PS", name = "Penalty Shot";
+    }
+}
+
+impl PenaltySeverity {
+    /// Returns true if the penalty puts the other team on a power play.
+    /// Misconducts and penalty shots don't.
+    pub const fn creates_power_play(&self) -> bool {
+        matches!(
+            self,
+            PenaltySeverity::Minor
+                | PenaltySeverity::BenchMinor
+                | PenaltySeverity::Major
+                | PenaltySeverity::Match
+        )
+    }
 }
 
 #[cfg(test)]
@@ -155,12 +240,14 @@ mod tests {
             assert_eq!("SO".parse::<PeriodType>().unwrap(), PeriodType::Shootout);
         }
 
+        #[cfg(not(feature = "lenient-enums"))]
         #[test]
         fn test_period_type_from_str_invalid() {
             let result = "INVALID".parse::<PeriodType>();
             assert!(result.is_err());
         }
 
+        #[cfg(not(feature = "lenient-enums"))]
         #[test]
         fn test_period_type_from_str_unknown_enum_value() {
             let err = "INVALID".parse::<PeriodType>().unwrap_err();
@@ -175,6 +262,7 @@ mod tests {
             assert_eq!(err.value, "INVALID");
         }
 
+        #[cfg(not(feature = "lenient-enums"))]
         #[test]
         fn test_period_type_deserialize_unknown_error_message() {
             let err = serde_json::from_str::<PeriodType>(r#""INVALID""#).unwrap_err();
@@ -376,6 +464,7 @@ mod tests {
             assert_eq!("N".parse::<ZoneCode>().unwrap(), ZoneCode::Neutral);
         }
 
+        #[cfg(not(feature = "lenient-enums"))]
         #[test]
         fn test_zone_code_from_str_invalid() {
             let result = "X".parse::<ZoneCode>();
@@ -469,6 +558,7 @@ mod tests {
             );
         }
 
+        #[cfg(not(feature = "lenient-enums"))]
         #[test]
         fn test_defending_side_from_str_invalid() {
             let result = "center".parse::<DefendingSide>();
@@ -628,6 +718,7 @@ mod tests {
             );
         }
 
+        #[cfg(not(feature = "lenient-enums"))]
         #[test]
         fn test_game_schedule_state_from_str_invalid() {
             let result = "UNKNOWN".parse::<GameScheduleState>();
@@ -663,6 +754,16 @@ mod tests {
             assert!(!GameScheduleState::Cancelled.is_playable());
         }
 
+        #[test]
+        fn test_game_schedule_state_predicates() {
+            assert!(GameScheduleState::Postponed.is_postponed());
+            assert!(GameScheduleState::Suspended.is_suspended());
+            assert!(GameScheduleState::Cancelled.is_cancelled());
+            assert!(!GameScheduleState::Ok.is_postponed());
+            assert!(!GameScheduleState::Postponed.is_suspended());
+            assert!(!GameScheduleState::Postponed.is_cancelled());
+        }
+
         /// Historical/administrative schedule entries use these three states;
         /// exercise them via a boxscore-shaped fixture rather than bare strings.
         #[test]
@@ -716,4 +817,80 @@ mod tests {
             assert_eq!(set.len(), 7);
         }
     }
+
+    mod broadcast_market_tests {
+        use super::*;
+
+        #[test]
+        fn test_broadcast_market_from_short_and_long_forms() {
+            for (raw, market) in [
+                ("N", BroadcastMarket::National),
+                ("NATIONAL", BroadcastMarket::National),
+                ("H", BroadcastMarket::Home),
+                ("HOME", BroadcastMarket::Home),
+                ("A", BroadcastMarket::Away),
+                ("AWAY", BroadcastMarket::Away),
+            ] {
+                assert_eq!(raw.parse::<BroadcastMarket>().unwrap(), market);
+            }
+        }
+
+        #[test]
+        fn test_broadcast_market_serializes_short_code() {
+            let market: BroadcastMarket = serde_json::from_str(r#""AWAY""#).unwrap();
+            assert_eq!(market, BroadcastMarket::Away);
+            assert_eq!(serde_json::to_string(&market).unwrap(), r#""A""#);
+            assert_eq!(market.to_string(), "A");
+            assert_eq!(market.name(), "Away");
+        }
+
+        #[test]
+        fn test_broadcast_market_from_str_invalid() {
+            assert_eq!(
+                "R".parse::<BroadcastMarket>().unwrap_err(),
+                UnknownEnumValue {
+                    enum_name: "broadcast market",
+                    value: "R".to_string(),
+                }
+            );
+        }
+    }
+
+    mod penalty_severity_tests {
+        use super::*;
+
+        #[test]
+        fn test_penalty_severity_round_trips_api_codes() {
+            for (raw, severity) in [
+                ("MIN", PenaltySeverity::Minor),
+                ("BEN", PenaltySeverity::BenchMinor),
+                ("MAJ", PenaltySeverity::Major),
+                ("MIS", PenaltySeverity::Misconduct),
+                ("GMIS", PenaltySeverity::GameMisconduct),
+                ("MAT", PenaltySeverity::Match),
+                ("PS", PenaltySeverity::PenaltyShot),
+            ] {
+                let json = format!("\"{}\"", raw);
+                let parsed: PenaltySeverity = serde_json::from_str(&json).unwrap();
+                assert_eq!(parsed, severity);
+                assert_eq!(serde_json::to_string(&parsed).unwrap(), json);
+            }
+            assert_eq!(
+                "GAM".parse::<PenaltySeverity>().unwrap(),
+                PenaltySeverity::GameMisconduct
+            );
+        }
+
+        #[test]
+        fn test_penalty_severity_creates_power_play() {
+            assert!(PenaltySeverity::Minor.creates_power_play());
+            assert!(PenaltySeverity::BenchMinor.creates_power_play());
+            assert!(PenaltySeverity::Major.creates_power_play());
+            assert!(PenaltySeverity::Match.creates_power_play());
+            assert!(!PenaltySeverity::Misconduct.creates_power_play());
+            assert!(!PenaltySeverity::GameMisconduct.creates_power_play());
+            assert!(!PenaltySeverity::PenaltyShot.creates_power_play());
+            assert_eq!(PenaltySeverity::BenchMinor.name(), "Bench Minor");
+        }
+    }
 }
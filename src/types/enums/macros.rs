@@ -31,6 +31,14 @@
 //!   [`serde::de::Error::custom`]; the type name and offending value are preserved
 //!   in the message (`invalid <enum_name>: "<value>"`) but not as a recoverable
 //!   typed error at the serde boundary.
+//! - **`unknown = Variant` opts an enum into the `lenient-enums` feature.**
+//!   With the feature on, the enum gains `Variant(Box<str>)` and `FromStr`
+//!   (so `Deserialize` too) returns it for unrecognized strings instead of an
+//!   error; `code()` and `name()` return the raw string, so it round-trips.
+//!   Without the feature the variant doesn't exist and parsing stays strict.
+//!   Such enums must be `#[non_exhaustive]`, and are `Clone` but not `Copy`
+//!   (their `code()`/`name()` borrow from `self`) with or without the
+//!   feature, which keeps it additive.
 //!
 //! [`UnknownEnumValue`]: crate::types::enums::UnknownEnumValue
 
@@ -60,6 +68,20 @@
 /// ```ignore
 /// OvertimeLoss = "O", name = "Overtime Loss", aliases = ["OTL"];
 /// ```
+///
+/// An enum that should accept unknown values under `lenient-enums` names the
+/// variant before `display`, and is marked `#[non_exhaustive]`:
+///
+/// ```ignore
+/// nhl_string_enum! {
+///     error_name = "zone code",
+///     unknown = Unknown,
+///     display = code,
+///     /// Ice zone
+///     #[non_exhaustive]
+///     pub enum ZoneCode { ... }
+/// }
+/// ```
 macro_rules! nhl_string_enum {
     // Internal: resolve the Display source expression for a given mode keyword.
     (@display_value $value:ident, code) => {
@@ -72,17 +94,31 @@ macro_rules! nhl_string_enum {
         $value.name()
     };
 
+    // Internal: the enum itself. Enums with an unknown variant own its
+    // string, so they can't be `Copy`; they drop it with or without the
+    // feature so enabling it never breaks a dependent crate.
     (
-        error_name = $error_name:literal,
-        display = $display_mode:ident,
+        @enum [$unknown:ident]
         $(#[$enum_meta:meta])*
-        $vis:vis enum $name:ident {
+        $vis:vis enum $name:ident { $( $(#[$var_meta:meta])* $variant:ident ),* }
+    ) => {
+        $(#[$enum_meta])*
+        #[derive(Debug, Clone, PartialEq, Eq, Hash)]
+        $vis enum $name {
             $(
-                $(#[$var_meta:meta])*
-                $variant:ident = $canonical:literal, name = $display_name:literal
-                $(, aliases = [$($alias:literal),* $(,)?])?
-            );* $(;)?
+                $(#[$var_meta])*
+                $variant,
+            )*
+            /// A value with no named variant, kept as sent (`lenient-enums`
+            /// feature only)
+            #[cfg(feature = "lenient-enums")]
+            $unknown(::std::boxed::Box<str>),
         }
+    };
+    (
+        @enum []
+        $(#[$enum_meta:meta])*
+        $vis:vis enum $name:ident { $( $(#[$var_meta:meta])* $variant:ident ),* }
     ) => {
         $(#[$enum_meta])*
         #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
@@ -92,20 +128,87 @@ macro_rules! nhl_string_enum {
                 $variant,
             )*
         }
+    };
 
-        impl $name {
-            /// Returns the canonical API code for this value (the string used on the wire).
-            pub const fn code(&self) -> &'static str {
-                match self {
-                    $( Self::$variant => $canonical, )*
-                }
+    // Internal: `code()` and `name()`. An unknown value's string lives in
+    // the enum, so those enums return it borrowed from `self`.
+    (
+        @accessors [$unknown:ident]
+        $( $variant:ident => $canonical:literal, $display_name:literal ),*
+    ) => {
+        /// Returns the canonical API code for this value (the string used on
+        /// the wire); the raw code for an unknown one.
+        pub fn code(&self) -> &str {
+            match self {
+                $( Self::$variant => $canonical, )*
+                #[cfg(feature = "lenient-enums")]
+                Self::$unknown(code) => code,
             }
+        }
 
-            /// Returns the human-readable name for this value.
-            pub const fn name(&self) -> &'static str {
-                match self {
-                    $( Self::$variant => $display_name, )*
-                }
+        /// Returns the human-readable name for this value; the raw code for
+        /// an unknown one.
+        pub fn name(&self) -> &str {
+            match self {
+                $( Self::$variant => $display_name, )*
+                #[cfg(feature = "lenient-enums")]
+                Self::$unknown(code) => code,
+            }
+        }
+
+        /// Whether this is a value with no named variant; always `false`
+        /// without the `lenient-enums` feature.
+        pub const fn is_unknown(&self) -> bool {
+            match self {
+                #[cfg(feature = "lenient-enums")]
+                Self::$unknown(_) => true,
+                #[allow(unreachable_patterns)]
+                _ => false,
+            }
+        }
+    };
+    (
+        @accessors []
+        $( $variant:ident => $canonical:literal, $display_name:literal ),*
+    ) => {
+        /// Returns the canonical API code for this value (the string used on the wire).
+        pub const fn code(&self) -> &'static str {
+            match self {
+                $( Self::$variant => $canonical, )*
+            }
+        }
+
+        /// Returns the human-readable name for this value.
+        pub const fn name(&self) -> &'static str {
+            match self {
+                $( Self::$variant => $display_name, )*
+            }
+        }
+    };
+
+    (
+        error_name = $error_name:literal,
+        $(unknown = $unknown:ident,)?
+        display = $display_mode:ident,
+        $(#[$enum_meta:meta])*
+        $vis:vis enum $name:ident {
+            $(
+                $(#[$var_meta:meta])*
+                $variant:ident = $canonical:literal, name = $display_name:literal
+                $(, aliases = [$($alias:literal),* $(,)?])?
+            );* $(;)?
+        }
+    ) => {
+        nhl_string_enum! {
+            @enum [$($unknown)?]
+            $(#[$enum_meta])*
+            $vis enum $name { $( $(#[$var_meta])* $variant ),* }
+        }
+
+        impl $name {
+            nhl_string_enum! {
+                @accessors [$($unknown)?]
+                $( $variant => $canonical, $display_name ),*
             }
         }
 
@@ -124,6 +227,11 @@ macro_rules! nhl_string_enum {
                     $(
                         $canonical $( $(| $alias)* )? => ::core::result::Result::Ok(Self::$variant),
                     )*
+                    $(
+                        #[cfg(feature = "lenient-enums")]
+                        _ => ::core::result::Result::Ok(Self::$unknown(s.into())),
+                    )?
+                    #[allow(unreachable_patterns)]
                     _ => ::core::result::Result::Err($crate::types::enums::UnknownEnumValue {
                         enum_name: $error_name,
                         value: s.to_string(),
@@ -157,7 +265,6 @@ pub(crate) use nhl_string_enum;
 
 #[cfg(test)]
 mod tests {
-    use super::nhl_string_enum;
     use crate::types::enums::UnknownEnumValue;
     use std::str::FromStr;
 
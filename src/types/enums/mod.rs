@@ -2,11 +2,15 @@
 //!
 //! This module re-exports all enum types from their logical groupings:
 //! - `player_enums`: Player-related enums (Position, Handedness, GoalieDecision)
-//! - `game_enums`: Game/play-related enums (PeriodType, HomeRoad, ZoneCode, DefendingSide, GameScheduleState)
+//! - `game_enums`: Game/play-related enums (PeriodType, HomeRoad, ZoneCode, DefendingSide, GameScheduleState, BroadcastMarket, PenaltySeverity)
 //!
 //! String-backed enums are generated by the [`nhl_string_enum!`] macro (see
 //! `macros.rs`), which gives every enum a uniform code/name/Display/FromStr/serde
 //! surface and routes unknown values through the shared [`UnknownEnumValue`] error.
+//! With the `lenient-enums` feature, `GameScheduleState`, `Position`,
+//! `PeriodType`, `GoalieDecision`, `ZoneCode`, `DefendingSide` and
+//! `PenaltySeverity` instead keep unknown values in an `Unknown(Box<str>)`
+//! variant.
 
 use std::fmt;
 use std::str::FromStr;
@@ -113,6 +117,7 @@ mod empty_string_as_none_tests {
         assert_eq!(fixture.period_type, Some(PeriodType::Regulation));
     }
 
+    #[cfg(not(feature = "lenient-enums"))]
     #[test]
     fn test_empty_string_as_none_unknown_value_still_errors() {
         let err = serde_json::from_str::<Fixture>(r#"{"period_type": "BOGUS"}"#).unwrap_err();
@@ -140,3 +145,103 @@ mod empty_string_as_none_tests {
         );
     }
 }
+
+/// Without `lenient-enums`, the enums that opt into it still reject values
+/// they don't know.
+#[cfg(all(test, not(feature = "lenient-enums")))]
+mod strict_enum_tests {
+    use super::*;
+
+    fn rejects<T>(enum_name: &str)
+    where
+        T: FromStr<Err = UnknownEnumValue> + for<'de> Deserialize<'de> + fmt::Debug,
+    {
+        let err = "BOGUS".parse::<T>().unwrap_err();
+        assert_eq!(err.enum_name, enum_name);
+        assert_eq!(err.value, "BOGUS");
+        let message = serde_json::from_str::<T>(r#""BOGUS""#)
+            .unwrap_err()
+            .to_string();
+        assert!(message.contains(enum_name), "{}", message);
+    }
+
+    #[test]
+    fn test_unknown_values_are_errors() {
+        rejects::<GameScheduleState>("game schedule state");
+        rejects::<Position>("position");
+        rejects::<PeriodType>("period type");
+        rejects::<GoalieDecision>("goalie decision");
+        rejects::<ZoneCode>("zone code");
+        rejects::<DefendingSide>("defending side");
+        rejects::<PenaltySeverity>("penalty severity");
+        assert!(!Position::Center.is_unknown());
+    }
+}
+
+/// With `lenient-enums`, unknown values parse to `Unknown` and round-trip.
+#[cfg(all(test, feature = "lenient-enums"))]
+mod lenient_enum_tests {
+    use super::*;
+
+    fn round_trips<T>(known: &str, unknown: fn(Box<str>) -> T)
+    where
+        T: FromStr<Err = UnknownEnumValue>
+            + for<'de> Deserialize<'de>
+            + serde::Serialize
+            + fmt::Display
+            + fmt::Debug
+            + PartialEq,
+    {
+        let parsed: T = "BOGUS".parse().unwrap();
+        assert_eq!(parsed, unknown("BOGUS".into()));
+        assert_eq!(parsed.to_string(), "BOGUS");
+
+        let decoded: T = serde_json::from_str(r#""BOGUS""#).unwrap();
+        assert_eq!(decoded, parsed);
+        assert_eq!(serde_json::to_string(&decoded).unwrap(), r#""BOGUS""#);
+
+        let named: T = known.parse().unwrap();
+        assert_ne!(named, parsed);
+        assert_eq!(named.to_string(), known);
+    }
+
+    #[test]
+    fn test_unknown_values_round_trip() {
+        round_trips("OK", GameScheduleState::Unknown);
+        round_trips("C", Position::Unknown);
+        round_trips("REG", PeriodType::Unknown);
+        round_trips("W", GoalieDecision::Unknown);
+        round_trips("O", ZoneCode::Unknown);
+        round_trips("left", DefendingSide::Unknown);
+        round_trips("MIN", PenaltySeverity::Unknown);
+    }
+
+    #[test]
+    fn test_unknown_value_accessors() {
+        let position: Position = "LD".parse().unwrap();
+        assert!(position.is_unknown());
+        assert_eq!(position.code(), "LD");
+        assert_eq!(position.name(), "LD");
+        assert!(!position.is_forward());
+        assert!(!Position::Center.is_unknown());
+        // Aliases still map to named variants.
+        assert_eq!("L".parse::<Position>().unwrap(), Position::LeftWing);
+    }
+
+    #[test]
+    fn test_empty_string_is_still_none() {
+        #[derive(Debug, serde::Deserialize)]
+        struct Fixture {
+            #[serde(deserialize_with = "empty_string_as_none", default)]
+            period_type: Option<PeriodType>,
+        }
+
+        let empty: Fixture = serde_json::from_str(r#"{"period_type": ""}"#).unwrap();
+        assert_eq!(empty.period_type, None);
+        let unknown: Fixture = serde_json::from_str(r#"{"period_type": "SD"}"#).unwrap();
+        assert_eq!(
+            unknown.period_type.as_ref().map(|period| period.code()),
+            Some("SD")
+        );
+    }
+}
@@ -10,8 +10,10 @@ use super::macros::nhl_string_enum;
 
 nhl_string_enum! {
     error_name = "position",
+    unknown = Unknown,
     display = code,
     /// NHL player position
+    #[non_exhaustive]
     pub enum Position {
         /// Center
         Center = "C", name = "Center";
@@ -66,8 +68,10 @@ nhl_string_enum! {
 
 nhl_string_enum! {
     error_name = "goalie decision",
+    unknown = Unknown,
     display = code,
     /// Goalie game decision (win/loss/tie/OT loss)
+    #[non_exhaustive]
     pub enum GoalieDecision {
         /// Win
         Win = "W", name = "Win";
@@ -150,6 +154,7 @@ mod tests {
             assert_eq!("G".parse::<Position>().unwrap(), Position::Goalie);
         }
 
+        #[cfg(not(feature = "lenient-enums"))]
         #[test]
         fn test_position_from_str_invalid() {
             let result = "X".parse::<Position>();
@@ -418,6 +423,7 @@ mod tests {
             );
         }
 
+        #[cfg(not(feature = "lenient-enums"))]
         #[test]
         fn test_goalie_decision_from_str_invalid() {
             let result = "X".parse::<GoalieDecision>();
@@ -1,16 +1,30 @@
 use serde::{Deserialize, Serialize};
+use std::collections::HashMap;
 use std::fmt;
+use tracing::warn;
 
+use crate::analytics::{
+    derive_three_stars, enrich_shot_attempts, DerivedStar, ShotAttempt, ShotContextConfig,
+};
 use crate::date::Season;
+use crate::ice_time::IceTime;
 use crate::ids::{GameId, PlayerId, TeamId};
 
-use super::boxscore::{BoxscoreTeam, GameClock, PeriodDescriptor, SpecialEvent, TvBroadcast};
+use super::boxscore::{
+    game_elapsed_seconds, period_length_seconds, Boxscore, BoxscorePlayer, BoxscoreTeam, GameClock,
+    PeriodDescriptor, SpecialEvent, TeamGameStats, TvBroadcast, PERIOD_SECONDS,
+    REGULAR_SEASON_OT_SECONDS,
+};
 use super::common::LocalizedString;
 use super::enums::{
-    empty_string_as_none, DefendingSide, GameScheduleState, PeriodType, Position, ZoneCode,
+    empty_string_as_none, DefendingSide, GameScheduleState, HomeRoad, PenaltySeverity, PeriodType,
+    Position, ZoneCode,
 };
 use super::game_state::GameState;
 use super::game_type::GameType;
+use super::rink;
+use super::serde_helpers::string_or_number_i64;
+use super::venue_time::GameVenueTime;
 
 /// Type of play event in a game
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
@@ -47,7 +61,44 @@ pub enum PlayEventType {
     Unknown,
 }
 
+/// `typeCode` for each event type that has one. `GameStart` and `Unknown`
+/// have no code.
+const PLAY_EVENT_TYPE_CODES: &[(PlayEventType, i32)] = &[
+    (PlayEventType::Faceoff, 502),
+    (PlayEventType::Hit, 503),
+    (PlayEventType::Giveaway, 504),
+    (PlayEventType::Goal, 505),
+    (PlayEventType::ShotOnGoal, 506),
+    (PlayEventType::MissedShot, 507),
+    (PlayEventType::BlockedShot, 508),
+    (PlayEventType::Penalty, 509),
+    (PlayEventType::Stoppage, 516),
+    (PlayEventType::PeriodStart, 520),
+    (PlayEventType::PeriodEnd, 521),
+    (PlayEventType::ShootoutComplete, 523),
+    (PlayEventType::GameEnd, 524),
+    (PlayEventType::Takeaway, 525),
+    (PlayEventType::DelayedPenalty, 535),
+    (PlayEventType::FailedShotAttempt, 537),
+];
+
 impl PlayEventType {
+    /// The numeric `typeCode` the API sends alongside this `typeDescKey`
+    pub fn code(&self) -> Option<i32> {
+        PLAY_EVENT_TYPE_CODES
+            .iter()
+            .find(|(event_type, _)| event_type == self)
+            .map(|(_, code)| *code)
+    }
+
+    /// The event type for a numeric `typeCode`; `None` for unknown codes
+    pub fn from_code(code: i32) -> Option<Self> {
+        PLAY_EVENT_TYPE_CODES
+            .iter()
+            .find(|(_, known)| *known == code)
+            .map(|(event_type, _)| *event_type)
+    }
+
     /// Returns true if this event is a scoring chance (shot, goal, missed shot, blocked shot)
     pub fn is_scoring_chance(&self) -> bool {
         matches!(
@@ -61,6 +112,17 @@ impl PlayEventType {
         matches!(self, Self::Goal)
     }
 
+    /// Returns true for every shot attempt: on goal, goal, missed or blocked
+    /// (the Corsi events). Same set as [`Self::is_scoring_chance`].
+    pub fn is_shot_attempt(&self) -> bool {
+        self.is_scoring_chance()
+    }
+
+    /// Returns true if this is a called penalty (not a delayed-penalty signal)
+    pub fn is_penalty(&self) -> bool {
+        matches!(self, Self::Penalty)
+    }
+
     /// Returns true if this is a period boundary event
     pub fn is_period_boundary(&self) -> bool {
         matches!(
@@ -229,6 +291,8 @@ pub struct PlayByPlay {
     pub shootout_in_use: bool,
     #[serde(rename = "otInUse")]
     pub ot_in_use: bool,
+    /// Zeroed when the payload has no clock (limited-scoring games).
+    #[serde(default)]
     pub clock: GameClock,
     #[serde(rename = "displayPeriod")]
     pub display_period: i32,
@@ -248,6 +312,11 @@ pub struct PlayByPlay {
 }
 
 impl PlayByPlay {
+    /// Whether the game was postponed (`gameScheduleState` `"PPD"`).
+    pub fn is_postponed(&self) -> bool {
+        self.game_schedule_state.is_postponed()
+    }
+
     /// Get the most recent N plays (most recent first)
     pub fn recent_plays(&self, count: usize) -> Vec<&PlayEvent> {
         self.plays.iter().rev().take(count).collect()
@@ -255,42 +324,186 @@ impl PlayByPlay {
 
     /// Get all goals in the game
     pub fn goals(&self) -> Vec<&PlayEvent> {
-        self.plays
-            .iter()
-            .filter(|p| p.type_desc_key == PlayEventType::Goal)
-            .collect()
+        self.plays.iter().filter(|p| p.is_goal()).collect()
     }
 
-    /// Get all penalties in the game
+    /// Get all called penalties in the game
     pub fn penalties(&self) -> Vec<&PlayEvent> {
+        self.plays.iter().filter(|p| p.is_penalty()).collect()
+    }
+
+    /// Get shots on goal (goals included), optionally only those taken by
+    /// `team_id`
+    pub fn shots_on_goal(&self, team_id: Option<TeamId>) -> Vec<&PlayEvent> {
         self.plays
             .iter()
-            .filter(|p| p.type_desc_key == PlayEventType::Penalty)
+            .filter(|p| {
+                matches!(
+                    p.event_type(),
+                    PlayEventType::ShotOnGoal | PlayEventType::Goal
+                )
+            })
+            .filter(|p| team_id.is_none() || self.shooting_team_id(p) == team_id)
             .collect()
     }
 
-    /// Get all shots (on goal, missed, blocked)
-    pub fn shots(&self) -> Vec<&PlayEvent> {
+    /// Get every shot attempt (goals, shots on goal, missed and blocked
+    /// shots: the Corsi events), optionally only those taken by `team_id`
+    ///
+    /// Blocked shots count for the shooter's team, although the API reports
+    /// them with the blocker's team as `eventOwnerTeamId`; see
+    /// [`Self::shooting_team_id`].
+    pub fn shot_attempts(&self, team_id: Option<TeamId>) -> Vec<&PlayEvent> {
         self.plays
             .iter()
-            .filter(|p| p.type_desc_key.is_scoring_chance())
+            .filter(|p| p.is_shot_attempt())
+            .filter(|p| team_id.is_none() || self.shooting_team_id(p) == team_id)
             .collect()
     }
 
+    /// The team that took a shot attempt, or `None` for other events
+    ///
+    /// This is `eventOwnerTeamId` except for blocked shots, where the API
+    /// names the blocking team as owner and the shooter is on the other one.
+    pub fn shooting_team_id(&self, play: &PlayEvent) -> Option<TeamId> {
+        let owner = play.details.as_ref()?.event_owner_team_id?;
+        match play.event_type() {
+            PlayEventType::BlockedShot if owner == self.away_team.id => Some(self.home_team.id),
+            PlayEventType::BlockedShot if owner == self.home_team.id => Some(self.away_team.id),
+            PlayEventType::BlockedShot => None,
+            event_type if event_type.is_shot_attempt() => Some(owner),
+            _ => None,
+        }
+    }
+
     /// Get plays for a specific period
-    pub fn plays_in_period(&self, period: i32) -> Vec<&PlayEvent> {
+    pub fn events_in_period(&self, period: i32) -> Vec<&PlayEvent> {
         self.plays
             .iter()
             .filter(|p| p.period_descriptor.number == period)
             .collect()
     }
 
+    /// Get plays that name `player_id` in any role: shooter, scorer,
+    /// assist, goalie in net, blocker, penalty taker or drawer, hitter or
+    /// hittee, faceoff winner or loser
+    ///
+    /// A blocked shot is listed for both the shooter and the blocker.
+    pub fn events_by_player(&self, player_id: impl Into<PlayerId>) -> Vec<&PlayEvent> {
+        let player_id = player_id.into();
+        self.plays
+            .iter()
+            .filter(|p| {
+                p.details
+                    .as_ref()
+                    .is_some_and(|d| d.player_ids().any(|id| id == player_id))
+            })
+            .collect()
+    }
+
     /// Get a player from the roster by ID
     pub fn get_player(&self, player_id: impl Into<PlayerId>) -> Option<&RosterSpot> {
         let player_id = player_id.into();
         self.roster_spots.iter().find(|p| p.player_id == player_id)
     }
 
+    /// A player's full name (`"Nico Hischier"`) from the roster spots, for
+    /// labelling events
+    pub fn player_name(&self, player_id: impl Into<PlayerId>) -> Option<String> {
+        self.get_player(player_id)
+            .map(|p| format!("{} {}", p.first_name.default, p.last_name.default))
+    }
+
+    /// One side's team statistics counted from the events (see
+    /// [`TeamGameStats::from_play_by_play`])
+    ///
+    /// Unlike the boxscore-derived stats, this includes faceoff counts and
+    /// power-play opportunities.
+    pub fn team_stats(&self, side: HomeRoad) -> TeamGameStats {
+        TeamGameStats::from_play_by_play(self, side)
+    }
+
+    /// Per-player goals, assists, shots on goal, hits, blocked shots and
+    /// faceoff wins/losses, counted from the events
+    ///
+    /// Only players named in a counted role get an entry. Shots are credited
+    /// to the shooter and blocked shots to the blocker. Shootout attempts
+    /// are ignored, as in the boxscore.
+    pub fn player_event_counts(&self) -> HashMap<PlayerId, PlayerEventCounts> {
+        let mut counts: HashMap<PlayerId, PlayerEventCounts> = HashMap::new();
+        let mut count = |player_id: Option<PlayerId>, field: fn(&mut PlayerEventCounts)| {
+            if let Some(player_id) = player_id {
+                field(counts.entry(player_id).or_default());
+            }
+        };
+
+        for play in &self.plays {
+            if play.period_descriptor.period_type == Some(PeriodType::Shootout) {
+                continue;
+            }
+            let Some(details) = &play.details else {
+                continue;
+            };
+            match play.event_type() {
+                PlayEventType::Goal => {
+                    count(details.scoring_player_id, |c| {
+                        c.goals += 1;
+                        c.shots += 1;
+                    });
+                    count(details.assist1_player_id, |c| c.assists += 1);
+                    count(details.assist2_player_id, |c| c.assists += 1);
+                }
+                PlayEventType::ShotOnGoal => count(details.shooting_player_id, |c| c.shots += 1),
+                PlayEventType::Hit => count(details.hitting_player_id, |c| c.hits += 1),
+                PlayEventType::BlockedShot => {
+                    count(details.blocking_player_id, |c| c.blocked_shots += 1)
+                }
+                PlayEventType::Faceoff => {
+                    count(details.winning_player_id, |c| c.faceoff_wins += 1);
+                    count(details.losing_player_id, |c| c.faceoff_losses += 1);
+                }
+                _ => {}
+            }
+        }
+        counts
+    }
+
+    /// Plays whose `zoneCode` disagrees with their coordinates
+    ///
+    /// The zone is from the event owner's point of view (the blocker's, for
+    /// blocked shots), classified with [`ZoneCode::classify`]. Mismatches
+    /// are a known data-quality issue in the feed. Plays missing the zone,
+    /// coordinates, owner or defending side are skipped, as is the
+    /// shootout.
+    pub fn zone_code_mismatches(&self) -> Vec<&PlayEvent> {
+        self.plays
+            .iter()
+            .filter(|play| {
+                if play.period_descriptor.period_type == Some(PeriodType::Shootout) {
+                    return false;
+                }
+                let (Some(details), Some(side)) = (
+                    &play.details,
+                    play.home_team_defending_side
+                        .as_ref()
+                        .filter(|side| !side.is_unknown()),
+                ) else {
+                    return false;
+                };
+                let (Some(zone), Some(x), Some(owner)) = (
+                    &details.zone_code,
+                    details.x_coord,
+                    details.event_owner_team_id,
+                ) else {
+                    return false;
+                };
+                let attacking_positive_x =
+                    rink::attacks_positive_x(side.clone(), owner == self.home_team.id);
+                ZoneCode::classify(x as f32, attacking_positive_x) != *zone
+            })
+            .collect()
+    }
+
     /// Get all players for a team
     pub fn team_roster(&self, team_id: impl Into<TeamId>) -> Vec<&RosterSpot> {
         let team_id = team_id.into();
@@ -304,6 +517,253 @@ impl PlayByPlay {
     pub fn current_situation(&self) -> Option<GameSituation> {
         self.plays.last()?.situation()
     }
+
+    /// The period being played, or the one that just ended during an
+    /// intermission
+    ///
+    /// `period_descriptor` wins whenever it carries a period number. Only
+    /// when it doesn't (pre-game payloads) is `display_period` used, typed
+    /// against the regulation period count. `display_period` running one
+    /// ahead during an intermission is expected; any other disagreement is
+    /// logged at `warn` and `period_descriptor` is still returned.
+    pub fn current_period(&self) -> PeriodDescriptor {
+        let descriptor = &self.period_descriptor;
+        if descriptor.number > 0 {
+            let display_ahead_in_intermission =
+                self.is_intermission() && self.display_period == descriptor.number + 1;
+            if self.display_period > 0
+                && self.display_period != descriptor.number
+                && !display_ahead_in_intermission
+            {
+                warn!(
+                    game_id = %self.id,
+                    period = descriptor.number,
+                    display_period = self.display_period,
+                    in_intermission = self.clock.in_intermission,
+                    "Play-by-play period fields disagree"
+                );
+            }
+            return descriptor.clone();
+        }
+
+        let regulation = self.regulation_periods();
+        let number = self.display_period.max(0);
+        let period_type = if number == 0 {
+            None
+        } else if number <= regulation {
+            Some(PeriodType::Regulation)
+        } else if self.game_type != GameType::Playoffs && number > regulation + 1 {
+            Some(PeriodType::Shootout)
+        } else {
+            Some(PeriodType::Overtime)
+        };
+        PeriodDescriptor {
+            number,
+            period_type,
+            max_regulation_periods: regulation,
+        }
+    }
+
+    /// Whether the game clock is stopped for an intermission.
+    pub fn is_intermission(&self) -> bool {
+        self.clock.in_intermission
+    }
+
+    /// Total game seconds elapsed (see [`Boxscore::game_elapsed_seconds`]),
+    /// using [`Self::current_period`]
+    pub fn game_elapsed_seconds(&self) -> u32 {
+        game_elapsed_seconds(
+            self.game_type,
+            &self.game_state,
+            &self.current_period(),
+            self.regulation_periods(),
+            &self.clock,
+        )
+    }
+
+    /// Share of the current period already played, from `0.0` to `1.0`
+    ///
+    /// `0.0` before the game starts; `1.0` during intermissions, shootouts
+    /// and once the game is final. Periods are 20 minutes, except 5-minute
+    /// overtime outside the playoffs.
+    pub fn period_progress(&self) -> f32 {
+        if !self.game_state.has_started() {
+            return 0.0;
+        }
+        if self.game_state.is_final() || self.is_intermission() {
+            return 1.0;
+        }
+        let period_seconds = match self.current_period().period_type {
+            Some(PeriodType::Shootout) => return 1.0,
+            Some(PeriodType::Overtime) if !self.game_type.is_playoffs() => {
+                REGULAR_SEASON_OT_SECONDS
+            }
+            _ => PERIOD_SECONDS,
+        };
+        let remaining = self.clock.seconds_remaining.clamp(0, period_seconds);
+        (period_seconds - remaining) as f32 / period_seconds as f32
+    }
+
+    /// Running shots-on-goal totals after each event that carries them, for
+    /// shot-count charts
+    ///
+    /// Totals never decrease: when the feed's counters drop (after a
+    /// correction), the previous total is kept, the point is marked
+    /// `repaired` and a `warn` is logged. A side missing from an event
+    /// carries its previous total. Shootout attempts are skipped.
+    pub fn sog_progression(&self) -> Vec<SogPoint> {
+        let regulation = self.regulation_periods();
+        let (mut away, mut home) = (0, 0);
+        let mut points = Vec::new();
+
+        for play in &self.plays {
+            if play.period_descriptor.period_type == Some(PeriodType::Shootout) {
+                continue;
+            }
+            let Some(details) = &play.details else {
+                continue;
+            };
+            if details.away_sog.is_none() && details.home_sog.is_none() {
+                continue;
+            }
+            let reported_away = details.away_sog.unwrap_or(away);
+            let reported_home = details.home_sog.unwrap_or(home);
+            let repaired = reported_away < away || reported_home < home;
+            if repaired {
+                warn!(
+                    game_id = %self.id,
+                    event_id = play.event_id,
+                    away_sog = reported_away,
+                    home_sog = reported_home,
+                    previous_away_sog = away,
+                    previous_home_sog = home,
+                    "Shots-on-goal counters decreased; keeping previous totals"
+                );
+            }
+            away = away.max(reported_away);
+            home = home.max(reported_home);
+
+            points.push(SogPoint {
+                event_id: play.event_id,
+                period: play.period_descriptor.number,
+                elapsed: self.elapsed_in(play, regulation),
+                away,
+                home,
+                repaired,
+            });
+        }
+        points
+    }
+
+    /// Cross-check the final [`Self::sog_progression`] totals against
+    /// `away_team.sog`/`home_team.sog`
+    pub fn validate_sog(&self) -> SogValidation {
+        let progression = self.sog_progression();
+        let last = progression.last();
+        SogValidation {
+            away_counted: last.map_or(0, |point| point.away),
+            home_counted: last.map_or(0, |point| point.home),
+            away_reported: self.away_team.sog,
+            home_reported: self.home_team.sog,
+            repairs: progression.iter().filter(|point| point.repaired).count(),
+        }
+    }
+
+    /// Game time elapsed at `play`, across periods: the earlier periods'
+    /// full lengths (regular-season overtime is 5 minutes) plus
+    /// `timeInPeriod`, which counts as zero when it can't be parsed.
+    pub fn elapsed_at(&self, play: &PlayEvent) -> IceTime {
+        self.elapsed_in(play, self.regulation_periods())
+    }
+
+    /// Each shot attempt with its context: distance and angle, strength,
+    /// and rebound and rush flags (see [`enrich_shot_attempts`]).
+    pub fn shot_attempts_enriched(&self, config: ShotContextConfig) -> Vec<ShotAttempt> {
+        enrich_shot_attempts(self, config)
+    }
+
+    fn elapsed_in(&self, play: &PlayEvent, regulation: i32) -> IceTime {
+        let elapsed_in_period = play
+            .time_in_period
+            .parse::<IceTime>()
+            .map_or(0, |time| time.as_seconds());
+        IceTime::from_seconds(
+            self.period_start_seconds(play.period_descriptor.number, regulation)
+                + elapsed_in_period,
+        )
+    }
+
+    /// Game seconds elapsed before `period` starts: 20-minute periods,
+    /// except regular-season overtime.
+    fn period_start_seconds(&self, period: i32, regulation: i32) -> u32 {
+        (1..period.max(1))
+            .map(|earlier| period_length_seconds(self.game_type, earlier, regulation))
+            .sum()
+    }
+
+    /// Regulation periods per game: the descriptor's count, then
+    /// `reg_periods`, then the standard three.
+    fn regulation_periods(&self) -> i32 {
+        [
+            self.period_descriptor.max_regulation_periods,
+            self.reg_periods,
+        ]
+        .into_iter()
+        .find(|&periods| periods > 0)
+        .unwrap_or(3)
+    }
+}
+
+/// One player's event totals from [`PlayByPlay::player_event_counts`]
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq)]
+pub struct PlayerEventCounts {
+    pub goals: i32,
+    pub assists: i32,
+    /// Shots on goal, goals included.
+    pub shots: i32,
+    pub hits: i32,
+    /// Opponent shots this player blocked.
+    pub blocked_shots: i32,
+    pub faceoff_wins: i32,
+    pub faceoff_losses: i32,
+}
+
+/// Running shots-on-goal totals at one event, from
+/// [`PlayByPlay::sog_progression`]
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct SogPoint {
+    pub event_id: i64,
+    pub period: i32,
+    /// Game time elapsed at the event, across periods.
+    pub elapsed: IceTime,
+    pub away: i32,
+    pub home: i32,
+    /// The feed's counters went down here and were held at the previous
+    /// totals.
+    pub repaired: bool,
+}
+
+/// Final shots-on-goal totals from the event counters compared with the
+/// team totals, from [`PlayByPlay::validate_sog`]
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct SogValidation {
+    pub away_counted: i32,
+    pub home_counted: i32,
+    /// `away_team.sog`; `None` in limited-scoring games
+    pub away_reported: Option<i32>,
+    /// `home_team.sog`; `None` in limited-scoring games
+    pub home_reported: Option<i32>,
+    /// Events whose counters had to be repaired.
+    pub repairs: usize,
+}
+
+impl SogValidation {
+    /// Whether the event counters end on the team totals. `false` when
+    /// the payload has no team totals to check against.
+    pub fn is_consistent(&self) -> bool {
+        self.away_reported == Some(self.away_counted)
+            && self.home_reported == Some(self.home_counted)
+    }
 }
 
 /// Game outcome information
@@ -360,6 +820,64 @@ impl PlayEvent {
     pub fn situation(&self) -> Option<GameSituation> {
         GameSituation::from_code(&self.situation_code)
     }
+
+    /// The event type, from `typeDescKey` or, when that key isn't one this
+    /// crate knows, from `typeCode`
+    ///
+    /// Still `Unknown` when neither is recognized; `type_code` keeps the raw
+    /// code in that case.
+    pub fn event_type(&self) -> PlayEventType {
+        match self.type_desc_key {
+            PlayEventType::Unknown => {
+                PlayEventType::from_code(self.type_code).unwrap_or(PlayEventType::Unknown)
+            }
+            known => known,
+        }
+    }
+
+    /// Returns true if this event is a goal
+    pub fn is_goal(&self) -> bool {
+        self.event_type().is_goal()
+    }
+
+    /// Returns true if this event is a shot attempt (see
+    /// [`PlayEventType::is_shot_attempt`])
+    pub fn is_shot_attempt(&self) -> bool {
+        self.event_type().is_shot_attempt()
+    }
+
+    /// Returns true if this event is a called penalty
+    pub fn is_penalty(&self) -> bool {
+        self.event_type().is_penalty()
+    }
+
+    /// The event's coordinates rotated so `event_team_id` attacks toward
+    /// `+x` (see [`rink::normalize_shot`])
+    ///
+    /// Pass the shooter's team as `event_team_id` for shots; for blocked
+    /// shots that is not `eventOwnerTeamId` (see
+    /// [`PlayByPlay::shooting_team_id`]). `None` when the event has no
+    /// coordinates or no known defending side (some historical games), and
+    /// in the shootout, where both teams shoot at the same net.
+    pub fn normalized_coords(
+        &self,
+        home_team_id: impl Into<TeamId>,
+        event_team_id: impl Into<TeamId>,
+    ) -> Option<(f32, f32)> {
+        if self.period_descriptor.period_type == Some(PeriodType::Shootout) {
+            return None;
+        }
+        let details = self.details.as_ref()?;
+        let (x, y) = (details.x_coord?, details.y_coord?);
+        Some(rink::normalize_shot(
+            x as f32,
+            y as f32,
+            self.home_team_defending_side
+                .clone()
+                .filter(|side| !side.is_unknown())?,
+            home_team_id.into() == event_team_id.into(),
+        ))
+    }
 }
 
 /// Details for a play event (varies by event type)
@@ -438,10 +956,10 @@ pub struct PlayEventDetails {
     // Penalty details
     #[serde(skip_serializing_if = "Option::is_none")]
     #[serde(rename = "typeCode")]
-    pub type_code: Option<String>,
+    pub type_code: Option<PenaltySeverity>,
     #[serde(skip_serializing_if = "Option::is_none")]
     #[serde(rename = "descKey")]
-    pub desc_key: Option<String>,
+    pub desc_key: Option<PenaltyDescKey>,
     #[serde(skip_serializing_if = "Option::is_none")]
     pub duration: Option<i32>,
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -481,6 +999,29 @@ pub struct PlayEventDetails {
     pub home_sog: Option<i32>,
 }
 
+impl PlayEventDetails {
+    /// Every player id set on this event, in field order
+    fn player_ids(&self) -> impl Iterator<Item = PlayerId> {
+        [
+            self.shooting_player_id,
+            self.goalie_in_net_id,
+            self.blocking_player_id,
+            self.scoring_player_id,
+            self.assist1_player_id,
+            self.assist2_player_id,
+            self.committed_by_player_id,
+            self.drawn_by_player_id,
+            self.hitting_player_id,
+            self.hittee_player_id,
+            self.winning_player_id,
+            self.losing_player_id,
+            self.player_id,
+        ]
+        .into_iter()
+        .flatten()
+    }
+}
+
 /// Roster spot with player information
 #[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
 pub struct RosterSpot {
@@ -492,8 +1033,11 @@ pub struct RosterSpot {
     pub first_name: LocalizedString,
     #[serde(rename = "lastName")]
     pub last_name: LocalizedString,
-    #[serde(rename = "sweaterNumber")]
-    pub sweater_number: i32,
+    /// `None` when the API omits it or sends `null`, as for emergency backup
+    /// goalies and players signed mid-game.
+    #[serde(rename = "sweaterNumber", default)]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub sweater_number: Option<i32>,
     /// `None` for historical roster data where the API returns an empty
     /// position code.
     #[serde(
@@ -506,6 +1050,26 @@ pub struct RosterSpot {
     pub headshot: String,
 }
 
+/// Whether a game id exists, from [`Client::game_exists`](crate::Client::game_exists).
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum GameExistence {
+    /// The game exists. A game that hasn't started has no plays yet;
+    /// `schedule_state` tells a postponed or cancelled game apart from one
+    /// that is still on schedule.
+    Exists {
+        state: GameState,
+        schedule_state: GameScheduleState,
+    },
+    /// No game has this id.
+    NotFound,
+}
+
+impl GameExistence {
+    pub fn exists(&self) -> bool {
+        matches!(self, Self::Exists { .. })
+    }
+}
+
 /// Game matchup/landing response
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct GameMatchup {
@@ -557,6 +1121,35 @@ pub struct GameMatchup {
     pub summary: Option<GameSummary>,
     #[serde(skip_serializing_if = "Option::is_none")]
     pub clock: Option<GameClock>,
+    /// Present only while the game is live.
+    #[serde(default)]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub situation: Option<OnIceSituation>,
+}
+
+impl GameMatchup {
+    /// Whether the game was postponed (`gameScheduleState` `"PPD"`).
+    pub fn is_postponed(&self) -> bool {
+        self.game_schedule_state.is_postponed()
+    }
+
+    /// Start time and UTC offsets, for the start in UTC, venue or Eastern
+    /// time.
+    pub fn venue_time(&self) -> GameVenueTime<'_> {
+        GameVenueTime {
+            start_time_utc: &self.start_time_utc,
+            venue_utc_offset: &self.venue_utc_offset,
+            eastern_utc_offset: &self.eastern_utc_offset,
+        }
+    }
+
+    /// Officials listed in the landing summary, if the payload carries any.
+    pub fn officials(&self) -> Option<&Officials> {
+        self.summary
+            .as_ref()
+            .and_then(|summary| summary.game_info.as_ref())
+            .filter(|officials| !officials.is_empty())
+    }
 }
 
 /// Team information in game matchup
@@ -571,12 +1164,117 @@ pub struct MatchupTeam {
     #[serde(rename = "placeNameWithPreposition")]
     pub place_name_with_preposition: LocalizedString,
     pub score: i32,
-    pub sog: i32,
+    /// `None` in limited-scoring games, which have no shot totals.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub sog: Option<i32>,
     pub logo: String,
     #[serde(rename = "darkLogo")]
     pub dark_logo: String,
 }
 
+/// Current on-ice situation, carried by live landing/boxscore payloads only
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+pub struct OnIceSituation {
+    #[serde(rename = "awayTeam")]
+    pub away_team: SituationTeam,
+    #[serde(rename = "homeTeam")]
+    pub home_team: SituationTeam,
+    /// Four digits: away goalie, away skaters, home skaters, home goalie
+    /// (`"1451"` is a home power play).
+    #[serde(rename = "situationCode", default)]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub situation_code: Option<String>,
+    /// Time left in the current manpower situation, `"MM:SS"`.
+    #[serde(rename = "timeRemaining", default)]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub time_remaining: Option<String>,
+    #[serde(rename = "secondsRemaining", default)]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub seconds_remaining: Option<i32>,
+}
+
+impl OnIceSituation {
+    /// The side with more skaters on the ice, if either.
+    pub fn power_play_team(&self) -> Option<HomeRoad> {
+        let home = self.home_team.strength?;
+        let away = self.away_team.strength?;
+        match home.cmp(&away) {
+            std::cmp::Ordering::Greater => Some(HomeRoad::Home),
+            std::cmp::Ordering::Less => Some(HomeRoad::Road),
+            std::cmp::Ordering::Equal => None,
+        }
+    }
+
+    /// Skater strength, larger side first (`"5v4"`, `"4v4"`).
+    pub fn strength(&self) -> Option<String> {
+        let home = self.home_team.strength?;
+        let away = self.away_team.strength?;
+        Some(format!("{}v{}", home.max(away), home.min(away)))
+    }
+
+    /// Seconds until both sides have the same number of skaters again: the
+    /// expiry that releases enough of the short-handed side's penalties to
+    /// close the gap. Falls back to `seconds_remaining` when the penalty box
+    /// isn't itemized. `None` at even strength.
+    pub fn seconds_until_even_strength(&self) -> Option<i32> {
+        let short_handed = match self.power_play_team()? {
+            HomeRoad::Home => &self.away_team,
+            HomeRoad::Road => &self.home_team,
+        };
+        let gap = (self.home_team.strength? - self.away_team.strength?).unsigned_abs() as usize;
+
+        let mut remaining: Vec<i32> = short_handed
+            .penalty_box
+            .iter()
+            .map(|entry| entry.seconds_remaining)
+            .collect();
+        remaining.sort_unstable();
+        remaining
+            .get(gap.saturating_sub(1))
+            .copied()
+            .or(self.seconds_remaining)
+    }
+}
+
+/// One side of an [`OnIceSituation`]
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+pub struct SituationTeam {
+    pub abbrev: String,
+    /// Skaters on the ice (goalies excluded).
+    #[serde(default)]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub strength: Option<i32>,
+    /// Short labels such as `"PP"` or `"EN"` (empty net).
+    #[serde(rename = "situationDescriptions", default)]
+    pub situation_descriptions: Vec<String>,
+    #[serde(rename = "onIce", default)]
+    pub on_ice: Vec<OnIcePlayer>,
+    #[serde(rename = "penaltyBox", default)]
+    pub penalty_box: Vec<PenaltyBoxEntry>,
+}
+
+/// A player currently on the ice
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+pub struct OnIcePlayer {
+    #[serde(rename = "playerId")]
+    pub player_id: PlayerId,
+    pub name: LocalizedString,
+    #[serde(rename = "sweaterNumber")]
+    pub sweater_number: i32,
+}
+
+/// A player serving a penalty
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+pub struct PenaltyBoxEntry {
+    #[serde(rename = "playerId")]
+    pub player_id: PlayerId,
+    pub name: LocalizedString,
+    #[serde(rename = "sweaterNumber")]
+    pub sweater_number: i32,
+    #[serde(rename = "secondsRemaining")]
+    pub seconds_remaining: i32,
+}
+
 /// Game summary with scoring and penalties
 #[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
 pub struct GameSummary {
@@ -588,6 +1286,111 @@ pub struct GameSummary {
     pub three_stars: Vec<ThreeStar>,
     #[serde(default)]
     pub penalties: Vec<PeriodPenalties>,
+    /// Officials block, only present in some landing payload versions.
+    #[serde(rename = "gameInfo", default)]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub game_info: Option<Officials>,
+}
+
+impl GameSummary {
+    /// The official three stars, or three derived from `boxscore` with
+    /// [`derive_three_stars`] when the summary has none (older and some
+    /// anomalous games). Check [`DerivedStar::official`] to tell them apart.
+    pub fn three_stars_or_derived(&self, boxscore: &Boxscore) -> Vec<DerivedStar> {
+        if self.three_stars.is_empty() {
+            return derive_three_stars(boxscore);
+        }
+        let mut stars: Vec<DerivedStar> = self.three_stars.iter().map(DerivedStar::from).collect();
+        stars.sort_by_key(|star| star.star);
+        stars
+    }
+
+    /// Every goal, in game order: by period, then time in period.
+    pub fn all_goals(&self) -> Vec<&GoalSummary> {
+        let mut goals: Vec<(i32, IceTime, &GoalSummary)> = self
+            .scoring
+            .iter()
+            .flat_map(|period| {
+                period.goals.iter().map(move |goal| {
+                    let time = goal.time_in_period.parse().unwrap_or_default();
+                    (period.period_descriptor.number, time, goal)
+                })
+            })
+            .collect();
+        goals.sort_by_key(|(period, time, _)| (*period, *time));
+        goals.into_iter().map(|(_, _, goal)| goal).collect()
+    }
+
+    /// `abbrev`'s goals in game order. ASCII case-insensitive.
+    pub fn goals_by_team(&self, abbrev: &str) -> Vec<&GoalSummary> {
+        self.all_goals()
+            .into_iter()
+            .filter(|goal| goal.team_abbrev.default.eq_ignore_ascii_case(abbrev))
+            .collect()
+    }
+
+    /// `(away, home)` after the last goal; `None` when nobody scored. A
+    /// shootout isn't part of the scoring summary, so a shootout game ends
+    /// tied here.
+    pub fn final_score(&self) -> Option<(i32, i32)> {
+        self.all_goals()
+            .last()
+            .map(|goal| (goal.away_score, goal.home_score))
+    }
+
+    /// The game-winning goal: the winner's goal that gave it one more than
+    /// the loser's final total, i.e. the one that put it ahead for good
+    /// (the winner's third in a 4-2 comeback after trailing 0-2).
+    ///
+    /// `None` for a game decided in a shootout, where the deciding attempt
+    /// is a [`ShootoutAttempt`] rather than a goal (see
+    /// [`ShootoutAttempt::game_winner`]), and for a game whose summary ends
+    /// tied or scoreless (not finished, or decided in a shootout).
+    pub fn game_winning_goal(&self) -> Option<&GoalSummary> {
+        let went_to_shootout = !self.shootout.is_empty()
+            || self
+                .scoring
+                .iter()
+                .any(|period| period.period_descriptor.period_type == Some(PeriodType::Shootout));
+        if went_to_shootout {
+            return None;
+        }
+        let (away, home) = self.final_score()?;
+        let (home_won, losing_total) = match away.cmp(&home) {
+            std::cmp::Ordering::Less => (true, away),
+            std::cmp::Ordering::Greater => (false, home),
+            std::cmp::Ordering::Equal => return None,
+        };
+        self.all_goals().into_iter().find(|goal| {
+            let winner_total = if home_won {
+                goal.home_score
+            } else {
+                goal.away_score
+            };
+            goal.is_home == home_won && winner_total == losing_total + 1
+        })
+    }
+
+    /// The official three stars, first star first.
+    pub fn three_stars_sorted(&self) -> Vec<&ThreeStar> {
+        let mut stars: Vec<&ThreeStar> = self.three_stars.iter().collect();
+        stars.sort_by_key(|star| star.star);
+        stars
+    }
+
+    /// The official three stars, first star first, each with its line in
+    /// `boxscore` (skater or goalie). The line is `None` when the player
+    /// isn't in the boxscore, e.g. one from another game. Empty when the
+    /// summary has no stars, as in some all-star and preseason games.
+    pub fn three_star_stats<'a>(
+        &'a self,
+        boxscore: &'a Boxscore,
+    ) -> Vec<(&'a ThreeStar, Option<BoxscorePlayer<'a>>)> {
+        self.three_stars_sorted()
+            .into_iter()
+            .map(|star| (star, boxscore.player_stats(star.player_id)))
+            .collect()
+    }
 }
 
 /// Scoring summary for a period
@@ -667,8 +1470,11 @@ pub struct AssistSummary {
     pub name: LocalizedString,
     #[serde(rename = "assistsToDate")]
     pub assists_to_date: i32,
-    #[serde(rename = "sweaterNumber")]
-    pub sweater_number: i32,
+    /// `None` when the API omits it or sends `null`, as for emergency backup
+    /// goalies and players signed mid-game.
+    #[serde(rename = "sweaterNumber", default)]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub sweater_number: Option<i32>,
 }
 
 /// Shootout attempt information
@@ -737,7 +1543,7 @@ pub struct PenaltySummary {
     #[serde(rename = "timeInPeriod")]
     pub time_in_period: String,
     #[serde(rename = "type")]
-    pub penalty_type: String,
+    pub penalty_type: PenaltySeverity,
     pub duration: i32,
     #[serde(skip_serializing_if = "Option::is_none")]
     #[serde(rename = "committedByPlayer")]
@@ -748,7 +1554,7 @@ pub struct PenaltySummary {
     #[serde(rename = "drawnBy")]
     pub drawn_by: Option<PenaltyPlayer>,
     #[serde(rename = "descKey")]
-    pub desc_key: String,
+    pub desc_key: PenaltyDescKey,
     // Bench penalty specific field
     #[serde(skip_serializing_if = "Option::is_none")]
     #[serde(rename = "servedBy")]
@@ -759,6 +1565,119 @@ pub struct PenaltySummary {
     pub event_id: Option<i64>,
 }
 
+impl PenaltySummary {
+    /// Returns true for a penalty assessed to the bench: no player committed
+    /// it, someone only served it.
+    pub fn is_bench_penalty(&self) -> bool {
+        self.committed_by_player.is_none() && self.served_by.is_some()
+    }
+}
+
+/// Penalty infraction key, e.g. `"slashing"` or
+/// `"delaying-game-puck-over-glass"`. Serializes as the raw key.
+#[derive(Debug, Clone, PartialEq, Eq, Hash, Serialize, Deserialize)]
+#[serde(transparent)]
+pub struct PenaltyDescKey(String);
+
+/// Infractions the API qualifies with a suffix, e.g.
+/// `"interference-goalkeeper"`; [`PenaltyDescKey::humanize`] sets the
+/// suffix off with a dash.
+const QUALIFIED_INFRACTIONS: &[&str] = &["delaying-game", "high-sticking", "interference"];
+
+/// Hyphenated words that stay hyphenated once humanized.
+const HYPHENATED_WORDS: &[&str] = &[
+    "butt-ending",
+    "cross-checking",
+    "face-off",
+    "head-butting",
+    "high-sticking",
+];
+
+/// Words left lowercase unless they start the phrase.
+const MINOR_WORDS: &[&str] = &[
+    "a", "by", "for", "in", "of", "on", "over", "the", "to", "with",
+];
+
+impl PenaltyDescKey {
+    pub fn new(key: impl Into<String>) -> Self {
+        Self(key.into())
+    }
+
+    pub fn as_str(&self) -> &str {
+        &self.0
+    }
+
+    /// The key as a title: `"delaying-game-puck-over-glass"` becomes
+    /// `"Delaying Game – Puck over Glass"`, `"too-many-men-on-the-ice"`
+    /// becomes `"Too Many Men on the Ice"`.
+    pub fn humanize(&self) -> String {
+        let qualified = QUALIFIED_INFRACTIONS.iter().find_map(|infraction| {
+            self.0
+                .strip_prefix(infraction)
+                .and_then(|rest| rest.strip_prefix('-'))
+                .filter(|qualifier| !qualifier.is_empty())
+                .map(|qualifier| (*infraction, qualifier))
+        });
+        match qualified {
+            Some((infraction, qualifier)) => {
+                format!("{} – {}", title_case(infraction), title_case(qualifier))
+            }
+            None => title_case(&self.0),
+        }
+    }
+}
+
+impl fmt::Display for PenaltyDescKey {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(&self.0)
+    }
+}
+
+impl From<&str> for PenaltyDescKey {
+    fn from(key: &str) -> Self {
+        Self::new(key)
+    }
+}
+
+/// Capitalizes each dash-separated word of `key`, keeping
+/// [`HYPHENATED_WORDS`] joined and [`MINOR_WORDS`] lowercase.
+fn title_case(key: &str) -> String {
+    let parts: Vec<&str> = key.split('-').filter(|part| !part.is_empty()).collect();
+    let mut words: Vec<String> = Vec::new();
+    let mut index = 0;
+    while index < parts.len() {
+        let pair = parts
+            .get(index + 1)
+            .map(|next| format!("{}-{}", parts[index], next));
+        if let Some(pair) = pair.filter(|pair| HYPHENATED_WORDS.contains(&pair.as_str())) {
+            words.push(
+                pair.split('-')
+                    .map(capitalize)
+                    .collect::<Vec<_>>()
+                    .join("-"),
+            );
+            index += 2;
+            continue;
+        }
+        let part = parts[index];
+        if !words.is_empty() && MINOR_WORDS.contains(&part) {
+            words.push(part.to_string());
+        } else {
+            words.push(capitalize(part));
+        }
+        index += 1;
+    }
+    words.join(" ")
+}
+
+fn capitalize(word: &str) -> String {
+    let mut chars = word.chars();
+    match chars.next() {
+        Some(first) => first.to_uppercase().chain(chars).collect(),
+        None => String::new(),
+    }
+}
+
 /// Player information in penalty summary
 #[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
 pub struct PenaltyPlayer {
@@ -782,14 +1701,20 @@ pub struct ShiftEntry {
     pub id: i64,
     #[serde(rename = "detailCode")]
     pub detail_code: i32,
-    pub duration: String,
+    /// `00:00` on goal rows, which send `null`.
+    #[serde(deserialize_with = "null_as_zero")]
+    pub duration: IceTime,
     #[serde(rename = "endTime")]
-    pub end_time: String,
+    pub end_time: IceTime,
     #[serde(skip_serializing_if = "Option::is_none")]
     #[serde(rename = "eventDescription")]
     pub event_description: Option<String>,
+    /// Numeric in current data, a string in some older seasons, and
+    /// occasionally `null`.
     #[serde(rename = "eventNumber")]
-    pub event_number: i64,
+    #[serde(deserialize_with = "string_or_number_i64", default)]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub event_number: Option<i64>,
     #[serde(rename = "firstName")]
     pub first_name: String,
     #[serde(rename = "gameId")]
@@ -804,7 +1729,7 @@ pub struct ShiftEntry {
     #[serde(rename = "shiftNumber")]
     pub shift_number: i32,
     #[serde(rename = "startTime")]
-    pub start_time: String,
+    pub start_time: IceTime,
     #[serde(rename = "teamAbbrev")]
     pub team_abbrev: String,
     #[serde(rename = "teamId")]
@@ -815,15 +1740,427 @@ pub struct ShiftEntry {
     pub type_code: i32,
 }
 
-/// Season series matchup
-#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
-pub struct SeasonSeriesMatchup {
-    #[serde(rename = "seasonSeries")]
-    pub season_series: Vec<SeriesGame>,
-    #[serde(rename = "seasonSeriesWins")]
+fn null_as_zero<'de, D>(deserializer: D) -> Result<IceTime, D::Error>
+where
+    D: serde::Deserializer<'de>,
+{
+    Ok(Option::<IceTime>::deserialize(deserializer)?.unwrap_or_default())
+}
+
+impl ShiftEntry {
+    /// `typeCode` of an actual shift. Other rows (505 for goals) mark events
+    /// and put no one on the ice.
+    pub const SHIFT_TYPE_CODE: i32 = 517;
+
+    /// True for an actual shift rather than an event row
+    pub fn is_shift(&self) -> bool {
+        self.type_code == Self::SHIFT_TYPE_CODE
+    }
+
+    /// Start and end in seconds since the opening faceoff. Start and end
+    /// times restart every period; periods before overtime are 20 minutes,
+    /// so overtime starts at 60:00 in any game type.
+    pub fn game_seconds(&self) -> (u32, u32) {
+        let period_start = (self.period.max(1) - 1) as u32 * PERIOD_SECONDS as u32;
+        (
+            period_start + self.start_time.as_seconds(),
+            period_start + self.end_time.as_seconds(),
+        )
+    }
+}
+
+impl ShiftChart {
+    /// Actual shifts, without the goal and other event rows
+    pub fn shifts(&self) -> impl Iterator<Item = &ShiftEntry> {
+        self.data.iter().filter(|entry| entry.is_shift())
+    }
+
+    /// Total time on ice per player, summed over their shifts
+    pub fn toi_by_player(&self) -> HashMap<PlayerId, IceTime> {
+        let mut toi: HashMap<PlayerId, IceTime> = HashMap::new();
+        for shift in self.shifts() {
+            *toi.entry(shift.player_id).or_default() += shift.duration;
+        }
+        toi
+    }
+
+    /// A player's shifts, in chart order
+    pub fn shifts_for_player(&self, player_id: impl Into<PlayerId>) -> Vec<&ShiftEntry> {
+        let player_id = player_id.into();
+        self.shifts()
+            .filter(|shift| shift.player_id == player_id)
+            .collect()
+    }
+
+    /// Shifts in a period (4 and up are overtime)
+    pub fn shifts_in_period(&self, period: i32) -> Vec<&ShiftEntry> {
+        self.shifts()
+            .filter(|shift| shift.period == period)
+            .collect()
+    }
+
+    /// Pairs and triples of teammates on the ice together for at least
+    /// `min_overlap_seconds`, longest first.
+    ///
+    /// Time together is the overlap of their shift intervals over the whole
+    /// game. The chart doesn't say who the goalies are, so they pair with
+    /// everyone; drop them with [`LineCombination::contains`].
+    pub fn line_combinations(&self, min_overlap_seconds: u32) -> Vec<LineCombination> {
+        // Per team, +1/-1 on-ice changes at each second a shift starts or ends.
+        let mut changes: HashMap<TeamId, Vec<(u32, bool, PlayerId)>> = HashMap::new();
+        for shift in self.shifts() {
+            let (start, end) = shift.game_seconds();
+            if end > start {
+                let team = changes.entry(shift.team_id).or_default();
+                team.push((start, true, shift.player_id));
+                team.push((end, false, shift.player_id));
+            }
+        }
+
+        let mut together: HashMap<(TeamId, Vec<PlayerId>), u32> = HashMap::new();
+        for (team_id, mut team_changes) in changes {
+            // Ends sort before starts at the same second.
+            team_changes.sort();
+            let mut on_ice: Vec<PlayerId> = Vec::new();
+            let mut previous = 0;
+            for (second, starts, player_id) in team_changes {
+                let elapsed = second - previous;
+                if elapsed > 0 {
+                    for group in groups_of_two_and_three(&on_ice) {
+                        *together.entry((team_id, group)).or_default() += elapsed;
+                    }
+                }
+                previous = second;
+                if starts {
+                    on_ice.push(player_id);
+                    on_ice.sort();
+                } else if let Some(index) = on_ice.iter().position(|id| *id == player_id) {
+                    on_ice.remove(index);
+                }
+            }
+        }
+
+        let mut combinations: Vec<LineCombination> = together
+            .into_iter()
+            .filter(|(_, seconds)| *seconds >= min_overlap_seconds)
+            .map(|((team_id, players), seconds)| LineCombination {
+                team_id,
+                players,
+                time_together: IceTime::from_seconds(seconds),
+            })
+            .collect();
+        combinations.sort_by(|a, b| {
+            b.time_together
+                .cmp(&a.time_together)
+                .then_with(|| a.team_id.cmp(&b.team_id))
+                .then_with(|| a.players.cmp(&b.players))
+        });
+        combinations
+    }
+}
+
+/// Every pair and triple from `players`, which is sorted, each sorted.
+/// A player with overlapping shifts of their own counts once.
+fn groups_of_two_and_three(players: &[PlayerId]) -> Vec<Vec<PlayerId>> {
+    let mut unique = players.to_vec();
+    unique.dedup();
+    let mut groups = Vec::new();
+    for (i, &first) in unique.iter().enumerate() {
+        for (j, &second) in unique.iter().enumerate().skip(i + 1) {
+            groups.push(vec![first, second]);
+            for &third in &unique[j + 1..] {
+                groups.push(vec![first, second, third]);
+            }
+        }
+    }
+    groups
+}
+
+/// Two or three teammates and how long they were on the ice together, from
+/// [`ShiftChart::line_combinations`]
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct LineCombination {
+    pub team_id: TeamId,
+    /// Sorted by id
+    pub players: Vec<PlayerId>,
+    pub time_together: IceTime,
+}
+
+impl LineCombination {
+    /// True if `player_id` is part of this combination
+    pub fn contains(&self, player_id: impl Into<PlayerId>) -> bool {
+        self.players.contains(&player_id.into())
+    }
+}
+
+/// Season series matchup
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+pub struct SeasonSeriesMatchup {
+    #[serde(rename = "seasonSeries")]
+    pub season_series: Vec<SeriesGame>,
+    #[serde(rename = "seasonSeriesWins")]
     pub season_series_wins: SeriesWins,
-    #[serde(rename = "gameInfo")]
+    /// Officials, coaches and scratches; empty before they're announced.
+    #[serde(rename = "gameInfo", default)]
     pub game_info: SeriesGameInfo,
+    /// Team comparison rows (`sog`, `pim`, `powerPlay`, ...); empty before
+    /// the game starts.
+    #[serde(rename = "teamGameStats", default)]
+    pub team_game_stats: Vec<RightRailTeamStat>,
+    /// Shots on goal per period, overtime included; empty before the game
+    /// starts.
+    #[serde(rename = "shotsByPeriod", default)]
+    pub shots_by_period: Vec<PeriodShots>,
+}
+
+/// One period's shots on goal from the right rail
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+pub struct PeriodShots {
+    #[serde(rename = "periodDescriptor")]
+    pub period_descriptor: PeriodDescriptor,
+    pub away: i32,
+    pub home: i32,
+}
+
+impl PeriodShots {
+    /// The shots for one side
+    pub fn for_side(&self, side: HomeRoad) -> i32 {
+        match side {
+            HomeRoad::Home => self.home,
+            HomeRoad::Road => self.away,
+        }
+    }
+}
+
+/// One row of the right-rail team comparison
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+pub struct RightRailTeamStat {
+    /// e.g. `"sog"`, `"faceoffWinningPctg"`, `"powerPlay"`, `"pim"`,
+    /// `"hits"`, `"blockedShots"`, `"giveaways"`, `"takeaways"`
+    pub category: String,
+    #[serde(rename = "awayValue")]
+    pub away_value: TeamStatValue,
+    #[serde(rename = "homeValue")]
+    pub home_value: TeamStatValue,
+}
+
+impl RightRailTeamStat {
+    /// The value for one side
+    pub fn value(&self, side: HomeRoad) -> &TeamStatValue {
+        match side {
+            HomeRoad::Home => &self.home_value,
+            HomeRoad::Road => &self.away_value,
+        }
+    }
+}
+
+/// A right-rail stat value: counts come as strings (`"27"`), percentages as
+/// numbers (`0.518`) and power plays as `"goals/opportunities"` (`"1/3"`)
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+#[serde(untagged)]
+pub enum TeamStatValue {
+    Number(serde_json::Number),
+    Text(String),
+}
+
+impl TeamStatValue {
+    /// Whole-number value, from a number or a numeric string
+    pub fn as_i32(&self) -> Option<i32> {
+        match self {
+            TeamStatValue::Number(number) => number.as_i64().and_then(|n| i32::try_from(n).ok()),
+            TeamStatValue::Text(text) => text.trim().parse().ok(),
+        }
+    }
+
+    /// `(made, attempts)` from an `"x/y"` string
+    pub fn as_fraction(&self) -> Option<(i32, i32)> {
+        let TeamStatValue::Text(text) = self else {
+            return None;
+        };
+        let (made, attempts) = text.split_once('/')?;
+        Some((made.trim().parse().ok()?, attempts.trim().parse().ok()?))
+    }
+}
+
+/// Games a playoff series can last (best-of-seven).
+const PLAYOFF_SERIES_MAX_GAMES: i32 = 7;
+
+/// Wins needed to take a best-of-seven playoff series.
+const PLAYOFF_SERIES_WINS_NEEDED: i32 = PLAYOFF_SERIES_MAX_GAMES / 2 + 1;
+
+/// One team's side of a season/playoff series, tallied from game outcomes
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct SeriesTeamRecord {
+    pub id: TeamId,
+    pub abbrev: String,
+    pub wins: i32,
+}
+
+impl SeasonSeriesMatchup {
+    /// The team comparison row for `category`, if the right rail has one
+    pub fn team_stat(&self, category: &str) -> Option<&RightRailTeamStat> {
+        self.team_game_stats
+            .iter()
+            .find(|stat| stat.category == category)
+    }
+
+    /// True when the right-rail series is a playoff series, i.e. every game
+    /// listed is a playoff game.
+    pub fn is_playoff_series(&self) -> bool {
+        !self.season_series.is_empty()
+            && self
+                .season_series
+                .iter()
+                .all(|game| game.game_type.is_playoffs())
+    }
+
+    /// Both teams' win totals, counted from the completed games' final
+    /// scores, in the first game's away/home order. `None` when the series
+    /// lists no games.
+    pub fn series_records(&self) -> Option<[SeriesTeamRecord; 2]> {
+        let first = self.season_series.first()?;
+        let mut records = [&first.away_team, &first.home_team].map(|team| SeriesTeamRecord {
+            id: team.id,
+            abbrev: team.abbrev.clone(),
+            wins: 0,
+        });
+
+        for game in &self.season_series {
+            if let Some(winner) = game.winner() {
+                if let Some(record) = records.iter_mut().find(|r| r.id == winner.id) {
+                    record.wins += 1;
+                }
+            }
+        }
+        Some(records)
+    }
+
+    /// Playoff series status, e.g. `"EDM leads 3-2"`, `"Series tied 2-2"`
+    /// or `"EDM wins 4-0"`. `None` for regular-season series.
+    pub fn series_score_string(&self) -> Option<String> {
+        if !self.is_playoff_series() {
+            return None;
+        }
+        let [a, b] = self.series_records()?;
+        let (leader, trailer) = if a.wins >= b.wins { (a, b) } else { (b, a) };
+
+        Some(if leader.wins == trailer.wins {
+            format!("Series tied {}-{}", leader.wins, trailer.wins)
+        } else if leader.wins >= PLAYOFF_SERIES_WINS_NEEDED {
+            format!("{} wins {}-{}", leader.abbrev, leader.wins, trailer.wins)
+        } else {
+            format!("{} leads {}-{}", leader.abbrev, leader.wins, trailer.wins)
+        })
+    }
+
+    /// The team one win from clinching an undecided playoff series. `None`
+    /// at 3-3, where the next game is match point for both sides.
+    pub fn match_point_for(&self) -> Option<SeriesTeamRecord> {
+        if !self.is_playoff_series() || self.eliminated().is_some() {
+            return None;
+        }
+        let [a, b] = self.series_records()?;
+        let needs_one = |record: &SeriesTeamRecord| record.wins == PLAYOFF_SERIES_WINS_NEEDED - 1;
+        match (needs_one(&a), needs_one(&b)) {
+            (true, false) => Some(a),
+            (false, true) => Some(b),
+            _ => None,
+        }
+    }
+
+    /// The team knocked out of a decided playoff series.
+    pub fn eliminated(&self) -> Option<SeriesTeamRecord> {
+        if !self.is_playoff_series() {
+            return None;
+        }
+        let [a, b] = self.series_records()?;
+        if a.wins >= PLAYOFF_SERIES_WINS_NEEDED {
+            Some(b)
+        } else if b.wins >= PLAYOFF_SERIES_WINS_NEEDED {
+            Some(a)
+        } else {
+            None
+        }
+    }
+
+    /// Games that have gone final, in series order.
+    pub fn completed_games(&self) -> Vec<&SeriesGame> {
+        self.season_series
+            .iter()
+            .filter(|game| game.game_state.is_final())
+            .collect()
+    }
+
+    /// Games that haven't gone final yet, including one in progress, in
+    /// series order.
+    pub fn remaining_games(&self) -> Vec<&SeriesGame> {
+        self.season_series
+            .iter()
+            .filter(|game| !game.game_state.is_final())
+            .collect()
+    }
+
+    /// The earliest game that hasn't started (`FUT`), by start time.
+    pub fn next_game(&self) -> Option<&SeriesGame> {
+        self.season_series
+            .iter()
+            .filter(|game| game.game_state == GameState::Future)
+            .min_by(|a, b| a.start_time_utc.cmp(&b.start_time_utc))
+    }
+
+    /// `team_id`'s goals for minus goals against over the completed games.
+    /// `0` for a team that isn't in the series.
+    pub fn goal_differential(&self, team_id: TeamId) -> i32 {
+        self.completed_games()
+            .iter()
+            .filter_map(|game| game.scores_for(team_id))
+            .map(|(scored, allowed)| scored - allowed)
+            .sum()
+    }
+
+    /// `team_id`'s `(wins, losses, ot_losses)` over the completed games. A
+    /// loss whose `gameOutcome.lastPeriodType` is overtime or a shootout is
+    /// an OT loss, except in the playoffs, where every loss is a loss.
+    pub fn record_for(&self, team_id: TeamId) -> (i32, i32, i32) {
+        let mut record = (0, 0, 0);
+        for game in self.completed_games() {
+            let Some((scored, allowed)) = game.scores_for(team_id) else {
+                continue;
+            };
+            let past_regulation = game
+                .game_outcome
+                .last_period_type
+                .as_ref()
+                .is_some_and(|period_type| period_type.is_overtime());
+            if scored > allowed {
+                record.0 += 1;
+            } else if past_regulation && !game.game_type.is_playoffs() {
+                record.2 += 1;
+            } else {
+                record.1 += 1;
+            }
+        }
+        record
+    }
+
+    /// The game's referees, then its linesmen, each with their role.
+    pub fn officials(&self) -> Vec<Official<'_>> {
+        self.game_info.officials.with_roles()
+    }
+
+    /// Most games the playoff series can still go: `0` once decided,
+    /// otherwise seven minus the games already decided. `None` for
+    /// regular-season series.
+    pub fn games_remaining_max(&self) -> Option<i32> {
+        if !self.is_playoff_series() {
+            return None;
+        }
+        if self.eliminated().is_some() {
+            return Some(0);
+        }
+        let [a, b] = self.series_records()?;
+        Some((PLAYOFF_SERIES_MAX_GAMES - a.wins - b.wins).max(0))
+    }
 }
 
 /// Individual game in the season series
@@ -857,6 +2194,37 @@ pub struct SeriesGame {
     pub game_outcome: GameOutcome,
 }
 
+impl SeriesGame {
+    /// Whether the game was postponed (`gameScheduleState` `"PPD"`).
+    pub fn is_postponed(&self) -> bool {
+        self.game_schedule_state.is_postponed()
+    }
+
+    /// The winning side of a completed game, by final score.
+    pub fn winner(&self) -> Option<&SeriesTeam> {
+        if !self.game_state.is_final() {
+            return None;
+        }
+        match self.away_team.score.cmp(&self.home_team.score) {
+            std::cmp::Ordering::Greater => Some(&self.away_team),
+            std::cmp::Ordering::Less => Some(&self.home_team),
+            std::cmp::Ordering::Equal => None,
+        }
+    }
+
+    /// `(goals for, goals against)` from `team_id`'s side, `None` if it
+    /// didn't play in this game.
+    fn scores_for(&self, team_id: TeamId) -> Option<(i32, i32)> {
+        if self.away_team.id == team_id {
+            Some((self.away_team.score, self.home_team.score))
+        } else if self.home_team.id == team_id {
+            Some((self.home_team.score, self.away_team.score))
+        } else {
+            None
+        }
+    }
+}
+
 /// Team information in season series
 #[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
 pub struct SeriesTeam {
@@ -875,22 +2243,73 @@ pub struct SeriesWins {
     pub home_team_wins: i32,
 }
 
-/// Game information including officials and scratches
-#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
-pub struct SeriesGameInfo {
+/// On-ice officials for a game.
+///
+/// Appears under `gameInfo` in the right-rail payload and, in some payload
+/// versions, under `gameInfo`/`summary.gameInfo` in boxscore and landing
+/// responses. Officials are name-only — the API carries no ids for them.
+#[derive(Debug, Clone, Default, Serialize, Deserialize, PartialEq)]
+pub struct Officials {
+    #[serde(default)]
     pub referees: Vec<LocalizedString>,
+    #[serde(default)]
     pub linesmen: Vec<LocalizedString>,
-    #[serde(rename = "awayTeam")]
+}
+
+impl Officials {
+    /// True when neither referees nor linesmen are listed.
+    pub fn is_empty(&self) -> bool {
+        self.referees.is_empty() && self.linesmen.is_empty()
+    }
+
+    /// Referees, then linesmen, each with their role.
+    pub fn with_roles(&self) -> Vec<Official<'_>> {
+        let referees = self.referees.iter().map(|name| Official {
+            role: OfficialRole::Referee,
+            name,
+        });
+        let linesmen = self.linesmen.iter().map(|name| Official {
+            role: OfficialRole::Linesman,
+            name,
+        });
+        referees.chain(linesmen).collect()
+    }
+}
+
+/// What an on-ice official does.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum OfficialRole {
+    Referee,
+    Linesman,
+}
+
+/// One official with their role, from [`Officials::with_roles`].
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Official<'a> {
+    pub role: OfficialRole,
+    pub name: &'a LocalizedString,
+}
+
+/// Game information including officials and scratches
+#[derive(Debug, Clone, Default, Serialize, Deserialize, PartialEq)]
+pub struct SeriesGameInfo {
+    #[serde(flatten)]
+    pub officials: Officials,
+    #[serde(rename = "awayTeam", default)]
     pub away_team: TeamGameInfo,
-    #[serde(rename = "homeTeam")]
+    #[serde(rename = "homeTeam", default)]
     pub home_team: TeamGameInfo,
 }
 
 /// Team-specific game information
-#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+///
+/// Pregame payloads may leave out the coach or the scratches; they read as
+/// empty.
+#[derive(Debug, Clone, Default, Serialize, Deserialize, PartialEq)]
 pub struct TeamGameInfo {
-    #[serde(rename = "headCoach")]
+    #[serde(rename = "headCoach", default)]
     pub head_coach: LocalizedString,
+    #[serde(default)]
     pub scratches: Vec<ScratchedPlayer>,
 }
 
@@ -904,6 +2323,38 @@ pub struct ScratchedPlayer {
     pub last_name: LocalizedString,
 }
 
+/// A game's officials, head coaches and scratches with names resolved to
+/// their default strings, from [`Client::game_officials`](crate::Client::game_officials).
+///
+/// Everything is empty until announced: officials and scratches are empty
+/// lists and coaches empty strings before the game.
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct GameOfficials {
+    pub referees: Vec<String>,
+    pub linesmen: Vec<String>,
+    pub home_coach: String,
+    pub away_coach: String,
+    pub home_scratches: Vec<ScratchedPlayer>,
+    pub away_scratches: Vec<ScratchedPlayer>,
+}
+
+impl GameOfficials {
+    /// Flattens a right-rail `gameInfo` block.
+    pub fn from_game_info(info: &SeriesGameInfo) -> Self {
+        let names = |names: &[LocalizedString]| -> Vec<String> {
+            names.iter().map(|name| name.default.clone()).collect()
+        };
+        Self {
+            referees: names(&info.officials.referees),
+            linesmen: names(&info.officials.linesmen),
+            home_coach: info.home_team.head_coach.default.clone(),
+            away_coach: info.away_team.head_coach.default.clone(),
+            home_scratches: info.home_team.scratches.clone(),
+            away_scratches: info.away_team.scratches.clone(),
+        }
+    }
+}
+
 /// Game story
 #[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
 pub struct GameStory {
@@ -950,6 +2401,23 @@ pub struct GameStory {
     pub summary: Option<GameSummary>,
 }
 
+impl GameStory {
+    /// Whether the game was postponed (`gameScheduleState` `"PPD"`).
+    pub fn is_postponed(&self) -> bool {
+        self.game_schedule_state.is_postponed()
+    }
+
+    /// Start time and UTC offsets, for the start in UTC, venue or Eastern
+    /// time.
+    pub fn venue_time(&self) -> GameVenueTime<'_> {
+        GameVenueTime {
+            start_time_utc: &self.start_time_utc,
+            venue_utc_offset: &self.venue_utc_offset,
+            eastern_utc_offset: &self.eastern_utc_offset,
+        }
+    }
+}
+
 /// Team information in game story
 #[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
 pub struct StoryTeam {
@@ -966,42 +2434,30 @@ pub struct StoryTeam {
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::test_util::assert_no_unmapped_fields;
+
+    /// A final play-by-play trimmed with `test_util::fixture_trim` to one
+    /// faceoff, shot, penalty and goal, and four roster spots.
+    const PLAY_BY_PLAY_FIXTURE: &str = include_str!("../../tests/fixtures/play_by_play.json");
+
+    /// The fixture play with `event_id`, as JSON.
+    fn fixture_play(event_id: i64) -> String {
+        let json: serde_json::Value = serde_json::from_str(PLAY_BY_PLAY_FIXTURE).unwrap();
+        let play = json["plays"]
+            .as_array()
+            .unwrap()
+            .iter()
+            .find(|play| play["eventId"] == event_id)
+            .unwrap();
+        play.to_string()
+    }
 
     #[test]
     fn test_play_event_goal_deserialization() {
-        let json = r#"{
-            "eventId": 274,
-            "periodDescriptor": {
-                "number": 1,
-                "periodType": "REG",
-                "maxRegulationPeriods": 3
-            },
-            "timeInPeriod": "08:39",
-            "timeRemaining": "11:21",
-            "situationCode": "1551",
-            "homeTeamDefendingSide": "right",
-            "typeCode": 505,
-            "typeDescKey": "goal",
-            "sortOrder": 146,
-            "details": {
-                "xCoord": 71,
-                "yCoord": -12,
-                "zoneCode": "O",
-                "shotType": "snap",
-                "scoringPlayerId": 8476474,
-                "scoringPlayerTotal": 1,
-                "assist1PlayerId": 8480192,
-                "assist1PlayerTotal": 1,
-                "eventOwnerTeamId": 1,
-                "goalieInNetId": 8480045,
-                "awayScore": 1,
-                "homeScore": 0,
-                "highlightClip": 6362848229112,
-                "discreteClip": 6362846260112
-            }
-        }"#;
+        let json = fixture_play(274);
 
-        let event: PlayEvent = serde_json::from_str(json).unwrap();
+        let event: PlayEvent = serde_json::from_str(&json).unwrap();
+        assert_no_unmapped_fields::<PlayEvent>(&json);
         assert_eq!(event.event_id, 274);
         assert_eq!(event.type_desc_key, PlayEventType::Goal);
         assert_eq!(event.time_in_period, "08:39");
@@ -1017,40 +2473,16 @@ mod tests {
 
     #[test]
     fn test_play_event_penalty_deserialization() {
-        let json = r#"{
-            "eventId": 135,
-            "periodDescriptor": {
-                "number": 1,
-                "periodType": "REG",
-                "maxRegulationPeriods": 3
-            },
-            "timeInPeriod": "01:37",
-            "timeRemaining": "18:23",
-            "situationCode": "1560",
-            "homeTeamDefendingSide": "right",
-            "typeCode": 509,
-            "typeDescKey": "penalty",
-            "sortOrder": 45,
-            "details": {
-                "xCoord": 1,
-                "yCoord": -37,
-                "zoneCode": "N",
-                "typeCode": "MIN",
-                "descKey": "slashing",
-                "duration": 2,
-                "committedByPlayerId": 8475287,
-                "drawnByPlayerId": 8479420,
-                "eventOwnerTeamId": 1
-            }
-        }"#;
+        let json = fixture_play(135);
 
-        let event: PlayEvent = serde_json::from_str(json).unwrap();
+        let event: PlayEvent = serde_json::from_str(&json).unwrap();
+        assert_no_unmapped_fields::<PlayEvent>(&json);
         assert_eq!(event.event_id, 135);
         assert_eq!(event.type_desc_key, PlayEventType::Penalty);
 
         let details = event.details.unwrap();
-        assert_eq!(details.type_code, Some("MIN".to_string()));
-        assert_eq!(details.desc_key, Some("slashing".to_string()));
+        assert_eq!(details.type_code, Some(PenaltySeverity::Minor));
+        assert_eq!(details.desc_key, Some(PenaltyDescKey::from("slashing")));
         assert_eq!(details.duration, Some(2));
         assert_eq!(details.committed_by_player_id, Some(PlayerId::new(8475287)));
         assert_eq!(details.drawn_by_player_id, Some(PlayerId::new(8479420)));
@@ -1058,34 +2490,10 @@ mod tests {
 
     #[test]
     fn test_play_event_shot_deserialization() {
-        let json = r#"{
-            "eventId": 103,
-            "periodDescriptor": {
-                "number": 1,
-                "periodType": "REG",
-                "maxRegulationPeriods": 3
-            },
-            "timeInPeriod": "00:08",
-            "timeRemaining": "19:52",
-            "situationCode": "1551",
-            "homeTeamDefendingSide": "right",
-            "typeCode": 506,
-            "typeDescKey": "shot-on-goal",
-            "sortOrder": 13,
-            "details": {
-                "xCoord": 56,
-                "yCoord": -39,
-                "zoneCode": "O",
-                "shotType": "wrist",
-                "shootingPlayerId": 8483495,
-                "goalieInNetId": 8480045,
-                "eventOwnerTeamId": 1,
-                "awaySOG": 1,
-                "homeSOG": 0
-            }
-        }"#;
+        let json = fixture_play(103);
 
-        let event: PlayEvent = serde_json::from_str(json).unwrap();
+        let event: PlayEvent = serde_json::from_str(&json).unwrap();
+        assert_no_unmapped_fields::<PlayEvent>(&json);
         assert_eq!(event.event_id, 103);
         assert_eq!(event.type_desc_key, PlayEventType::ShotOnGoal);
 
@@ -1099,31 +2507,10 @@ mod tests {
 
     #[test]
     fn test_play_event_faceoff_deserialization() {
-        let json = r#"{
-            "eventId": 151,
-            "periodDescriptor": {
-                "number": 1,
-                "periodType": "REG",
-                "maxRegulationPeriods": 3
-            },
-            "timeInPeriod": "00:00",
-            "timeRemaining": "20:00",
-            "situationCode": "1551",
-            "homeTeamDefendingSide": "right",
-            "typeCode": 502,
-            "typeDescKey": "faceoff",
-            "sortOrder": 11,
-            "details": {
-                "eventOwnerTeamId": 1,
-                "losingPlayerId": 8478043,
-                "winningPlayerId": 8480002,
-                "xCoord": 0,
-                "yCoord": 0,
-                "zoneCode": "N"
-            }
-        }"#;
+        let json = fixture_play(151);
 
-        let event: PlayEvent = serde_json::from_str(json).unwrap();
+        let event: PlayEvent = serde_json::from_str(&json).unwrap();
+        assert_no_unmapped_fields::<PlayEvent>(&json);
         assert_eq!(event.event_id, 151);
         assert_eq!(event.type_desc_key, PlayEventType::Faceoff);
 
@@ -1200,6 +2587,44 @@ mod tests {
         assert_eq!(event.home_team_defending_side, None);
     }
 
+    /// Every play of the fixture games decodes on its own, with the defending
+    /// side and zone matching the raw strings.
+    #[test]
+    fn test_fixture_plays_decode_defending_side_and_zone() {
+        for fixture in [
+            PLAY_BY_PLAY_FIXTURE,
+            include_str!("../../tests/fixtures/play_by_play_sog_blip.json"),
+        ] {
+            let json: serde_json::Value = serde_json::from_str(fixture).unwrap();
+            for raw in json["plays"].as_array().unwrap() {
+                let event: PlayEvent = serde_json::from_value(raw.clone())
+                    .unwrap_or_else(|e| panic!("play {} failed: {}", raw["eventId"], e));
+
+                let raw_side = raw["homeTeamDefendingSide"].as_str();
+                assert_eq!(
+                    event
+                        .home_team_defending_side
+                        .as_ref()
+                        .map(|side| side.code()),
+                    raw_side,
+                    "play {}",
+                    event.event_id
+                );
+                assert_eq!(
+                    event
+                        .details
+                        .as_ref()
+                        .and_then(|d| d.zone_code.as_ref())
+                        .map(|zone| zone.code()),
+                    raw["details"]["zoneCode"].as_str(),
+                    "play {}",
+                    event.event_id
+                );
+            }
+            serde_json::from_str::<PlayByPlay>(fixture).unwrap();
+        }
+    }
+
     /// Builds a minimal, valid `GoalSummary` JSON fixture with the given
     /// `homeTeamDefendingSide` fragment spliced in (e.g. `"\"left\""`,
     /// `"\"\""`, or `""` to omit the key entirely).
@@ -1231,6 +2656,7 @@ mod tests {
     fn test_goal_summary_real_defending_side() {
         let json = goal_summary_json_with_defending_side(r#""homeTeamDefendingSide": "left","#);
         let goal: GoalSummary = serde_json::from_str(&json).unwrap();
+        assert_no_unmapped_fields::<GoalSummary>(&json);
         assert_eq!(goal.home_team_defending_side, Some(DefendingSide::Left));
     }
 
@@ -1305,14 +2731,38 @@ mod tests {
         }"#;
 
         let roster_spot: RosterSpot = serde_json::from_str(json).unwrap();
+        assert_no_unmapped_fields::<RosterSpot>(json);
         assert_eq!(roster_spot.team_id, TeamId::new(1));
         assert_eq!(roster_spot.player_id, PlayerId::new(8474593));
         assert_eq!(roster_spot.first_name.default, "Jacob");
         assert_eq!(roster_spot.last_name.default, "Markstrom");
-        assert_eq!(roster_spot.sweater_number, 25);
+        assert_eq!(roster_spot.sweater_number, Some(25));
         assert_eq!(roster_spot.position, Some(Position::Goalie));
     }
 
+    #[test]
+    fn test_roster_spot_and_assist_without_sweater_number() {
+        for field in ["", r#""sweaterNumber": null, "#] {
+            let spot: RosterSpot = serde_json::from_str(&format!(
+                r#"{{"teamId": 1, "playerId": 8482823, "firstName": {{"default": "Scott"}},
+                    "lastName": {{"default": "Ebug"}}, {}"positionCode": "G",
+                    "headshot": ""}}"#,
+                field
+            ))
+            .unwrap();
+            assert_eq!(spot.sweater_number, None);
+
+            let assist: AssistSummary = serde_json::from_str(&format!(
+                r#"{{"playerId": 8482823, "firstName": {{"default": "Scott"}},
+                    "lastName": {{"default": "Ebug"}}, "name": {{"default": "S. Ebug"}},
+                    {}"assistsToDate": 1}}"#,
+                field
+            ))
+            .unwrap();
+            assert_eq!(assist.sweater_number, None);
+        }
+    }
+
     /// Historical roster spots (e.g. 1988 BOS) return an empty position code.
     #[test]
     fn test_roster_spot_empty_position() {
@@ -1368,6 +2818,7 @@ mod tests {
     fn test_game_outcome_deserialization() {
         let json = r#"{"lastPeriodType": "REG"}"#;
         let outcome: GameOutcome = serde_json::from_str(json).unwrap();
+        assert_no_unmapped_fields::<GameOutcome>(json);
         assert_eq!(outcome.last_period_type, Some(PeriodType::Regulation));
     }
 
@@ -1418,12 +2869,13 @@ mod tests {
         }"##;
 
         let shift: ShiftEntry = serde_json::from_str(json).unwrap();
+        assert_no_unmapped_fields::<ShiftEntry>(json);
         assert_eq!(shift.id, 14376602);
         assert_eq!(shift.detail_code, 0);
-        assert_eq!(shift.duration, "17:15");
-        assert_eq!(shift.end_time, "17:15");
+        assert_eq!(shift.duration, "17:15".parse::<IceTime>().unwrap());
+        assert_eq!(shift.end_time, "17:15".parse::<IceTime>().unwrap());
         assert_eq!(shift.event_description, None);
-        assert_eq!(shift.event_number, 101);
+        assert_eq!(shift.event_number, Some(101));
         assert_eq!(shift.first_name, "Jacob");
         assert_eq!(shift.game_id, GameId::new(2024020001));
         assert_eq!(shift.hex_value, "#C8102E");
@@ -1431,13 +2883,33 @@ mod tests {
         assert_eq!(shift.period, 1);
         assert_eq!(shift.player_id, PlayerId::new(8474593));
         assert_eq!(shift.shift_number, 1);
-        assert_eq!(shift.start_time, "00:00");
+        assert_eq!(shift.start_time, "00:00".parse::<IceTime>().unwrap());
         assert_eq!(shift.team_abbrev, "NJD");
         assert_eq!(shift.team_id, TeamId::new(1));
         assert_eq!(shift.team_name, "New Jersey Devils");
         assert_eq!(shift.type_code, 517);
     }
 
+    #[test]
+    fn test_shift_entry_event_number_string_and_null() {
+        let shift = |event_number: &str| -> ShiftEntry {
+            let json = format!(
+                r##"{{
+                    "id": 1, "detailCode": 0, "duration": "00:45", "endTime": "00:45",
+                    "eventNumber": {}, "firstName": "A", "gameId": 2009020001,
+                    "hexValue": "#000000", "lastName": "B", "period": 1, "playerId": 8470000,
+                    "shiftNumber": 1, "startTime": "00:00", "teamAbbrev": "TOR", "teamId": 10,
+                    "teamName": "Toronto Maple Leafs", "typeCode": 517
+                }}"##,
+                event_number
+            );
+            serde_json::from_str(&json).unwrap()
+        };
+
+        assert_eq!(shift(r#""42""#).event_number, Some(42));
+        assert_eq!(shift("null").event_number, None);
+    }
+
     #[test]
     fn test_shift_chart_deserialization() {
         let json = r##"{
@@ -1466,13 +2938,130 @@ mod tests {
         }"##;
 
         let chart: ShiftChart = serde_json::from_str(json).unwrap();
+        assert_no_unmapped_fields::<ShiftChart>(json);
         assert_eq!(chart.data.len(), 1);
         assert_eq!(chart.data[0].player_id, PlayerId::new(8474593));
         assert_eq!(chart.data[0].first_name, "Jacob");
         assert_eq!(chart.data[0].last_name, "Markstrom");
     }
 
-    #[test]
+    /// A shift (or, with another `type_code`, an event row) for a synthetic
+    /// chart; `null` duration like real goal rows.
+    fn shift_row(player_id: i64, period: i32, start: &str, end: &str, type_code: i32) -> String {
+        format!(
+            r##"{{"id": 1, "detailCode": 0, "duration": null, "endTime": "{}",
+                "eventNumber": null, "firstName": "", "gameId": 2024020001,
+                "hexValue": "#000000", "lastName": "", "period": {}, "playerId": {},
+                "shiftNumber": 1, "startTime": "{}", "teamAbbrev": "NJD", "teamId": 1,
+                "teamName": "New Jersey Devils", "typeCode": {}}}"##,
+            end, period, player_id, start, type_code
+        )
+    }
+
+    fn synthetic_chart(rows: &[String]) -> ShiftChart {
+        let mut chart: ShiftChart =
+            serde_json::from_str(&format!(r#"{{"data": [{}]}}"#, rows.join(","))).unwrap();
+        for entry in &mut chart.data {
+            if entry.is_shift() {
+                entry.duration = IceTime::from_seconds(
+                    entry.end_time.as_seconds() - entry.start_time.as_seconds(),
+                );
+            }
+        }
+        chart
+    }
+
+    #[test]
+    fn test_shift_chart_two_player_analysis() {
+        let chart = synthetic_chart(&[
+            shift_row(1, 1, "00:00", "00:50", 517),
+            shift_row(1, 1, "00:30", "00:30", 505),
+            shift_row(2, 1, "00:20", "01:00", 517),
+            // Ends at the period break, right where player 2's next shift
+            // starts: no time together.
+            shift_row(1, 1, "19:30", "20:00", 517),
+            shift_row(2, 2, "00:00", "00:40", 517),
+            shift_row(1, 2, "00:10", "00:30", 517),
+        ]);
+
+        assert_eq!(chart.data[1].duration, IceTime::ZERO);
+        let toi = chart.toi_by_player();
+        assert_eq!(toi[&PlayerId::new(1)], IceTime::from_seconds(100));
+        assert_eq!(toi[&PlayerId::new(2)], IceTime::from_seconds(80));
+        assert_eq!(chart.shifts_for_player(1).len(), 3);
+        assert_eq!(chart.shifts_in_period(2).len(), 2);
+        assert_eq!(chart.data[4].game_seconds(), (1200, 1240));
+
+        let combinations = chart.line_combinations(0);
+        assert_eq!(
+            combinations,
+            vec![LineCombination {
+                team_id: TeamId::new(1),
+                players: vec![PlayerId::new(1), PlayerId::new(2)],
+                time_together: IceTime::from_seconds(50),
+            }]
+        );
+        assert!(chart.line_combinations(51).is_empty());
+    }
+
+    #[test]
+    fn test_shift_chart_fixture_toi_matches_boxscore() {
+        let chart: ShiftChart =
+            serde_json::from_str(include_str!("../../tests/fixtures/shift_chart.json")).unwrap();
+        let boxscore: Boxscore =
+            serde_json::from_str(include_str!("../../tests/fixtures/boxscore.json")).unwrap();
+        let toi = chart.toi_by_player();
+
+        assert_eq!(chart.shifts().count(), chart.data.len() - 1);
+        for team in [
+            &boxscore.player_by_game_stats.away_team,
+            &boxscore.player_by_game_stats.home_team,
+        ] {
+            let skaters = team.forwards.iter().chain(&team.defense);
+            let players = skaters.map(|skater| (skater.player_id, skater.toi)).chain(
+                team.goalies
+                    .iter()
+                    .map(|goalie| (goalie.player_id, goalie.toi)),
+            );
+            for (player_id, boxscore_toi) in players {
+                let shift_toi = toi[&player_id].as_seconds();
+                assert!(
+                    shift_toi.abs_diff(boxscore_toi.as_seconds()) <= 2,
+                    "{}: {} from shifts, {} in the boxscore",
+                    player_id,
+                    shift_toi,
+                    boxscore_toi
+                );
+            }
+        }
+    }
+
+    #[test]
+    fn test_shift_chart_fixture_line_combinations() {
+        let chart: ShiftChart =
+            serde_json::from_str(include_str!("../../tests/fixtures/shift_chart.json")).unwrap();
+        let goalies = [PlayerId::new(8474593), PlayerId::new(8480045)];
+
+        let combinations = chart.line_combinations(60);
+        let top_devils = combinations
+            .iter()
+            .filter(|combination| combination.team_id == TeamId::new(1))
+            .find(|combination| !goalies.iter().any(|goalie| combination.contains(*goalie)))
+            .unwrap();
+
+        assert_eq!(
+            top_devils.players,
+            vec![PlayerId::new(8479407), PlayerId::new(8481559)]
+        );
+        assert!(combinations
+            .windows(2)
+            .all(|pair| pair[0].time_together >= pair[1].time_together));
+        assert!(combinations
+            .iter()
+            .all(|combination| combination.time_together.as_seconds() >= 60));
+    }
+
+    #[test]
     fn test_play_event_blocked_shot_deserialization() {
         let json = r#"{
             "eventId": 63,
@@ -1500,6 +3089,7 @@ mod tests {
         }"#;
 
         let event: PlayEvent = serde_json::from_str(json).unwrap();
+        assert_no_unmapped_fields::<PlayEvent>(json);
         assert_eq!(event.type_desc_key, PlayEventType::BlockedShot);
 
         let details = event.details.unwrap();
@@ -1541,6 +3131,73 @@ mod tests {
         assert!(!PlayEventType::Goal.is_period_boundary());
     }
 
+    #[test]
+    fn test_play_event_type_codes_round_trip() {
+        for (event_type, desc_key, code) in [
+            (PlayEventType::Faceoff, "faceoff", 502),
+            (PlayEventType::Hit, "hit", 503),
+            (PlayEventType::Giveaway, "giveaway", 504),
+            (PlayEventType::Goal, "goal", 505),
+            (PlayEventType::ShotOnGoal, "shot-on-goal", 506),
+            (PlayEventType::MissedShot, "missed-shot", 507),
+            (PlayEventType::BlockedShot, "blocked-shot", 508),
+            (PlayEventType::Penalty, "penalty", 509),
+            (PlayEventType::Stoppage, "stoppage", 516),
+            (PlayEventType::PeriodStart, "period-start", 520),
+            (PlayEventType::PeriodEnd, "period-end", 521),
+            (PlayEventType::ShootoutComplete, "shootout-complete", 523),
+            (PlayEventType::GameEnd, "game-end", 524),
+            (PlayEventType::Takeaway, "takeaway", 525),
+            (PlayEventType::DelayedPenalty, "delayed-penalty", 535),
+            (PlayEventType::FailedShotAttempt, "failed-shot-attempt", 537),
+        ] {
+            assert_eq!(event_type.code(), Some(code));
+            assert_eq!(PlayEventType::from_code(code), Some(event_type));
+            assert_eq!(
+                serde_json::to_string(&event_type).unwrap(),
+                format!("\"{}\"", desc_key)
+            );
+        }
+        assert_eq!(PlayEventType::GameStart.code(), None);
+        assert_eq!(PlayEventType::Unknown.code(), None);
+        assert_eq!(PlayEventType::from_code(999), None);
+    }
+
+    fn event_with(type_code: i32, type_desc_key: &str) -> PlayEvent {
+        serde_json::from_str(&format!(
+            r#"{{"eventId": 1, "periodDescriptor": {{"number": 1, "periodType": "REG"}},
+                "timeInPeriod": "01:00", "timeRemaining": "19:00", "situationCode": "1551",
+                "typeCode": {}, "typeDescKey": "{}", "sortOrder": 10}}"#,
+            type_code, type_desc_key
+        ))
+        .unwrap()
+    }
+
+    #[test]
+    fn test_play_event_event_type_falls_back_to_code() {
+        assert_eq!(event_with(505, "goal").event_type(), PlayEventType::Goal);
+        // An unrecognized desc key with a known code still resolves.
+        assert_eq!(
+            event_with(509, "penalty-v2").event_type(),
+            PlayEventType::Penalty
+        );
+
+        let unknown = event_with(999, "new-event");
+        assert_eq!(unknown.event_type(), PlayEventType::Unknown);
+        assert_eq!(unknown.type_code, 999);
+    }
+
+    #[test]
+    fn test_play_event_predicates() {
+        assert!(event_with(505, "goal").is_goal());
+        assert!(event_with(505, "goal").is_shot_attempt());
+        assert!(event_with(508, "blocked-shot").is_shot_attempt());
+        assert!(!event_with(503, "hit").is_shot_attempt());
+        assert!(event_with(509, "penalty").is_penalty());
+        assert!(!event_with(535, "delayed-penalty").is_penalty());
+        assert!(!event_with(999, "new-event").is_goal());
+    }
+
     #[test]
     fn test_play_event_type_display() {
         assert_eq!(format!("{}", PlayEventType::GameStart), "Game Start");
@@ -1720,6 +3377,7 @@ mod tests {
         }"#;
 
         let matchup: SeasonSeriesMatchup = serde_json::from_str(json).unwrap();
+        assert_no_unmapped_fields::<SeasonSeriesMatchup>(json);
         assert_eq!(matchup.season_series.len(), 2);
 
         let played = &matchup.season_series[0];
@@ -1738,66 +3396,830 @@ mod tests {
         assert_eq!(unplayed.game_outcome.last_period_type, None);
     }
 
-    /// Minimal fields required to deserialize a `PlayByPlay`, with an
-    /// optional trailing `regPeriods` fragment appended by the caller.
-    fn play_by_play_json(reg_periods_fragment: &str) -> String {
+    /// Minimal landing payload in `game_state`, with an optional trailing
+    /// fragment appended by the caller.
+    fn game_matchup_json(game_state: &str, extra_fragment: &str) -> String {
         format!(
             r#"{{
-                "id": 2024020444,
-                "season": 20242025,
-                "gameType": 2,
-                "limitedScoring": false,
-                "gameDate": "2024-11-01",
-                "venue": {{"default": "Test Arena"}},
-                "venueLocation": {{"default": "Test City"}},
-                "startTimeUTC": "2024-11-01T19:00:00Z",
-                "easternUTCOffset": "-04:00",
-                "venueUTCOffset": "-04:00",
-                "gameState": "FINAL",
-                "gameScheduleState": "OK",
+                "id": 2024020500, "season": 20242025, "gameType": 2,
+                "limitedScoring": false, "gameDate": "2024-12-20",
+                "venue": {{"default": "Rogers Place"}},
+                "venueLocation": {{"default": "Edmonton"}},
+                "startTimeUTC": "2024-12-21T02:00:00Z",
+                "easternUTCOffset": "-05:00", "venueUTCOffset": "-07:00",
+                "venueTimezone": "America/Edmonton",
                 "periodDescriptor": {{}},
+                "gameState": "{}", "gameScheduleState": "OK",
                 "awayTeam": {{
-                    "id": 1,
-                    "commonName": {{"default": "Devils"}},
-                    "abbrev": "NJD",
-                    "score": 2,
-                    "sog": 15,
-                    "logo": "https://example.com/njd_light.svg",
-                    "darkLogo": "https://example.com/njd_dark.svg",
-                    "placeName": {{"default": "New Jersey"}},
-                    "placeNameWithPreposition": {{"default": "New Jersey"}}
+                    "id": 13, "commonName": {{"default": "Panthers"}}, "abbrev": "FLA",
+                    "placeName": {{"default": "Florida"}},
+                    "placeNameWithPreposition": {{"default": "Florida"}},
+                    "score": 1, "sog": 12, "logo": "", "darkLogo": ""
                 }},
                 "homeTeam": {{
-                    "id": 7,
-                    "commonName": {{"default": "Sabres"}},
-                    "abbrev": "BUF",
-                    "score": 1,
-                    "sog": 12,
-                    "logo": "https://example.com/buf_light.svg",
-                    "darkLogo": "https://example.com/buf_dark.svg",
-                    "placeName": {{"default": "Buffalo"}},
-                    "placeNameWithPreposition": {{"default": "Buffalo"}}
+                    "id": 22, "commonName": {{"default": "Oilers"}}, "abbrev": "EDM",
+                    "placeName": {{"default": "Edmonton"}},
+                    "placeNameWithPreposition": {{"default": "Edmonton"}},
+                    "score": 2, "sog": 15, "logo": "", "darkLogo": ""
                 }},
-                "shootoutInUse": false,
-                "otInUse": false,
-                "clock": {{
-                    "timeRemaining": "00:00",
-                    "secondsRemaining": 0,
-                    "running": false,
-                    "inIntermission": false
-                }},
-                "displayPeriod": 3,
-                "maxPeriods": 3
-                {reg_periods_fragment}
-            }}"#
+                "shootoutInUse": true, "maxPeriods": 5, "otInUse": true,
+                "tiesInUse": false{}
+            }}"#,
+            game_state, extra_fragment
         )
     }
 
+    const FIVE_ON_FOUR_SITUATION: &str = r#", "situation": {
+        "homeTeam": {
+            "abbrev": "EDM", "strength": 5, "situationDescriptions": ["PP"],
+            "onIce": [
+                {"playerId": 8478402, "name": {"default": "C. McDavid"}, "sweaterNumber": 97},
+                {"playerId": 8477934, "name": {"default": "L. Draisaitl"}, "sweaterNumber": 29}
+            ]
+        },
+        "awayTeam": {
+            "abbrev": "FLA", "strength": 4,
+            "onIce": [
+                {"playerId": 8477493, "name": {"default": "A. Barkov"}, "sweaterNumber": 16}
+            ],
+            "penaltyBox": [
+                {"playerId": 8477935, "name": {"default": "S. Reinhart"},
+                 "sweaterNumber": 13, "secondsRemaining": 84}
+            ]
+        },
+        "situationCode": "1451",
+        "timeRemaining": "01:24",
+        "secondsRemaining": 84
+    }"#;
+
+    #[test]
+    fn test_game_matchup_live_five_on_four_situation() {
+        let json = game_matchup_json("LIVE", FIVE_ON_FOUR_SITUATION);
+        let matchup: GameMatchup = serde_json::from_str(&json).unwrap();
+        assert_no_unmapped_fields::<GameMatchup>(&json);
+        let situation = matchup.situation.expect("live game carries a situation");
+
+        assert_eq!(situation.power_play_team(), Some(HomeRoad::Home));
+        assert_eq!(situation.strength().as_deref(), Some("5v4"));
+        assert_eq!(situation.seconds_until_even_strength(), Some(84));
+        assert_eq!(situation.situation_code.as_deref(), Some("1451"));
+        assert_eq!(situation.home_team.on_ice[0].sweater_number, 97);
+        assert_eq!(
+            situation.away_team.penalty_box[0].player_id,
+            PlayerId::new(8477935)
+        );
+        assert_eq!(situation.home_team.situation_descriptions, vec!["PP"]);
+    }
+
+    #[test]
+    fn test_game_matchup_final_has_no_situation() {
+        let matchup: GameMatchup = serde_json::from_str(&game_matchup_json("OFF", "")).unwrap();
+        assert!(matchup.situation.is_none());
+        let serialized = serde_json::to_string(&matchup).unwrap();
+        assert!(!serialized.contains("situation"));
+    }
+
+    #[test]
+    fn test_game_matchup_limited_scoring_has_no_sog() {
+        let json = game_matchup_json("OFF", "").replace(r#""sog": 12, "#, "");
+        let matchup: GameMatchup = serde_json::from_str(&json).unwrap();
+        assert_eq!(matchup.away_team.sog, None);
+        assert_eq!(matchup.home_team.sog, Some(15));
+    }
+
+    fn situation_team(abbrev: &str, strength: i32, penalties: &[i32]) -> SituationTeam {
+        SituationTeam {
+            abbrev: abbrev.to_string(),
+            strength: Some(strength),
+            situation_descriptions: Vec::new(),
+            on_ice: Vec::new(),
+            penalty_box: penalties
+                .iter()
+                .map(|&seconds_remaining| PenaltyBoxEntry {
+                    player_id: PlayerId::new(1),
+                    name: LocalizedString::default(),
+                    sweater_number: 0,
+                    seconds_remaining,
+                })
+                .collect(),
+        }
+    }
+
+    fn situation(away: SituationTeam, home: SituationTeam) -> OnIceSituation {
+        OnIceSituation {
+            away_team: away,
+            home_team: home,
+            situation_code: None,
+            time_remaining: None,
+            seconds_remaining: None,
+        }
+    }
+
+    #[test]
+    fn test_on_ice_situation_five_on_three_waits_for_second_penalty() {
+        let situation = situation(
+            situation_team("FLA", 5, &[]),
+            situation_team("EDM", 3, &[95, 30]),
+        );
+        assert_eq!(situation.power_play_team(), Some(HomeRoad::Road));
+        assert_eq!(situation.strength().as_deref(), Some("5v3"));
+        assert_eq!(situation.seconds_until_even_strength(), Some(95));
+    }
+
+    #[test]
+    fn test_on_ice_situation_even_strength() {
+        let situation = situation(
+            situation_team("FLA", 4, &[40]),
+            situation_team("EDM", 4, &[40]),
+        );
+        assert_eq!(situation.power_play_team(), None);
+        assert_eq!(situation.strength().as_deref(), Some("4v4"));
+        assert_eq!(situation.seconds_until_even_strength(), None);
+    }
+
+    #[test]
+    fn test_on_ice_situation_falls_back_to_seconds_remaining() {
+        let mut situation = situation(situation_team("FLA", 4, &[]), situation_team("EDM", 5, &[]));
+        situation.seconds_remaining = Some(61);
+        assert_eq!(situation.seconds_until_even_strength(), Some(61));
+    }
+
+    /// One right-rail series game between EDM (id 22) and FLA (id 13).
+    /// `edm_home` alternates venue; scores are `(edm, fla)`.
+    fn series_game_json(
+        game_type: i32,
+        number: i64,
+        state: &str,
+        edm_home: bool,
+        edm: i32,
+        fla: i32,
+    ) -> String {
+        let edm_team = format!(
+            r#"{{"id": 22, "abbrev": "EDM", "logo": "", "score": {}}}"#,
+            edm
+        );
+        let fla_team = format!(
+            r#"{{"id": 13, "abbrev": "FLA", "logo": "", "score": {}}}"#,
+            fla
+        );
+        let (away, home) = if edm_home {
+            (fla_team, edm_team)
+        } else {
+            (edm_team, fla_team)
+        };
+        format!(
+            r#"{{
+                "id": {id},
+                "season": 20232024,
+                "gameType": {game_type},
+                "gameDate": "2024-06-01",
+                "startTimeUTC": "2024-06-01T00:00:00Z",
+                "easternUTCOffset": "-04:00",
+                "venueUTCOffset": "-04:00",
+                "gameState": "{state}",
+                "gameScheduleState": "OK",
+                "awayTeam": {away},
+                "homeTeam": {home},
+                "periodDescriptor": {{}},
+                "gameCenterLink": "",
+                "gameOutcome": {{}}
+            }}"#,
+            id = 2023030400 + number,
+            game_type = game_type,
+            state = state,
+            away = away,
+            home = home
+        )
+    }
+
+    fn series_matchup(games: &[String]) -> SeasonSeriesMatchup {
+        let json = format!(
+            r#"{{
+                "seasonSeries": [{}],
+                "seasonSeriesWins": {{"awayTeamWins": 0, "homeTeamWins": 0}},
+                "gameInfo": {{
+                    "referees": [], "linesmen": [],
+                    "awayTeam": {{"headCoach": {{"default": "A"}}, "scratches": []}},
+                    "homeTeam": {{"headCoach": {{"default": "B"}}, "scratches": []}}
+                }}
+            }}"#,
+            games.join(",")
+        );
+        serde_json::from_str(&json).unwrap()
+    }
+
+    #[test]
+    fn test_season_series_playoff_three_two() {
+        let matchup = series_matchup(&[
+            series_game_json(3, 1, "OFF", false, 3, 1),
+            series_game_json(3, 2, "OFF", false, 1, 4),
+            series_game_json(3, 3, "OFF", true, 5, 2),
+            series_game_json(3, 4, "OFF", true, 2, 3),
+            series_game_json(3, 5, "FINAL", false, 4, 3),
+            series_game_json(3, 6, "FUT", true, 0, 0),
+        ]);
+
+        assert!(matchup.is_playoff_series());
+        assert_eq!(
+            matchup.series_score_string().as_deref(),
+            Some("EDM leads 3-2")
+        );
+        let match_point = matchup.match_point_for().unwrap();
+        assert_eq!(match_point.abbrev, "EDM");
+        assert_eq!(match_point.wins, 3);
+        assert!(matchup.eliminated().is_none());
+        assert_eq!(matchup.games_remaining_max(), Some(2));
+    }
+
+    #[test]
+    fn test_season_series_playoff_sweep() {
+        let matchup = series_matchup(&[
+            series_game_json(3, 1, "OFF", true, 2, 1),
+            series_game_json(3, 2, "OFF", true, 4, 0),
+            series_game_json(3, 3, "OFF", false, 3, 2),
+            series_game_json(3, 4, "OFF", false, 5, 1),
+        ]);
+
+        assert_eq!(
+            matchup.series_score_string().as_deref(),
+            Some("EDM wins 4-0")
+        );
+        assert_eq!(matchup.eliminated().unwrap().abbrev, "FLA");
+        assert!(matchup.match_point_for().is_none());
+        assert_eq!(matchup.games_remaining_max(), Some(0));
+    }
+
+    #[test]
+    fn test_season_series_playoff_tied_three_three() {
+        let mut games: Vec<String> = (1..=6)
+            .map(|n| {
+                let edm_wins = n % 2 == 1;
+                let (edm, fla) = if edm_wins { (2, 1) } else { (1, 2) };
+                series_game_json(3, n, "OFF", n % 2 == 0, edm, fla)
+            })
+            .collect();
+        games.push(series_game_json(3, 7, "FUT", true, 0, 0));
+        let matchup = series_matchup(&games);
+
+        assert_eq!(
+            matchup.series_score_string().as_deref(),
+            Some("Series tied 3-3")
+        );
+        assert!(matchup.match_point_for().is_none());
+        assert_eq!(matchup.games_remaining_max(), Some(1));
+    }
+
+    #[test]
+    fn test_season_series_regular_season_has_no_playoff_status() {
+        let matchup = series_matchup(&[
+            series_game_json(2, 1, "OFF", true, 3, 2),
+            series_game_json(2, 2, "FUT", false, 0, 0),
+        ]);
+
+        assert!(!matchup.is_playoff_series());
+        assert!(matchup.series_score_string().is_none());
+        assert!(matchup.match_point_for().is_none());
+        assert!(matchup.eliminated().is_none());
+        assert!(matchup.games_remaining_max().is_none());
+        // Records are still tallied for regular-season series, away side first.
+        let [fla, edm] = matchup.series_records().unwrap();
+        assert_eq!((edm.abbrev.as_str(), edm.wins), ("EDM", 1));
+        assert_eq!((fla.abbrev.as_str(), fla.wins), ("FLA", 0));
+    }
+
+    /// [`PLAY_BY_PLAY_FIXTURE`] with the top-level fields in `extra_fragment`
+    /// (`, "key": value, ...`) added or replaced.
+    fn play_by_play_json(extra_fragment: &str) -> String {
+        let mut json: serde_json::Value = serde_json::from_str(PLAY_BY_PLAY_FIXTURE).unwrap();
+        let extra: serde_json::Value = serde_json::from_str(&format!(
+            "{{{}}}",
+            extra_fragment.trim_start().trim_start_matches(',')
+        ))
+        .unwrap();
+        json.as_object_mut()
+            .unwrap()
+            .extend(extra.as_object().unwrap().clone());
+        json.to_string()
+    }
+
     #[test]
     fn test_play_by_play_missing_reg_periods_defaults_to_zero() {
         let json = play_by_play_json("");
         let pbp: PlayByPlay = serde_json::from_str(&json).unwrap();
+        assert_no_unmapped_fields::<PlayByPlay>(&json);
         assert_eq!(pbp.reg_periods, 0);
+        assert_eq!(pbp.plays.len(), 6);
+        assert_eq!(pbp.goals().len(), 1);
+        assert_eq!(pbp.penalties().len(), 1);
+        assert_eq!(pbp.team_roster(7).len(), 2);
+    }
+
+    #[test]
+    fn test_play_event_normalized_coords_from_fixture() {
+        let pbp: PlayByPlay = serde_json::from_str(PLAY_BY_PLAY_FIXTURE).unwrap();
+        let (njd, buf) = (TeamId::new(1), TeamId::new(7));
+        let play = |event_id: i64| pbp.plays.iter().find(|p| p.event_id == event_id).unwrap();
+
+        // Buffalo defends the right end in period 1, so New Jersey attacks +x.
+        assert_eq!(play(103).normalized_coords(buf, njd), Some((56.0, -39.0)));
+        assert_eq!(play(121).normalized_coords(buf, buf), Some((61.0, -18.0)));
+        // The blocked shot is normalized for the shooter's team, not the
+        // blocker's (its eventOwnerTeamId).
+        let blocked = play(188);
+        let shooter = pbp.shooting_team_id(blocked).unwrap();
+        assert_eq!(blocked.normalized_coords(buf, shooter), Some((68.0, 6.0)));
+        let (x, y) = blocked.normalized_coords(buf, shooter).unwrap();
+        assert!(rink::distance_to_net(x, y) < 25.0);
+    }
+
+    /// The fixture's period 1 wrister, repeated from the mirrored spot in
+    /// period 2 after the teams change ends, normalizes to the same point.
+    #[test]
+    fn test_play_event_normalized_coords_same_spot_next_period() {
+        let first: PlayEvent = serde_json::from_str(&fixture_play(103)).unwrap();
+        let mut second = first.clone();
+        second.period_descriptor.number = 2;
+        second.home_team_defending_side = Some(DefendingSide::Left);
+        let details = second.details.as_mut().unwrap();
+        details.x_coord = Some(-56);
+        details.y_coord = Some(39);
+
+        let normalized = first.normalized_coords(7, 1);
+        assert_eq!(normalized, Some((56.0, -39.0)));
+        assert_eq!(second.normalized_coords(7, 1), normalized);
+        assert_eq!(second.details.unwrap().shot_type.as_deref(), Some("wrist"));
+    }
+
+    #[test]
+    fn test_play_event_normalized_coords_missing_data() {
+        let play: PlayEvent = serde_json::from_str(&fixture_play(103)).unwrap();
+
+        let mut no_coords = play.clone();
+        no_coords.details.as_mut().unwrap().x_coord = None;
+        assert_eq!(no_coords.normalized_coords(7, 1), None);
+
+        let mut no_side = play.clone();
+        no_side.home_team_defending_side = None;
+        assert_eq!(no_side.normalized_coords(7, 1), None);
+
+        let mut shootout = play;
+        shootout.period_descriptor.number = 5;
+        shootout.period_descriptor.period_type = Some(PeriodType::Shootout);
+        assert_eq!(shootout.normalized_coords(7, 1), None);
+    }
+
+    #[test]
+    fn test_play_by_play_team_stats_from_fixture() {
+        let pbp: PlayByPlay = serde_json::from_str(PLAY_BY_PLAY_FIXTURE).unwrap();
+
+        let away = pbp.team_stats(HomeRoad::Road);
+        assert_eq!(away, TeamGameStats::from_play_by_play(&pbp, HomeRoad::Road));
+        assert_eq!(away.shots_on_goal, 2);
+        assert_eq!(away.faceoff_wins, Some(1));
+        assert_eq!(away.faceoff_total, Some(1));
+        assert_eq!(away.penalty_minutes, 2);
+        assert_eq!(away.blocked_shots, 0);
+        assert_eq!(away.power_play_opportunities, Some(0));
+
+        let home = pbp.team_stats(HomeRoad::Home);
+        assert_eq!(home.shots_on_goal, 0);
+        assert_eq!(home.faceoff_wins, Some(0));
+        assert_eq!(home.penalty_minutes, 0);
+        // Greenway blocked Noesen's shot.
+        assert_eq!(home.blocked_shots, 1);
+        assert_eq!(home.power_play_opportunities, Some(1));
+    }
+
+    #[test]
+    fn test_play_by_play_player_event_counts_from_fixture() {
+        let pbp: PlayByPlay = serde_json::from_str(PLAY_BY_PLAY_FIXTURE).unwrap();
+        let counts = pbp.player_event_counts();
+
+        let noesen = counts[&PlayerId::new(8476474)];
+        assert_eq!(noesen.goals, 1);
+        assert_eq!(noesen.shots, 1);
+        assert_eq!(counts[&PlayerId::new(8480192)].assists, 1);
+        assert_eq!(counts[&PlayerId::new(8483495)].shots, 1);
+        assert_eq!(counts[&PlayerId::new(8480002)].faceoff_wins, 1);
+
+        let greenway = counts[&PlayerId::new(8478043)];
+        assert_eq!(greenway.faceoff_losses, 1);
+        assert_eq!(greenway.blocked_shots, 1);
+        // A missed shot is not a shot on goal.
+        assert_eq!(greenway.shots, 0);
+        // Goalies in net and penalty takers aren't counted.
+        assert!(!counts.contains_key(&PlayerId::new(8480045)));
+        assert!(!counts.contains_key(&PlayerId::new(8475287)));
+        assert_eq!(counts.len(), 5);
+    }
+
+    #[test]
+    fn test_play_by_play_player_event_counts_skips_shootout() {
+        let mut pbp: PlayByPlay = serde_json::from_str(PLAY_BY_PLAY_FIXTURE).unwrap();
+        let before = pbp.player_event_counts();
+        let mut shootout_goal = pbp.plays.iter().find(|p| p.is_goal()).unwrap().clone();
+        shootout_goal.period_descriptor.number = 5;
+        shootout_goal.period_descriptor.period_type = Some(PeriodType::Shootout);
+        pbp.plays.push(shootout_goal);
+
+        assert_eq!(pbp.player_event_counts(), before);
+    }
+
+    #[test]
+    fn test_play_by_play_zone_code_mismatches() {
+        let mut pbp: PlayByPlay = serde_json::from_str(PLAY_BY_PLAY_FIXTURE).unwrap();
+        // Every fixture zone agrees with its coordinates, including the
+        // blocked shot in the blocker's defensive zone.
+        assert!(pbp.zone_code_mismatches().is_empty());
+
+        let shot = pbp.plays.iter_mut().find(|p| p.event_id == 103).unwrap();
+        shot.details.as_mut().unwrap().zone_code = Some(ZoneCode::Defensive);
+        let faceoff = pbp.plays.iter_mut().find(|p| p.event_id == 151).unwrap();
+        faceoff.home_team_defending_side = None;
+        faceoff.details.as_mut().unwrap().zone_code = Some(ZoneCode::Offensive);
+
+        // The faceoff has no defending side, so it can't be checked.
+        let mismatches: Vec<i64> = pbp
+            .zone_code_mismatches()
+            .iter()
+            .map(|p| p.event_id)
+            .collect();
+        assert_eq!(mismatches, [103]);
+    }
+
+    /// Five shots and a goal whose away counter dips from 2 to 1 on the
+    /// second-period home shot (event 141); team totals are 3 and 2.
+    const SOG_BLIP_FIXTURE: &str = include_str!("../../tests/fixtures/play_by_play_sog_blip.json");
+
+    #[test]
+    fn test_play_by_play_sog_progression_repairs_blip() {
+        let pbp: PlayByPlay = serde_json::from_str(SOG_BLIP_FIXTURE).unwrap();
+        assert_no_unmapped_fields::<PlayByPlay>(SOG_BLIP_FIXTURE);
+
+        let points = pbp.sog_progression();
+        let series: Vec<(i64, u32, i32, i32, bool)> = points
+            .iter()
+            .map(|p| {
+                (
+                    p.event_id,
+                    p.elapsed.as_seconds(),
+                    p.away,
+                    p.home,
+                    p.repaired,
+                )
+            })
+            .collect();
+        // The faceoff carries no counters and is skipped.
+        assert_eq!(
+            series,
+            [
+                (103, 8, 1, 0, false),
+                (120, 600, 1, 1, false),
+                (140, 1500, 2, 1, false),
+                (141, 1560, 2, 2, true),
+                (274, 2400 + 519, 3, 2, false),
+            ]
+        );
+    }
+
+    #[test]
+    fn test_play_by_play_validate_sog() {
+        let mut pbp: PlayByPlay = serde_json::from_str(SOG_BLIP_FIXTURE).unwrap();
+
+        let validation = pbp.validate_sog();
+        assert_eq!(validation.away_counted, 3);
+        assert_eq!(validation.home_counted, 2);
+        assert_eq!(validation.repairs, 1);
+        assert!(validation.is_consistent());
+
+        pbp.home_team.sog = Some(3);
+        assert!(!pbp.validate_sog().is_consistent());
+    }
+
+    #[test]
+    fn test_play_by_play_sog_elapsed_counts_short_overtime() {
+        let mut pbp: PlayByPlay = serde_json::from_str(SOG_BLIP_FIXTURE).unwrap();
+        let mut ot_shot = pbp.plays.last().unwrap().clone();
+        ot_shot.event_id = 400;
+        ot_shot.period_descriptor.number = 5;
+        ot_shot.period_descriptor.period_type = Some(PeriodType::Overtime);
+        ot_shot.time_in_period = "00:30".to_string();
+        pbp.plays.push(ot_shot.clone());
+
+        // Regular season: three 20-minute periods, then 5-minute overtimes.
+        assert_eq!(
+            pbp.sog_progression().last().unwrap().elapsed.as_seconds(),
+            3 * 1200 + 300 + 30
+        );
+
+        pbp.game_type = GameType::Playoffs;
+        assert_eq!(
+            pbp.sog_progression().last().unwrap().elapsed.as_seconds(),
+            4 * 1200 + 30
+        );
+    }
+
+    fn event_ids(plays: Vec<&PlayEvent>) -> Vec<i64> {
+        plays.iter().map(|p| p.event_id).collect()
+    }
+
+    #[test]
+    fn test_play_by_play_query_helpers() {
+        let pbp: PlayByPlay = serde_json::from_str(PLAY_BY_PLAY_FIXTURE).unwrap();
+        assert_eq!(event_ids(pbp.goals()), [274]);
+        assert_eq!(event_ids(pbp.penalties()), [135]);
+        assert_eq!(event_ids(pbp.shots_on_goal(None)), [103, 274]);
+        assert_eq!(event_ids(pbp.shot_attempts(None)), [103, 121, 188, 274]);
+        assert_eq!(pbp.events_in_period(1).len(), 6);
+        assert!(pbp.events_in_period(2).is_empty());
+    }
+
+    #[test]
+    fn test_play_by_play_shots_by_team() {
+        let pbp: PlayByPlay = serde_json::from_str(PLAY_BY_PLAY_FIXTURE).unwrap();
+        let (njd, buf) = (Some(TeamId::new(1)), Some(TeamId::new(7)));
+        assert_eq!(event_ids(pbp.shots_on_goal(njd)), [103, 274]);
+        assert!(pbp.shots_on_goal(buf).is_empty());
+        assert_eq!(event_ids(pbp.shot_attempts(njd)), [103, 188, 274]);
+        assert_eq!(event_ids(pbp.shot_attempts(buf)), [121]);
+    }
+
+    /// The API reports a blocked shot with the blocker's team as owner; the
+    /// helpers credit the attempt to the shooter's team.
+    #[test]
+    fn test_play_by_play_blocked_shot_attribution() {
+        let pbp: PlayByPlay = serde_json::from_str(PLAY_BY_PLAY_FIXTURE).unwrap();
+        let blocked = pbp.plays.iter().find(|p| p.event_id == 188).unwrap();
+        let details = blocked.details.as_ref().unwrap();
+        assert_eq!(blocked.event_type(), PlayEventType::BlockedShot);
+        assert_eq!(details.event_owner_team_id, Some(TeamId::new(7)));
+        assert_eq!(pbp.get_player(8478043).unwrap().team_id, TeamId::new(7));
+        assert_eq!(pbp.get_player(8476474).unwrap().team_id, TeamId::new(1));
+
+        assert_eq!(pbp.shooting_team_id(blocked), Some(TeamId::new(1)));
+        let missed = pbp.plays.iter().find(|p| p.event_id == 121).unwrap();
+        assert_eq!(pbp.shooting_team_id(missed), Some(TeamId::new(7)));
+        let faceoff = pbp.plays.iter().find(|p| p.event_id == 151).unwrap();
+        assert_eq!(pbp.shooting_team_id(faceoff), None);
+    }
+
+    #[test]
+    fn test_play_by_play_events_by_player() {
+        let pbp: PlayByPlay = serde_json::from_str(PLAY_BY_PLAY_FIXTURE).unwrap();
+        // Greenway: lost the faceoff, missed a shot, blocked a shot.
+        assert_eq!(event_ids(pbp.events_by_player(8478043)), [151, 121, 188]);
+        // Noesen: shot blocked, then scored.
+        assert_eq!(event_ids(pbp.events_by_player(8476474)), [188, 274]);
+        // Luukkonen was in net for both shots on goal.
+        assert_eq!(event_ids(pbp.events_by_player(8480045)), [103, 274]);
+        assert!(pbp.events_by_player(1).is_empty());
+    }
+
+    #[test]
+    fn test_play_by_play_player_name() {
+        let pbp: PlayByPlay = serde_json::from_str(PLAY_BY_PLAY_FIXTURE).unwrap();
+        assert_eq!(pbp.player_name(8480002).as_deref(), Some("Nico Hischier"));
+        assert_eq!(
+            pbp.player_name(PlayerId::new(8478043)).as_deref(),
+            Some("Jordan Greenway")
+        );
+        assert_eq!(pbp.player_name(1), None);
+    }
+
+    #[test]
+    fn test_game_story_maps_every_field() {
+        let team = |id: i64, abbrev: &str, score: i32| {
+            format!(
+                r#"{{"id": {}, "name": {{"default": "Team"}}, "abbrev": "{}",
+                    "placeName": {{"default": "Place"}}, "score": {}, "sog": 30,
+                    "logo": "https://assets.nhle.com/logos/nhl/svg/{}_light.svg"}}"#,
+                id, abbrev, score, abbrev
+            )
+        };
+        let json = format!(
+            r#"{{
+                "id": 2024020500, "season": 20242025, "gameType": 2, "limitedScoring": false,
+                "gameDate": "2024-12-01", "venue": {{"default": "Scotiabank Arena"}},
+                "venueLocation": {{"default": "Toronto"}},
+                "startTimeUTC": "2024-12-02T00:00:00Z", "easternUTCOffset": "-05:00",
+                "venueUTCOffset": "-05:00", "venueTimezone": "America/Toronto",
+                "tvBroadcasts": [{{"id": 1, "market": "N", "countryCode": "CA",
+                    "network": "SN", "sequenceNumber": 1}}],
+                "gameState": "OFF", "gameScheduleState": "OK",
+                "awayTeam": {}, "homeTeam": {},
+                "shootoutInUse": true, "maxPeriods": 5, "regPeriods": 3,
+                "otInUse": true, "tiesInUse": false
+            }}"#,
+            team(8, "MTL", 2),
+            team(10, "TOR", 3)
+        );
+        let story: GameStory = serde_json::from_str(&json).unwrap();
+        assert_no_unmapped_fields::<GameStory>(&json);
+        assert_eq!(story.home_team.score, 3);
+    }
+
+    /// Header fields of a live play-by-play at one moment of a game.
+    fn live_play_by_play(
+        game_type: GameType,
+        state: GameState,
+        period: i32,
+        period_type: &str,
+        display_period: i32,
+        clock: &str,
+    ) -> PlayByPlay {
+        let mut pbp = crate::fixtures::play_by_play();
+        pbp.game_type = game_type;
+        pbp.game_state = state;
+        pbp.period_descriptor = serde_json::from_str(&format!(
+            r#"{{"number": {}, "periodType": "{}", "maxRegulationPeriods": 3}}"#,
+            period, period_type
+        ))
+        .unwrap();
+        pbp.display_period = display_period;
+        pbp.max_periods = 5;
+        pbp.reg_periods = 3;
+        pbp.clock = serde_json::from_str(clock).unwrap();
+        pbp
+    }
+
+    #[test]
+    fn test_play_by_play_mid_period() {
+        let pbp = live_play_by_play(
+            GameType::RegularSeason,
+            GameState::Live,
+            2,
+            "REG",
+            2,
+            r#"{"timeRemaining": "05:00", "secondsRemaining": 300, "running": true,
+                "inIntermission": false}"#,
+        );
+
+        assert_eq!(pbp.current_period().number, 2);
+        assert_eq!(
+            pbp.current_period().period_type,
+            Some(PeriodType::Regulation)
+        );
+        assert!(!pbp.is_intermission());
+        assert_eq!(pbp.period_progress(), 0.75);
+    }
+
+    #[test]
+    fn test_play_by_play_intermission_display_period_ahead() {
+        // First intermission: the clock counts down the break while
+        // displayPeriod already shows the second period.
+        let pbp = live_play_by_play(
+            GameType::RegularSeason,
+            GameState::Live,
+            1,
+            "REG",
+            2,
+            r#"{"timeRemaining": "14:21", "secondsRemaining": 861, "running": true,
+                "inIntermission": true}"#,
+        );
+
+        assert!(pbp.is_intermission());
+        assert_eq!(pbp.current_period().number, 1);
+        assert_eq!(pbp.period_progress(), 1.0);
+    }
+
+    #[test]
+    fn test_play_by_play_regular_season_overtime() {
+        let pbp = live_play_by_play(
+            GameType::RegularSeason,
+            GameState::Live,
+            4,
+            "OT",
+            4,
+            r#"{"timeRemaining": "03:45", "secondsRemaining": 225, "running": true,
+                "inIntermission": false}"#,
+        );
+
+        assert_eq!(pbp.current_period().period_type, Some(PeriodType::Overtime));
+        // 5-minute overtime: 75 of 300 seconds played.
+        assert_eq!(pbp.period_progress(), 0.25);
+    }
+
+    #[test]
+    fn test_play_by_play_playoff_overtime_is_twenty_minutes() {
+        let pbp = live_play_by_play(
+            GameType::Playoffs,
+            GameState::Critical,
+            5,
+            "OT",
+            5,
+            r#"{"timeRemaining": "10:00", "secondsRemaining": 600, "running": true,
+                "inIntermission": false}"#,
+        );
+
+        assert_eq!(pbp.current_period().number, 5);
+        assert_eq!(pbp.period_progress(), 0.5);
+    }
+
+    #[test]
+    fn test_play_by_play_current_period_prefers_descriptor_on_mismatch() {
+        // Not an intermission, so this disagreement is logged; the
+        // descriptor still wins.
+        let pbp = live_play_by_play(
+            GameType::RegularSeason,
+            GameState::Live,
+            2,
+            "REG",
+            3,
+            r#"{"timeRemaining": "19:00", "secondsRemaining": 1140, "running": true,
+                "inIntermission": false}"#,
+        );
+
+        assert_eq!(pbp.current_period().number, 2);
+    }
+
+    #[test]
+    fn test_play_by_play_current_period_falls_back_to_display_period() {
+        let mut pbp = crate::fixtures::play_by_play();
+        pbp.game_state = GameState::PreGame;
+        pbp.display_period = 1;
+        pbp.reg_periods = 3;
+
+        let period = pbp.current_period();
+        assert_eq!(period.number, 1);
+        assert_eq!(period.period_type, Some(PeriodType::Regulation));
+        assert_eq!(period.max_regulation_periods, 3);
+        assert_eq!(pbp.period_progress(), 0.0);
+
+        pbp.display_period = 5;
+        assert_eq!(pbp.current_period().period_type, Some(PeriodType::Shootout));
+        pbp.game_type = GameType::Playoffs;
+        assert_eq!(pbp.current_period().period_type, Some(PeriodType::Overtime));
+    }
+
+    #[test]
+    fn test_game_elapsed_seconds_intermission() {
+        // First intermission: the break clock doesn't count as game time.
+        let pbp = live_play_by_play(
+            GameType::RegularSeason,
+            GameState::Live,
+            1,
+            "REG",
+            2,
+            r#"{"timeRemaining": "14:21", "secondsRemaining": 861, "running": true,
+                "inIntermission": true}"#,
+        );
+        assert_eq!(pbp.game_elapsed_seconds(), 1200);
+    }
+
+    #[test]
+    fn test_game_elapsed_seconds_playoff_overtime() {
+        // Second playoff overtime, 10:00 left: three regulation periods and
+        // one full 20-minute overtime before it.
+        let pbp = live_play_by_play(
+            GameType::Playoffs,
+            GameState::Critical,
+            5,
+            "OT",
+            5,
+            r#"{"timeRemaining": "10:00", "secondsRemaining": 600, "running": true,
+                "inIntermission": false}"#,
+        );
+        assert_eq!(pbp.game_elapsed_seconds(), 4 * 1200 + 600);
+    }
+
+    #[test]
+    fn test_game_elapsed_seconds_shootout_caps_at_overtime() {
+        let shootout = live_play_by_play(
+            GameType::RegularSeason,
+            GameState::Live,
+            5,
+            "SO",
+            5,
+            r#"{"timeRemaining": "00:00", "secondsRemaining": 0, "running": false,
+                "inIntermission": false}"#,
+        );
+        assert_eq!(shootout.game_elapsed_seconds(), 3 * 1200 + 300);
+
+        let mut finished = shootout.clone();
+        finished.game_state = GameState::Off;
+        assert_eq!(finished.game_elapsed_seconds(), 3 * 1200 + 300);
+
+        let mut pre_game = shootout;
+        pre_game.game_state = GameState::Future;
+        assert_eq!(pre_game.game_elapsed_seconds(), 0);
+    }
+
+    #[test]
+    fn test_play_by_play_period_progress_final_and_shootout() {
+        let shootout = live_play_by_play(
+            GameType::RegularSeason,
+            GameState::Live,
+            5,
+            "SO",
+            5,
+            r#"{"timeRemaining": "00:00", "secondsRemaining": 0, "running": false,
+                "inIntermission": false}"#,
+        );
+        assert_eq!(shootout.period_progress(), 1.0);
+
+        let mut finished = shootout.clone();
+        finished.game_state = GameState::Final;
+        assert_eq!(finished.period_progress(), 1.0);
     }
 
     #[test]
@@ -1811,13 +4233,59 @@ mod tests {
     /// `GameId`/`Season` serde support from 1.1/1.3.
     #[test]
     fn test_play_by_play_id_and_season_deserialize_from_numeric_strings() {
-        let json = play_by_play_json("").replacen("2024020444", "\"2024020444\"", 1);
-        let json = json.replacen("20242025", "\"20242025\"", 1);
+        let json = play_by_play_json(r#", "id": "2024020444", "season": "20242025""#);
         let pbp: PlayByPlay = serde_json::from_str(&json).unwrap();
         assert_eq!(pbp.id, GameId::new(2024020444));
         assert_eq!(pbp.season, Season::new(2024));
     }
 
+    #[test]
+    fn test_game_summary_game_info_officials() {
+        let json = r#"{
+            "scoring": [],
+            "penalties": [],
+            "gameInfo": {
+                "referees": [{"default": "Wes McCauley"}, {"default": "Chris Rooney"}],
+                "linesmen": [{"default": "Steve Barton"}],
+                "awayTeam": {"headCoach": {"default": "Coach A"}, "scratches": []}
+            }
+        }"#;
+
+        let summary: GameSummary = serde_json::from_str(json).unwrap();
+        let officials = summary.game_info.unwrap();
+        assert_eq!(officials.referees.len(), 2);
+        assert_eq!(officials.linesmen[0].default, "Steve Barton");
+    }
+
+    #[test]
+    fn test_series_game_info_officials_round_trip() {
+        let json = r#"{
+            "referees": [{"default": "J. Referee"}],
+            "linesmen": [{"default": "L. Linesman"}],
+            "awayTeam": {"headCoach": {"default": "Coach A"}, "scratches": []},
+            "homeTeam": {"headCoach": {"default": "Coach B"}, "scratches": []}
+        }"#;
+
+        let info: SeriesGameInfo = serde_json::from_str(json).unwrap();
+        assert_eq!(info.officials.referees[0].default, "J. Referee");
+        assert!(!info.officials.is_empty());
+
+        // Flattened: serializes back to the API's top-level field names.
+        let value = serde_json::to_value(&info).unwrap();
+        assert!(value.get("referees").is_some());
+        assert!(value.get("officials").is_none());
+        let round_tripped: SeriesGameInfo = serde_json::from_value(value).unwrap();
+        assert_eq!(round_tripped, info);
+    }
+
+    #[test]
+    fn test_officials_is_empty() {
+        assert!(Officials::default().is_empty());
+        let officials: Officials =
+            serde_json::from_str(r#"{"linesmen": [{"default": "X"}]}"#).unwrap();
+        assert!(!officials.is_empty());
+    }
+
     #[test]
     fn test_game_summary_missing_shootout_and_three_stars() {
         let json = r#"{
@@ -1830,6 +4298,93 @@ mod tests {
         assert!(summary.three_stars.is_empty());
     }
 
+    /// The boxscore fixture is a live game, whose landing summary has no
+    /// `threeStars` block yet.
+    #[test]
+    fn test_game_summary_three_stars_derived_from_fixture() {
+        let boxscore: Boxscore =
+            serde_json::from_str(include_str!("../../tests/fixtures/boxscore.json")).unwrap();
+        let summary: GameSummary =
+            serde_json::from_str(r#"{"scoring": [], "penalties": []}"#).unwrap();
+
+        let stars = summary.three_stars_or_derived(&boxscore);
+        let names: Vec<&str> = stars.iter().map(|s| s.name.default.as_str()).collect();
+        // Hischier has two points; Dahlin and Hamilton lead the one-point
+        // skaters on ice time.
+        assert_eq!(names, ["N. Hischier", "R. Dahlin", "D. Hamilton"]);
+        assert_eq!(stars[1].team_abbrev, "BUF");
+        assert_eq!(stars[1].sweater_no, Some(26));
+        assert!(stars.iter().all(|s| !s.official));
+    }
+
+    #[test]
+    fn test_game_summary_three_stars_prefers_official() {
+        let boxscore: Boxscore =
+            serde_json::from_str(include_str!("../../tests/fixtures/boxscore.json")).unwrap();
+        let star = |n: i32, id: i64| {
+            format!(
+                r#"{{"star": {}, "playerId": {}, "teamAbbrev": "BUF", "headshot": "",
+                    "name": {{"default": "Player {}"}}, "sweaterNo": {}, "position": "C"}}"#,
+                n, id, id, n
+            )
+        };
+        let json = format!(
+            r#"{{"threeStars": [{}, {}, {}]}}"#,
+            star(2, 8480839),
+            star(1, 8479420),
+            star(3, 8480045)
+        );
+        let summary: GameSummary = serde_json::from_str(&json).unwrap();
+
+        let stars = summary.three_stars_or_derived(&boxscore);
+        let ids: Vec<i64> = stars.iter().map(|s| s.player_id.as_i64()).collect();
+        assert_eq!(ids, [8479420, 8480839, 8480045]);
+        assert!(stars.iter().all(|s| s.official));
+        assert_eq!(stars[0].position, Some(Position::Center));
+    }
+
+    #[test]
+    fn test_game_summary_three_star_stats_links_skater_and_goalie() {
+        let boxscore: Boxscore =
+            serde_json::from_str(include_str!("../../tests/fixtures/boxscore.json")).unwrap();
+        let summary: GameSummary = serde_json::from_str(
+            r#"{"threeStars": [
+                {"star": 2, "playerId": 8474593, "teamAbbrev": "NJD", "headshot": "",
+                 "name": {"default": "J. Markstrom"}, "sweaterNo": 25, "position": "G",
+                 "goalsAgainstAverage": 1.0, "savePctg": 0.967},
+                {"star": 1, "playerId": 8479407, "teamAbbrev": "NJD", "headshot": "",
+                 "name": {"default": "N. Hischier"}, "sweaterNo": 13, "position": "C",
+                 "goals": 1, "assists": 1, "points": 2},
+                {"star": 3, "playerId": 1, "teamAbbrev": "BUF", "headshot": "",
+                 "name": {"default": "Nobody"}, "sweaterNo": 1, "position": "L"}
+            ]}"#,
+        )
+        .unwrap();
+
+        let stars = summary.three_star_stats(&boxscore);
+
+        assert_eq!(stars.len(), 3);
+        match stars[0] {
+            (star, Some(BoxscorePlayer::Skater(skater))) => {
+                assert_eq!(star.position, Some(Position::Center));
+                assert_eq!(skater.name.default, "N. Hischier");
+            }
+            other => panic!("expected a skater first star, got {:?}", other),
+        }
+        match stars[1] {
+            (star, Some(BoxscorePlayer::Goalie(goalie))) => {
+                assert_eq!(star.position, Some(Position::Goalie));
+                assert_eq!((star.goals, star.save_pctg), (None, Some(0.967)));
+                assert_eq!(goalie.name.default, "J. Markstrom");
+            }
+            other => panic!("expected a goalie second star, got {:?}", other),
+        }
+        assert_eq!(stars[2].0.position, Some(Position::LeftWing));
+        assert!(stars[2].1.is_none());
+        let starless: GameSummary = serde_json::from_str("{}").unwrap();
+        assert!(starless.three_star_stats(&boxscore).is_empty());
+    }
+
     #[test]
     fn test_game_summary_with_shootout_and_three_stars() {
         let json = r#"{
@@ -1862,4 +4417,322 @@ mod tests {
         assert_eq!(summary.three_stars.len(), 1);
         assert_eq!(summary.three_stars[0].player_id, PlayerId::new(8478402));
     }
+
+    /// A summary whose scoring lists `goals` of `(period, time, team,
+    /// is_home, away_score, home_score)`, grouped by period in the order
+    /// periods first appear.
+    fn scoring_summary(goals: &[(i32, &str, &str, bool, i32, i32)]) -> GameSummary {
+        let mut periods: Vec<(i32, Vec<String>)> = Vec::new();
+        for (event_id, (period, time, team, is_home, away, home)) in goals.iter().enumerate() {
+            let goal = format!(
+                r#"{{"situationCode": "1551", "eventId": {event_id}, "strength": "ev",
+                    "playerId": 8478402, "firstName": {{"default": ""}},
+                    "lastName": {{"default": ""}}, "name": {{"default": ""}},
+                    "teamAbbrev": {{"default": "{team}"}}, "headshot": "",
+                    "awayScore": {away}, "homeScore": {home}, "timeInPeriod": "{time}",
+                    "shotType": "wrist", "goalModifier": "none", "isHome": {is_home}}}"#
+            );
+            match periods.iter_mut().find(|(number, _)| number == period) {
+                Some((_, period_goals)) => period_goals.push(goal),
+                None => periods.push((*period, vec![goal])),
+            }
+        }
+        let scoring: Vec<String> = periods
+            .iter()
+            .map(|(number, goals)| {
+                format!(
+                    r#"{{"periodDescriptor": {{"number": {number}}}, "goals": [{}]}}"#,
+                    goals.join(",")
+                )
+            })
+            .collect();
+        serde_json::from_str(&format!(r#"{{"scoring": [{}]}}"#, scoring.join(","))).unwrap()
+    }
+
+    fn goal_key(goal: &GoalSummary) -> (&str, i32, i32) {
+        (
+            goal.team_abbrev.default.as_str(),
+            goal.away_score,
+            goal.home_score,
+        )
+    }
+
+    /// BOS trails 2-0 at home, then scores four: the third puts it ahead
+    /// for good. Periods and goals come out of order.
+    #[test]
+    fn test_game_summary_comeback_timeline_and_winning_goal() {
+        let summary = scoring_summary(&[
+            (3, "15:30", "BOS", true, 2, 4),
+            (3, "04:10", "BOS", true, 2, 3),
+            (1, "02:00", "TOR", false, 1, 0),
+            (1, "18:45", "TOR", false, 2, 0),
+            (2, "09:00", "BOS", true, 2, 1),
+            (2, "19:59", "BOS", true, 2, 2),
+        ]);
+
+        let timeline: Vec<_> = summary.all_goals().into_iter().map(goal_key).collect();
+        assert_eq!(
+            timeline,
+            [
+                ("TOR", 1, 0),
+                ("TOR", 2, 0),
+                ("BOS", 2, 1),
+                ("BOS", 2, 2),
+                ("BOS", 2, 3),
+                ("BOS", 2, 4),
+            ]
+        );
+        assert_eq!(summary.goals_by_team("tor").len(), 2);
+        assert_eq!(summary.final_score(), Some((2, 4)));
+        assert_eq!(
+            summary.game_winning_goal().map(goal_key),
+            Some(("BOS", 2, 3))
+        );
+    }
+
+    /// A lead taken and lost doesn't count: the road team's winner is the
+    /// goal after the tie, and an overtime goal wins a game tied after three.
+    #[test]
+    fn test_game_summary_winning_goal_after_lost_lead() {
+        let summary = scoring_summary(&[
+            (1, "05:00", "TOR", false, 1, 0),
+            (2, "05:00", "BOS", true, 1, 1),
+            (2, "10:00", "TOR", false, 2, 1),
+            (3, "19:00", "TOR", false, 3, 1),
+        ]);
+        assert_eq!(
+            summary.game_winning_goal().map(goal_key),
+            Some(("TOR", 2, 1))
+        );
+
+        let overtime = scoring_summary(&[
+            (1, "05:00", "TOR", false, 1, 0),
+            (3, "05:00", "BOS", true, 1, 1),
+            (4, "01:12", "BOS", true, 1, 2),
+        ]);
+        assert_eq!(
+            overtime.game_winning_goal().map(goal_key),
+            Some(("BOS", 1, 2))
+        );
+
+        let scoreless = scoring_summary(&[]);
+        assert_eq!(scoreless.final_score(), None);
+        assert!(scoreless.game_winning_goal().is_none());
+    }
+
+    const SHOOTOUT_SUMMARY_JSON: &str =
+        include_str!("../../tests/fixtures/game_summary_shootout.json");
+
+    #[test]
+    fn test_game_summary_shootout_has_no_winning_goal() {
+        assert_no_unmapped_fields::<GameSummary>(SHOOTOUT_SUMMARY_JSON);
+        let summary: GameSummary = serde_json::from_str(SHOOTOUT_SUMMARY_JSON).unwrap();
+
+        assert_eq!(summary.all_goals().len(), 4);
+        let bos: Vec<&str> = summary
+            .goals_by_team("BOS")
+            .iter()
+            .map(|goal| goal.name.default.as_str())
+            .collect();
+        assert_eq!(bos, ["D. Pastrnak", "B. Carlo"]);
+        assert_eq!(summary.final_score(), Some((2, 2)));
+        // The shootout winner is only in the attempts.
+        assert!(summary.game_winning_goal().is_none());
+        assert!(summary.shootout.last().unwrap().game_winner);
+
+        let stars: Vec<(i32, &str)> = summary
+            .three_stars_sorted()
+            .iter()
+            .map(|star| (star.star, star.name.default.as_str()))
+            .collect();
+        assert_eq!(
+            stars,
+            [(1, "D. Pastrnak"), (2, "B. Carlo"), (3, "M. Marner")]
+        );
+    }
+
+    const FIGHT_SUMMARY_JSON: &str = include_str!("../../tests/fixtures/game_summary_fight.json");
+
+    #[test]
+    fn test_penalty_summary_fight_severities() {
+        let summary: GameSummary = serde_json::from_str(FIGHT_SUMMARY_JSON).unwrap();
+        let penalties: Vec<&PenaltySummary> = summary
+            .penalties
+            .iter()
+            .flat_map(|period| &period.penalties)
+            .collect();
+        assert_eq!(penalties.len(), 10);
+
+        let fight: Vec<(PenaltySeverity, &str, i32)> = summary.penalties[1]
+            .penalties
+            .iter()
+            .map(|p| {
+                (
+                    p.penalty_type.clone(),
+                    p.team_abbrev.default.as_str(),
+                    p.duration,
+                )
+            })
+            .collect();
+        assert_eq!(
+            fight,
+            [
+                (PenaltySeverity::Major, "NJD", 5),
+                (PenaltySeverity::Major, "BUF", 5),
+                (PenaltySeverity::Minor, "NJD", 2),
+                (PenaltySeverity::Misconduct, "NJD", 10),
+                (PenaltySeverity::Misconduct, "BUF", 10),
+            ]
+        );
+        // Offsetting majors and misconducts: only the instigator minor
+        // leaves a team short-handed.
+        let power_plays = summary.penalties[1]
+            .penalties
+            .iter()
+            .filter(|p| p.penalty_type.creates_power_play())
+            .count();
+        assert_eq!(power_plays, 3);
+
+        let game_misconduct = penalties.last().unwrap();
+        assert_eq!(
+            game_misconduct.penalty_type,
+            PenaltySeverity::GameMisconduct
+        );
+        assert_eq!(game_misconduct.desc_key.humanize(), "Abuse of Officials");
+
+        let bench: Vec<&PenaltySummary> = penalties
+            .iter()
+            .copied()
+            .filter(|p| p.is_bench_penalty())
+            .collect();
+        assert_eq!(bench.len(), 1);
+        assert_eq!(bench[0].penalty_type, PenaltySeverity::BenchMinor);
+        assert_eq!(bench[0].served_by.as_ref().unwrap().default, "J. Quinn");
+        assert_eq!(bench[0].desc_key.humanize(), "Too Many Men on the Ice");
+
+        let round_tripped: GameSummary =
+            serde_json::from_value(serde_json::to_value(&summary).unwrap()).unwrap();
+        assert_eq!(round_tripped.penalties, summary.penalties);
+    }
+
+    #[test]
+    fn test_penalty_desc_key_humanize() {
+        for (key, humanized) in [
+            ("slashing", "Slashing"),
+            (
+                "delaying-game-puck-over-glass",
+                "Delaying Game – Puck over Glass",
+            ),
+            (
+                "delaying-game-face-off-violation",
+                "Delaying Game – Face-Off Violation",
+            ),
+            ("high-sticking-double-minor", "High-Sticking – Double Minor"),
+            ("high-sticking", "High-Sticking"),
+            ("interference-goalkeeper", "Interference – Goalkeeper"),
+            ("cross-checking", "Cross-Checking"),
+            ("instigator-misconduct", "Instigator Misconduct"),
+        ] {
+            assert_eq!(PenaltyDescKey::from(key).humanize(), humanized, "{}", key);
+        }
+        assert_eq!(PenaltyDescKey::new("slashing").to_string(), "slashing");
+    }
+
+    const SEASON_SERIES_JSON: &str = include_str!("../../tests/fixtures/season_series.json");
+
+    #[test]
+    fn test_season_series_head_to_head() {
+        let matchup: SeasonSeriesMatchup = serde_json::from_str(SEASON_SERIES_JSON).unwrap();
+        assert_no_unmapped_fields::<SeasonSeriesMatchup>(SEASON_SERIES_JSON);
+        let (toronto, boston) = (TeamId::new(10), TeamId::new(6));
+
+        assert_eq!(matchup.completed_games().len(), 3);
+        let remaining: Vec<GameId> = matchup.remaining_games().iter().map(|g| g.id).collect();
+        assert_eq!(remaining, [GameId::new(2023021174)]);
+        assert_eq!(matchup.next_game().unwrap().id, GameId::new(2023021174));
+
+        // Toronto won in overtime and in a shootout, and lost 5-1.
+        assert_eq!(matchup.record_for(toronto), (2, 1, 0));
+        assert_eq!(matchup.record_for(boston), (1, 0, 2));
+        assert_eq!(matchup.goal_differential(toronto), -2);
+        assert_eq!(matchup.goal_differential(boston), 2);
+
+        // The tally agrees with the API's win counts for the upcoming game.
+        let wins = &matchup.season_series_wins;
+        assert_eq!(
+            (wins.away_team_wins, wins.home_team_wins),
+            (matchup.record_for(toronto).0, matchup.record_for(boston).0)
+        );
+
+        let stranger = TeamId::new(55);
+        assert_eq!(matchup.record_for(stranger), (0, 0, 0));
+        assert_eq!(matchup.goal_differential(stranger), 0);
+    }
+
+    #[test]
+    fn test_season_series_playoff_overtime_loss_is_a_loss() {
+        let matchup = series_matchup(&[
+            series_game_json(3, 1, "OFF", false, 3, 4),
+            series_game_json(3, 2, "OFF", false, 1, 0),
+        ]);
+        let mut overtime = matchup.clone();
+        overtime.season_series[0].game_outcome.last_period_type = Some(PeriodType::Overtime);
+        let edmonton = overtime.season_series[0].away_team.id;
+
+        assert_eq!(overtime.record_for(edmonton), (1, 1, 0));
+        assert!(overtime.next_game().is_none());
+        assert!(overtime.remaining_games().is_empty());
+    }
+
+    #[test]
+    fn test_season_series_officials_with_roles() {
+        let matchup: SeasonSeriesMatchup = serde_json::from_str(SEASON_SERIES_JSON).unwrap();
+
+        let officials: Vec<(OfficialRole, &str)> = matchup
+            .officials()
+            .iter()
+            .map(|official| (official.role, official.name.default.as_str()))
+            .collect();
+        assert_eq!(
+            officials,
+            [
+                (OfficialRole::Referee, "Wes McCauley"),
+                (OfficialRole::Referee, "Chris Rooney"),
+                (OfficialRole::Linesman, "Steve Barton"),
+                (OfficialRole::Linesman, "Ryan Daisy"),
+            ]
+        );
+        assert!(Officials::default().with_roles().is_empty());
+    }
+
+    /// Deserialize `json` with `gameScheduleState` at `pointer` set to
+    /// `"PPD"`, and check that serializing writes the code back unchanged.
+    fn postponed<T: serde::de::DeserializeOwned + Serialize>(json: &str, pointer: &str) -> T {
+        let mut value: serde_json::Value = serde_json::from_str(json).unwrap();
+        *value.pointer_mut(pointer).unwrap() = serde_json::json!("PPD");
+        let parsed: T = serde_json::from_value(value.clone()).unwrap();
+        let serialized = serde_json::to_value(&parsed).unwrap();
+        assert_eq!(serialized.pointer(pointer), value.pointer(pointer));
+        parsed
+    }
+
+    #[test]
+    fn test_postponed_schedule_state_round_trips() {
+        let pbp: PlayByPlay = postponed(PLAY_BY_PLAY_FIXTURE, "/gameScheduleState");
+        assert!(pbp.is_postponed());
+        assert!(!crate::fixtures::play_by_play().is_postponed());
+
+        let matchup: GameMatchup = postponed(&game_matchup_json("FUT", ""), "/gameScheduleState");
+        assert!(matchup.is_postponed());
+
+        let matchup: SeasonSeriesMatchup =
+            postponed(SEASON_SERIES_JSON, "/seasonSeries/3/gameScheduleState");
+        assert!(matchup.season_series[3].is_postponed());
+        assert!(!matchup.season_series[0].is_postponed());
+
+        let story_json = serde_json::to_string(&crate::fixtures::game_story()).unwrap();
+        let story: GameStory = postponed(&story_json, "/gameScheduleState");
+        assert!(story.is_postponed());
+        assert_eq!(story.game_schedule_state, GameScheduleState::Postponed);
+    }
 }
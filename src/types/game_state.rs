@@ -1,4 +1,4 @@
-use serde::{Deserialize, Serialize};
+use serde::{Deserialize, Deserializer, Serialize, Serializer};
 use std::fmt;
 use std::str::FromStr;
 use thiserror::Error;
@@ -9,40 +9,39 @@ use thiserror::Error;
 pub struct ParseGameStateError(String);
 
 /// NHL game state representing the current status of a game
-#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
-#[serde(rename_all = "UPPERCASE")]
+///
+/// States without a named variant deserialize to [`GameState::Unknown`]
+/// instead of failing, so a state the NHL starts sending mid-season doesn't
+/// break every schedule and score payload. [`FromStr`] stays strict; use
+/// [`GameState::from_str_or_unknown`] for the lenient parse.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
 pub enum GameState {
     /// Future/scheduled game
-    #[serde(rename = "FUT")]
     Future,
 
     /// Pre-game
-    #[serde(rename = "PRE")]
     PreGame,
 
     /// Live/in progress
-    #[serde(rename = "LIVE")]
     Live,
 
-    /// Final/completed
-    #[serde(rename = "FINAL")]
+    /// Final/completed, before the result is made official
     Final,
 
-    /// Off/completed (alternative to Final)
-    #[serde(rename = "OFF")]
+    /// Off/completed: the official final (alternative to Final)
     Off,
 
     /// Postponed
-    #[serde(rename = "PPD")]
     Postponed,
 
     /// Suspended
-    #[serde(rename = "SUSP")]
     Suspended,
 
     /// Critical (close game, possibly final minutes)
-    #[serde(rename = "CRIT")]
     Critical,
+
+    /// A state with no named variant, kept as sent
+    Unknown(String),
 }
 
 /// Mapping of GameState variants to their string representations
@@ -71,6 +70,11 @@ impl GameState {
         matches!(self, GameState::Final | GameState::Off)
     }
 
+    /// Same as [`Self::is_final`]: `FINAL` (unofficial) or `OFF` (official).
+    pub fn is_finished(&self) -> bool {
+        self.is_final()
+    }
+
     /// Returns true if the game is currently in progress
     pub fn is_live(&self) -> bool {
         matches!(self, GameState::Live | GameState::Critical)
@@ -81,8 +85,24 @@ impl GameState {
         matches!(self, GameState::Future | GameState::PreGame)
     }
 
+    /// Same as [`Self::is_scheduled`]: `FUT` or `PRE`.
+    pub fn is_upcoming(&self) -> bool {
+        self.is_scheduled()
+    }
+
+    /// Returns true for a state this crate doesn't know
+    pub fn is_unknown(&self) -> bool {
+        matches!(self, GameState::Unknown(_))
+    }
+
+    /// Parses `s`, keeping an unrecognized state as [`GameState::Unknown`]
+    pub fn from_str_or_unknown(s: &str) -> Self {
+        s.parse()
+            .unwrap_or_else(|_| GameState::Unknown(s.to_string()))
+    }
+
     /// Returns the string representation for this game state
-    const fn as_str(&self) -> &'static str {
+    fn as_str(&self) -> &str {
         match self {
             GameState::Future => GAME_STATE_MAPPINGS[0].1,
             GameState::PreGame => GAME_STATE_MAPPINGS[1].1,
@@ -92,6 +112,7 @@ impl GameState {
             GameState::Postponed => GAME_STATE_MAPPINGS[5].1,
             GameState::Suspended => GAME_STATE_MAPPINGS[6].1,
             GameState::Critical => GAME_STATE_MAPPINGS[7].1,
+            GameState::Unknown(state) => state,
         }
     }
 }
@@ -109,11 +130,31 @@ impl FromStr for GameState {
         GAME_STATE_MAPPINGS
             .iter()
             .find(|(_, string)| *string == s)
-            .map(|(state, _)| *state)
+            .map(|(state, _)| state.clone())
             .ok_or_else(|| ParseGameStateError(s.to_string()))
     }
 }
 
+impl Serialize for GameState {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+impl<'de> Deserialize<'de> for GameState {
+    /// Lenient: see [`GameState::from_str_or_unknown`].
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        let state = String::deserialize(deserializer)?;
+        Ok(GameState::from_str_or_unknown(&state))
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -314,4 +355,45 @@ mod tests {
         assert_eq!(GameState::Final, GameState::Final);
         assert_ne!(GameState::Final, GameState::Off);
     }
+
+    #[test]
+    fn test_serde_round_trip_every_state() {
+        for (state, code) in GAME_STATE_MAPPINGS {
+            let json = serde_json::to_string(&state).unwrap();
+            assert_eq!(json, format!(r#""{}""#, code));
+            assert_eq!(serde_json::from_str::<GameState>(&json).unwrap(), state);
+            assert_eq!(code.parse::<GameState>().unwrap(), state);
+            assert!(!state.is_unknown());
+        }
+    }
+
+    #[test]
+    fn test_unknown_state_is_lenient_in_serde_only() {
+        let state: GameState = serde_json::from_str(r#""DELAY""#).unwrap();
+        assert_eq!(state, GameState::Unknown("DELAY".to_string()));
+        assert!(state.is_unknown());
+        assert_eq!(state.to_string(), "DELAY");
+        assert_eq!(serde_json::to_string(&state).unwrap(), r#""DELAY""#);
+        assert!(!state.has_started() && !state.is_live() && !state.is_finished());
+
+        assert!("DELAY".parse::<GameState>().is_err());
+        assert_eq!(GameState::from_str_or_unknown("DELAY"), state);
+        assert_eq!(GameState::from_str_or_unknown("CRIT"), GameState::Critical);
+    }
+
+    #[test]
+    fn test_is_finished_and_is_upcoming() {
+        let finished: Vec<GameState> = GAME_STATE_MAPPINGS
+            .into_iter()
+            .map(|(state, _)| state)
+            .filter(GameState::is_finished)
+            .collect();
+        assert_eq!(finished, [GameState::Final, GameState::Off]);
+        let upcoming: Vec<GameState> = GAME_STATE_MAPPINGS
+            .into_iter()
+            .map(|(state, _)| state)
+            .filter(GameState::is_upcoming)
+            .collect();
+        assert_eq!(upcoming, [GameState::Future, GameState::PreGame]);
+    }
 }
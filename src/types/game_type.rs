@@ -13,6 +13,10 @@ const ENUM_NAME: &str = "game type";
 /// schedule as well as historical and special event game types (World Cup,
 /// Olympics, All-Star Weekend variants, etc.) that appear on older schedule
 /// and boxscore data.
+///
+/// Codes without a named variant deserialize to [`GameType::Other`] instead
+/// of failing, so a new or rare historical code doesn't break a whole
+/// schedule or game payload.
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
 pub enum GameType {
     /// Preseason games
@@ -45,6 +49,8 @@ pub enum GameType {
     WomensAllStar,
     /// 4 Nations Face-Off game
     FourNations,
+    /// A game type code with no named variant, kept as sent
+    Other(i32),
 }
 
 impl GameType {
@@ -66,12 +72,15 @@ impl GameType {
             Self::ExhibitionOverseas => 18,
             Self::WomensAllStar => 19,
             Self::FourNations => 20,
+            Self::Other(code) => code,
         }
     }
 
     /// Convert integer to GameType
     ///
-    /// Returns None for unknown game type values
+    /// Returns None for unknown game type values; use
+    /// [`from_int_or_other`](Self::from_int_or_other) to keep them as
+    /// [`GameType::Other`].
     pub fn from_int(value: i32) -> Option<Self> {
         match value {
             1 => Some(Self::Preseason),
@@ -93,12 +102,29 @@ impl GameType {
         }
     }
 
+    /// Convert integer to GameType, mapping unknown values to
+    /// [`GameType::Other`]
+    pub fn from_int_or_other(value: i32) -> Self {
+        Self::from_int(value).unwrap_or(Self::Other(value))
+    }
+
+    /// Whether this is a playoff game
+    pub fn is_playoffs(self) -> bool {
+        self == Self::Playoffs
+    }
+
+    /// Whether this is a regular season game
+    pub fn is_regular_season(self) -> bool {
+        self == Self::RegularSeason
+    }
+
     /// Returns the snake_case label for the GameType, suitable for use as a
     /// database enum value or a normalized identifier.
     ///
     /// These strings mirror the Go client's `GameType.Label()` byte-for-byte;
     /// downstream consumers persist them as database enum values, so they
-    /// must not be changed without a coordinated migration.
+    /// must not be changed without a coordinated migration. Every
+    /// [`GameType::Other`] code shares the label `"other"`.
     pub fn label(&self) -> &'static str {
         match self {
             Self::Preseason => "preseason",
@@ -116,6 +142,7 @@ impl GameType {
             Self::ExhibitionOverseas => "exhibition_overseas",
             Self::WomensAllStar => "womens_all_star",
             Self::FourNations => "four_nations",
+            Self::Other(_) => "other",
         }
     }
 }
@@ -138,6 +165,7 @@ impl fmt::Display for GameType {
             Self::ExhibitionOverseas => write!(f, "Exhibition Overseas"),
             Self::WomensAllStar => write!(f, "Women's All-Star"),
             Self::FourNations => write!(f, "4 Nations Face-Off"),
+            Self::Other(code) => write!(f, "Game Type {}", code),
         }
     }
 }
@@ -149,7 +177,8 @@ impl FromStr for GameType {
     /// case/hyphenation variants such as `"WorldCup2004"`), or a snake_case
     /// [`label`](Self::label) (`"world_cup_2004"`) into a [`GameType`].
     ///
-    /// Mirrors the Go client's `GameTypeFromString`.
+    /// Mirrors the Go client's `GameTypeFromString`, except that an
+    /// unrecognized numeric string parses to [`GameType::Other`].
     fn from_str(s: &str) -> Result<Self, Self::Err> {
         match s {
             "1" | "Preseason" | "preseason" => Ok(Self::Preseason),
@@ -176,10 +205,13 @@ impl FromStr for GameType {
             "20" | "4 Nations Face-Off" | "4NationsFaceOff" | "four_nations" => {
                 Ok(Self::FourNations)
             }
-            _ => Err(UnknownEnumValue {
-                enum_name: ENUM_NAME,
-                value: s.to_string(),
-            }),
+            _ => s
+                .parse::<i32>()
+                .map(Self::Other)
+                .map_err(|_| UnknownEnumValue {
+                    enum_name: ENUM_NAME,
+                    value: s.to_string(),
+                }),
         }
     }
 }
@@ -198,7 +230,8 @@ impl<'de> Deserialize<'de> for GameType {
     /// fallback (`"2"`, a display name, or a [`label`](Self::label)) — the
     /// NHL API is consistently integer-coded, but this mirrors the Go
     /// client's tolerant `UnmarshalJSON`, which tries int first and falls
-    /// back to string parsing.
+    /// back to string parsing. Unknown integer codes become
+    /// [`GameType::Other`]; unknown non-numeric strings are still an error.
     fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
     where
         D: Deserializer<'de>,
@@ -216,12 +249,14 @@ impl<'de> Deserialize<'de> for GameType {
             where
                 E: DeError,
             {
-                GameType::from_int(value as i32).ok_or_else(|| {
-                    E::custom(UnknownEnumValue {
-                        enum_name: ENUM_NAME,
-                        value: value.to_string(),
+                i32::try_from(value)
+                    .map(GameType::from_int_or_other)
+                    .map_err(|_| {
+                        E::custom(UnknownEnumValue {
+                            enum_name: ENUM_NAME,
+                            value: value.to_string(),
+                        })
                     })
-                })
             }
 
             fn visit_u64<E>(self, value: u64) -> Result<GameType, E>
@@ -487,16 +522,61 @@ mod tests {
     }
 
     #[test]
-    fn test_deserialize_unknown_int() {
-        let result = serde_json::from_str::<GameType>("5");
+    fn test_deserialize_unknown_int_is_other() {
+        assert_eq!(
+            serde_json::from_str::<GameType>("5").unwrap(),
+            GameType::Other(5)
+        );
+        assert_eq!(
+            serde_json::from_str::<GameType>("\"99\"").unwrap(),
+            GameType::Other(99)
+        );
+    }
+
+    #[test]
+    fn test_deserialize_out_of_range_int() {
+        let result = serde_json::from_str::<GameType>("4294967296");
         assert!(result.is_err());
         let message = result.unwrap_err().to_string();
         assert!(
-            message.contains("game type") && message.contains('5'),
+            message.contains("game type") && message.contains("4294967296"),
             "message missing enum name or value: {message}"
         );
     }
 
+    #[test]
+    fn test_other_round_trips_unchanged() {
+        for code in [0, 5, 11, 99] {
+            let game_type: GameType = serde_json::from_str(&code.to_string()).unwrap();
+            assert_eq!(game_type, GameType::Other(code));
+            assert_eq!(game_type.to_int(), code);
+            assert_eq!(serde_json::to_string(&game_type).unwrap(), code.to_string());
+        }
+    }
+
+    #[test]
+    fn test_other_display_and_label() {
+        assert_eq!(GameType::Other(5).to_string(), "Game Type 5");
+        assert_eq!(GameType::Other(5).label(), "other");
+        assert_eq!("5".parse::<GameType>(), Ok(GameType::Other(5)));
+    }
+
+    #[test]
+    fn test_from_int_or_other() {
+        assert_eq!(GameType::from_int_or_other(3), GameType::Playoffs);
+        assert_eq!(GameType::from_int_or_other(5), GameType::Other(5));
+    }
+
+    #[test]
+    fn test_is_playoffs_and_is_regular_season() {
+        assert!(GameType::Playoffs.is_playoffs());
+        assert!(!GameType::Playoffs.is_regular_season());
+        assert!(GameType::RegularSeason.is_regular_season());
+        assert!(!GameType::RegularSeason.is_playoffs());
+        assert!(!GameType::Other(3).is_playoffs());
+        assert!(!GameType::Preseason.is_regular_season());
+    }
+
     #[test]
     fn test_deserialize_unknown_string() {
         let result = serde_json::from_str::<GameType>("\"not-a-game-type\"");
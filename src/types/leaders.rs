@@ -0,0 +1,184 @@
+//! League stat leaders from `skater-stats-leaders` and
+//! `goalie-stats-leaders`.
+
+use serde::{Deserialize, Serialize};
+use std::collections::HashMap;
+
+use crate::ids::PlayerId;
+
+use super::common::LocalizedString;
+use super::enums::macros::nhl_string_enum;
+use super::enums::{empty_string_as_none, Position};
+
+nhl_string_enum! {
+    error_name = "skater leader category",
+    display = name,
+    /// A skater stat leaders category; the code is the API's category key
+    pub enum SkaterLeaderCategory {
+        /// Goals
+        Goals = "goals", name = "Goals";
+        /// Assists
+        Assists = "assists", name = "Assists";
+        /// Points
+        Points = "points", name = "Points";
+        /// Plus/minus
+        PlusMinus = "plusMinus", name = "Plus/Minus";
+        /// Power-play goals
+        PowerPlayGoals = "goalsPp", name = "Power Play Goals";
+        /// Shorthanded goals
+        ShorthandedGoals = "goalsSh", name = "Shorthanded Goals";
+        /// Penalty minutes
+        PenaltyMinutes = "penaltyMins", name = "Penalty Minutes";
+        /// Faceoff win percentage
+        FaceoffLeaders = "faceoffLeaders", name = "Faceoff Percentage";
+        /// Average time on ice, in seconds
+        TimeOnIce = "toi", name = "Time On Ice";
+    }
+}
+
+nhl_string_enum! {
+    error_name = "goalie leader category",
+    display = name,
+    /// A goalie stat leaders category; the code is the API's category key
+    pub enum GoalieLeaderCategory {
+        /// Goals-against average
+        GoalsAgainstAverage = "goalsAgainstAverage", name = "Goals Against Average",
+            aliases = ["gaa"];
+        /// Save percentage
+        SavePercentage = "savePctg", name = "Save Percentage";
+        /// Shutouts
+        Shutouts = "shutouts", name = "Shutouts";
+        /// Wins
+        Wins = "wins", name = "Wins";
+    }
+}
+
+/// One ranked player in a stat leaders category
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+#[serde(rename_all = "camelCase")]
+pub struct StatLeader {
+    pub id: PlayerId,
+    pub first_name: LocalizedString,
+    pub last_name: LocalizedString,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub sweater_number: Option<i32>,
+    pub headshot: String,
+    pub team_abbrev: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub team_name: Option<LocalizedString>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub team_logo: Option<String>,
+    /// `None` when the API returns an empty position code.
+    #[serde(deserialize_with = "empty_string_as_none", default)]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub position: Option<Position>,
+    /// The category's stat: a count, a percentage as a fraction
+    /// (`0.925`), or seconds for time on ice.
+    pub value: f64,
+}
+
+/// Skater stat leaders, ranked best first in each requested category
+#[derive(Debug, Clone, Default, Serialize, Deserialize, PartialEq)]
+#[serde(transparent)]
+pub struct SkaterStatsLeaders {
+    pub categories: HashMap<SkaterLeaderCategory, Vec<StatLeader>>,
+}
+
+impl SkaterStatsLeaders {
+    /// Leaders in `category`, empty if it wasn't returned
+    pub fn category(&self, category: SkaterLeaderCategory) -> &[StatLeader] {
+        self.categories
+            .get(&category)
+            .map_or(&[], |leaders| leaders.as_slice())
+    }
+}
+
+/// Goalie stat leaders, ranked best first in each requested category
+#[derive(Debug, Clone, Default, Serialize, Deserialize, PartialEq)]
+#[serde(transparent)]
+pub struct GoalieStatsLeaders {
+    pub categories: HashMap<GoalieLeaderCategory, Vec<StatLeader>>,
+}
+
+impl GoalieStatsLeaders {
+    /// Leaders in `category`, empty if it wasn't returned
+    pub fn category(&self, category: GoalieLeaderCategory) -> &[StatLeader] {
+        self.categories
+            .get(&category)
+            .map_or(&[], |leaders| leaders.as_slice())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_util::assert_no_unmapped_fields;
+
+    const SKATER_LEADERS_JSON: &str = r#"{
+        "goals": [
+            {"id": 8477934, "firstName": {"default": "Leon"},
+             "lastName": {"default": "Draisaitl"}, "sweaterNumber": 29,
+             "headshot": "https://assets.nhle.com/mugs/nhl/20242025/EDM/8477934.png",
+             "teamAbbrev": "EDM", "teamName": {"default": "Edmonton Oilers"},
+             "teamLogo": "https://assets.nhle.com/logos/nhl/svg/EDM_light.svg",
+             "position": "C", "value": 52}
+        ],
+        "toi": [
+            {"id": 8480800, "firstName": {"default": "Quinn"},
+             "lastName": {"default": "Hughes"}, "sweaterNumber": 43,
+             "headshot": "", "teamAbbrev": "VAN", "position": "D", "value": 1485.3}
+        ]
+    }"#;
+
+    #[test]
+    fn test_skater_stats_leaders_deserialization() {
+        let leaders: SkaterStatsLeaders = serde_json::from_str(SKATER_LEADERS_JSON).unwrap();
+        assert_no_unmapped_fields::<SkaterStatsLeaders>(SKATER_LEADERS_JSON);
+
+        let goals = leaders.category(SkaterLeaderCategory::Goals);
+        assert_eq!(goals[0].id, PlayerId::new(8477934));
+        assert_eq!(goals[0].team_abbrev, "EDM");
+        assert_eq!(goals[0].position, Some(Position::Center));
+        assert_eq!(goals[0].value, 52.0);
+        assert_eq!(
+            leaders.category(SkaterLeaderCategory::TimeOnIce)[0].value,
+            1485.3
+        );
+        assert!(leaders.category(SkaterLeaderCategory::Assists).is_empty());
+    }
+
+    #[test]
+    fn test_goalie_stats_leaders_deserialization() {
+        let json = r#"{"savePctg": [
+            {"id": 8476945, "firstName": {"default": "Connor"},
+             "lastName": {"default": "Hellebuyck"}, "sweaterNumber": 37,
+             "headshot": "", "teamAbbrev": "WPG", "position": "G", "value": 0.925}
+        ]}"#;
+
+        let leaders: GoalieStatsLeaders = serde_json::from_str(json).unwrap();
+        assert_no_unmapped_fields::<GoalieStatsLeaders>(json);
+        let save_pctg = leaders.category(GoalieLeaderCategory::SavePercentage);
+        assert_eq!(save_pctg[0].team_abbrev, "WPG");
+        assert_eq!(save_pctg[0].value, 0.925);
+    }
+
+    #[test]
+    fn test_unknown_leader_category_fails() {
+        let result = serde_json::from_str::<GoalieStatsLeaders>(r#"{"bogus": []}"#);
+        assert!(result.is_err());
+    }
+
+    #[test]
+    fn test_leader_category_codes() {
+        assert_eq!(SkaterLeaderCategory::PlusMinus.code(), "plusMinus");
+        assert_eq!(
+            SkaterLeaderCategory::FaceoffLeaders.code(),
+            "faceoffLeaders"
+        );
+        assert_eq!(
+            "gaa".parse::<GoalieLeaderCategory>(),
+            Ok(GoalieLeaderCategory::GoalsAgainstAverage)
+        );
+        assert_eq!(GoalieLeaderCategory::Wins.to_string(), "Wins");
+    }
+}
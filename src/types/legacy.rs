@@ -0,0 +1,609 @@
+//! Relaxed play-by-play schema for very old games.
+//!
+//! Gamecenter data from before roughly 2010 lacks coordinates, situation
+//! codes and several fields [`PlayByPlay`] requires, so those games fail to
+//! decode. [`LegacyPlayByPlay`] accepts them with almost everything
+//! optional, and [`LegacyPlayByPlay::convert`] turns one into a regular
+//! `PlayByPlay`, reporting which fields it had to fill in.
+
+use serde::{Deserialize, Serialize};
+use std::collections::BTreeSet;
+
+use crate::date::Season;
+use crate::error::NHLApiError;
+use crate::ice_time::IceTime;
+use crate::ids::{GameId, PlayerId, TeamId};
+
+use super::boxscore::{BoxscoreTeam, GameClock, PeriodDescriptor, TvBroadcast};
+use super::common::LocalizedString;
+use super::enums::{empty_string_as_none, DefendingSide, GameScheduleState, PeriodType, Position};
+use super::game_center::{
+    GameOutcome, PlayByPlay, PlayEvent, PlayEventDetails, PlayEventType, RosterSpot,
+};
+use super::game_state::GameState;
+use super::game_type::GameType;
+
+/// First season with the shootout.
+const FIRST_SHOOTOUT_SEASON: u16 = 2005;
+
+/// First season with regular-season overtime.
+const FIRST_REGULAR_SEASON_OT_SEASON: u16 = 1983;
+
+/// Regulation periods assumed for every legacy game.
+const REGULATION_PERIODS: i32 = 3;
+
+/// Play-by-play for a pre-2010 game, where nearly every field may be
+/// missing
+///
+/// Convert with [`Self::convert`] (or `PlayByPlay::try_from`) to use the
+/// [`PlayByPlay`] helpers. After conversion these work on legacy data:
+/// `goals()`, `penalties()`, `shots_on_goal()`, `shot_attempts()`,
+/// `events_in_period()`, `events_by_player()`, `player_name()`,
+/// `player_event_counts()` and `team_stats()` (except power-play goals,
+/// which need situation codes and come out as 0). These return `None` or
+/// nothing useful: `current_situation()`, `PlayEvent::situation()` and
+/// `PlayEvent::normalized_coords()`. The boxscore-based `analytics`
+/// helpers don't take play-by-play at all.
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+pub struct LegacyPlayByPlay {
+    pub id: GameId,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub season: Option<Season>,
+    #[serde(rename = "gameType")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub game_type: Option<GameType>,
+    #[serde(rename = "limitedScoring")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub limited_scoring: Option<bool>,
+    #[serde(rename = "gameDate")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub game_date: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub venue: Option<LocalizedString>,
+    #[serde(rename = "venueLocation")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub venue_location: Option<LocalizedString>,
+    #[serde(rename = "startTimeUTC")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub start_time_utc: Option<String>,
+    #[serde(rename = "easternUTCOffset")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub eastern_utc_offset: Option<String>,
+    #[serde(rename = "venueUTCOffset")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub venue_utc_offset: Option<String>,
+    #[serde(rename = "tvBroadcasts", default)]
+    pub tv_broadcasts: Vec<TvBroadcast>,
+    #[serde(rename = "gameState")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub game_state: Option<GameState>,
+    #[serde(rename = "gameScheduleState")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub game_schedule_state: Option<GameScheduleState>,
+    #[serde(rename = "periodDescriptor")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub period_descriptor: Option<PeriodDescriptor>,
+    #[serde(rename = "awayTeam")]
+    pub away_team: LegacyTeam,
+    #[serde(rename = "homeTeam")]
+    pub home_team: LegacyTeam,
+    #[serde(rename = "shootoutInUse")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub shootout_in_use: Option<bool>,
+    #[serde(rename = "otInUse")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub ot_in_use: Option<bool>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub clock: Option<GameClock>,
+    #[serde(rename = "displayPeriod")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub display_period: Option<i32>,
+    #[serde(rename = "maxPeriods")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub max_periods: Option<i32>,
+    #[serde(rename = "gameOutcome")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub game_outcome: Option<GameOutcome>,
+    #[serde(default)]
+    pub plays: Vec<LegacyPlayEvent>,
+    #[serde(rename = "rosterSpots", default)]
+    pub roster_spots: Vec<LegacyRosterSpot>,
+}
+
+/// A team in a [`LegacyPlayByPlay`]
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+pub struct LegacyTeam {
+    pub id: TeamId,
+    #[serde(rename = "commonName")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub common_name: Option<LocalizedString>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub abbrev: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub score: Option<i32>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub sog: Option<i32>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub logo: Option<String>,
+    #[serde(rename = "darkLogo")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub dark_logo: Option<String>,
+    #[serde(rename = "placeName")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub place_name: Option<LocalizedString>,
+    #[serde(rename = "placeNameWithPreposition")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub place_name_with_preposition: Option<LocalizedString>,
+}
+
+/// A play in a [`LegacyPlayByPlay`]
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+pub struct LegacyPlayEvent {
+    #[serde(rename = "eventId")]
+    pub event_id: i64,
+    #[serde(rename = "periodDescriptor")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub period_descriptor: Option<PeriodDescriptor>,
+    #[serde(rename = "timeInPeriod")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub time_in_period: Option<String>,
+    #[serde(rename = "timeRemaining")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub time_remaining: Option<String>,
+    #[serde(
+        rename = "situationCode",
+        deserialize_with = "empty_string_as_none",
+        default
+    )]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub situation_code: Option<String>,
+    #[serde(
+        rename = "homeTeamDefendingSide",
+        deserialize_with = "empty_string_as_none",
+        default
+    )]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub home_team_defending_side: Option<DefendingSide>,
+    #[serde(rename = "typeCode")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub type_code: Option<i32>,
+    #[serde(rename = "typeDescKey", default = "unknown_event_type")]
+    pub type_desc_key: PlayEventType,
+    #[serde(rename = "sortOrder")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub sort_order: Option<i32>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub details: Option<PlayEventDetails>,
+}
+
+/// A roster entry in a [`LegacyPlayByPlay`]
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+pub struct LegacyRosterSpot {
+    #[serde(rename = "teamId")]
+    pub team_id: TeamId,
+    #[serde(rename = "playerId")]
+    pub player_id: PlayerId,
+    #[serde(rename = "firstName")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub first_name: Option<LocalizedString>,
+    #[serde(rename = "lastName")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub last_name: Option<LocalizedString>,
+    #[serde(rename = "sweaterNumber")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub sweater_number: Option<i32>,
+    #[serde(
+        rename = "positionCode",
+        deserialize_with = "empty_string_as_none",
+        default
+    )]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub position: Option<Position>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub headshot: Option<String>,
+}
+
+/// A [`PlayByPlay`] converted from a [`LegacyPlayByPlay`], with the fields
+/// that were filled in rather than read from the payload
+#[derive(Debug, Clone, PartialEq)]
+pub struct LegacyConversion {
+    pub play_by_play: PlayByPlay,
+    /// JSON paths of synthesized fields, e.g. `"season"`, `"awayTeam.sog"`
+    /// or `"plays.situationCode"` (listed once however many plays lacked
+    /// it).
+    pub synthesized: BTreeSet<String>,
+}
+
+impl LegacyConversion {
+    /// Whether `field` (a path as listed in [`Self::synthesized`]) was
+    /// filled in.
+    pub fn is_synthesized(&self, field: &str) -> bool {
+        self.synthesized.contains(field)
+    }
+}
+
+fn unknown_event_type() -> PlayEventType {
+    PlayEventType::Unknown
+}
+
+/// Hands out fallbacks for missing fields and records which ones it used.
+#[derive(Default)]
+struct Filler(BTreeSet<String>);
+
+impl Filler {
+    fn mark(&mut self, field: &str) {
+        self.0.insert(field.to_string());
+    }
+
+    fn or<T>(&mut self, value: Option<T>, field: &str, fallback: impl FnOnce() -> T) -> T {
+        value.unwrap_or_else(|| {
+            self.mark(field);
+            fallback()
+        })
+    }
+
+    fn or_default<T: Default>(&mut self, value: Option<T>, field: &str) -> T {
+        self.or(value, field, T::default)
+    }
+
+    fn team(&mut self, team: LegacyTeam, side: &str, plays: &[PlayEvent]) -> BoxscoreTeam {
+        let field = |name: &str| format!("{}.{}", side, name);
+        let owned = |play: &&PlayEvent| {
+            play.period_descriptor.period_type != Some(PeriodType::Shootout)
+                && play.details.as_ref().and_then(|d| d.event_owner_team_id) == Some(team.id)
+        };
+        BoxscoreTeam {
+            id: team.id,
+            common_name: self.or_default(team.common_name, &field("commonName")),
+            abbrev: self.or_default(team.abbrev, &field("abbrev")),
+            score: self.or(team.score, &field("score"), || {
+                plays.iter().filter(owned).filter(|p| p.is_goal()).count() as i32
+            }),
+            sog: Some(self.or(team.sog, &field("sog"), || {
+                plays
+                    .iter()
+                    .filter(owned)
+                    .filter(|p| {
+                        matches!(
+                            p.event_type(),
+                            PlayEventType::ShotOnGoal | PlayEventType::Goal
+                        )
+                    })
+                    .count() as i32
+            })),
+            logo: self.or_default(team.logo, &field("logo")),
+            dark_logo: self.or_default(team.dark_logo, &field("darkLogo")),
+            place_name: self.or_default(team.place_name, &field("placeName")),
+            place_name_with_preposition: self.or_default(
+                team.place_name_with_preposition,
+                &field("placeNameWithPreposition"),
+            ),
+        }
+    }
+}
+
+impl LegacyPlayByPlay {
+    /// Convert to a [`PlayByPlay`], filling missing fields with defaults
+    ///
+    /// - season and game type come from the game id;
+    /// - scores (shootout excluded) and shots on goal are counted from the
+    ///   plays;
+    /// - a missing game state is taken as final, since these games are
+    ///   long over;
+    /// - overtime and shootout flags follow the rules of the season
+    ///   (regular-season overtime from 1983-84, shootouts from 2005-06);
+    /// - periods default to three regulation periods and the last period
+    ///   played;
+    /// - missing text becomes empty, including situation codes (so
+    ///   `situation()` is `None`), and missing play order comes from the
+    ///   list order.
+    ///
+    /// Fails with [`NHLApiError::InvalidInput`] when the season or game
+    /// type is missing and can't be read from the game id, or a play has no
+    /// period.
+    pub fn convert(self) -> Result<LegacyConversion, NHLApiError> {
+        let mut fill = Filler::default();
+        let id = self.id;
+
+        let season = match self.season {
+            Some(season) => season,
+            None => {
+                fill.mark("season");
+                id.season().ok_or_else(|| {
+                    NHLApiError::InvalidInput(format!("game {}: no season in payload or id", id))
+                })?
+            }
+        };
+        let game_type = match self.game_type {
+            Some(game_type) => game_type,
+            None => {
+                fill.mark("gameType");
+                id.game_type().ok_or_else(|| {
+                    NHLApiError::InvalidInput(format!("game {}: no game type in payload or id", id))
+                })?
+            }
+        };
+
+        let mut plays = Vec::with_capacity(self.plays.len());
+        for (index, play) in self.plays.into_iter().enumerate() {
+            let period_descriptor = play
+                .period_descriptor
+                .filter(|period| period.number > 0)
+                .ok_or_else(|| {
+                    NHLApiError::InvalidInput(format!(
+                        "game {}: play {} has no period",
+                        id, play.event_id
+                    ))
+                })?;
+            let type_desc_key = play.type_desc_key;
+            plays.push(PlayEvent {
+                event_id: play.event_id,
+                period_descriptor,
+                time_in_period: fill.or_default(play.time_in_period, "plays.timeInPeriod"),
+                time_remaining: fill.or_default(play.time_remaining, "plays.timeRemaining"),
+                situation_code: fill.or_default(play.situation_code, "plays.situationCode"),
+                home_team_defending_side: play.home_team_defending_side,
+                type_code: fill.or(play.type_code, "plays.typeCode", || {
+                    type_desc_key.code().unwrap_or(0)
+                }),
+                type_desc_key,
+                sort_order: fill.or(play.sort_order, "plays.sortOrder", || index as i32),
+                details: play.details,
+                ppt_replay_url: None,
+            });
+        }
+
+        let last_period = plays
+            .last()
+            .map(|play| play.period_descriptor.clone())
+            .unwrap_or_default();
+        let regular_season = game_type.is_regular_season();
+        let roster_spots = self
+            .roster_spots
+            .into_iter()
+            .map(|spot| RosterSpot {
+                team_id: spot.team_id,
+                player_id: spot.player_id,
+                first_name: fill.or_default(spot.first_name, "rosterSpots.firstName"),
+                last_name: fill.or_default(spot.last_name, "rosterSpots.lastName"),
+                sweater_number: spot.sweater_number,
+                position: spot.position,
+                headshot: fill.or_default(spot.headshot, "rosterSpots.headshot"),
+            })
+            .collect();
+
+        let play_by_play = PlayByPlay {
+            id,
+            season,
+            game_type,
+            limited_scoring: fill.or_default(self.limited_scoring, "limitedScoring"),
+            game_date: fill.or_default(self.game_date, "gameDate"),
+            venue: fill.or_default(self.venue, "venue"),
+            venue_location: fill.or_default(self.venue_location, "venueLocation"),
+            start_time_utc: fill.or_default(self.start_time_utc, "startTimeUTC"),
+            eastern_utc_offset: fill.or_default(self.eastern_utc_offset, "easternUTCOffset"),
+            venue_utc_offset: fill.or_default(self.venue_utc_offset, "venueUTCOffset"),
+            tv_broadcasts: self.tv_broadcasts,
+            game_state: fill.or(self.game_state, "gameState", || GameState::Off),
+            game_schedule_state: fill.or(self.game_schedule_state, "gameScheduleState", || {
+                GameScheduleState::Ok
+            }),
+            period_descriptor: fill.or(self.period_descriptor, "periodDescriptor", || {
+                last_period.clone()
+            }),
+            special_event: None,
+            away_team: fill.team(self.away_team, "awayTeam", &plays),
+            home_team: fill.team(self.home_team, "homeTeam", &plays),
+            shootout_in_use: fill.or(self.shootout_in_use, "shootoutInUse", || {
+                regular_season && season.start_year() >= FIRST_SHOOTOUT_SEASON
+            }),
+            ot_in_use: fill.or(self.ot_in_use, "otInUse", || {
+                !regular_season || season.start_year() >= FIRST_REGULAR_SEASON_OT_SEASON
+            }),
+            clock: fill.or(self.clock, "clock", || GameClock {
+                time_remaining: IceTime::ZERO,
+                seconds_remaining: 0,
+                running: false,
+                in_intermission: false,
+            }),
+            display_period: fill.or(self.display_period, "displayPeriod", || last_period.number),
+            max_periods: fill.or(self.max_periods, "maxPeriods", || {
+                last_period.number.max(REGULATION_PERIODS)
+            }),
+            game_outcome: self.game_outcome,
+            plays,
+            roster_spots,
+            reg_periods: REGULATION_PERIODS,
+            summary: None,
+        };
+        Ok(LegacyConversion {
+            play_by_play,
+            synthesized: fill.0,
+        })
+    }
+}
+
+impl TryFrom<LegacyPlayByPlay> for PlayByPlay {
+    type Error = NHLApiError;
+
+    /// Lossy: see [`LegacyPlayByPlay::convert`], which also reports the
+    /// synthesized fields.
+    fn try_from(legacy: LegacyPlayByPlay) -> Result<Self, Self::Error> {
+        legacy.convert().map(|conversion| conversion.play_by_play)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::types::HomeRoad;
+
+    const LEGACY_FIXTURE: &str = include_str!("../../tests/fixtures/play_by_play_2008.json");
+
+    fn legacy() -> LegacyPlayByPlay {
+        serde_json::from_str(LEGACY_FIXTURE).unwrap()
+    }
+
+    #[test]
+    fn test_legacy_fixture_fails_strict_parse() {
+        assert!(serde_json::from_str::<PlayByPlay>(LEGACY_FIXTURE).is_err());
+    }
+
+    #[test]
+    fn test_legacy_play_by_play_relaxed_parse() {
+        let legacy = legacy();
+        assert_eq!(legacy.id, GameId::new(2008020001));
+        assert_eq!(legacy.season, Some(Season::new(2008)));
+        assert_eq!(legacy.game_state, Some(GameState::Off));
+        assert_eq!(legacy.clock, None);
+        assert_eq!(legacy.away_team.sog, None);
+        assert_eq!(legacy.away_team.score, Some(2));
+        assert_eq!(legacy.plays.len(), 10);
+        assert_eq!(legacy.roster_spots.len(), 7);
+        assert_eq!(legacy.roster_spots[0].headshot, None);
+
+        let goal = &legacy.plays[3];
+        assert_eq!(goal.type_desc_key, PlayEventType::Goal);
+        assert_eq!(goal.situation_code, None);
+        assert_eq!(goal.home_team_defending_side, None);
+        let details = goal.details.as_ref().unwrap();
+        assert_eq!(details.x_coord, None);
+        assert_eq!(details.scoring_player_id, Some(PlayerId::new(8400002)));
+    }
+
+    #[test]
+    fn test_legacy_conversion_reports_synthesized_fields() {
+        let conversion = legacy().convert().unwrap();
+        let expected = [
+            "awayTeam.darkLogo",
+            "awayTeam.placeNameWithPreposition",
+            "awayTeam.sog",
+            "clock",
+            "displayPeriod",
+            "easternUTCOffset",
+            "gameScheduleState",
+            "homeTeam.darkLogo",
+            "homeTeam.placeNameWithPreposition",
+            "homeTeam.sog",
+            "limitedScoring",
+            "maxPeriods",
+            "otInUse",
+            "periodDescriptor",
+            "plays.situationCode",
+            "rosterSpots.headshot",
+            "shootoutInUse",
+            "venueLocation",
+            "venueUTCOffset",
+        ];
+        let synthesized: Vec<&str> = conversion.synthesized.iter().map(String::as_str).collect();
+        assert_eq!(synthesized, expected);
+        assert!(conversion.is_synthesized("clock"));
+        assert!(!conversion.is_synthesized("season"));
+        assert!(!conversion.is_synthesized("awayTeam.score"));
+    }
+
+    #[test]
+    fn test_legacy_conversion_fills_values() {
+        let pbp = legacy().convert().unwrap().play_by_play;
+        assert_eq!(pbp.away_team.score, 2);
+        assert_eq!(pbp.home_team.score, 1);
+        // Two goals and one shot on goal for New York, one of each for Tampa.
+        assert_eq!(pbp.away_team.sog, Some(3));
+        assert_eq!(pbp.home_team.sog, Some(2));
+        assert!(pbp.shootout_in_use);
+        assert!(pbp.ot_in_use);
+        assert_eq!(pbp.display_period, 3);
+        assert_eq!(pbp.max_periods, 3);
+        assert_eq!(pbp.reg_periods, 3);
+        assert_eq!(pbp.period_descriptor.number, 3);
+        assert_eq!(pbp.clock.time_remaining, IceTime::ZERO);
+        assert_eq!(pbp.game_schedule_state, GameScheduleState::Ok);
+        assert_eq!(pbp.roster_spots[0].headshot, "");
+        assert_eq!(pbp.plays[3].situation_code, "");
+        assert_eq!(pbp.plays[3].situation(), None);
+    }
+
+    #[test]
+    fn test_legacy_conversion_supports_play_by_play_helpers() {
+        let pbp = PlayByPlay::try_from(legacy()).unwrap();
+        assert_eq!(pbp.goals().len(), 3);
+        assert_eq!(pbp.penalties().len(), 1);
+        assert_eq!(pbp.events_in_period(2).len(), 3);
+        assert_eq!(pbp.player_name(8400003).as_deref(), Some("Away Defenseman"));
+
+        let counts = pbp.player_event_counts();
+        assert_eq!(counts[&PlayerId::new(8400001)].assists, 2);
+        assert_eq!(counts[&PlayerId::new(8400003)].shots, 2);
+
+        let away = pbp.team_stats(HomeRoad::Road);
+        assert_eq!(Some(away.shots_on_goal), pbp.away_team.sog);
+        assert_eq!(away.hits, 1);
+        assert_eq!(away.power_play_opportunities, Some(1));
+        assert_eq!(pbp.team_stats(HomeRoad::Home).penalty_minutes, 2);
+
+        assert_eq!(pbp.current_situation(), None);
+        assert_eq!(pbp.plays[3].normalized_coords(14, 3), None);
+    }
+
+    #[test]
+    fn test_legacy_conversion_derives_season_and_type_from_id() {
+        let mut legacy = legacy();
+        legacy.season = None;
+        legacy.game_type = None;
+        let conversion = legacy.convert().unwrap();
+        assert_eq!(conversion.play_by_play.season, Season::new(2008));
+        assert_eq!(conversion.play_by_play.game_type, GameType::RegularSeason);
+        assert!(conversion.is_synthesized("season"));
+        assert!(conversion.is_synthesized("gameType"));
+    }
+
+    #[test]
+    fn test_legacy_conversion_counts_score_without_shootout() {
+        let mut legacy = legacy();
+        legacy.away_team.score = None;
+        let mut shootout_goal = legacy.plays[3].clone();
+        shootout_goal.period_descriptor = Some(PeriodDescriptor {
+            number: 5,
+            period_type: Some(PeriodType::Shootout),
+            max_regulation_periods: 3,
+        });
+        legacy.plays.push(shootout_goal);
+
+        let conversion = legacy.convert().unwrap();
+        assert_eq!(conversion.play_by_play.away_team.score, 2);
+        assert!(conversion.is_synthesized("awayTeam.score"));
+    }
+
+    #[test]
+    fn test_legacy_conversion_errors() {
+        let mut no_period = legacy();
+        no_period.plays[4].period_descriptor = None;
+        let err = no_period.convert().unwrap_err();
+        assert!(matches!(err, NHLApiError::InvalidInput(ref msg) if msg.contains("play 5")));
+
+        let mut bad_id = legacy();
+        bad_id.id = GameId::new(42);
+        bad_id.season = None;
+        assert!(matches!(
+            bad_id.convert(),
+            Err(NHLApiError::InvalidInput(_))
+        ));
+    }
+
+    #[test]
+    fn test_legacy_shootout_and_overtime_follow_season_rules() {
+        let mut legacy = legacy();
+        legacy.season = Some(Season::new(1995));
+        let pbp = PlayByPlay::try_from(legacy.clone()).unwrap();
+        assert!(!pbp.shootout_in_use);
+        assert!(pbp.ot_in_use);
+
+        legacy.season = Some(Season::new(1980));
+        assert!(!PlayByPlay::try_from(legacy.clone()).unwrap().ot_in_use);
+
+        legacy.game_type = Some(GameType::Playoffs);
+        let playoffs = PlayByPlay::try_from(legacy).unwrap();
+        assert!(playoffs.ot_in_use);
+        assert!(!playoffs.shootout_in_use);
+    }
+}
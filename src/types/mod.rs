@@ -1,18 +1,26 @@
 pub mod boxscore;
 pub mod club_stats;
 pub mod common;
+pub mod draft;
 pub mod edge;
 pub mod enums;
 pub mod game_center;
 pub mod game_state;
 pub mod game_type;
+pub mod leaders;
+pub mod legacy;
 pub mod player;
+pub mod playoffs;
+pub mod rink;
 pub mod schedule;
+pub(crate) mod serde_helpers;
 pub mod standings;
+pub mod venue_time;
 
 pub use boxscore::*;
 pub use club_stats::*;
 pub use common::*;
+pub use draft::*;
 // Re-export Edge shared types (`edge::common::*` rather than `edge::*` to avoid
 // colliding the `common` submodule name with `types::common`).
 pub use edge::common::*;
@@ -23,6 +31,10 @@ pub use enums::*;
 pub use game_center::*;
 pub use game_state::*;
 pub use game_type::*;
+pub use leaders::*;
+pub use legacy::*;
 pub use player::*;
+pub use playoffs::*;
 pub use schedule::*;
 pub use standings::*;
+pub use venue_time::*;
@@ -1,9 +1,16 @@
 use crate::date::Season;
+use crate::ice_time::IceTime;
 use crate::ids::{GameId, PlayerId, TeamId};
-use crate::types::common::LocalizedString;
-use crate::types::enums::{empty_string_as_none, Handedness, HomeRoad, Position};
+use crate::types::common::{
+    centimeters_to_inches, height_cm, height_imperial, weight_kg, weight_lbs, BirthInfo,
+    LocalizedString, INCHES_PER_FOOT,
+};
+use crate::types::enums::{empty_string_as_none, GoalieDecision, Handedness, HomeRoad, Position};
 use crate::types::game_type::GameType;
+use crate::types::serde_helpers::string_or_number_i32;
+use chrono::NaiveDate;
 use serde::{Deserialize, Serialize};
+use std::fmt;
 
 /// Player landing page data - comprehensive player profile
 #[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
@@ -33,18 +40,20 @@ pub struct PlayerLanding {
     #[serde(skip_serializing_if = "Option::is_none")]
     pub hero_image: Option<String>,
 
-    pub height_in_inches: i32,
-    pub weight_in_pounds: i32,
+    /// Imperial and metric height and weight, whichever were sent; see
+    /// [`Self::height_cm`] and friends for either unit.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub height_in_inches: Option<i32>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub weight_in_pounds: Option<i32>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub height_in_centimeters: Option<i32>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub weight_in_kilograms: Option<i32>,
     pub birth_date: String,
 
-    #[serde(skip_serializing_if = "Option::is_none")]
-    pub birth_city: Option<LocalizedString>,
-
-    #[serde(skip_serializing_if = "Option::is_none")]
-    pub birth_state_province: Option<LocalizedString>,
-
-    #[serde(skip_serializing_if = "Option::is_none")]
-    pub birth_country: Option<String>,
+    #[serde(flatten)]
+    pub birth: BirthInfo,
 
     /// `None` for players with missing handedness data from the API.
     #[serde(deserialize_with = "empty_string_as_none", default)]
@@ -73,6 +82,106 @@ pub struct PlayerLanding {
     pub last_five_games: Option<Vec<GameLog>>,
 }
 
+impl PlayerLanding {
+    /// Age in whole years today (UTC); `None` if `birth_date` isn't
+    /// `YYYY-MM-DD`.
+    pub fn current_age(&self) -> Option<u32> {
+        self.age_on(chrono::Utc::now().date_naive())
+    }
+
+    /// Age in whole years on `date`; `None` if `birth_date` isn't
+    /// `YYYY-MM-DD` or is after `date`.
+    pub fn age_on(&self, date: NaiveDate) -> Option<u32> {
+        let birth = NaiveDate::parse_from_str(&self.birth_date, "%Y-%m-%d").ok()?;
+        date.years_since(birth)
+    }
+
+    /// Height in centimeters, converted from inches when only those were
+    /// sent (rounded to the nearest centimeter).
+    pub fn height_cm(&self) -> Option<u32> {
+        height_cm(self.height_in_inches, self.height_in_centimeters)
+    }
+
+    /// Height as feet and inches, e.g. `6'2"`.
+    pub fn height_imperial(&self) -> Option<String> {
+        height_imperial(self.height_in_inches, self.height_in_centimeters)
+    }
+
+    /// Weight in kilograms, converted from pounds when only those were sent.
+    pub fn weight_kg(&self) -> Option<u32> {
+        weight_kg(self.weight_in_pounds, self.weight_in_kilograms)
+    }
+
+    /// Weight in pounds, converted from kilograms when only those were sent.
+    pub fn weight_lbs(&self) -> Option<u32> {
+        weight_lbs(self.weight_in_pounds, self.weight_in_kilograms)
+    }
+
+    /// Nationality, taken from `birthCountry` since the landing has no
+    /// separate field for it: the country's English name (`"Canada"`) when
+    /// [`country_name`](crate::country_name) knows the code, else
+    /// the code as sent.
+    pub fn nationality(&self) -> Option<&str> {
+        self.birth
+            .country_name()
+            .or(self.birth.country_code.as_deref())
+            .filter(|nationality| !nationality.is_empty())
+    }
+
+    /// Draft line such as `"2015 Round 1, Pick 3 (TOR)"`; `None` for
+    /// undrafted players.
+    pub fn draft_summary(&self) -> Option<String> {
+        self.draft_details.as_ref().map(DraftDetails::to_string)
+    }
+
+    /// Career regular-season totals
+    pub fn career_regular_season(&self) -> Option<&PlayerStats> {
+        self.career_totals
+            .as_ref()
+            .map(|totals| &totals.regular_season)
+    }
+
+    /// Career playoff totals; `None` for players without playoff games.
+    pub fn career_playoffs(&self) -> Option<&PlayerStats> {
+        self.career_totals.as_ref()?.playoffs.as_ref()
+    }
+
+    /// The regular-season row with the most points, NHL only unless
+    /// `include_other_leagues`
+    ///
+    /// Rows are per team, so a season split by a trade is compared team by
+    /// team. Ties go to the earlier season.
+    pub fn best_season_by_points(&self, include_other_leagues: bool) -> Option<&SeasonTotal> {
+        self.season_totals
+            .as_deref()?
+            .iter()
+            .filter(|total| total.game_type.is_regular_season())
+            .filter(|total| include_other_leagues || total.is_nhl())
+            .filter(|total| total.points.is_some())
+            .rev()
+            .max_by_key(|total| total.points)
+    }
+
+    /// Season rows (any league and game type) with a team whose full or
+    /// common name is `team_name`, ignoring case
+    ///
+    /// The landing payload's season totals carry team names but no
+    /// abbreviations, e.g. `"Toronto Maple Leafs"` or `"Maple Leafs"`.
+    pub fn seasons_with_team(&self, team_name: &str) -> Vec<&SeasonTotal> {
+        self.season_totals
+            .iter()
+            .flatten()
+            .filter(|total| {
+                total.team_name.default.eq_ignore_ascii_case(team_name)
+                    || total
+                        .team_common_name
+                        .as_ref()
+                        .is_some_and(|name| name.default.eq_ignore_ascii_case(team_name))
+            })
+            .collect()
+    }
+}
+
 /// Draft details for a player
 #[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
 #[serde(rename_all = "camelCase")]
@@ -84,6 +193,17 @@ pub struct DraftDetails {
     pub overall_pick: i32,
 }
 
+impl fmt::Display for DraftDetails {
+    /// `"2015 Round 1, Pick 3 (TOR)"`, with the pick in its round.
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "{} Round {}, Pick {} ({})",
+            self.year, self.round, self.pick_in_round, self.team_abbrev
+        )
+    }
+}
+
 /// Featured stats shown prominently on player page
 #[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
 #[serde(rename_all = "camelCase")]
@@ -206,6 +326,14 @@ pub struct SeasonTotal {
     pub pim: Option<i32>,
 }
 
+impl SeasonTotal {
+    /// Whether this row is for an NHL season (`leagueAbbrev` `"NHL"`), as
+    /// opposed to the AHL, junior or European leagues.
+    pub fn is_nhl(&self) -> bool {
+        self.league_abbrev == "NHL"
+    }
+}
+
 /// Award won by player
 #[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
 #[serde(rename_all = "camelCase")]
@@ -222,6 +350,10 @@ pub struct AwardSeason {
 }
 
 /// Game log entry for a single game
+///
+/// Skater and goalie logs share this type. Skater-only counters are `0` in
+/// goalie logs, which don't send them; the goalie fields are `None` in
+/// skater logs.
 #[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
 #[serde(rename_all = "camelCase")]
 pub struct GameLog {
@@ -232,14 +364,41 @@ pub struct GameLog {
     pub opponent_abbrev: String,
     pub goals: i32,
     pub assists: i32,
+    #[serde(default)]
     pub points: i32,
+    #[serde(default)]
     pub plus_minus: i32,
+    #[serde(default)]
     pub power_play_goals: i32,
+    #[serde(default)]
     pub power_play_points: i32,
+    #[serde(default)]
     pub shots: i32,
+    #[serde(default)]
     pub shifts: i32,
     pub toi: String,
 
+    // Goalie stats
+    /// `1` for a start, `0` for a relief appearance.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub games_started: Option<i32>,
+
+    /// `None` for goalie appearances without a decision.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub decision: Option<GoalieDecision>,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub shots_against: Option<i32>,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub goals_against: Option<i32>,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub save_pctg: Option<f64>,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub shutouts: Option<i32>,
+
     #[serde(skip_serializing_if = "Option::is_none")]
     pub game_winning_goals: Option<i32>,
 
@@ -267,6 +426,157 @@ pub struct PlayerGameLog {
     pub game_log: Vec<GameLog>,
 }
 
+impl GameLog {
+    /// True for a goalie's game
+    pub fn is_goalie(&self) -> bool {
+        self.games_started.is_some() || self.shots_against.is_some()
+    }
+
+    fn date(&self) -> Option<NaiveDate> {
+        NaiveDate::parse_from_str(&self.game_date, "%Y-%m-%d").ok()
+    }
+}
+
+/// A skater's sums over a [`PlayerGameLog`], from [`PlayerGameLog::totals`]
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct GameLogTotals {
+    pub games_played: u32,
+    pub goals: i32,
+    pub assists: i32,
+    pub points: i32,
+    pub plus_minus: i32,
+    pub power_play_goals: i32,
+    pub shots: i32,
+    pub pim: i32,
+    /// Games with an unreadable `toi` add nothing
+    pub toi: IceTime,
+}
+
+impl PlayerGameLog {
+    /// Games dated `from` to `to`, both included, as a log of their own
+    ///
+    /// ```
+    /// # use chrono::NaiveDate;
+    /// # fn example(log: &nhl_api::PlayerGameLog) {
+    /// let march = log.between(
+    ///     NaiveDate::from_ymd_opt(2024, 3, 1).unwrap(),
+    ///     NaiveDate::from_ymd_opt(2024, 3, 31).unwrap(),
+    /// );
+    /// println!("{} starts in March", march.games_started());
+    /// # }
+    /// ```
+    pub fn between(&self, from: NaiveDate, to: NaiveDate) -> PlayerGameLog {
+        PlayerGameLog {
+            game_log: self
+                .game_log
+                .iter()
+                .filter(|game| game.date().is_some_and(|date| from <= date && date <= to))
+                .cloned()
+                .collect(),
+            ..self.clone()
+        }
+    }
+
+    /// Games oldest first (the API lists the latest first)
+    fn chronological(&self) -> Vec<&GameLog> {
+        let mut games: Vec<&GameLog> = self.game_log.iter().collect();
+        games.sort_by(|a, b| a.game_date.cmp(&b.game_date));
+        games
+    }
+
+    /// A goalie's starts
+    pub fn games_started(&self) -> i32 {
+        self.game_log
+            .iter()
+            .filter_map(|game| game.games_started)
+            .sum()
+    }
+
+    /// A goalie's wins
+    pub fn wins(&self) -> u32 {
+        self.record().0
+    }
+
+    /// A goalie's wins, losses and overtime/shootout losses. Ties (before
+    /// 2005-06) and unknown decisions are left out.
+    pub fn record(&self) -> (u32, u32, u32) {
+        let mut record = (0, 0, 0);
+        for game in &self.game_log {
+            match game.decision {
+                Some(GoalieDecision::Win) => record.0 += 1,
+                Some(GoalieDecision::Loss) => record.1 += 1,
+                Some(GoalieDecision::OvertimeLoss) => record.2 += 1,
+                Some(GoalieDecision::Tie) | None => {}
+                #[cfg(feature = "lenient-enums")]
+                Some(GoalieDecision::Unknown(_)) => {}
+            }
+        }
+        record
+    }
+
+    /// A skater's sums over every game in the log
+    pub fn totals(&self) -> GameLogTotals {
+        self.game_log
+            .iter()
+            .fold(GameLogTotals::default(), |mut totals, game| {
+                totals.games_played += 1;
+                totals.goals += game.goals;
+                totals.assists += game.assists;
+                totals.points += game.points;
+                totals.plus_minus += game.plus_minus;
+                totals.power_play_goals += game.power_play_goals;
+                totals.shots += game.shots;
+                totals.pim += game.pim.unwrap_or(0);
+                totals.toi += game.toi.parse().unwrap_or_default();
+                totals
+            })
+    }
+
+    /// Games with a point in a row, counting back from the latest game
+    ///
+    /// Streaks run over the player's own games: games they missed don't
+    /// end one.
+    pub fn points_streak(&self) -> u32 {
+        self.chronological()
+            .iter()
+            .rev()
+            .take_while(|game| game.points > 0)
+            .count() as u32
+    }
+
+    /// Most games with a goal in a row, over the player's own games
+    pub fn longest_goal_streak(&self) -> u32 {
+        let mut longest = 0;
+        let mut current = 0;
+        for game in self.chronological() {
+            current = if game.goals > 0 { current + 1 } else { 0 };
+            longest = longest.max(current);
+        }
+        longest
+    }
+}
+
+/// Player featured in the NHL.com spotlight
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+#[serde(rename_all = "camelCase")]
+pub struct SpotlightPlayer {
+    pub player_id: PlayerId,
+    pub name: LocalizedString,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub player_slug: Option<String>,
+    /// `None` when the API returns an empty position code.
+    #[serde(deserialize_with = "empty_string_as_none", default)]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub position: Option<Position>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub sweater_number: Option<i32>,
+    pub team_id: TeamId,
+    pub headshot: String,
+    pub team_tri_code: String,
+    pub team_logo: String,
+    pub sort_id: i32,
+}
+
 /// Player search result
 #[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
 #[serde(rename_all = "camelCase")]
@@ -283,33 +593,117 @@ pub struct PlayerSearchResult {
     #[serde(skip_serializing_if = "Option::is_none")]
     pub position: Option<Position>,
 
+    /// Current team; `None` (`null`) for retired players and free agents.
     #[serde(skip_serializing_if = "Option::is_none")]
     pub team_id: Option<TeamId>,
 
     #[serde(skip_serializing_if = "Option::is_none")]
     pub team_abbrev: Option<String>,
 
+    /// Last team played for; set for retired players too.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub last_team_id: Option<TeamId>,
+
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub last_team_abbrev: Option<String>,
+
+    /// Last season played, sent as a string (`"19981999"`); `null` for
+    /// active players.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub last_season_id: Option<Season>,
+
+    /// Sent as a string (`"97"`) by the search host; integers are accepted too.
+    #[serde(deserialize_with = "string_or_number_i32", default)]
     #[serde(skip_serializing_if = "Option::is_none")]
     pub sweater_number: Option<i32>,
 
     pub active: bool,
 
+    /// Display height as sent, e.g. `6'1"`, `6' 2"` or, for some
+    /// prospects, centimeters. See [`Self::height_inches`].
     #[serde(skip_serializing_if = "Option::is_none")]
     pub height: Option<String>,
 
+    #[serde(deserialize_with = "string_or_number_i32", default)]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub height_in_inches: Option<i32>,
+
+    #[serde(deserialize_with = "string_or_number_i32", default)]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub birth_city: Option<String>,
+    pub height_in_centimeters: Option<i32>,
 
+    #[serde(deserialize_with = "string_or_number_i32", default)]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub birth_state_province: Option<String>,
+    pub weight_in_pounds: Option<i32>,
 
+    #[serde(deserialize_with = "string_or_number_i32", default)]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub birth_country: Option<String>,
+    pub weight_in_kilograms: Option<i32>,
+
+    #[serde(flatten)]
+    pub birth: BirthInfo,
+}
+
+impl PlayerSearchResult {
+    /// Height in inches, the unit heights are normalized to. Taken from
+    /// `heightInInches` when sent, else read from the `height` display
+    /// string, else converted from `heightInCentimeters`. Centimeters are
+    /// rounded to the nearest inch.
+    ///
+    /// `height` may be feet and inches with or without spaces and marks
+    /// (`6'1"`, `6' 2"`, `6'2`) or centimeters (`188 cm`, `188cm`). A bare
+    /// number is centimeters from 100 up and inches below that.
+    pub fn height_inches(&self) -> Option<i32> {
+        self.height_in_inches
+            .or_else(|| self.height.as_deref().and_then(parse_display_height))
+            .or_else(|| self.height_in_centimeters.map(centimeters_to_inches))
+    }
+
+    /// Whether `name` is this player's full name, ignoring case and
+    /// surrounding or repeated whitespace.
+    pub fn name_matches(&self, name: &str) -> bool {
+        let normalize = |name: &str| {
+            name.split_whitespace()
+                .collect::<Vec<_>>()
+                .join(" ")
+                .to_lowercase()
+        };
+        normalize(&self.name) == normalize(name)
+    }
+}
+
+/// Smallest bare number [`PlayerSearchResult::height_inches`] reads as
+/// centimeters rather than inches.
+const MIN_BARE_CENTIMETERS: i32 = 100;
+
+/// Inches from a display height; see [`PlayerSearchResult::height_inches`].
+fn parse_display_height(height: &str) -> Option<i32> {
+    let height = height.trim();
+    if let Some((feet, inches)) = height.split_once('\'') {
+        let feet: i32 = feet.trim().parse().ok()?;
+        let inches = inches.trim().trim_end_matches('"').trim();
+        let inches: i32 = if inches.is_empty() {
+            0
+        } else {
+            inches.parse().ok()?
+        };
+        return Some(feet * INCHES_PER_FOOT + inches);
+    }
+    if let Some(centimeters) = height.strip_suffix("cm") {
+        return centimeters.trim().parse().ok().map(centimeters_to_inches);
+    }
+    let number: i32 = height.parse().ok()?;
+    Some(if number >= MIN_BARE_CENTIMETERS {
+        centimeters_to_inches(number)
+    } else {
+        number
+    })
 }
 
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::test_util::assert_no_unmapped_fields;
 
     #[test]
     fn test_player_stats_deserialization() {
@@ -329,6 +723,7 @@ mod tests {
         }"#;
 
         let stats: PlayerStats = serde_json::from_str(json).unwrap();
+        assert_no_unmapped_fields::<PlayerStats>(json);
         assert_eq!(stats.games_played, Some(82));
         assert_eq!(stats.goals, Some(41));
         assert_eq!(stats.assists, Some(52));
@@ -347,6 +742,7 @@ mod tests {
         }"#;
 
         let draft: DraftDetails = serde_json::from_str(json).unwrap();
+        assert_no_unmapped_fields::<DraftDetails>(json);
         assert_eq!(draft.year, 2015);
         assert_eq!(draft.team_abbrev, "EDM");
         assert_eq!(draft.overall_pick, 1);
@@ -369,6 +765,7 @@ mod tests {
         }"#;
 
         let result: PlayerSearchResult = serde_json::from_str(json).unwrap();
+        assert_no_unmapped_fields::<PlayerSearchResult>(json);
         assert_eq!(result.player_id, PlayerId::new(8478402));
         assert_eq!(result.team_id, Some(TeamId::new(22)));
         assert_eq!(result.name, "Connor McDavid");
@@ -393,6 +790,116 @@ mod tests {
         assert_eq!(result.team_id, Some(TeamId::new(22)));
     }
 
+    /// The search host sends sweater numbers and body measurements as
+    /// numeric strings.
+    #[test]
+    fn test_player_search_result_numeric_strings() {
+        let json = r#"{
+            "playerId": "8478402",
+            "name": "Connor McDavid",
+            "sweaterNumber": "97",
+            "active": true,
+            "height": "6'1\"",
+            "heightInInches": "73",
+            "heightInCentimeters": 185,
+            "weightInPounds": "194",
+            "weightInKilograms": null
+        }"#;
+
+        let result: PlayerSearchResult = serde_json::from_str(json).unwrap();
+        assert_eq!(result.sweater_number, Some(97));
+        assert_eq!(result.height.as_deref(), Some("6'1\""));
+        assert_eq!(result.height_in_inches, Some(73));
+        assert_eq!(result.height_in_centimeters, Some(185));
+        assert_eq!(result.weight_in_pounds, Some(194));
+        assert_eq!(result.weight_in_kilograms, None);
+    }
+
+    const PLAYER_SEARCH_JSON: &str = include_str!("../../tests/fixtures/player_search.json");
+
+    #[test]
+    fn test_player_search_fixture_active_and_retired() {
+        assert_no_unmapped_fields::<Vec<PlayerSearchResult>>(PLAYER_SEARCH_JSON);
+        let results: Vec<PlayerSearchResult> = serde_json::from_str(PLAYER_SEARCH_JSON).unwrap();
+
+        let mcdavid = &results[0];
+        assert!(mcdavid.active);
+        assert_eq!(mcdavid.team_abbrev.as_deref(), Some("EDM"));
+        assert_eq!(mcdavid.last_season_id, None);
+        assert_eq!(mcdavid.height_inches(), Some(73));
+
+        let gretzky = &results[1];
+        assert!(!gretzky.active);
+        assert_eq!(gretzky.position, Some(Position::Center));
+        assert_eq!(
+            (gretzky.team_id, gretzky.team_abbrev.as_deref()),
+            (None, None)
+        );
+        assert_eq!(gretzky.last_team_id, Some(TeamId::new(3)));
+        assert_eq!(gretzky.last_team_abbrev.as_deref(), Some("NYR"));
+        assert_eq!(gretzky.last_season_id, Some(Season::new(1998)));
+        assert_eq!(gretzky.height_in_inches, None);
+        assert_eq!(gretzky.height_inches(), Some(72));
+
+        let prospect = &results[4];
+        assert_eq!(prospect.sweater_number, None);
+        assert_eq!(prospect.height_inches(), Some(74));
+    }
+
+    /// Search results send birth city and state/province as plain strings,
+    /// and `null` state/province for European players.
+    #[test]
+    fn test_player_search_fixture_birth_info() {
+        let results: Vec<PlayerSearchResult> = serde_json::from_str(PLAYER_SEARCH_JSON).unwrap();
+
+        assert_eq!(results[0].birth.place(), "Richmond Hill, ON, CAN");
+        let finn = &results[2].birth;
+        assert_eq!(finn.city, Some(LocalizedString::new("Rauma")));
+        assert_eq!(finn.state_province, None);
+        assert_eq!(finn.country_name(), Some("Finland"));
+
+        let serialized = serde_json::to_value(&results[2]).unwrap();
+        assert_eq!(serialized["birthCity"]["default"], "Rauma");
+        assert_eq!(serialized["birthCountry"], "FIN");
+        assert!(serialized.get("birthStateProvince").is_none());
+    }
+
+    #[test]
+    fn test_player_search_result_height_normalization() {
+        let with_height = |height: &str| PlayerSearchResult {
+            height: Some(height.to_string()),
+            height_in_inches: None,
+            height_in_centimeters: None,
+            ..serde_json::from_str(r#"{"playerId": 1, "name": "", "active": false}"#).unwrap()
+        };
+        for (height, inches) in [
+            ("6'1\"", Some(73)),
+            ("6' 2\"", Some(74)),
+            ("6'2", Some(74)),
+            ("6'", Some(72)),
+            ("188 cm", Some(74)),
+            ("180cm", Some(71)),
+            ("185", Some(73)),
+            ("73", Some(73)),
+            ("tall", None),
+        ] {
+            assert_eq!(with_height(height).height_inches(), inches, "{}", height);
+        }
+
+        let mut metric_only = with_height("");
+        metric_only.height = None;
+        metric_only.height_in_centimeters = Some(191);
+        assert_eq!(metric_only.height_inches(), Some(75));
+    }
+
+    #[test]
+    fn test_player_search_result_name_matches() {
+        let results: Vec<PlayerSearchResult> = serde_json::from_str(PLAYER_SEARCH_JSON).unwrap();
+        assert!(results[2].name_matches("  sebastian   AHO "));
+        assert!(!results[4].name_matches("Sebastian Aho"));
+        assert!(!results[0].name_matches("McDavid"));
+    }
+
     #[test]
     fn test_player_search_result_empty_position() {
         let json = r#"{
@@ -474,6 +981,7 @@ mod tests {
         }"#;
 
         let landing: PlayerLanding = serde_json::from_str(json).unwrap();
+        assert_no_unmapped_fields::<PlayerLanding>(json);
         assert_eq!(landing.position, Some(Position::Center));
         assert_eq!(landing.shoots_catches, Some(Handedness::Left));
     }
@@ -511,6 +1019,7 @@ mod tests {
         }"#;
 
         let stats: FeaturedStats = serde_json::from_str(json).unwrap();
+        assert_no_unmapped_fields::<FeaturedStats>(json);
         assert_eq!(stats.season, Season::new(2024));
         assert_eq!(stats.regular_season.games_played, Some(10));
     }
@@ -541,6 +1050,7 @@ mod tests {
         }"#;
 
         let total: SeasonTotal = serde_json::from_str(json).unwrap();
+        assert_no_unmapped_fields::<SeasonTotal>(json);
         assert_eq!(total.season, Season::new(2023));
         assert_eq!(total.game_type, GameType::RegularSeason);
         assert_eq!(total.games_played, 82);
@@ -551,6 +1061,7 @@ mod tests {
     fn test_award_season_deserialization() {
         let json = r#"{"seasonId": 20142015}"#;
         let award_season: AwardSeason = serde_json::from_str(json).unwrap();
+        assert_no_unmapped_fields::<AwardSeason>(json);
         assert_eq!(award_season.season_id, Season::new(2014));
     }
 
@@ -570,11 +1081,163 @@ mod tests {
         }"#;
 
         let award: Award = serde_json::from_str(json).unwrap();
+        assert_no_unmapped_fields::<Award>(json);
         assert_eq!(award.trophy.default, "Hart Memorial Trophy");
         assert_eq!(award.seasons.len(), 2);
         assert_eq!(award.seasons[0].season_id, Season::new(2014));
     }
 
+    /// A hand-written landing payload (placeholder player) with AHL and NHL
+    /// seasons, one playoff run and one award.
+    const PLAYER_LANDING_FIXTURE: &str = include_str!("../../tests/fixtures/player_landing.json");
+
+    fn landing_fixture() -> PlayerLanding {
+        serde_json::from_str(PLAYER_LANDING_FIXTURE).unwrap()
+    }
+
+    #[test]
+    fn test_player_landing_fixture_maps_every_field() {
+        assert_no_unmapped_fields::<PlayerLanding>(PLAYER_LANDING_FIXTURE);
+        let landing = landing_fixture();
+        assert_eq!(landing.awards.as_ref().unwrap().len(), 1);
+    }
+
+    #[test]
+    fn test_player_landing_birth_info_and_nationality() {
+        let landing = landing_fixture();
+        assert_eq!(landing.birth.place(), "Calgary, AB, CAN");
+        assert_eq!(landing.nationality(), Some("Canada"));
+
+        let european: PlayerLanding = serde_json::from_str(
+            &PLAYER_LANDING_FIXTURE
+                .replace(r#""default": "Calgary""#, r#""default": "Umeå""#)
+                .replace(
+                    r#"{
+    "default": "AB"
+  }"#,
+                    "null",
+                )
+                .replace(r#""birthCountry": "CAN""#, r#""birthCountry": "SWE""#),
+        )
+        .unwrap();
+        assert_eq!(european.birth.state_province, None);
+        assert_eq!(european.birth.place(), "Umeå, SWE");
+        assert_eq!(european.nationality(), Some("Sweden"));
+
+        let mut unknown = landing_fixture();
+        unknown.birth.country_code = Some("XYZ".to_string());
+        assert_eq!(unknown.nationality(), Some("XYZ"));
+        unknown.birth.country_code = None;
+        assert_eq!(unknown.nationality(), None);
+    }
+
+    #[test]
+    fn test_player_landing_age_on() {
+        let landing = landing_fixture();
+        let on = |y, m, d| landing.age_on(NaiveDate::from_ymd_opt(y, m, d).unwrap());
+        assert_eq!(on(2024, 4, 30), Some(27));
+        assert_eq!(on(2024, 5, 1), Some(28));
+        assert_eq!(on(1990, 1, 1), None);
+        assert!(landing.current_age().unwrap() >= 28);
+    }
+
+    #[test]
+    fn test_player_landing_measurements() {
+        let mut landing = landing_fixture();
+        assert_eq!(landing.height_cm(), Some(183));
+        assert_eq!(landing.height_imperial().as_deref(), Some("6'0\""));
+        assert_eq!(landing.weight_kg(), Some(86));
+        assert_eq!(landing.weight_lbs(), Some(190));
+
+        let metric = PLAYER_LANDING_FIXTURE
+            .replace(r#""heightInInches": 72"#, r#""heightInCentimeters": 188"#)
+            .replace(r#""weightInPounds": 190"#, r#""weightInKilograms": 91"#);
+        landing = serde_json::from_str(&metric).unwrap();
+        assert_eq!(landing.height_in_inches, None);
+        assert_eq!(landing.height_imperial().as_deref(), Some("6'2\""));
+        assert_eq!(landing.weight_lbs(), Some(201));
+    }
+
+    #[test]
+    fn test_player_landing_draft_summary() {
+        let mut landing = landing_fixture();
+        assert_eq!(
+            landing.draft_summary().as_deref(),
+            Some("2014 Round 1, Pick 8 (TOR)")
+        );
+        landing.draft_details = None;
+        assert_eq!(landing.draft_summary(), None);
+    }
+
+    #[test]
+    fn test_player_landing_career_totals() {
+        let mut landing = landing_fixture();
+        assert_eq!(landing.career_regular_season().unwrap().points, Some(134));
+        assert_eq!(landing.career_playoffs().unwrap().points, Some(4));
+
+        landing.career_totals.as_mut().unwrap().playoffs = None;
+        assert_eq!(landing.career_playoffs(), None);
+        landing.career_totals = None;
+        assert_eq!(landing.career_regular_season(), None);
+    }
+
+    #[test]
+    fn test_player_landing_best_season_by_points() {
+        let landing = landing_fixture();
+
+        let nhl = landing.best_season_by_points(false).unwrap();
+        assert_eq!(nhl.season, Season::new(2016));
+        assert_eq!(nhl.points, Some(61));
+        assert!(nhl.is_nhl());
+
+        let any_league = landing.best_season_by_points(true).unwrap();
+        assert_eq!(any_league.league_abbrev, "AHL");
+        assert_eq!(any_league.points, Some(70));
+    }
+
+    #[test]
+    fn test_player_landing_best_season_tie_goes_to_earlier_season() {
+        let mut landing = landing_fixture();
+        let totals = landing.season_totals.as_mut().unwrap();
+        totals.last_mut().unwrap().points = Some(61);
+
+        let best = landing.best_season_by_points(false).unwrap();
+        assert_eq!(best.season, Season::new(2016));
+    }
+
+    #[test]
+    fn test_player_landing_seasons_with_team() {
+        let landing = landing_fixture();
+
+        let leafs = landing.seasons_with_team("Toronto Maple Leafs");
+        assert_eq!(leafs.len(), 4);
+        assert_eq!(landing.seasons_with_team("maple leafs"), leafs);
+        assert_eq!(landing.seasons_with_team("Toronto Marlies").len(), 2);
+        assert!(landing.seasons_with_team("Toronto").is_empty());
+    }
+
+    #[test]
+    fn test_spotlight_player_deserialization() {
+        let json = r#"{
+            "playerId": 8478402,
+            "name": {"default": "Connor McDavid"},
+            "playerSlug": "connor-mcdavid-8478402",
+            "position": "C",
+            "sweaterNumber": 97,
+            "teamId": 22,
+            "headshot": "https://assets.nhle.com/mugs/nhl/20242025/EDM/8478402.png",
+            "teamTriCode": "EDM",
+            "teamLogo": "https://assets.nhle.com/logos/nhl/svg/EDM_light.svg",
+            "sortId": 1
+        }"#;
+
+        let player: SpotlightPlayer = serde_json::from_str(json).unwrap();
+        assert_no_unmapped_fields::<SpotlightPlayer>(json);
+        assert_eq!(player.player_id, PlayerId::new(8478402));
+        assert_eq!(player.position, Some(Position::Center));
+        assert_eq!(player.team_id, TeamId::new(22));
+    }
+
     #[test]
     fn test_game_log_deserialization() {
         let json = r#"{
@@ -595,6 +1258,7 @@ mod tests {
         }"#;
 
         let game_log: GameLog = serde_json::from_str(json).unwrap();
+        assert_no_unmapped_fields::<GameLog>(json);
         assert_eq!(game_log.game_id, GameId::new(2023020001));
         assert_eq!(game_log.goals, 1);
         assert_eq!(game_log.points, 3);
@@ -624,6 +1288,78 @@ mod tests {
         assert_eq!(game_log.game_id, GameId::new(2023020001));
     }
 
+    const SKATER_GAME_LOG: &str = include_str!("../../tests/fixtures/game_log_skater.json");
+    const GOALIE_GAME_LOG: &str = include_str!("../../tests/fixtures/game_log_goalie.json");
+
+    fn date(month: u32, day: u32) -> NaiveDate {
+        NaiveDate::from_ymd_opt(2024, month, day).unwrap()
+    }
+
+    #[test]
+    fn test_goalie_game_log_starts_and_record() {
+        let log: PlayerGameLog = serde_json::from_str(GOALIE_GAME_LOG).unwrap();
+        assert_no_unmapped_fields::<PlayerGameLog>(GOALIE_GAME_LOG);
+
+        assert!(log.game_log.iter().all(GameLog::is_goalie));
+        assert_eq!(log.game_log[0].decision, Some(GoalieDecision::Win));
+        assert_eq!(log.game_log[0].shots_against, Some(30));
+        assert_eq!(log.game_log[0].points, 0);
+        assert_eq!(log.game_log[2].decision, None);
+        assert_eq!(log.game_log[4].shutouts, Some(1));
+
+        assert_eq!(log.games_started(), 4);
+        assert_eq!(log.wins(), 2);
+        assert_eq!(log.record(), (2, 1, 1));
+
+        // One start and one relief appearance in March, plus the OT loss.
+        let march = log.between(date(3, 1), date(3, 31));
+        assert_eq!(march.game_log.len(), 3);
+        assert_eq!(march.games_started(), 2);
+        assert_eq!(march.record(), (1, 0, 1));
+        assert_eq!(march.season, log.season);
+    }
+
+    #[test]
+    fn test_skater_game_log_totals() {
+        let log: PlayerGameLog = serde_json::from_str(SKATER_GAME_LOG).unwrap();
+        assert_no_unmapped_fields::<PlayerGameLog>(SKATER_GAME_LOG);
+
+        assert!(!log.game_log.iter().any(GameLog::is_goalie));
+        assert_eq!(log.games_started(), 0);
+        assert_eq!(log.record(), (0, 0, 0));
+        assert_eq!(
+            log.totals(),
+            GameLogTotals {
+                games_played: 8,
+                goals: 6,
+                assists: 5,
+                points: 11,
+                plus_minus: 4,
+                power_play_goals: 2,
+                shots: 26,
+                pim: 4,
+                toi: "152:07".parse().unwrap(),
+            }
+        );
+    }
+
+    /// The fixture's games are days apart (the player sat out in between)
+    /// and listed latest first; streaks follow the player's own games.
+    #[test]
+    fn test_skater_game_log_streaks() {
+        let log: PlayerGameLog = serde_json::from_str(SKATER_GAME_LOG).unwrap();
+
+        // Points in the last four games, 03-03 through 03-12.
+        assert_eq!(log.points_streak(), 4);
+        // Goals on 03-03, 03-07 and 03-10, across a four-day gap.
+        assert_eq!(log.longest_goal_streak(), 3);
+
+        let february = log.between(date(2, 1), date(2, 29));
+        assert_eq!(february.points_streak(), 0);
+        assert_eq!(february.longest_goal_streak(), 2);
+        assert_eq!(log.between(date(4, 1), date(4, 30)).points_streak(), 0);
+    }
+
     #[test]
     fn test_player_game_log_deserialization() {
         let json = r#"{
@@ -633,6 +1369,7 @@ mod tests {
         }"#;
 
         let mut game_log: PlayerGameLog = serde_json::from_str(json).unwrap();
+        assert_no_unmapped_fields::<PlayerGameLog>(json);
         // Not present in the API response; the client sets it from the request parameter.
         assert_eq!(game_log.player_id, PlayerId::default());
         game_log.player_id = PlayerId::new(8478402);
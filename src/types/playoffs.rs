@@ -0,0 +1,344 @@
+use serde::{Deserialize, Serialize};
+use std::fmt;
+
+use crate::date::Season;
+use crate::ids::{GameId, TeamId};
+
+use super::boxscore::{PeriodDescriptor, TvBroadcast};
+use super::common::LocalizedString;
+use super::enums::GameScheduleState;
+use super::game_center::GameOutcome;
+use super::game_state::GameState;
+use super::game_type::GameType;
+
+/// Playoff series schedule (`/schedule/playoff-series/{season}/{letter}`)
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+pub struct PlayoffSeriesSchedule {
+    pub round: i32,
+    #[serde(rename = "roundAbbrev", default)]
+    pub round_abbrev: String,
+    #[serde(rename = "roundLabel", default)]
+    pub round_label: String,
+    #[serde(rename = "seriesLetter")]
+    pub series_letter: String,
+    #[serde(rename = "seriesLogo", skip_serializing_if = "Option::is_none")]
+    pub series_logo: Option<String>,
+    #[serde(rename = "neededToWin")]
+    pub needed_to_win: i32,
+    #[serde(default)]
+    pub length: i32,
+    #[serde(rename = "topSeedTeam")]
+    pub top_seed_team: PlayoffSeriesTeam,
+    #[serde(rename = "bottomSeedTeam")]
+    pub bottom_seed_team: PlayoffSeriesTeam,
+    #[serde(default)]
+    pub games: Vec<PlayoffSeriesGame>,
+}
+
+impl PlayoffSeriesSchedule {
+    /// Current series score as `(top_seed_wins, bottom_seed_wins)`.
+    pub fn series_score(&self) -> (i32, i32) {
+        (
+            self.top_seed_team.series_wins,
+            self.bottom_seed_team.series_wins,
+        )
+    }
+
+    /// Team that has reached `needed_to_win`, if the series is decided.
+    pub fn winner(&self) -> Option<&PlayoffSeriesTeam> {
+        if self.needed_to_win <= 0 {
+            return None;
+        }
+        [&self.top_seed_team, &self.bottom_seed_team]
+            .into_iter()
+            .find(|team| team.series_wins >= self.needed_to_win)
+    }
+
+    /// Returns true once either team has won the series.
+    pub fn is_complete(&self) -> bool {
+        self.winner().is_some()
+    }
+
+    /// First game that has not been completed, i.e. the next game to watch
+    /// for. `None` once the series is over.
+    pub fn next_game(&self) -> Option<&PlayoffSeriesGame> {
+        if self.is_complete() {
+            return None;
+        }
+        self.games.iter().find(|game| !game.game_state.is_final())
+    }
+
+    /// One-line series status, e.g. `"TOR leads 3-2"`, `"Series tied 2-2"`
+    /// or `"TOR wins 4-2"`.
+    pub fn status_text(&self) -> String {
+        let (top, bottom) = self.series_score();
+        let (leader, high, low) = if top >= bottom {
+            (&self.top_seed_team, top, bottom)
+        } else {
+            (&self.bottom_seed_team, bottom, top)
+        };
+
+        if top == bottom {
+            format!("Series tied {}-{}", top, bottom)
+        } else if self.is_complete() {
+            format!("{} wins {}-{}", leader.abbrev, high, low)
+        } else {
+            format!("{} leads {}-{}", leader.abbrev, high, low)
+        }
+    }
+}
+
+impl fmt::Display for PlayoffSeriesSchedule {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "Series {}: {} vs {} ({})",
+            self.series_letter.to_uppercase(),
+            self.top_seed_team.abbrev,
+            self.bottom_seed_team.abbrev,
+            self.status_text()
+        )
+    }
+}
+
+/// Seeded team in a playoff series
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+pub struct PlayoffSeriesTeam {
+    pub id: TeamId,
+    #[serde(default)]
+    pub name: LocalizedString,
+    pub abbrev: String,
+    #[serde(rename = "placeName", default)]
+    pub place_name: LocalizedString,
+    #[serde(default)]
+    pub record: String,
+    #[serde(rename = "seriesWins", default)]
+    pub series_wins: i32,
+    #[serde(default)]
+    pub seed: i32,
+    #[serde(default)]
+    pub logo: String,
+    #[serde(rename = "darkLogo", default)]
+    pub dark_logo: String,
+}
+
+/// Individual game in a playoff series
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+pub struct PlayoffSeriesGame {
+    pub id: GameId,
+    pub season: Season,
+    #[serde(rename = "gameType")]
+    pub game_type: GameType,
+    #[serde(rename = "gameNumber")]
+    pub game_number: i32,
+    #[serde(rename = "ifNecessary", default)]
+    pub if_necessary: bool,
+    #[serde(default)]
+    pub venue: LocalizedString,
+    #[serde(rename = "neutralSite", default)]
+    pub neutral_site: bool,
+    #[serde(rename = "startTimeUTC")]
+    pub start_time_utc: String,
+    #[serde(rename = "easternUTCOffset", default)]
+    pub eastern_utc_offset: String,
+    #[serde(rename = "venueUTCOffset", default)]
+    pub venue_utc_offset: String,
+    #[serde(rename = "venueTimezone", default)]
+    pub venue_timezone: String,
+    #[serde(rename = "gameState")]
+    pub game_state: GameState,
+    #[serde(rename = "gameScheduleState")]
+    pub game_schedule_state: GameScheduleState,
+    #[serde(rename = "tvBroadcasts", default)]
+    pub tv_broadcasts: Vec<TvBroadcast>,
+    #[serde(rename = "awayTeam")]
+    pub away_team: PlayoffSeriesGameTeam,
+    #[serde(rename = "homeTeam")]
+    pub home_team: PlayoffSeriesGameTeam,
+    #[serde(rename = "gameCenterLink", default)]
+    pub game_center_link: String,
+    #[serde(rename = "periodDescriptor", default)]
+    pub period_descriptor: PeriodDescriptor,
+    #[serde(rename = "seriesStatus", skip_serializing_if = "Option::is_none")]
+    pub series_status: Option<PlayoffSeriesStatus>,
+    /// Absent for games that have not been played yet.
+    #[serde(rename = "gameOutcome", skip_serializing_if = "Option::is_none")]
+    pub game_outcome: Option<GameOutcome>,
+}
+
+impl fmt::Display for PlayoffSeriesGame {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "Game {}: {} @ {} [{}]",
+            self.game_number, self.away_team.abbrev, self.home_team.abbrev, self.game_state
+        )
+    }
+}
+
+/// Team information in a playoff series game
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+pub struct PlayoffSeriesGameTeam {
+    pub id: TeamId,
+    #[serde(rename = "commonName", default)]
+    pub common_name: LocalizedString,
+    #[serde(rename = "placeName", default)]
+    pub place_name: LocalizedString,
+    pub abbrev: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub score: Option<i32>,
+}
+
+/// Series score after a given game
+#[derive(Debug, Clone, Copy, Default, Serialize, Deserialize, PartialEq, Eq)]
+pub struct PlayoffSeriesStatus {
+    #[serde(rename = "topSeedWins", default)]
+    pub top_seed_wins: i32,
+    #[serde(rename = "bottomSeedWins", default)]
+    pub bottom_seed_wins: i32,
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_util::assert_no_unmapped_fields;
+
+    fn series_json(top_wins: i32, bottom_wins: i32, games: &str) -> String {
+        format!(
+            r#"{{
+                "round": 1,
+                "roundAbbrev": "R1",
+                "roundLabel": "1st-round",
+                "seriesLetter": "A",
+                "seriesLogo": "https://assets.nhle.com/logos/playoffs/png/scf-2024.png",
+                "neededToWin": 4,
+                "length": 7,
+                "topSeedTeam": {{
+                    "id": 10,
+                    "name": {{"default": "Toronto Maple Leafs"}},
+                    "abbrev": "TOR",
+                    "placeName": {{"default": "Toronto"}},
+                    "record": "46-26-10",
+                    "seriesWins": {top},
+                    "seed": 1,
+                    "logo": "https://assets.nhle.com/logos/nhl/svg/TOR_light.svg",
+                    "darkLogo": "https://assets.nhle.com/logos/nhl/svg/TOR_dark.svg"
+                }},
+                "bottomSeedTeam": {{
+                    "id": 6,
+                    "name": {{"default": "Boston Bruins"}},
+                    "abbrev": "BOS",
+                    "placeName": {{"default": "Boston"}},
+                    "record": "47-20-15",
+                    "seriesWins": {bottom},
+                    "seed": 4
+                }},
+                "games": [{games}]
+            }}"#,
+            top = top_wins,
+            bottom = bottom_wins,
+            games = games
+        )
+    }
+
+    const PLAYED_GAME: &str = r#"{
+        "id": 2023030111,
+        "season": 20232024,
+        "gameType": 3,
+        "gameNumber": 1,
+        "ifNecessary": false,
+        "venue": {"default": "TD Garden"},
+        "neutralSite": false,
+        "startTimeUTC": "2024-04-20T23:00:00Z",
+        "easternUTCOffset": "-04:00",
+        "venueUTCOffset": "-04:00",
+        "venueTimezone": "America/New_York",
+        "gameState": "OFF",
+        "gameScheduleState": "OK",
+        "tvBroadcasts": [],
+        "awayTeam": {"id": 10, "commonName": {"default": "Maple Leafs"}, "abbrev": "TOR", "score": 1},
+        "homeTeam": {"id": 6, "commonName": {"default": "Bruins"}, "abbrev": "BOS", "score": 5},
+        "gameCenterLink": "/gamecenter/tor-vs-bos/2024/04/20/2023030111",
+        "periodDescriptor": {"number": 3, "periodType": "REG", "maxRegulationPeriods": 3},
+        "seriesStatus": {"topSeedWins": 0, "bottomSeedWins": 1},
+        "gameOutcome": {"lastPeriodType": "REG"}
+    }"#;
+
+    const FUTURE_GAME: &str = r#"{
+        "id": 2023030116,
+        "season": 20232024,
+        "gameType": 3,
+        "gameNumber": 6,
+        "ifNecessary": true,
+        "startTimeUTC": "2024-05-02T23:00:00Z",
+        "gameState": "FUT",
+        "gameScheduleState": "OK",
+        "awayTeam": {"id": 6, "abbrev": "BOS"},
+        "homeTeam": {"id": 10, "abbrev": "TOR"}
+    }"#;
+
+    #[test]
+    fn test_playoff_series_schedule_deserialization() {
+        let json = series_json(2, 3, &format!("{},{}", PLAYED_GAME, FUTURE_GAME));
+        let series: PlayoffSeriesSchedule = serde_json::from_str(&json).unwrap();
+        assert_no_unmapped_fields::<PlayoffSeriesSchedule>(&json);
+
+        assert_eq!(series.series_letter, "A");
+        assert_eq!(series.needed_to_win, 4);
+        assert_eq!(series.top_seed_team.abbrev, "TOR");
+        assert_eq!(series.bottom_seed_team.seed, 4);
+        assert_eq!(series.games.len(), 2);
+
+        let played = &series.games[0];
+        assert_eq!(played.game_type, GameType::Playoffs);
+        assert_eq!(played.home_team.score, Some(5));
+        assert_eq!(
+            played.series_status,
+            Some(PlayoffSeriesStatus {
+                top_seed_wins: 0,
+                bottom_seed_wins: 1
+            })
+        );
+        assert!(played.game_outcome.is_some());
+
+        let future = &series.games[1];
+        assert!(future.if_necessary);
+        assert_eq!(future.away_team.score, None);
+        assert!(future.game_outcome.is_none());
+        assert!(future.series_status.is_none());
+    }
+
+    #[test]
+    fn test_playoff_series_status_text_and_next_game() {
+        let json = series_json(2, 3, &format!("{},{}", PLAYED_GAME, FUTURE_GAME));
+        let series: PlayoffSeriesSchedule = serde_json::from_str(&json).unwrap();
+
+        assert_eq!(series.series_score(), (2, 3));
+        assert_eq!(series.status_text(), "BOS leads 3-2");
+        assert!(!series.is_complete());
+        assert_eq!(
+            series.next_game().map(|game| game.id),
+            Some(GameId::new(2023030116))
+        );
+        assert_eq!(series.to_string(), "Series A: TOR vs BOS (BOS leads 3-2)");
+    }
+
+    #[test]
+    fn test_playoff_series_tied() {
+        let series: PlayoffSeriesSchedule = serde_json::from_str(&series_json(2, 2, "")).unwrap();
+        assert_eq!(series.status_text(), "Series tied 2-2");
+        assert!(series.winner().is_none());
+    }
+
+    #[test]
+    fn test_playoff_series_complete() {
+        let json = series_json(4, 2, FUTURE_GAME);
+        let series: PlayoffSeriesSchedule = serde_json::from_str(&json).unwrap();
+
+        assert!(series.is_complete());
+        assert_eq!(series.winner().unwrap().abbrev, "TOR");
+        assert_eq!(series.status_text(), "TOR wins 4-2");
+        // An unplayed if-necessary game is not "next" once the series is over.
+        assert!(series.next_game().is_none());
+    }
+}
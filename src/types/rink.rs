@@ -0,0 +1,341 @@
+//! Rink geometry and shot coordinate normalization.
+//!
+//! Play-by-play `xCoord`/`yCoord` are in feet from center ice: `x` runs
+//! along the rink (-100 to 100, goal lines at ±89) and `y` across it
+//! (-42.5 to 42.5). Raw coordinates are physical positions, so a team's
+//! shots land at opposite ends in alternate periods. [`normalize_shot`]
+//! rotates them so the shooting team always attacks toward `+x`, the net
+//! at ([`GOAL_LINE_X`], 0).
+
+use super::enums::{DefendingSide, ZoneCode};
+
+/// Length of an NHL rink, end boards to end boards, in feet.
+pub const RINK_LENGTH: f32 = 200.0;
+
+/// Width of an NHL rink, in feet.
+pub const RINK_WIDTH: f32 = 85.0;
+
+/// Distance from center ice to either goal line, in feet.
+pub const GOAL_LINE_X: f32 = 89.0;
+
+/// Distance from center ice to either blue line, in feet. Events with
+/// `|x|` below this are in the neutral zone.
+pub const BLUE_LINE_X: f32 = 25.0;
+
+/// Distance from center ice to the end-zone faceoff dots, in feet.
+pub const END_ZONE_DOT_X: f32 = 69.0;
+
+/// Distance from center ice to the neutral-zone faceoff dots, in feet.
+pub const NEUTRAL_ZONE_DOT_X: f32 = 20.0;
+
+/// Distance from the middle of the rink to every faceoff dot except center
+/// ice, in feet.
+pub const FACEOFF_DOT_Y: f32 = 22.0;
+
+/// One of the nine faceoff dots, named for a team attacking toward `+x`
+/// (normalized coordinates), so "left" is `+y`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum FaceoffDot {
+    OffensiveLeft,
+    OffensiveRight,
+    NeutralOffensiveLeft,
+    NeutralOffensiveRight,
+    Center,
+    NeutralDefensiveLeft,
+    NeutralDefensiveRight,
+    DefensiveLeft,
+    DefensiveRight,
+}
+
+impl FaceoffDot {
+    /// Every dot, from the attacked end to the defended end.
+    pub const ALL: [Self; 9] = [
+        Self::OffensiveLeft,
+        Self::OffensiveRight,
+        Self::NeutralOffensiveLeft,
+        Self::NeutralOffensiveRight,
+        Self::Center,
+        Self::NeutralDefensiveLeft,
+        Self::NeutralDefensiveRight,
+        Self::DefensiveLeft,
+        Self::DefensiveRight,
+    ];
+
+    /// The dot's `(x, y)` in normalized coordinates.
+    pub const fn position(self) -> (f32, f32) {
+        match self {
+            Self::OffensiveLeft => (END_ZONE_DOT_X, FACEOFF_DOT_Y),
+            Self::OffensiveRight => (END_ZONE_DOT_X, -FACEOFF_DOT_Y),
+            Self::NeutralOffensiveLeft => (NEUTRAL_ZONE_DOT_X, FACEOFF_DOT_Y),
+            Self::NeutralOffensiveRight => (NEUTRAL_ZONE_DOT_X, -FACEOFF_DOT_Y),
+            Self::Center => (0.0, 0.0),
+            Self::NeutralDefensiveLeft => (-NEUTRAL_ZONE_DOT_X, FACEOFF_DOT_Y),
+            Self::NeutralDefensiveRight => (-NEUTRAL_ZONE_DOT_X, -FACEOFF_DOT_Y),
+            Self::DefensiveLeft => (-END_ZONE_DOT_X, FACEOFF_DOT_Y),
+            Self::DefensiveRight => (-END_ZONE_DOT_X, -FACEOFF_DOT_Y),
+        }
+    }
+
+    /// The dot closest to normalized `(x, y)`, e.g. where a faceoff was
+    /// taken. Ties go to the first dot in [`Self::ALL`] order.
+    pub fn nearest(x: f32, y: f32) -> Self {
+        let distance = |dot: &Self| {
+            let (dot_x, dot_y) = dot.position();
+            (x - dot_x).hypot(y - dot_y)
+        };
+        Self::ALL
+            .into_iter()
+            .min_by(|a, b| distance(a).total_cmp(&distance(b)))
+            .unwrap_or(Self::Center)
+    }
+
+    /// The zone the dot is in.
+    pub const fn zone(self) -> ZoneCode {
+        match self {
+            Self::OffensiveLeft | Self::OffensiveRight => ZoneCode::Offensive,
+            Self::DefensiveLeft | Self::DefensiveRight => ZoneCode::Defensive,
+            _ => ZoneCode::Neutral,
+        }
+    }
+}
+
+impl ZoneCode {
+    /// The zone at `x` for a team attacking toward `+x` when
+    /// `attacking_positive_x` is true, or toward `-x` otherwise.
+    ///
+    /// The blue lines belong to the neutral zone: `|x| <=` [`BLUE_LINE_X`]
+    /// is neutral, so the puck has to be past a line to be in a zone.
+    pub fn classify(x: f32, attacking_positive_x: bool) -> Self {
+        let x = if attacking_positive_x { x } else { -x };
+        if x > BLUE_LINE_X {
+            Self::Offensive
+        } else if x < -BLUE_LINE_X {
+            Self::Defensive
+        } else {
+            Self::Neutral
+        }
+    }
+}
+
+/// Whether a team attacks toward `+x`, given the home team's defending
+/// side in the period: the home team attacks away from the end it defends.
+/// An unknown side (`lenient-enums`) leaves coordinates as they are.
+pub fn attacks_positive_x(home_defending_side: DefendingSide, is_home_team_event: bool) -> bool {
+    match home_defending_side {
+        DefendingSide::Left => is_home_team_event,
+        DefendingSide::Right => !is_home_team_event,
+        #[cfg(feature = "lenient-enums")]
+        DefendingSide::Unknown(_) => true,
+    }
+}
+
+/// Which side the home team defends in `period`, given the side it defends
+/// in the first period.
+///
+/// Teams change ends every period, including each overtime, so even periods
+/// (2, overtime 4, double overtime 6, ...) are flipped. For historical games
+/// whose events lack `homeTeamDefendingSide` but where the opening side is
+/// known some other way. The shootout (one net for both teams) has no
+/// meaningful side.
+pub fn defending_side_in_period(first_period: DefendingSide, period: i32) -> DefendingSide {
+    if period % 2 == 0 {
+        flip(first_period)
+    } else {
+        first_period
+    }
+}
+
+/// Rotates a shot's coordinates so the shooting team attacks toward `+x`.
+///
+/// `home_defending_side` is the home team's side in the event's own period,
+/// as carried on each play (`PlayEvent::home_team_defending_side`). The home
+/// team attacks away from the end it defends (see [`attacks_positive_x`]).
+/// Rotation negates both axes, keeping the shooter's left/right
+/// relative to the net. Neutral and defensive zone events are rotated the
+/// same way and end up with `x < BLUE_LINE_X`.
+pub fn normalize_shot(
+    x: f32,
+    y: f32,
+    home_defending_side: DefendingSide,
+    is_home_team_event: bool,
+) -> (f32, f32) {
+    if attacks_positive_x(home_defending_side, is_home_team_event) {
+        (x, y)
+    } else {
+        (-x, -y)
+    }
+}
+
+/// Distance in feet from normalized coordinates to the attacked net.
+pub fn distance_to_net(x: f32, y: f32) -> f32 {
+    (GOAL_LINE_X - x).hypot(y)
+}
+
+/// Angle in degrees between the shot and the line through the middle of the
+/// rink, from normalized coordinates: 0 straight on, 90 from the goal line,
+/// above 90 from behind the net. Symmetric for both sides of the net.
+pub fn shot_angle(x: f32, y: f32) -> f32 {
+    y.abs().atan2(GOAL_LINE_X - x).to_degrees()
+}
+
+fn flip(side: DefendingSide) -> DefendingSide {
+    match side {
+        DefendingSide::Left => DefendingSide::Right,
+        DefendingSide::Right => DefendingSide::Left,
+        #[cfg(feature = "lenient-enums")]
+        DefendingSide::Unknown(_) => side,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_rink_defending_side_alternates_including_overtime() {
+        let left = DefendingSide::Left;
+        assert_eq!(
+            defending_side_in_period(left.clone(), 1),
+            DefendingSide::Left
+        );
+        assert_eq!(
+            defending_side_in_period(left.clone(), 2),
+            DefendingSide::Right
+        );
+        assert_eq!(
+            defending_side_in_period(left.clone(), 3),
+            DefendingSide::Left
+        );
+        assert_eq!(
+            defending_side_in_period(left.clone(), 4),
+            DefendingSide::Right
+        );
+        assert_eq!(
+            defending_side_in_period(left.clone(), 5),
+            DefendingSide::Left
+        );
+        assert_eq!(
+            defending_side_in_period(DefendingSide::Right, 6),
+            DefendingSide::Left
+        );
+    }
+
+    #[test]
+    fn test_rink_normalize_shot_home_and_away() {
+        // Home defends left, so home attacks +x and away attacks -x.
+        assert_eq!(
+            normalize_shot(70.0, 10.0, DefendingSide::Left, true),
+            (70.0, 10.0)
+        );
+        assert_eq!(
+            normalize_shot(-70.0, -10.0, DefendingSide::Left, false),
+            (70.0, 10.0)
+        );
+        // Home defends right: the reverse.
+        assert_eq!(
+            normalize_shot(-70.0, -10.0, DefendingSide::Right, true),
+            (70.0, 10.0)
+        );
+        assert_eq!(
+            normalize_shot(70.0, 10.0, DefendingSide::Right, false),
+            (70.0, 10.0)
+        );
+    }
+
+    /// The same wrister taken in periods 1 and 2 (teams changed ends, so the
+    /// raw coordinates are mirrored) lands on the same normalized spot, and
+    /// the second overtime matches period 1 again.
+    #[test]
+    fn test_rink_same_shot_across_periods() {
+        let opening = DefendingSide::Left;
+        let shot_in = |period: i32, x: f32, y: f32| {
+            normalize_shot(
+                x,
+                y,
+                defending_side_in_period(opening.clone(), period),
+                true,
+            )
+        };
+        let first = shot_in(1, 60.0, -15.0);
+        assert_eq!(first, (60.0, -15.0));
+        assert_eq!(shot_in(2, -60.0, 15.0), first);
+        assert_eq!(shot_in(3, 60.0, -15.0), first);
+        assert_eq!(shot_in(4, -60.0, 15.0), first);
+        assert_eq!(shot_in(5, 60.0, -15.0), first);
+    }
+
+    #[test]
+    fn test_rink_normalize_shot_neutral_and_defensive_zones() {
+        // A neutral zone event stays in the neutral zone.
+        let (x, _) = normalize_shot(-20.0, 5.0, DefendingSide::Left, false);
+        assert_eq!(x, 20.0);
+        assert!(x.abs() < BLUE_LINE_X);
+        // A home clear from its own zone stays on the far side of center.
+        let (x, _) = normalize_shot(-80.0, 0.0, DefendingSide::Left, true);
+        assert!(x < -BLUE_LINE_X);
+    }
+
+    #[test]
+    fn test_rink_distance_and_angle() {
+        assert_eq!(distance_to_net(GOAL_LINE_X, 0.0), 0.0);
+        assert_eq!(distance_to_net(59.0, 0.0), 30.0);
+        assert_eq!(distance_to_net(85.0, 3.0), 5.0);
+
+        assert_eq!(shot_angle(59.0, 0.0), 0.0);
+        assert!((shot_angle(69.0, 20.0) - 45.0).abs() < 1e-4);
+        assert_eq!(shot_angle(69.0, -20.0), shot_angle(69.0, 20.0));
+        assert_eq!(shot_angle(GOAL_LINE_X, 10.0), 90.0);
+        assert!(shot_angle(95.0, 5.0) > 90.0);
+    }
+
+    #[test]
+    fn test_rink_zone_classify_blue_line_boundaries() {
+        assert_eq!(ZoneCode::classify(25.0, true), ZoneCode::Neutral);
+        assert_eq!(ZoneCode::classify(25.5, true), ZoneCode::Offensive);
+        assert_eq!(ZoneCode::classify(-25.0, true), ZoneCode::Neutral);
+        assert_eq!(ZoneCode::classify(-26.0, true), ZoneCode::Defensive);
+        assert_eq!(ZoneCode::classify(0.0, true), ZoneCode::Neutral);
+        // Attacking -x mirrors the zones.
+        assert_eq!(ZoneCode::classify(26.0, false), ZoneCode::Defensive);
+        assert_eq!(ZoneCode::classify(-26.0, false), ZoneCode::Offensive);
+        assert_eq!(ZoneCode::classify(-25.0, false), ZoneCode::Neutral);
+        // Behind the net is still in the zone.
+        assert_eq!(ZoneCode::classify(95.0, true), ZoneCode::Offensive);
+    }
+
+    #[test]
+    fn test_rink_attacks_positive_x() {
+        assert!(attacks_positive_x(DefendingSide::Left, true));
+        assert!(!attacks_positive_x(DefendingSide::Left, false));
+        assert!(!attacks_positive_x(DefendingSide::Right, true));
+        assert!(attacks_positive_x(DefendingSide::Right, false));
+    }
+
+    #[test]
+    fn test_rink_faceoff_dot_nearest_snaps() {
+        for dot in FaceoffDot::ALL {
+            let (x, y) = dot.position();
+            assert_eq!(FaceoffDot::nearest(x, y), dot);
+            assert_eq!(FaceoffDot::nearest(x + 3.0, y - 2.0), dot);
+        }
+        assert_eq!(FaceoffDot::nearest(71.0, -20.0), FaceoffDot::OffensiveRight);
+        assert_eq!(
+            FaceoffDot::nearest(-22.0, 19.0),
+            FaceoffDot::NeutralDefensiveLeft
+        );
+        assert_eq!(FaceoffDot::nearest(4.0, 1.0), FaceoffDot::Center);
+        // Halfway between the two offensive dots: first in ALL order wins.
+        assert_eq!(
+            FaceoffDot::nearest(END_ZONE_DOT_X, 0.0),
+            FaceoffDot::OffensiveLeft
+        );
+    }
+
+    #[test]
+    fn test_rink_faceoff_dot_zones() {
+        for dot in FaceoffDot::ALL {
+            let (x, _) = dot.position();
+            assert_eq!(ZoneCode::classify(x, true), dot.zone(), "{:?}", dot);
+        }
+    }
+}
@@ -1,11 +1,19 @@
-use serde::{Deserialize, Serialize};
+use chrono::{DateTime, Utc};
+use serde::de::Error as DeError;
+use serde::{Deserialize, Deserializer, Serialize, Serializer};
 use std::fmt;
+use std::str::FromStr;
+use thiserror::Error;
 
-use crate::ids::{GameId, TeamId};
+use crate::ids::{GameId, PlayerId, TeamId};
 
+use super::boxscore::{GameClock, PeriodDescriptor, TvBroadcast};
 use super::common::LocalizedString;
+use super::enums::{GameScheduleState, PeriodType};
+use super::game_center::GameOutcome;
 use super::game_state::GameState;
 use super::game_type::GameType;
+use super::standings::Standing;
 
 /// Schedule game information
 #[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
@@ -15,14 +23,114 @@ pub struct ScheduleGame {
     pub game_type: GameType,
     #[serde(rename = "gameDate", skip_serializing_if = "Option::is_none")]
     pub game_date: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub venue: Option<LocalizedString>,
+    /// Played at neither team's home arena (e.g. the Global Series).
+    #[serde(rename = "neutralSite", default)]
+    pub neutral_site: bool,
     #[serde(rename = "startTimeUTC")]
     pub start_time_utc: String,
+    /// UTC offset of US Eastern time at the start, e.g. `"-04:00"`.
+    #[serde(rename = "easternUTCOffset", skip_serializing_if = "Option::is_none")]
+    pub eastern_utc_offset: Option<String>,
+    /// UTC offset at the venue at the start, e.g. `"+02:00"`.
+    #[serde(rename = "venueUTCOffset", skip_serializing_if = "Option::is_none")]
+    pub venue_utc_offset: Option<String>,
+    /// IANA time zone of the venue, e.g. `"Europe/Prague"`.
+    #[serde(rename = "venueTimezone", skip_serializing_if = "Option::is_none")]
+    pub venue_timezone: Option<String>,
+    #[serde(rename = "tvBroadcasts", default)]
+    pub tv_broadcasts: Vec<TvBroadcast>,
     #[serde(rename = "awayTeam")]
     pub away_team: ScheduleTeam,
     #[serde(rename = "homeTeam")]
     pub home_team: ScheduleTeam,
     #[serde(rename = "gameState")]
     pub game_state: GameState,
+    /// Current or last period; present once the game has started.
+    #[serde(
+        rename = "periodDescriptor",
+        default,
+        skip_serializing_if = "Option::is_none"
+    )]
+    pub period_descriptor: Option<PeriodDescriptor>,
+    /// Present while the game is live.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub clock: Option<GameClock>,
+    /// How a finished game ended; absent until the game is final.
+    #[serde(
+        rename = "gameOutcome",
+        default,
+        skip_serializing_if = "Option::is_none"
+    )]
+    pub game_outcome: Option<GameOutcome>,
+    /// Path of the game's page on nhl.com, e.g.
+    /// `"/gamecenter/njd-vs-buf/2024/10/04/2024020001"`.
+    #[serde(rename = "gameCenterLink", skip_serializing_if = "Option::is_none")]
+    pub game_center_link: Option<String>,
+    /// Goalie credited with the win; club schedules list it once the game
+    /// is final.
+    #[serde(
+        rename = "winningGoalie",
+        default,
+        skip_serializing_if = "Option::is_none"
+    )]
+    pub winning_goalie: Option<DecidingPlayer>,
+    /// Scorer of the game-winning goal; club schedules list it once the
+    /// game is final.
+    #[serde(
+        rename = "winningGoalScorer",
+        default,
+        skip_serializing_if = "Option::is_none"
+    )]
+    pub winning_goal_scorer: Option<DecidingPlayer>,
+}
+
+impl ScheduleGame {
+    /// Whether the game is in progress.
+    pub fn is_live(&self) -> bool {
+        self.game_state.is_live()
+    }
+
+    /// Whether `team_abbr` plays in the game, home or away. ASCII
+    /// case-insensitive.
+    pub fn involves(&self, team_abbr: &str) -> bool {
+        self.away_team.abbrev.eq_ignore_ascii_case(team_abbr)
+            || self.home_team.abbrev.eq_ignore_ascii_case(team_abbr)
+    }
+
+    /// Whether the game is over.
+    pub fn is_final(&self) -> bool {
+        self.game_state.is_final()
+    }
+
+    /// The team that won; `None` until the game is final or when the
+    /// payload has no scores.
+    pub fn winner(&self) -> Option<&ScheduleTeam> {
+        final_winner(&self.game_state, &self.away_team, &self.home_team)
+    }
+
+    /// Scoreboard period and clock of a live game, e.g. `"2nd 08:43"`; see
+    /// [`GameScore::period_clock`].
+    pub fn period_clock(&self) -> Option<String> {
+        period_clock(
+            &self.game_state,
+            self.period_descriptor.as_ref(),
+            self.clock.as_ref(),
+        )
+    }
+
+    /// [`GameScore::rewind_to`], also withholding the deciding players.
+    pub(crate) fn rewind_to(&mut self, as_of: DateTime<Utc>) {
+        self.game_state = rewound_state(&self.game_state, parse_start(&self.start_time_utc), as_of);
+        self.away_team.score = None;
+        self.home_team.score = None;
+        self.game_outcome = None;
+        self.period_descriptor = None;
+        self.clock = None;
+        self.winning_goalie = None;
+        self.winning_goal_scorer = None;
+    }
 }
 
 impl fmt::Display for ScheduleGame {
@@ -53,6 +161,112 @@ pub struct ScheduleTeam {
     pub logo: String,
     #[serde(skip_serializing_if = "Option::is_none")]
     pub score: Option<i32>,
+    /// Season record, e.g. `"10-5-2"`; club schedules send it with each
+    /// game.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub record: Option<TeamRecord>,
+}
+
+/// Error returned when a string is not a `"W-L-OTL"` record.
+#[derive(Debug, Clone, PartialEq, Eq, Error)]
+#[error("invalid team record: {0:?} (expected \"W-L-OTL\")")]
+pub struct ParseTeamRecordError(String);
+
+/// A team's wins, regulation losses and overtime/shootout losses, sent by
+/// the API as `"10-5-2"`
+///
+/// Serializes back to the same string.
+///
+/// ```
+/// use nhl_api::TeamRecord;
+///
+/// let record: TeamRecord = "10-5-2".parse().unwrap();
+/// assert_eq!(record.points(), 22);
+/// assert_eq!(record.to_string(), "10-5-2");
+/// ```
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct TeamRecord {
+    pub wins: i32,
+    pub losses: i32,
+    pub ot_losses: i32,
+}
+
+impl TeamRecord {
+    pub fn games_played(&self) -> i32 {
+        self.wins + self.losses + self.ot_losses
+    }
+
+    /// Standings points: two per win, one per overtime/shootout loss.
+    pub fn points(&self) -> i32 {
+        2 * self.wins + self.ot_losses
+    }
+}
+
+impl fmt::Display for TeamRecord {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}-{}-{}", self.wins, self.losses, self.ot_losses)
+    }
+}
+
+impl FromStr for TeamRecord {
+    type Err = ParseTeamRecordError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let invalid = || ParseTeamRecordError(s.to_string());
+        let mut parts = s.split('-').map(|part| {
+            if part.is_empty() || !part.bytes().all(|b| b.is_ascii_digit()) {
+                return Err(invalid());
+            }
+            part.parse::<i32>().map_err(|_| invalid())
+        });
+        let mut next = || parts.next().ok_or_else(invalid)?;
+        let record = Self {
+            wins: next()?,
+            losses: next()?,
+            ot_losses: next()?,
+        };
+        if parts.next().is_some() {
+            return Err(invalid());
+        }
+        Ok(record)
+    }
+}
+
+impl Serialize for TeamRecord {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        serializer.collect_str(self)
+    }
+}
+
+impl<'de> Deserialize<'de> for TeamRecord {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        let value = String::deserialize(deserializer)?;
+        value.parse().map_err(D::Error::custom)
+    }
+}
+
+/// The winning goalie or game-winning goal scorer of a finished game
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+pub struct DecidingPlayer {
+    #[serde(rename = "playerId")]
+    pub player_id: PlayerId,
+    #[serde(rename = "firstInitial")]
+    pub first_initial: LocalizedString,
+    #[serde(rename = "lastName")]
+    pub last_name: LocalizedString,
+}
+
+impl DecidingPlayer {
+    /// Initial and last name, e.g. `"J. Binnington"`.
+    pub fn name(&self) -> String {
+        format!("{} {}", self.first_initial.default, self.last_name.default)
+    }
 }
 
 /// Daily schedule response
@@ -78,21 +292,89 @@ pub struct WeeklyScheduleResponse {
     pub previous_start_date: String,
     #[serde(rename = "gameWeek")]
     pub game_week: Vec<GameDay>,
+    /// Betting partners nhl.com shows odds from.
+    #[serde(rename = "oddsPartners", default)]
+    pub odds_partners: Vec<OddsPartner>,
+    #[serde(rename = "preSeasonStartDate", skip_serializing_if = "Option::is_none")]
+    pub pre_season_start_date: Option<String>,
+    #[serde(
+        rename = "regularSeasonStartDate",
+        skip_serializing_if = "Option::is_none"
+    )]
+    pub regular_season_start_date: Option<String>,
+    #[serde(
+        rename = "regularSeasonEndDate",
+        skip_serializing_if = "Option::is_none"
+    )]
+    pub regular_season_end_date: Option<String>,
+    #[serde(rename = "playoffEndDate", skip_serializing_if = "Option::is_none")]
+    pub playoff_end_date: Option<String>,
 }
 
 /// A day of games
 #[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
 pub struct GameDay {
     pub date: String,
+    /// Weekday, e.g. `"FRI"`.
+    #[serde(rename = "dayAbbrev", skip_serializing_if = "Option::is_none")]
+    pub day_abbrev: Option<String>,
+    #[serde(rename = "numberOfGames", default)]
+    pub number_of_games: usize,
     pub games: Vec<ScheduleGame>,
 }
 
+/// A sportsbook listed with the weekly schedule
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+#[serde(rename_all = "camelCase")]
+pub struct OddsPartner {
+    pub partner_id: i64,
+    /// Country the partner is shown in, e.g. `"US"`.
+    pub country: String,
+    pub name: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub image_url: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub site_url: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub bg_color: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub text_color: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub accent_color: Option<String>,
+}
+
 /// Team schedule response (monthly/weekly)
 #[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
 pub struct TeamScheduleResponse {
     pub games: Vec<ScheduleGame>,
 }
 
+impl TeamScheduleResponse {
+    /// Final games, in schedule order.
+    pub fn completed_games(&self) -> Vec<&ScheduleGame> {
+        self.games.iter().filter(|game| game.is_final()).collect()
+    }
+
+    /// Games not started yet (`FUT` or `PRE`), in schedule order.
+    pub fn upcoming_games(&self) -> Vec<&ScheduleGame> {
+        self.games
+            .iter()
+            .filter(|game| game.game_state.is_upcoming())
+            .collect()
+    }
+
+    /// `team_abbr`'s record as listed with its most recent final game.
+    /// ASCII case-insensitive; `None` when no final game lists one.
+    pub fn record_as_of_latest_game(&self, team_abbr: &str) -> Option<TeamRecord> {
+        self.completed_games().into_iter().rev().find_map(|game| {
+            [&game.away_team, &game.home_team]
+                .into_iter()
+                .find(|team| team.abbrev.eq_ignore_ascii_case(team_abbr))
+                .and_then(|team| team.record)
+        })
+    }
+}
+
 /// Game scores for a day
 #[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
 pub struct DailyScores {
@@ -118,6 +400,263 @@ pub struct GameScore {
     pub away_team: ScheduleTeam,
     #[serde(rename = "homeTeam")]
     pub home_team: ScheduleTeam,
+    /// `None` when the payload omits it.
+    #[serde(
+        rename = "gameScheduleState",
+        default,
+        skip_serializing_if = "Option::is_none"
+    )]
+    pub game_schedule_state: Option<GameScheduleState>,
+    /// How a finished game ended (regulation, overtime or shootout); absent
+    /// until the game is final.
+    #[serde(
+        rename = "gameOutcome",
+        default,
+        skip_serializing_if = "Option::is_none"
+    )]
+    pub game_outcome: Option<GameOutcome>,
+    /// Current or last period; present once the game has started.
+    #[serde(
+        rename = "periodDescriptor",
+        default,
+        skip_serializing_if = "Option::is_none"
+    )]
+    pub period_descriptor: Option<PeriodDescriptor>,
+    /// Present while the game is live.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub clock: Option<GameClock>,
+    #[serde(
+        rename = "startTimeUTC",
+        default,
+        skip_serializing_if = "Option::is_none"
+    )]
+    pub start_time_utc: Option<String>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub venue: Option<LocalizedString>,
+}
+
+impl GameScore {
+    /// Whether the game is in progress.
+    pub fn is_live(&self) -> bool {
+        self.game_state.is_live()
+    }
+
+    /// Whether the game is over.
+    pub fn is_final(&self) -> bool {
+        self.game_state.is_final()
+    }
+
+    /// The team that won; `None` until the game is final or when the
+    /// payload has no scores.
+    pub fn winner(&self) -> Option<&ScheduleTeam> {
+        final_winner(&self.game_state, &self.away_team, &self.home_team)
+    }
+
+    /// Rewinds the game to how it stood at `as_of`: scheduled if it starts
+    /// later, live if it had started. The API only reports a game's current
+    /// progress, so the score, period, clock and outcome are withheld
+    /// either way. `start` is used when the payload has no start time.
+    pub(crate) fn rewind_to(&mut self, start: Option<DateTime<Utc>>, as_of: DateTime<Utc>) {
+        let start = self
+            .start_time_utc
+            .as_deref()
+            .and_then(parse_start)
+            .or(start);
+        self.game_state = rewound_state(&self.game_state, start, as_of);
+        self.away_team.score = None;
+        self.home_team.score = None;
+        self.game_outcome = None;
+        self.period_descriptor = None;
+        self.clock = None;
+    }
+
+    /// Scoreboard period and clock of a live game: `"2nd 08:43"`,
+    /// `"2nd INT"` during the following intermission, or just the period
+    /// (`"SO"`) when there's no clock. `None` unless the game is live and
+    /// the payload says which period it's in.
+    pub fn period_clock(&self) -> Option<String> {
+        period_clock(
+            &self.game_state,
+            self.period_descriptor.as_ref(),
+            self.clock.as_ref(),
+        )
+    }
+}
+
+/// The state at `as_of` of a game now in `state`: live once it had
+/// started, whatever came after, and scheduled before that. A game with no
+/// known start that has started now is taken to have started by then.
+fn rewound_state(
+    state: &GameState,
+    start: Option<DateTime<Utc>>,
+    as_of: DateTime<Utc>,
+) -> GameState {
+    if state.has_started() && start.is_none_or(|start| start <= as_of) {
+        GameState::Live
+    } else {
+        GameState::Future
+    }
+}
+
+/// A `startTimeUTC` value as a UTC instant.
+pub(crate) fn parse_start(start_time_utc: &str) -> Option<DateTime<Utc>> {
+    DateTime::parse_from_rfc3339(start_time_utc)
+        .ok()
+        .map(|start| start.with_timezone(&Utc))
+}
+
+impl DailySchedule {
+    /// The day's games as [`ScoreboardGame`]s, by start time.
+    pub fn to_scoreboard(&self) -> Vec<ScoreboardGame> {
+        by_start_time(self.games.iter().cloned().map(ScoreboardGame::from))
+    }
+}
+
+impl DailyScores {
+    /// The day's games as [`ScoreboardGame`]s, by start time.
+    pub fn to_scoreboard(&self) -> Vec<ScoreboardGame> {
+        by_start_time(self.games.iter().cloned().map(ScoreboardGame::from))
+    }
+}
+
+/// One game as a scoreboard shows it, from either [`DailySchedule`] or
+/// [`DailyScores`]
+///
+/// The two endpoints overlap, so a UI can render either one through this
+/// type. Fields only one of them sends are `None` when converted from the
+/// other.
+#[derive(Debug, Clone, PartialEq)]
+pub struct ScoreboardGame {
+    pub id: GameId,
+    pub game_type: GameType,
+    pub game_state: GameState,
+    /// Scores only.
+    pub game_schedule_state: Option<GameScheduleState>,
+    /// Scores are on the teams.
+    pub away_team: ScheduleTeam,
+    pub home_team: ScheduleTeam,
+    /// Schedule only, `YYYY-MM-DD`.
+    pub game_date: Option<String>,
+    /// `startTimeUTC`, e.g. `"2024-10-04T17:00:00Z"`.
+    pub start_time_utc: Option<String>,
+    pub venue: Option<LocalizedString>,
+    /// Once the game has started.
+    pub period_descriptor: Option<PeriodDescriptor>,
+    /// While the game is live.
+    pub clock: Option<GameClock>,
+    /// Once the game is final.
+    pub game_outcome: Option<GameOutcome>,
+}
+
+impl ScoreboardGame {
+    /// Whether the game is in progress.
+    pub fn is_live(&self) -> bool {
+        self.game_state.is_live()
+    }
+
+    /// Whether the game is over.
+    pub fn is_final(&self) -> bool {
+        self.game_state.is_final()
+    }
+
+    /// The team that won; `None` until the game is final or when the
+    /// source has no scores.
+    pub fn winner(&self) -> Option<&ScheduleTeam> {
+        final_winner(&self.game_state, &self.away_team, &self.home_team)
+    }
+
+    /// Scoreboard period and clock of a live game; see
+    /// [`GameScore::period_clock`].
+    pub fn period_clock(&self) -> Option<String> {
+        period_clock(
+            &self.game_state,
+            self.period_descriptor.as_ref(),
+            self.clock.as_ref(),
+        )
+    }
+}
+
+impl From<ScheduleGame> for ScoreboardGame {
+    fn from(game: ScheduleGame) -> Self {
+        Self {
+            id: game.id,
+            game_type: game.game_type,
+            game_state: game.game_state,
+            game_schedule_state: None,
+            away_team: game.away_team,
+            home_team: game.home_team,
+            game_date: game.game_date,
+            start_time_utc: Some(game.start_time_utc).filter(|start| !start.is_empty()),
+            venue: game.venue,
+            period_descriptor: game.period_descriptor,
+            clock: game.clock,
+            game_outcome: game.game_outcome,
+        }
+    }
+}
+
+impl From<GameScore> for ScoreboardGame {
+    fn from(game: GameScore) -> Self {
+        Self {
+            id: game.id,
+            game_type: game.game_type,
+            game_state: game.game_state,
+            game_schedule_state: game.game_schedule_state,
+            away_team: game.away_team,
+            home_team: game.home_team,
+            game_date: None,
+            start_time_utc: game.start_time_utc,
+            venue: game.venue,
+            period_descriptor: game.period_descriptor,
+            clock: game.clock,
+            game_outcome: game.game_outcome,
+        }
+    }
+}
+
+/// `games` by start time (UTC timestamps sort as strings), games without
+/// one last; ties keep payload order.
+fn by_start_time(games: impl Iterator<Item = ScoreboardGame>) -> Vec<ScoreboardGame> {
+    let mut games: Vec<ScoreboardGame> = games.collect();
+    games.sort_by(|a, b| {
+        a.start_time_utc
+            .is_none()
+            .cmp(&b.start_time_utc.is_none())
+            .then_with(|| a.start_time_utc.cmp(&b.start_time_utc))
+    });
+    games
+}
+
+/// The higher-scoring side of a final game.
+fn final_winner<'a>(
+    state: &GameState,
+    away: &'a ScheduleTeam,
+    home: &'a ScheduleTeam,
+) -> Option<&'a ScheduleTeam> {
+    if !state.is_final() {
+        return None;
+    }
+    match away.score?.cmp(&home.score?) {
+        std::cmp::Ordering::Greater => Some(away),
+        std::cmp::Ordering::Less => Some(home),
+        std::cmp::Ordering::Equal => None,
+    }
+}
+
+fn period_clock(
+    state: &GameState,
+    period: Option<&PeriodDescriptor>,
+    clock: Option<&GameClock>,
+) -> Option<String> {
+    if !state.is_live() {
+        return None;
+    }
+    let period = period.filter(|period| period.number > 0)?.label();
+    Some(match clock {
+        Some(clock) if clock.in_intermission => format!("{} INT", period),
+        Some(clock) if period != "SO" => format!("{} {}", period, clock.time_remaining),
+        _ => period,
+    })
 }
 
 impl fmt::Display for GameScore {
@@ -143,9 +682,138 @@ impl fmt::Display for GameScore {
     }
 }
 
+/// One team's day: its game on the date (if any), its standings row, and
+/// its next game when it has none to play that day. From
+/// [`Client::team_today`](crate::Client::team_today).
+#[derive(Debug, Clone, PartialEq)]
+pub struct TeamToday {
+    pub team_abbrev: String,
+    /// The date looked at, `YYYY-MM-DD`.
+    pub date: String,
+    /// The team's game on `date`, with its live score and state.
+    pub game: Option<GameScore>,
+    /// `None` when the team isn't in the standings for `date` (e.g. before
+    /// the season opens).
+    pub standing: Option<Standing>,
+    /// The team's next game after `date` that week; only looked up when
+    /// there is no game to play on `date` (an off day, or a postponed,
+    /// suspended or cancelled game).
+    pub next_game: Option<ScheduleGame>,
+}
+
+impl TeamToday {
+    /// Whether the team has a game to play on `date`.
+    pub fn has_playable_game(&self) -> bool {
+        self.game.as_ref().is_some_and(|game| {
+            game.game_schedule_state
+                .as_ref()
+                .is_none_or(|state| state.is_playable())
+        })
+    }
+
+    /// One-line summary, e.g. `"MTL (5-3-1, 11 pts): beat TOR 4-2 (OT)"`
+    /// or `"MTL (5-3-1, 11 pts): no game, next at BOS on 2024-10-22"`.
+    pub fn headline(&self) -> String {
+        let mut headline = self.team_abbrev.clone();
+        if let Some(standing) = &self.standing {
+            headline.push_str(&format!(
+                " ({}-{}-{}, {} pts)",
+                standing.wins, standing.losses, standing.ot_losses, standing.points
+            ));
+        }
+        headline.push_str(": ");
+        headline.push_str(&match &self.game {
+            Some(game) => self.game_summary(game),
+            None => "no game".to_string(),
+        });
+        if !self.has_playable_game() {
+            if let Some(next) = &self.next_game {
+                let (_, opponent, home) = self.sides(&next.away_team, &next.home_team);
+                headline.push_str(&format!(", next {} {}", versus(home), opponent.abbrev));
+                if let Some(date) = &next.game_date {
+                    headline.push_str(&format!(" on {}", date));
+                }
+            }
+        }
+        headline
+    }
+
+    fn game_summary(&self, game: &GameScore) -> String {
+        let (team, opponent, home) = self.sides(&game.away_team, &game.home_team);
+        if let Some(state) = game
+            .game_schedule_state
+            .as_ref()
+            .filter(|state| !state.is_playable())
+        {
+            return format!(
+                "game {} {} {}",
+                versus(home),
+                opponent.abbrev,
+                state.name().to_lowercase()
+            );
+        }
+
+        let (Some(score), Some(against)) = (team.score, opponent.score) else {
+            let live = if game.is_live() { ", live" } else { "" };
+            return format!("{} {}{}", versus(home), opponent.abbrev, live);
+        };
+        if game.is_final() {
+            let result = if score > against { "beat" } else { "lost to" };
+            let decided = match game
+                .game_outcome
+                .as_ref()
+                .and_then(|outcome| outcome.last_period_type.as_ref())
+            {
+                Some(PeriodType::Overtime) => " (OT)",
+                Some(PeriodType::Shootout) => " (SO)",
+                _ => "",
+            };
+            format!(
+                "{} {} {}-{}{}",
+                result, opponent.abbrev, score, against, decided
+            )
+        } else if game.is_live() {
+            let position = match score.cmp(&against) {
+                std::cmp::Ordering::Greater => "leads",
+                std::cmp::Ordering::Less => "trails",
+                std::cmp::Ordering::Equal => "tied with",
+            };
+            format!(
+                "{} {} {}-{}, live",
+                position, opponent.abbrev, score, against
+            )
+        } else {
+            format!("{} {}", versus(home), opponent.abbrev)
+        }
+    }
+
+    /// `(team, opponent, team is home)` for a game this team plays in.
+    fn sides<'a>(
+        &self,
+        away: &'a ScheduleTeam,
+        home: &'a ScheduleTeam,
+    ) -> (&'a ScheduleTeam, &'a ScheduleTeam, bool) {
+        if home.abbrev.eq_ignore_ascii_case(&self.team_abbrev) {
+            (home, away, true)
+        } else {
+            (away, home, false)
+        }
+    }
+}
+
+fn versus(home: bool) -> &'static str {
+    if home {
+        "vs"
+    } else {
+        "at"
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::test_util::assert_no_unmapped_fields;
+    use crate::types::PeriodType;
 
     /// Builder for creating test ScheduleTeam instances
     struct TeamBuilder {
@@ -185,6 +853,7 @@ mod tests {
                 place_name: self.place_name,
                 logo: self.logo,
                 score: self.score,
+                record: None,
             }
         }
     }
@@ -247,10 +916,22 @@ mod tests {
                 id: GameId::new(self.id),
                 game_type: self.game_type,
                 game_date: self.game_date,
+                venue: None,
+                neutral_site: false,
                 start_time_utc: self.start_time_utc,
+                eastern_utc_offset: None,
+                venue_utc_offset: None,
+                venue_timezone: None,
+                tv_broadcasts: Vec::new(),
                 away_team: self.away_team,
                 home_team: self.home_team,
                 game_state: self.game_state,
+                period_descriptor: None,
+                clock: None,
+                game_outcome: None,
+                game_center_link: None,
+                winning_goalie: None,
+                winning_goal_scorer: None,
             }
         }
     }
@@ -275,7 +956,6 @@ mod tests {
             }
         }
 
-        #[allow(dead_code)]
         fn id(mut self, id: i64) -> Self {
             self.id = id;
             self
@@ -303,6 +983,12 @@ mod tests {
                 game_state: self.game_state,
                 away_team: self.away_team,
                 home_team: self.home_team,
+                game_schedule_state: None,
+                game_outcome: None,
+                period_descriptor: None,
+                clock: None,
+                start_time_utc: None,
+                venue: None,
             }
         }
     }
@@ -331,10 +1017,90 @@ mod tests {
         }"#;
 
         let scores: DailyScores = serde_json::from_str(json).unwrap();
+        assert_no_unmapped_fields::<DailyScores>(json);
         assert_eq!(scores.current_date, "2024-10-19");
         assert_eq!(scores.games.len(), 0);
     }
 
+    #[test]
+    fn test_game_score_game_outcome() {
+        let json = r#"{
+            "id": 2024020201, "gameType": 2, "gameState": "OFF",
+            "awayTeam": {"id": 1, "abbrev": "NJD", "placeName": null, "logo": "", "score": 3},
+            "homeTeam": {"id": 7, "abbrev": "BUF", "placeName": null, "logo": "", "score": 2},
+            "gameOutcome": {"lastPeriodType": "SO"}
+        }"#;
+
+        let game: GameScore = serde_json::from_str(json).unwrap();
+        assert_no_unmapped_fields::<GameScore>(json);
+        assert_eq!(
+            game.game_outcome.unwrap().last_period_type,
+            Some(PeriodType::Shootout)
+        );
+    }
+
+    #[test]
+    fn test_game_score_live_game_period_clock() {
+        let json = r#"{
+            "id": 2024020301, "gameType": 2, "gameState": "LIVE",
+            "awayTeam": {"id": 8, "abbrev": "MTL", "placeName": null, "logo": "", "score": 1},
+            "homeTeam": {"id": 10, "abbrev": "TOR", "placeName": null, "logo": "", "score": 2},
+            "gameScheduleState": "OK",
+            "periodDescriptor": {"number": 2, "periodType": "REG", "maxRegulationPeriods": 3},
+            "clock": {"timeRemaining": "08:43", "secondsRemaining": 523, "running": true,
+                "inIntermission": false}
+        }"#;
+
+        let mut game: GameScore = serde_json::from_str(json).unwrap();
+        assert_no_unmapped_fields::<GameScore>(json);
+        assert!(game.is_live());
+        assert!(!game.is_final());
+        assert_eq!(game.winner(), None);
+        assert_eq!(game.period_clock().as_deref(), Some("2nd 08:43"));
+
+        game.clock.as_mut().unwrap().in_intermission = true;
+        assert_eq!(game.period_clock().as_deref(), Some("2nd INT"));
+    }
+
+    #[test]
+    fn test_game_score_final_overtime_winner() {
+        let json = r#"{
+            "id": 2024020302, "gameType": 2, "gameState": "OFF",
+            "awayTeam": {"id": 6, "abbrev": "BOS", "placeName": null, "logo": "", "score": 3},
+            "homeTeam": {"id": 9, "abbrev": "OTT", "placeName": null, "logo": "", "score": 4},
+            "periodDescriptor": {"number": 4, "periodType": "OT", "maxRegulationPeriods": 3},
+            "gameOutcome": {"lastPeriodType": "OT"}
+        }"#;
+
+        let game: GameScore = serde_json::from_str(json).unwrap();
+        assert_no_unmapped_fields::<GameScore>(json);
+        assert!(game.is_final());
+        assert_eq!(game.winner().map(|team| team.abbrev.as_str()), Some("OTT"));
+        assert_eq!(
+            game.game_outcome.as_ref().unwrap().last_period_type,
+            Some(PeriodType::Overtime)
+        );
+        assert_eq!(game.period_clock(), None);
+    }
+
+    #[test]
+    fn test_schedule_game_future_has_no_scores() {
+        let json = r#"{
+            "id": 2024020303, "gameType": 2, "startTimeUTC": "2024-11-02T23:00:00Z",
+            "awayTeam": {"id": 1, "abbrev": "NJD", "placeName": null, "logo": ""},
+            "homeTeam": {"id": 7, "abbrev": "BUF", "placeName": null, "logo": ""},
+            "gameState": "FUT"
+        }"#;
+
+        let game: ScheduleGame = serde_json::from_str(json).unwrap();
+        assert!(!game.is_live());
+        assert!(!game.is_final());
+        assert_eq!(game.winner(), None);
+        assert_eq!(game.period_clock(), None);
+        assert_eq!(game.period_descriptor, None);
+        assert_eq!(game.clock, None);
+    }
+
     #[test]
     fn test_schedule_game_display() {
         let game = ScheduleGameBuilder::new("BUF", "TOR")
@@ -371,12 +1137,214 @@ mod tests {
         }"#;
 
         let game: ScheduleGame = serde_json::from_str(json).unwrap();
+        assert_no_unmapped_fields::<ScheduleGame>(json);
         assert_eq!(game.id, GameId::new(2024020001));
         assert_eq!(game.game_date, None);
         assert_eq!(game.away_team.abbrev, "BUF");
         assert_eq!(game.home_team.abbrev, "TOR");
     }
 
+    #[test]
+    fn test_weekly_and_team_schedule_responses_map_every_field() {
+        let game = r#"{
+            "id": 2024020001, "gameType": 2, "gameDate": "2024-10-04",
+            "startTimeUTC": "2024-10-04T17:00:00Z",
+            "awayTeam": {"id": 1, "abbrev": "NJD", "placeName": {"default": "New Jersey"},
+                "logo": "https://assets.nhle.com/logos/nhl/svg/NJD_light.svg", "score": 4},
+            "homeTeam": {"id": 7, "abbrev": "BUF", "placeName": {"default": "Buffalo"},
+                "logo": "https://assets.nhle.com/logos/nhl/svg/BUF_light.svg", "score": 1},
+            "gameState": "OFF"
+        }"#;
+        let weekly = format!(
+            r#"{{"nextStartDate": "2024-10-11", "previousStartDate": "2024-09-27",
+                "gameWeek": [{{"date": "2024-10-04", "games": [{}]}}]}}"#,
+            game
+        );
+        assert_no_unmapped_fields::<WeeklyScheduleResponse>(&weekly);
+        assert_no_unmapped_fields::<TeamScheduleResponse>(&format!(r#"{{"games": [{}]}}"#, game));
+    }
+
+    #[test]
+    fn test_team_record_parse() {
+        let record: TeamRecord = "10-5-2".parse().unwrap();
+        assert_eq!(
+            record,
+            TeamRecord {
+                wins: 10,
+                losses: 5,
+                ot_losses: 2,
+            }
+        );
+        assert_eq!(record.games_played(), 17);
+        assert_eq!(record.points(), 22);
+        assert_eq!(record.to_string(), "10-5-2");
+
+        let opener: TeamRecord = "0-0-0".parse().unwrap();
+        assert_eq!(opener, TeamRecord::default());
+        assert_eq!(opener.to_string(), "0-0-0");
+
+        for invalid in [
+            "", "10-5", "10-5-2-1", "10--2", "10-5-x", "-1-5-2", " 10-5-2",
+        ] {
+            assert_eq!(
+                invalid.parse::<TeamRecord>(),
+                Err(ParseTeamRecordError(invalid.to_string())),
+                "{}",
+                invalid
+            );
+        }
+        assert!(serde_json::from_str::<TeamRecord>(r#""10-5""#).is_err());
+    }
+
+    const CLUB_SCHEDULE: &str = r#"{"games": [
+        {
+            "id": 2024020010, "gameType": 2, "startTimeUTC": "2024-10-09T23:00:00Z",
+            "awayTeam": {"id": 10, "abbrev": "TOR", "placeName": null, "logo": "", "score": 1,
+                "record": "0-1-0"},
+            "homeTeam": {"id": 8, "abbrev": "MTL", "placeName": null, "logo": "", "score": 2,
+                "record": "1-0-0"},
+            "gameState": "OFF",
+            "gameOutcome": {"lastPeriodType": "REG"},
+            "winningGoalie": {"playerId": 8474596, "firstInitial": {"default": "J."},
+                "lastName": {"default": "Doe"}},
+            "winningGoalScorer": {"playerId": 8480018, "firstInitial": {"default": "N."},
+                "lastName": {"default": "Suzuki"}}
+        },
+        {
+            "id": 2024020025, "gameType": 2, "startTimeUTC": "2024-10-12T23:00:00Z",
+            "awayTeam": {"id": 8, "abbrev": "MTL", "placeName": null, "logo": "", "score": 3,
+                "record": "1-0-1"},
+            "homeTeam": {"id": 6, "abbrev": "BOS", "placeName": null, "logo": "", "score": 4,
+                "record": "2-0-0"},
+            "gameState": "FINAL",
+            "gameOutcome": {"lastPeriodType": "OT"}
+        },
+        {
+            "id": 2024020040, "gameType": 2, "startTimeUTC": "2024-10-15T23:00:00Z",
+            "awayTeam": {"id": 1, "abbrev": "NJD", "placeName": null, "logo": "",
+                "record": "2-1-0"},
+            "homeTeam": {"id": 8, "abbrev": "MTL", "placeName": null, "logo": "",
+                "record": "1-0-1"},
+            "gameState": "FUT"
+        }
+    ]}"#;
+
+    #[test]
+    fn test_team_schedule_results_and_records() {
+        let schedule: TeamScheduleResponse = serde_json::from_str(CLUB_SCHEDULE).unwrap();
+        assert_no_unmapped_fields::<TeamScheduleResponse>(CLUB_SCHEDULE);
+
+        let completed = schedule.completed_games();
+        assert_eq!(
+            completed.iter().map(|game| game.id).collect::<Vec<_>>(),
+            [GameId::new(2024020010), GameId::new(2024020025)]
+        );
+        let upcoming = schedule.upcoming_games();
+        assert_eq!(upcoming.len(), 1);
+        assert_eq!(upcoming[0].id, GameId::new(2024020040));
+
+        let opener = completed[0];
+        assert_eq!(
+            opener
+                .winning_goal_scorer
+                .as_ref()
+                .map(DecidingPlayer::name),
+            Some("N. Suzuki".to_string())
+        );
+        assert_eq!(
+            opener
+                .winning_goalie
+                .as_ref()
+                .map(|goalie| goalie.player_id),
+            Some(PlayerId::new(8474596))
+        );
+        assert_eq!(completed[1].winning_goal_scorer, None);
+
+        assert_eq!(
+            schedule.record_as_of_latest_game("mtl"),
+            Some(TeamRecord {
+                wins: 1,
+                losses: 0,
+                ot_losses: 1,
+            })
+        );
+        assert_eq!(
+            schedule
+                .record_as_of_latest_game("TOR")
+                .map(|r| r.to_string()),
+            Some("0-1-0".to_string())
+        );
+        assert_eq!(schedule.record_as_of_latest_game("NJD"), None);
+
+        let round_trip = serde_json::to_value(opener).unwrap();
+        assert_eq!(round_trip["homeTeam"]["record"], "1-0-0");
+    }
+
+    const WEEKLY_SCHEDULE_FIXTURE: &str = include_str!("../../tests/fixtures/weekly_schedule.json");
+
+    #[test]
+    fn test_weekly_schedule_fixture_maps_every_field() {
+        assert_no_unmapped_fields::<WeeklyScheduleResponse>(WEEKLY_SCHEDULE_FIXTURE);
+        let weekly: WeeklyScheduleResponse = serde_json::from_str(WEEKLY_SCHEDULE_FIXTURE).unwrap();
+
+        assert_eq!(
+            weekly.regular_season_start_date.as_deref(),
+            Some("2024-10-04")
+        );
+        assert_eq!(weekly.playoff_end_date.as_deref(), Some("2025-06-20"));
+        assert_eq!(weekly.odds_partners[0].name, "DraftKings");
+
+        let day = &weekly.game_week[0];
+        assert_eq!(day.day_abbrev.as_deref(), Some("FRI"));
+        assert_eq!(day.number_of_games, 1);
+        assert_eq!(weekly.game_week[2].number_of_games, 0);
+
+        let game = &day.games[0];
+        assert_eq!(
+            game.venue.as_ref().map(|venue| venue.default.as_str()),
+            Some("O2 Czech Republic")
+        );
+        assert!(game.neutral_site);
+        assert_eq!(game.venue_utc_offset.as_deref(), Some("+02:00"));
+        assert_eq!(game.venue_timezone.as_deref(), Some("Europe/Prague"));
+        assert_eq!(game.tv_broadcasts.len(), 2);
+        assert_eq!(game.tv_broadcasts[0].network, "ESPN2");
+        assert_eq!(
+            game.game_center_link.as_deref(),
+            Some("/gamecenter/njd-vs-buf/2024/10/04/2024020001")
+        );
+    }
+
+    #[test]
+    fn test_weekly_schedule_with_unknown_game_state() {
+        let json =
+            WEEKLY_SCHEDULE_FIXTURE.replacen(r#""gameState": "OFF""#, r#""gameState": "DELAY""#, 1);
+        let weekly: WeeklyScheduleResponse = serde_json::from_str(&json).unwrap();
+
+        let game = &weekly.game_week[0].games[0];
+        assert_eq!(game.game_state, GameState::Unknown("DELAY".to_string()));
+        assert_eq!(game.winner(), None);
+    }
+
+    /// Historical game type codes, known or not, survive a round trip as the
+    /// same integer.
+    #[test]
+    fn test_schedule_game_type_round_trips_unchanged() {
+        for (code, expected) in [(7, GameType::WorldCup2004), (5, GameType::Other(5))] {
+            let json = format!(
+                r#"{{"id": 2004070001, "gameType": {code}, "startTimeUTC": "23:00:00Z",
+                    "awayTeam": {{"id": 7, "abbrev": "BUF", "logo": ""}},
+                    "homeTeam": {{"id": 10, "abbrev": "TOR", "logo": ""}},
+                    "gameState": "OFF"}}"#
+            );
+
+            let game: ScheduleGame = serde_json::from_str(&json).unwrap();
+            assert_eq!(game.game_type, expected);
+            let value = serde_json::to_value(&game).unwrap();
+            assert_eq!(value["gameType"], code);
+        }
+    }
+
     /// `ScheduleGame.id`/`ScheduleTeam.id` accept numeric-string forms as well
     /// as integers (1.3).
     #[test]
@@ -445,4 +1413,116 @@ mod tests {
 
         assert_eq!(game.to_string(), "BUF 0 @ TOR 0 [LIVE]");
     }
+
+    #[test]
+    fn test_team_today_headline_final_in_overtime() {
+        let mut game = GameScoreBuilder::new("BUF", "TOR")
+            .away_score(2)
+            .home_score(3)
+            .game_state(GameState::Off)
+            .build();
+        game.game_outcome = Some(GameOutcome {
+            last_period_type: Some(PeriodType::Overtime),
+        });
+        let today = TeamToday {
+            team_abbrev: "BUF".to_string(),
+            date: "2024-10-19".to_string(),
+            game: Some(game),
+            standing: None,
+            next_game: None,
+        };
+
+        assert!(today.has_playable_game());
+        assert_eq!(today.headline(), "BUF: lost to TOR 2-3 (OT)");
+    }
+
+    /// A live 2nd-period game and a later future game, each as the schedule
+    /// and the scores endpoints send it, listed in reverse start order.
+    const LIVE_GAME: &str = r#""id": 2024020100, "gameType": 2, "gameState": "LIVE",
+        "startTimeUTC": "2024-10-19T23:00:00Z", "venue": {"default": "Bell Centre"},
+        "awayTeam": {"id": 10, "abbrev": "TOR", "logo": "", "score": 1},
+        "homeTeam": {"id": 8, "abbrev": "MTL", "logo": "", "score": 2},
+        "periodDescriptor": {"number": 2, "periodType": "REG", "maxRegulationPeriods": 3},
+        "clock": {"timeRemaining": "08:43", "secondsRemaining": 523, "running": true,
+            "inIntermission": false}"#;
+    const FUTURE_GAME: &str = r#""id": 2024020101, "gameType": 2, "gameState": "FUT",
+        "startTimeUTC": "2024-10-20T02:00:00Z", "venue": {"default": "Rogers Place"},
+        "awayTeam": {"id": 6, "abbrev": "BOS", "logo": ""},
+        "homeTeam": {"id": 22, "abbrev": "EDM", "logo": ""}"#;
+
+    fn assert_scoreboard(games: &[ScoreboardGame]) {
+        let ids: Vec<i64> = games.iter().map(|game| game.id.as_i64()).collect();
+        assert_eq!(ids, vec![2024020100, 2024020101]);
+
+        let live = &games[0];
+        assert!(live.is_live());
+        assert_eq!(live.start_time_utc.as_deref(), Some("2024-10-19T23:00:00Z"));
+        assert_eq!(live.venue.as_ref().unwrap().default, "Bell Centre");
+        assert_eq!(live.away_team.score, Some(1));
+        assert_eq!(live.home_team.score, Some(2));
+        assert_eq!(live.period_clock().as_deref(), Some("2nd 08:43"));
+
+        let future = &games[1];
+        assert_eq!(future.game_state, GameState::Future);
+        assert_eq!(future.away_team.score, None);
+        assert_eq!(future.period_descriptor, None);
+        assert_eq!(future.clock, None);
+        assert_eq!(future.period_clock(), None);
+    }
+
+    #[test]
+    fn test_daily_schedule_to_scoreboard() {
+        let schedule: DailySchedule = serde_json::from_str(&format!(
+            r#"{{"date": "2024-10-19", "games": [
+                {{"gameDate": "2024-10-19", {FUTURE_GAME}}},
+                {{"gameDate": "2024-10-19", {LIVE_GAME}}}
+            ]}}"#
+        ))
+        .unwrap();
+
+        let games = schedule.to_scoreboard();
+        assert_scoreboard(&games);
+        assert_eq!(games[0].game_date.as_deref(), Some("2024-10-19"));
+        // The schedule doesn't send a schedule state.
+        assert_eq!(games[0].game_schedule_state, None);
+    }
+
+    #[test]
+    fn test_daily_scores_to_scoreboard() {
+        let scores: DailyScores = serde_json::from_str(&format!(
+            r#"{{"prevDate": "2024-10-18", "currentDate": "2024-10-19",
+                "nextDate": "2024-10-20", "games": [
+                {{"gameScheduleState": "OK", {FUTURE_GAME}}},
+                {{"gameScheduleState": "OK", {LIVE_GAME}}}
+            ]}}"#
+        ))
+        .unwrap();
+
+        let games = scores.to_scoreboard();
+        assert_scoreboard(&games);
+        assert_eq!(games[0].game_schedule_state, Some(GameScheduleState::Ok));
+        // Scores don't send the game date.
+        assert_eq!(games[0].game_date, None);
+    }
+
+    #[test]
+    fn test_scoreboard_games_without_start_time_sort_last() {
+        let mut unscheduled = GameScoreBuilder::new("BUF", "TOR").id(2024020001).build();
+        unscheduled.start_time_utc = None;
+        let mut scheduled = GameScoreBuilder::new("MTL", "OTT").id(2024020002).build();
+        scheduled.start_time_utc = Some("2024-10-19T23:00:00Z".to_string());
+        let scores = DailyScores {
+            prev_date: String::new(),
+            current_date: String::new(),
+            next_date: String::new(),
+            games: vec![unscheduled, scheduled],
+        };
+
+        let ids: Vec<i64> = scores
+            .to_scoreboard()
+            .iter()
+            .map(|game| game.id.as_i64())
+            .collect();
+        assert_eq!(ids, vec![2024020002, 2024020001]);
+    }
 }
@@ -0,0 +1,136 @@
+//! Deserialization helpers for fields the search and stats hosts send as
+//! either a JSON number or a numeric string (`"sweaterNumber": "97"` vs
+//! `97`).
+//!
+//! Each helper yields an `Option`: `null`, an empty string and (with
+//! `default` on the field) a missing key all become `None`, while a
+//! non-numeric string still fails loudly.
+//!
+//! ```ignore
+//! #[serde(deserialize_with = "string_or_number_i32", default)]
+//! #[serde(skip_serializing_if = "Option::is_none")]
+//! pub sweater_number: Option<i32>,
+//! ```
+
+use serde::de::Error as DeError;
+use serde::{Deserialize, Deserializer};
+use serde_json::Number;
+use std::str::FromStr;
+
+#[derive(Deserialize)]
+#[serde(untagged)]
+enum StringOrNumber {
+    Number(Number),
+    String(String),
+}
+
+fn string_or_number<'de, D, T>(
+    deserializer: D,
+    expected: &str,
+    from_number: fn(&Number) -> Option<T>,
+) -> Result<Option<T>, D::Error>
+where
+    D: Deserializer<'de>,
+    T: FromStr,
+{
+    let invalid =
+        |raw: &dyn std::fmt::Display| D::Error::custom(format!("invalid {}: {}", expected, raw));
+
+    match Option::<StringOrNumber>::deserialize(deserializer)? {
+        None => Ok(None),
+        Some(StringOrNumber::Number(number)) => from_number(&number)
+            .map(Some)
+            .ok_or_else(|| invalid(&number)),
+        Some(StringOrNumber::String(s)) => {
+            let trimmed = s.trim();
+            if trimmed.is_empty() {
+                return Ok(None);
+            }
+            trimmed
+                .parse()
+                .map(Some)
+                .map_err(|_| invalid(&format!("{:?}", s)))
+        }
+    }
+}
+
+/// `i32` from a number or numeric string; see the module docs.
+pub(crate) fn string_or_number_i32<'de, D>(deserializer: D) -> Result<Option<i32>, D::Error>
+where
+    D: Deserializer<'de>,
+{
+    string_or_number(deserializer, "i32", |number| {
+        number.as_i64().and_then(|n| i32::try_from(n).ok())
+    })
+}
+
+/// `i64` from a number or numeric string; see the module docs.
+pub(crate) fn string_or_number_i64<'de, D>(deserializer: D) -> Result<Option<i64>, D::Error>
+where
+    D: Deserializer<'de>,
+{
+    string_or_number(deserializer, "i64", Number::as_i64)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[derive(Debug, Deserialize, PartialEq)]
+    struct Fixture {
+        #[serde(deserialize_with = "string_or_number_i32", default)]
+        small: Option<i32>,
+        #[serde(deserialize_with = "string_or_number_i64", default)]
+        large: Option<i64>,
+    }
+
+    fn parse(json: &str) -> Result<Fixture, serde_json::Error> {
+        serde_json::from_str(json)
+    }
+
+    #[test]
+    fn test_string_or_number_i32_both_forms() {
+        assert_eq!(parse(r#"{"small": 97}"#).unwrap().small, Some(97));
+        assert_eq!(parse(r#"{"small": "97"}"#).unwrap().small, Some(97));
+    }
+
+    #[test]
+    fn test_string_or_number_i32_null_and_empty() {
+        assert_eq!(parse(r#"{"small": null}"#).unwrap().small, None);
+        assert_eq!(parse(r#"{"small": ""}"#).unwrap().small, None);
+        assert_eq!(parse("{}").unwrap().small, None);
+    }
+
+    #[test]
+    fn test_string_or_number_i32_out_of_range() {
+        let error = parse(r#"{"small": 3000000000}"#).unwrap_err();
+        assert!(error.to_string().contains("invalid i32"), "{}", error);
+    }
+
+    #[test]
+    fn test_string_or_number_i64_both_forms() {
+        assert_eq!(
+            parse(r#"{"large": 20242025}"#).unwrap().large,
+            Some(20242025)
+        );
+        assert_eq!(
+            parse(r#"{"large": "20242025"}"#).unwrap().large,
+            Some(20242025)
+        );
+    }
+
+    #[test]
+    fn test_string_or_number_i64_null() {
+        assert_eq!(parse(r#"{"large": null}"#).unwrap().large, None);
+    }
+
+    #[test]
+    fn test_string_or_number_i64_rejects_non_numeric_string() {
+        let error = parse(r#"{"large": "n/a"}"#).unwrap_err();
+        assert!(
+            error.to_string().contains(r#"invalid i64: "n/a""#),
+            "{}",
+            error
+        );
+    }
+}
@@ -55,9 +55,10 @@ impl Standing {
         Team {
             name: self.team_name.default.clone(),
             common_name: self.team_common_name.default.clone(),
-            place_name: LocalizedString {
-                default: place_name(&self.team_name.default, &self.team_common_name.default),
-            },
+            place_name: LocalizedString::new(place_name(
+                &self.team_name.default,
+                &self.team_common_name.default,
+            )),
             abbr: self.team_abbrev.default.clone(),
             logo: self.team_logo.clone(),
             conference: Conference {
@@ -128,6 +129,7 @@ pub struct SeasonsResponse {
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::test_util::assert_no_unmapped_fields;
 
     #[test]
     fn test_standings_response_deserialization() {
@@ -151,6 +153,7 @@ mod tests {
         }"#;
 
         let response: StandingsResponse = serde_json::from_str(json).unwrap();
+        assert_no_unmapped_fields::<StandingsResponse>(json);
         assert_eq!(response.standings.len(), 1);
         assert_eq!(response.standings[0].team_abbrev.default, "BUF");
         assert_eq!(response.standings[0].wins, 10);
@@ -164,15 +167,9 @@ mod tests {
             conference_name: Some("Western".to_string()),
             division_abbrev: "PAC".to_string(),
             division_name: "Pacific".to_string(),
-            team_name: LocalizedString {
-                default: "Vegas Golden Knights".to_string(),
-            },
-            team_common_name: LocalizedString {
-                default: "Golden Knights".to_string(),
-            },
-            team_abbrev: LocalizedString {
-                default: "VGK".to_string(),
-            },
+            team_name: LocalizedString::new("Vegas Golden Knights"),
+            team_common_name: LocalizedString::new("Golden Knights"),
+            team_abbrev: LocalizedString::new("VGK"),
             team_logo: "https://assets.nhle.com/logos/nhl/svg/VGK_light.svg".to_string(),
             wins: 12,
             losses: 3,
@@ -204,15 +201,9 @@ mod tests {
             conference_name: Some("Eastern".to_string()),
             division_abbrev: "ATL".to_string(),
             division_name: "Atlantic".to_string(),
-            team_name: LocalizedString {
-                default: "Boston Bruins".to_string(),
-            },
-            team_common_name: LocalizedString {
-                default: "Bruins".to_string(),
-            },
-            team_abbrev: LocalizedString {
-                default: "BOS".to_string(),
-            },
+            team_name: LocalizedString::new("Boston Bruins"),
+            team_common_name: LocalizedString::new("Bruins"),
+            team_abbrev: LocalizedString::new("BOS"),
             team_logo: "https://assets.nhle.com/logos/nhl/svg/BOS_light.svg".to_string(),
             wins: 15,
             losses: 2,
@@ -303,15 +294,9 @@ mod tests {
             conference_name: None,
             division_abbrev: "EAST".to_string(),
             division_name: "East".to_string(),
-            team_name: LocalizedString {
-                default: "Montreal Canadiens".to_string(),
-            },
-            team_common_name: LocalizedString {
-                default: "Canadiens".to_string(),
-            },
-            team_abbrev: LocalizedString {
-                default: "MTL".to_string(),
-            },
+            team_name: LocalizedString::new("Montreal Canadiens"),
+            team_common_name: LocalizedString::new("Canadiens"),
+            team_abbrev: LocalizedString::new("MTL"),
             team_logo: "https://assets.nhle.com/logos/nhl/svg/MTL_light.svg".to_string(),
             wins: 25,
             losses: 8,
@@ -338,15 +323,9 @@ mod tests {
             conference_name: Some("Eastern".to_string()),
             division_abbrev: "ATL".to_string(),
             division_name: "Atlantic".to_string(),
-            team_name: LocalizedString {
-                default: "Toronto Maple Leafs".to_string(),
-            },
-            team_common_name: LocalizedString {
-                default: "Maple Leafs".to_string(),
-            },
-            team_abbrev: LocalizedString {
-                default: "TOR".to_string(),
-            },
+            team_name: LocalizedString::new("Toronto Maple Leafs"),
+            team_common_name: LocalizedString::new("Maple Leafs"),
+            team_abbrev: LocalizedString::new("TOR"),
             team_logo: "https://assets.nhle.com/logos/nhl/svg/TOR_light.svg".to_string(),
             wins: 15,
             losses: 10,
@@ -364,15 +343,9 @@ mod tests {
             conference_name: Some("Western".to_string()),
             division_abbrev: "CEN".to_string(),
             division_name: "Central".to_string(),
-            team_name: LocalizedString {
-                default: "Test Team".to_string(),
-            },
-            team_common_name: LocalizedString {
-                default: "Test".to_string(),
-            },
-            team_abbrev: LocalizedString {
-                default: "TST".to_string(),
-            },
+            team_name: LocalizedString::new("Test Team"),
+            team_common_name: LocalizedString::new("Test"),
+            team_abbrev: LocalizedString::new("TST"),
             team_logo: "https://example.com/logo.svg".to_string(),
             wins: 0,
             losses: 0,
@@ -390,15 +363,9 @@ mod tests {
             conference_name: Some("Eastern".to_string()),
             division_abbrev: "ATL".to_string(),
             division_name: "Atlantic".to_string(),
-            team_name: LocalizedString {
-                default: "Undefeated Team".to_string(),
-            },
-            team_common_name: LocalizedString {
-                default: "Undefeated".to_string(),
-            },
-            team_abbrev: LocalizedString {
-                default: "UND".to_string(),
-            },
+            team_name: LocalizedString::new("Undefeated Team"),
+            team_common_name: LocalizedString::new("Undefeated"),
+            team_abbrev: LocalizedString::new("UND"),
             team_logo: "https://example.com/logo.svg".to_string(),
             wins: 10,
             losses: 0,
@@ -416,15 +383,9 @@ mod tests {
             conference_name: Some("Western".to_string()),
             division_abbrev: "PAC".to_string(),
             division_name: "Pacific".to_string(),
-            team_name: LocalizedString {
-                default: "Winless Team".to_string(),
-            },
-            team_common_name: LocalizedString {
-                default: "Winless".to_string(),
-            },
-            team_abbrev: LocalizedString {
-                default: "WLS".to_string(),
-            },
+            team_name: LocalizedString::new("Winless Team"),
+            team_common_name: LocalizedString::new("Winless"),
+            team_abbrev: LocalizedString::new("WLS"),
             team_logo: "https://example.com/logo.svg".to_string(),
             wins: 0,
             losses: 15,
@@ -442,15 +403,9 @@ mod tests {
             conference_name: Some("Eastern".to_string()),
             division_abbrev: "MET".to_string(),
             division_name: "Metropolitan".to_string(),
-            team_name: LocalizedString {
-                default: "OT Loss Team".to_string(),
-            },
-            team_common_name: LocalizedString {
-                default: "OT Loss".to_string(),
-            },
-            team_abbrev: LocalizedString {
-                default: "OTL".to_string(),
-            },
+            team_name: LocalizedString::new("OT Loss Team"),
+            team_common_name: LocalizedString::new("OT Loss"),
+            team_abbrev: LocalizedString::new("OTL"),
             team_logo: "https://example.com/logo.svg".to_string(),
             wins: 0,
             losses: 0,
@@ -468,15 +423,9 @@ mod tests {
             conference_name: Some("Western".to_string()),
             division_abbrev: "CEN".to_string(),
             division_name: "Central".to_string(),
-            team_name: LocalizedString {
-                default: "Colorado Avalanche".to_string(),
-            },
-            team_common_name: LocalizedString {
-                default: "Avalanche".to_string(),
-            },
-            team_abbrev: LocalizedString {
-                default: "COL".to_string(),
-            },
+            team_name: LocalizedString::new("Colorado Avalanche"),
+            team_common_name: LocalizedString::new("Avalanche"),
+            team_abbrev: LocalizedString::new("COL"),
             team_logo: "https://assets.nhle.com/logos/nhl/svg/COL_light.svg".to_string(),
             wins: 50,
             losses: 20,
@@ -495,15 +444,9 @@ mod tests {
             conference_name: Some("Eastern".to_string()),
             division_abbrev: "ATL".to_string(),
             division_name: "Atlantic".to_string(),
-            team_name: LocalizedString {
-                default: "Buffalo Sabres".to_string(),
-            },
-            team_common_name: LocalizedString {
-                default: "Sabres".to_string(),
-            },
-            team_abbrev: LocalizedString {
-                default: "BUF".to_string(),
-            },
+            team_name: LocalizedString::new("Buffalo Sabres"),
+            team_common_name: LocalizedString::new("Sabres"),
+            team_abbrev: LocalizedString::new("BUF"),
             team_logo: "https://assets.nhle.com/logos/nhl/svg/BUF_light.svg".to_string(),
             wins: 10,
             losses: 5,
@@ -578,15 +521,9 @@ mod tests {
             conference_name: Some("Western".to_string()),
             division_abbrev: "PAC".to_string(),
             division_name: "Pacific".to_string(),
-            team_name: LocalizedString {
-                default: "Vegas Golden Knights".to_string(),
-            },
-            team_common_name: LocalizedString {
-                default: "Senators".to_string(),
-            },
-            team_abbrev: LocalizedString {
-                default: "VGK".to_string(),
-            },
+            team_name: LocalizedString::new("Vegas Golden Knights"),
+            team_common_name: LocalizedString::new("Senators"),
+            team_abbrev: LocalizedString::new("VGK"),
             team_logo: "https://assets.nhle.com/logos/nhl/svg/VGK_light.svg".to_string(),
             wins: 0,
             losses: 0,
@@ -607,6 +544,7 @@ mod tests {
         }"#;
 
         let season_info: SeasonInfo = serde_json::from_str(json).unwrap();
+        assert_no_unmapped_fields::<SeasonInfo>(json);
         assert_eq!(season_info.id, Season::new(2024));
         assert_eq!(season_info.standings_start, "2024-10-04");
         assert_eq!(season_info.standings_end, "2025-04-17");
@@ -644,6 +582,7 @@ mod tests {
         }"#;
 
         let response: SeasonsResponse = serde_json::from_str(json).unwrap();
+        assert_no_unmapped_fields::<SeasonsResponse>(json);
         assert_eq!(response.seasons.len(), 2);
         assert_eq!(response.seasons[0].id, Season::new(2024));
         assert_eq!(response.seasons[1].id, Season::new(2023));
@@ -0,0 +1,161 @@
+//! Start times in UTC, venue and Eastern time.
+//!
+//! Game center payloads carry the start as a UTC timestamp string
+//! (`startTimeUTC`) plus `"±HH:MM"` offsets for the venue and for Eastern
+//! time. [`GameVenueTime`] reads them together; get one from
+//! `Boxscore::venue_time()`, `GameMatchup::venue_time()` or
+//! `GameStory::venue_time()`.
+
+use chrono::{DateTime, FixedOffset, NaiveDate, Utc};
+use thiserror::Error;
+
+/// Error returned when a start time isn't an RFC 3339 timestamp or a UTC
+/// offset isn't `"±HH:MM"`.
+#[derive(Debug, Clone, PartialEq, Eq, Error)]
+#[error("invalid game time: {0:?} (expected an RFC 3339 timestamp or a \"±HH:MM\" offset)")]
+pub struct ParseGameTimeError(String);
+
+/// A game's start time with the venue's and Eastern UTC offsets
+///
+/// ```
+/// use nhl_api::GameVenueTime;
+///
+/// // 10:30pm Eastern in Los Angeles: already the next day in UTC.
+/// let time = GameVenueTime {
+///     start_time_utc: "2024-01-10T03:30:00Z",
+///     venue_utc_offset: "-08:00",
+///     eastern_utc_offset: "-05:00",
+/// };
+/// assert_eq!(time.start_time_local()?.to_string(), "2024-01-09 19:30:00 -08:00");
+/// assert_eq!(time.local_date()?.to_string(), "2024-01-09");
+/// # Ok::<(), nhl_api::ParseGameTimeError>(())
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct GameVenueTime<'a> {
+    /// `startTimeUTC`, e.g. `"2024-10-04T17:00:00Z"`
+    pub start_time_utc: &'a str,
+    /// `venueUTCOffset`, e.g. `"+02:00"`
+    pub venue_utc_offset: &'a str,
+    /// `easternUTCOffset`, `"-05:00"` or `"-04:00"`
+    pub eastern_utc_offset: &'a str,
+}
+
+impl GameVenueTime<'_> {
+    /// The scheduled start.
+    pub fn start_time_utc(&self) -> Result<DateTime<Utc>, ParseGameTimeError> {
+        DateTime::parse_from_rfc3339(self.start_time_utc)
+            .map(|start| start.with_timezone(&Utc))
+            .map_err(|_| ParseGameTimeError(self.start_time_utc.to_string()))
+    }
+
+    /// The start in venue time.
+    pub fn start_time_local(&self) -> Result<DateTime<FixedOffset>, ParseGameTimeError> {
+        Ok(self
+            .start_time_utc()?
+            .with_timezone(&parse_utc_offset(self.venue_utc_offset)?))
+    }
+
+    /// The start in Eastern time, as the NHL lists it.
+    pub fn start_time_eastern(&self) -> Result<DateTime<FixedOffset>, ParseGameTimeError> {
+        Ok(self
+            .start_time_utc()?
+            .with_timezone(&parse_utc_offset(self.eastern_utc_offset)?))
+    }
+
+    /// The date at the venue, which is the day before the UTC date for
+    /// evening games in the Americas.
+    pub fn local_date(&self) -> Result<NaiveDate, ParseGameTimeError> {
+        Ok(self.start_time_local()?.date_naive())
+    }
+}
+
+/// Parse a `"±HH:MM"` offset, e.g. `"-05:00"` or `"-03:30"`.
+fn parse_utc_offset(offset: &str) -> Result<FixedOffset, ParseGameTimeError> {
+    let invalid = || ParseGameTimeError(offset.to_string());
+    let (sign, rest) = match offset.as_bytes().first() {
+        Some(b'+') => (1, &offset[1..]),
+        Some(b'-') => (-1, &offset[1..]),
+        _ => return Err(invalid()),
+    };
+    let (hours, minutes) = rest.split_once(':').ok_or_else(invalid)?;
+    if hours.len() != 2 || minutes.len() != 2 {
+        return Err(invalid());
+    }
+    let hours: i32 = hours.parse().map_err(|_| invalid())?;
+    let minutes: i32 = minutes.parse().map_err(|_| invalid())?;
+    if minutes >= 60 {
+        return Err(invalid());
+    }
+    FixedOffset::east_opt(sign * (hours * 3600 + minutes * 60)).ok_or_else(invalid)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_late_eastern_start_crosses_midnight_utc() {
+        let time = GameVenueTime {
+            start_time_utc: "2024-01-10T03:30:00Z",
+            venue_utc_offset: "-05:00",
+            eastern_utc_offset: "-05:00",
+        };
+
+        assert_eq!(
+            time.start_time_utc().unwrap().to_rfc3339(),
+            "2024-01-10T03:30:00+00:00"
+        );
+        assert_eq!(
+            time.start_time_eastern().unwrap().to_rfc3339(),
+            "2024-01-09T22:30:00-05:00"
+        );
+        assert_eq!(
+            time.local_date().unwrap(),
+            NaiveDate::from_ymd_opt(2024, 1, 9).unwrap()
+        );
+    }
+
+    #[test]
+    fn test_half_hour_venue_offset() {
+        let time = GameVenueTime {
+            start_time_utc: "2024-10-04T17:00:00Z",
+            venue_utc_offset: "-03:30",
+            eastern_utc_offset: "-04:00",
+        };
+
+        assert_eq!(
+            time.start_time_local().unwrap().to_rfc3339(),
+            "2024-10-04T13:30:00-03:30"
+        );
+        assert_eq!(
+            time.start_time_eastern().unwrap().to_rfc3339(),
+            "2024-10-04T13:00:00-04:00"
+        );
+        assert_eq!(
+            parse_utc_offset("+05:30").unwrap().local_minus_utc(),
+            5 * 3600 + 30 * 60
+        );
+    }
+
+    #[test]
+    fn test_invalid_times_are_errors() {
+        let time = GameVenueTime {
+            start_time_utc: "",
+            venue_utc_offset: "-05:00",
+            eastern_utc_offset: "-05:00",
+        };
+        assert_eq!(
+            time.start_time_local(),
+            Err(ParseGameTimeError(String::new()))
+        );
+
+        for offset in ["", "05:00", "-5:00", "-05", "-05:60", "+99:00", "-0a:00"] {
+            assert_eq!(
+                parse_utc_offset(offset),
+                Err(ParseGameTimeError(offset.to_string())),
+                "{}",
+                offset
+            );
+        }
+    }
+}
@@ -0,0 +1,63 @@
+//! `cargo check`s the library under curated feature combinations, each with
+//! no default features, to catch code that only builds next to another
+//! feature (a missing `#[cfg]` guard or optional dependency).
+//!
+//! Each combination is a separate build, so this is ignored by default:
+//!
+//! ```text
+//! cargo test --test feature_matrix -- --ignored
+//! ```
+
+use std::process::Command;
+
+use nhl_api::features::FEATURES;
+
+/// Combinations checked besides no features, each feature alone, and all of
+/// them together.
+const COMBINATIONS: &[&str] = &[
+    "blocking,csv",
+    "binary-cache,csv",
+    "fixtures,test-util",
+    "fixtures,lenient-enums",
+    "wasm,binary-cache",
+];
+
+fn cargo_check(features: Option<&str>) -> bool {
+    let mut command = Command::new(env!("CARGO"));
+    command.args([
+        "check",
+        "--lib",
+        "--quiet",
+        "--no-default-features",
+        "--manifest-path",
+        concat!(env!("CARGO_MANIFEST_DIR"), "/Cargo.toml"),
+        // A target dir of its own: the outer `cargo test` holds the lock on
+        // the default one.
+        "--target-dir",
+        concat!(env!("CARGO_MANIFEST_DIR"), "/target/feature-matrix"),
+    ]);
+    match features {
+        Some(features) => command.args(["--features", features]),
+        None => command.arg("--all-features"),
+    };
+    command.status().expect("failed to run cargo").success()
+}
+
+#[test]
+#[ignore = "runs one cargo check per feature combination"]
+fn test_feature_combinations_build() {
+    let mut combinations: Vec<String> = vec![String::new()];
+    combinations.extend(FEATURES.iter().map(|feature| feature.to_string()));
+    combinations.extend(COMBINATIONS.iter().map(|features| features.to_string()));
+
+    let mut failures: Vec<String> = combinations
+        .iter()
+        .filter(|features| !cargo_check(Some(features)))
+        .map(|features| format!("--features {:?}", features))
+        .collect();
+    if !cargo_check(None) {
+        failures.push("--all-features".to_string());
+    }
+
+    assert!(failures.is_empty(), "failed to build with {:?}", failures);
+}
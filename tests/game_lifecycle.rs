@@ -0,0 +1,308 @@
+//! One game followed from scheduled to final against a mock server.
+//!
+//! Every gamecenter route serves its payloads in lifecycle order (via
+//! `test_util::ResponseSequence`), and the public API is driven the way a
+//! live-game app would: poll while live, then compose the final payloads.
+//! The assertions are on how the pieces fit together (cache policy across
+//! state changes, SOG repair on a live snapshot, official vs derived stars)
+//! rather than on individual deserializations.
+
+use mockito::{Matcher, Mock, Server, ServerGuard};
+use nhl_api::analytics::derive_three_stars;
+use nhl_api::test_util::ResponseSequence;
+use nhl_api::{Client, ClientConfig, GameId, GameState, PlayerId};
+use serde_json::{json, Value};
+use std::time::Duration;
+
+const GAME_ID: i64 = 2024020444;
+
+const BOXSCORE_JSON: &str = include_str!("fixtures/boxscore.json");
+/// Six events over three periods; the away SOG counter dips on event 141.
+const PLAY_BY_PLAY_JSON: &str = include_str!("fixtures/play_by_play_sog_blip.json");
+
+/// Events in each live play-by-play snapshot, with the header's
+/// `(away, home)` shots on goal at that point.
+const LIVE_SNAPSHOTS: [(usize, (i64, i64)); 3] = [(2, (1, 0)), (4, (2, 1)), (5, (2, 2))];
+
+fn play_by_play() -> Value {
+    serde_json::from_str(PLAY_BY_PLAY_JSON).unwrap()
+}
+
+fn set_team_totals(payload: &mut Value, score: (i64, i64), sog: (i64, i64)) {
+    payload["awayTeam"]["score"] = json!(score.0);
+    payload["awayTeam"]["sog"] = json!(sog.0);
+    payload["homeTeam"]["score"] = json!(score.1);
+    payload["homeTeam"]["sog"] = json!(sog.1);
+}
+
+/// The boxscore at `state`, for the play-by-play fixture's game and teams.
+fn boxscore(state: &str, score: (i64, i64), sog: (i64, i64)) -> String {
+    let play_by_play = play_by_play();
+    let mut boxscore: Value = serde_json::from_str(BOXSCORE_JSON).unwrap();
+    boxscore["id"] = json!(GAME_ID);
+    boxscore["gameState"] = json!(state);
+    boxscore["awayTeam"] = play_by_play["awayTeam"].clone();
+    boxscore["homeTeam"] = play_by_play["homeTeam"].clone();
+    set_team_totals(&mut boxscore, score, sog);
+    boxscore.to_string()
+}
+
+fn live_play_by_play(events: usize, sog: (i64, i64)) -> String {
+    let mut play_by_play = play_by_play();
+    play_by_play["gameState"] = json!("LIVE");
+    let plays = play_by_play["plays"].as_array_mut().unwrap();
+    plays.truncate(events);
+    let period = plays.last().unwrap()["periodDescriptor"].clone();
+    play_by_play["periodDescriptor"] = period;
+    set_team_totals(&mut play_by_play, (0, 0), sog);
+    play_by_play.to_string()
+}
+
+/// Header fields shared by the landing and game story payloads.
+fn game_header(state: &str) -> Value {
+    let play_by_play = play_by_play();
+    let mut header = json!({
+        "venueTimezone": "America/New_York",
+        "tiesInUse": false,
+        "gameState": state,
+    });
+    for key in [
+        "id",
+        "season",
+        "gameType",
+        "limitedScoring",
+        "gameDate",
+        "venue",
+        "venueLocation",
+        "startTimeUTC",
+        "easternUTCOffset",
+        "venueUTCOffset",
+        "periodDescriptor",
+        "gameScheduleState",
+        "shootoutInUse",
+        "otInUse",
+        "maxPeriods",
+    ] {
+        header[key] = play_by_play[key].clone();
+    }
+    header
+}
+
+fn star(star: i32, player_id: i64, team_abbrev: &str, name: &str, position: &str) -> Value {
+    json!({
+        "star": star, "playerId": player_id, "teamAbbrev": team_abbrev, "headshot": "",
+        "name": {"default": name}, "sweaterNo": 0, "position": position,
+    })
+}
+
+fn landing(state: &str, three_stars: Option<Vec<Value>>) -> String {
+    let play_by_play = play_by_play();
+    let mut landing = game_header(state);
+    landing["awayTeam"] = play_by_play["awayTeam"].clone();
+    landing["homeTeam"] = play_by_play["homeTeam"].clone();
+    if let Some(three_stars) = three_stars {
+        landing["summary"] = json!({"scoring": [], "threeStars": three_stars});
+    }
+    landing.to_string()
+}
+
+/// A final game story whose summary has no three stars.
+fn game_story() -> String {
+    let play_by_play = play_by_play();
+    let mut story = game_header("OFF");
+    for side in ["awayTeam", "homeTeam"] {
+        let team = &play_by_play[side];
+        story[side] = json!({
+            "id": team["id"], "name": team["commonName"], "abbrev": team["abbrev"],
+            "placeName": team["placeName"], "score": team["score"], "sog": team["sog"],
+            "logo": team["logo"],
+        });
+    }
+    story["summary"] = json!({"scoring": []});
+    story.to_string()
+}
+
+fn shift(id: i64, player_id: i64, team_abbrev: &str, team_id: i64, period: i32) -> Value {
+    json!({
+        "id": id, "detailCode": 0, "duration": "00:45", "endTime": "01:30",
+        "eventNumber": null, "firstName": "", "gameId": GAME_ID, "hexValue": "#000000",
+        "lastName": "", "period": period, "playerId": player_id, "shiftNumber": 1,
+        "startTime": "00:45", "teamAbbrev": team_abbrev, "teamId": team_id, "teamName": "",
+        "typeCode": 517,
+    })
+}
+
+/// Mocks `path` to answer from `responses`, one body per request.
+async fn sequenced_mock(
+    server: &mut ServerGuard,
+    path: &str,
+    responses: &ResponseSequence,
+) -> Mock {
+    let responses = responses.clone();
+    server
+        .mock("GET", path)
+        .match_query(Matcher::Any)
+        .with_status(200)
+        .with_body_from_request(move |_| responses.next_body().into_bytes())
+        .create_async()
+        .await
+}
+
+fn lifecycle_client(server: &ServerGuard) -> Client {
+    let config = ClientConfig::default()
+        .with_api_web_base(format!("{}/web/", server.url()))
+        .with_api_stats_base(format!("{}/stats/", server.url()))
+        .with_cache_ttl(Duration::from_secs(3600));
+    Client::with_config(config).unwrap()
+}
+
+#[tokio::test]
+async fn test_game_lifecycle_from_scheduled_to_final() {
+    let mut server = Server::new_async().await;
+    let gamecenter = |resource: &str| format!("/web/gamecenter/{}/{}", GAME_ID, resource);
+
+    let boxscores = ResponseSequence::new([
+        boxscore("FUT", (0, 0), (0, 0)),
+        boxscore("OFF", (2, 1), (3, 2)),
+    ]);
+    let landings = ResponseSequence::new([
+        landing("FUT", None),
+        landing(
+            "OFF",
+            Some(vec![
+                star(1, 8479407, "NJD", "N. Hischier", "C"),
+                star(2, 8474593, "NJD", "J. Markstrom", "G"),
+                star(3, 8480840, "BUF", "R. Dahlin", "D"),
+            ]),
+        ),
+    ]);
+    let mut play_by_plays: Vec<String> = LIVE_SNAPSHOTS
+        .iter()
+        .map(|&(events, sog)| live_play_by_play(events, sog))
+        .collect();
+    play_by_plays.push(PLAY_BY_PLAY_JSON.replace("\"FINAL\"", "\"OFF\""));
+    let play_by_plays = ResponseSequence::new(play_by_plays);
+    let stories = ResponseSequence::new([game_story()]);
+    let shifts = ResponseSequence::new([json!({"data": [
+        shift(1, 8479407, "NJD", 1, 1),
+        shift(2, 8480840, "BUF", 7, 1),
+        shift(3, 8479407, "NJD", 1, 2),
+    ], "total": 3})
+    .to_string()]);
+
+    let _boxscore_mock = sequenced_mock(&mut server, &gamecenter("boxscore"), &boxscores).await;
+    let _landing_mock = sequenced_mock(&mut server, &gamecenter("landing"), &landings).await;
+    let _pbp_mock = sequenced_mock(&mut server, &gamecenter("play-by-play"), &play_by_plays).await;
+    let _story_mock = sequenced_mock(
+        &mut server,
+        &format!("/web/wsc/game-story/{}", GAME_ID),
+        &stories,
+    )
+    .await;
+    let _shift_mock = sequenced_mock(&mut server, "/stats/en/shiftcharts", &shifts).await;
+
+    let client = lifecycle_client(&server);
+
+    // Pre-game: nothing is final, so nothing is cached.
+    let scheduled = client.boxscore(GAME_ID).await.unwrap();
+    assert_eq!(scheduled.game_state, GameState::Future);
+    let pregame = client.landing(GAME_ID).await.unwrap();
+    assert_eq!(pregame.game_state, GameState::Future);
+    assert!(pregame.summary.is_none());
+
+    // Live: every poll goes to the network, and the SOG totals never go
+    // backwards even though the third snapshot carries a counter dip.
+    let mut previous_totals = (0, 0);
+    for (snapshot, &(events, (away_sog, home_sog))) in LIVE_SNAPSHOTS.iter().enumerate() {
+        let live = client.play_by_play(GAME_ID).await.unwrap();
+        assert_eq!(live.game_state, GameState::Live);
+        assert_eq!(live.plays.len(), events);
+        assert_eq!(play_by_plays.served(), snapshot + 1);
+
+        let progression = live.sog_progression();
+        let last = progression.last().unwrap();
+        assert!(last.away >= previous_totals.0 && last.home >= previous_totals.1);
+        previous_totals = (last.away, last.home);
+
+        let validation = live.validate_sog();
+        assert!(validation.is_consistent(), "snapshot {}", snapshot);
+        assert_eq!(
+            (validation.away_reported, validation.home_reported),
+            (Some(away_sog as i32), Some(home_sog as i32))
+        );
+    }
+
+    // Final: the boxscore agrees with the play-by-play, and both are now
+    // cached for good.
+    let final_play_by_play = client.play_by_play(GAME_ID).await.unwrap();
+    assert!(final_play_by_play.game_state.is_final());
+    let final_boxscore = client.boxscore(GAME_ID).await.unwrap();
+    assert!(final_boxscore.game_state.is_final());
+    let validation = final_play_by_play.validate_sog();
+    assert!(validation.is_consistent());
+    assert_eq!(validation.repairs, 1);
+    assert_eq!(Some(validation.away_counted), final_boxscore.away_team.sog);
+    assert_eq!(Some(validation.home_counted), final_boxscore.home_team.sog);
+    assert_eq!(
+        (
+            final_play_by_play.away_team.score,
+            final_play_by_play.home_team.score
+        ),
+        (
+            final_boxscore.away_team.score,
+            final_boxscore.home_team.score
+        )
+    );
+    client.play_by_play(GAME_ID).await.unwrap();
+    assert_eq!(play_by_plays.served(), 4);
+    assert_eq!(client.stats().cache_hits, 1);
+
+    // Stars: the landing's official ones win; the story has none, so they
+    // are derived from the final boxscore.
+    let final_landing = client.landing(GAME_ID).await.unwrap();
+    let official = final_landing
+        .summary
+        .as_ref()
+        .unwrap()
+        .three_stars_or_derived(&final_boxscore);
+    assert!(official.iter().all(|star| star.official));
+    assert_eq!(official[1].player_id, PlayerId::new(8474593));
+
+    let story = client.game_story(GAME_ID).await.unwrap();
+    assert_eq!(story.away_team.score, final_boxscore.away_team.score);
+    let derived = story
+        .summary
+        .as_ref()
+        .unwrap()
+        .three_stars_or_derived(&final_boxscore);
+    assert_eq!(derived, derive_three_stars(&final_boxscore));
+    assert!(derived.iter().all(|star| !star.official));
+    let derived_ids: Vec<PlayerId> = derived.iter().map(|star| star.player_id).collect();
+    assert_eq!(
+        derived_ids,
+        [8479407, 8480840, 8480002].map(PlayerId::new).to_vec()
+    );
+
+    // Shifts are never cached, but belong to the same game and players.
+    let shift_chart = client.shift_chart(GAME_ID).await.unwrap();
+    assert!(shift_chart
+        .data
+        .iter()
+        .all(|shift| shift.game_id == GameId::new(GAME_ID)));
+    let skaters = &final_boxscore.player_by_game_stats;
+    assert!(shift_chart.data.iter().all(|shift| skaters
+        .away_team
+        .forwards
+        .iter()
+        .chain(&skaters.home_team.defense)
+        .any(|skater| skater.player_id == shift.player_id)));
+    client.shift_chart(GAME_ID).await.unwrap();
+    assert_eq!(shifts.served(), 2);
+
+    // Everything final came from the network exactly once.
+    client.boxscore(GAME_ID).await.unwrap();
+    client.landing(GAME_ID).await.unwrap();
+    assert_eq!(boxscores.served(), 2);
+    assert_eq!(landings.served(), 2);
+    assert_eq!(stories.served(), 1);
+}
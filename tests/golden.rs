@@ -0,0 +1,129 @@
+//! Analytics outputs over the shared fixtures, pinned to `tests/golden/`.
+//!
+//! A change to any of these derived values shows up as a path-by-path
+//! diff. When the change is intended, rerun with `UPDATE_GOLDEN=1` and
+//! review the rewritten files like any other diff.
+
+use chrono::NaiveDate;
+use nhl_api::analytics::{derive_three_stars, GoalieStart, GoalieUsageReport, ShotContextConfig};
+use nhl_api::test_util::golden::{self, Golden, GoldenError};
+use nhl_api::{
+    Boxscore, GameState, GoalieDecision, HomeRoad, PlayByPlay, PlayerGameLog, TeamGameStats,
+};
+use serde_json::json;
+
+const BOXSCORE_JSON: &str = include_str!("fixtures/boxscore.json");
+const PLAY_BY_PLAY_JSON: &str = include_str!("fixtures/play_by_play.json");
+const GOALIE_GAME_LOG_JSON: &str = include_str!("fixtures/game_log_goalie.json");
+
+fn boxscore() -> Boxscore {
+    serde_json::from_str(BOXSCORE_JSON).unwrap()
+}
+
+fn play_by_play() -> PlayByPlay {
+    serde_json::from_str(PLAY_BY_PLAY_JSON).unwrap()
+}
+
+#[test]
+fn golden_three_stars() {
+    golden::assert_matches("three_stars", &derive_three_stars(&boxscore()));
+}
+
+#[test]
+fn golden_goalie_usage() {
+    // The boxscore fixture is a live game; starters are only read from
+    // final ones.
+    let mut final_boxscore = boxscore();
+    final_boxscore.game_state = GameState::Final;
+    let boxscore_starts: Vec<GoalieStart> = [&final_boxscore.away_team, &final_boxscore.home_team]
+        .iter()
+        .filter_map(|team| GoalieStart::from_boxscore(&team.abbrev, &final_boxscore))
+        .collect();
+
+    let log: PlayerGameLog = serde_json::from_str(GOALIE_GAME_LOG_JSON).unwrap();
+    let log_starts: Vec<GoalieStart> = log
+        .game_log
+        .iter()
+        .filter(|game| game.games_started == Some(1))
+        .map(|game| GoalieStart {
+            game_id: game.game_id,
+            date: NaiveDate::parse_from_str(&game.game_date, "%Y-%m-%d").unwrap(),
+            starter: log.player_id,
+            starter_name: "Game Log Goalie".to_string(),
+            won: game.decision == Some(GoalieDecision::Win),
+        })
+        .collect();
+
+    golden::assert_matches(
+        "goalie_usage",
+        &json!({
+            "boxscore_starts": boxscore_starts,
+            "game_log_report": GoalieUsageReport::from_starts(&log_starts, &[]),
+        }),
+    );
+}
+
+#[test]
+fn golden_team_stats() {
+    let boxscore = boxscore();
+    let play_by_play = play_by_play();
+    let side = |side: HomeRoad| {
+        let (combined, provenance) =
+            TeamGameStats::from_sources(&boxscore, None, Some(&play_by_play), side);
+        json!({
+            "play_by_play": play_by_play.team_stats(side),
+            "player_stats": TeamGameStats::from_team_player_stats(match side {
+                HomeRoad::Home => &boxscore.player_by_game_stats.home_team,
+                HomeRoad::Road => &boxscore.player_by_game_stats.away_team,
+            }),
+            "combined": combined,
+            "provenance": provenance,
+        })
+    };
+
+    golden::assert_matches(
+        "team_stats",
+        &json!({"away": side(HomeRoad::Road), "home": side(HomeRoad::Home)}),
+    );
+}
+
+#[test]
+fn golden_shot_attempts() {
+    golden::assert_matches(
+        "shot_attempts",
+        &play_by_play().shot_attempts_enriched(ShotContextConfig::default()),
+    );
+}
+
+/// A changed output fails with the differing paths, not a blanket
+/// "values differ".
+#[test]
+fn golden_mismatch_fails_with_a_diff() {
+    let mut stars = derive_three_stars(&boxscore());
+    stars[0].player_id = nhl_api::PlayerId::new(1);
+    stars.pop();
+
+    let err = Golden::default()
+        .update(false)
+        .check("three_stars", &stars)
+        .unwrap_err();
+    let GoldenError::Mismatch { differences, .. } = &err else {
+        panic!("expected a mismatch, got {}", err);
+    };
+    assert_eq!(differences.len(), 2, "{}", err);
+
+    let message = err.to_string();
+    assert!(message.contains("three_stars.json differs"), "{}", message);
+    assert!(message.contains("/0/player_id: expected "), "{}", message);
+    assert!(message.contains(", got 1"), "{}", message);
+    assert!(message.contains("/2: expected {"), "{}", message);
+    assert!(message.contains("UPDATE_GOLDEN=1"), "{}", message);
+}
+
+#[test]
+#[should_panic(expected = "differs from the golden file")]
+fn golden_assert_matches_panics_on_mismatch() {
+    Golden::default()
+        .update(false)
+        .assert_matches("shot_attempts", &Vec::<u8>::new());
+}
@@ -1,4 +1,4 @@
-use nhl_api::{Client, PlayEventType};
+use nhl_api::{Client, HomeRoad, PlayEventType, TeamGameStats, TeamStatValue};
 
 #[tokio::test]
 async fn test_play_by_play_real_game() {
@@ -30,6 +30,24 @@ async fn test_play_by_play_real_game() {
     }
 }
 
+#[tokio::test]
+async fn test_play_by_play_real_game_has_no_unknown_events() {
+    let client = Client::new().unwrap();
+    let pbp = client.play_by_play(2024020444).await.unwrap();
+
+    let unknown: Vec<_> = pbp
+        .plays
+        .iter()
+        .filter(|play| play.event_type() == PlayEventType::Unknown)
+        .map(|play| (play.type_code, play.event_id))
+        .collect();
+    assert!(
+        unknown.is_empty(),
+        "unmapped (typeCode, eventId): {:?}",
+        unknown
+    );
+}
+
 #[tokio::test]
 async fn test_recent_plays() {
     let client = Client::new().unwrap();
@@ -53,14 +71,28 @@ async fn test_play_by_play_helper_methods() {
         assert_eq!(penalty.type_desc_key, PlayEventType::Penalty);
     }
 
-    // Test shots helper (includes goals, shots on goal, missed shots, blocked shots)
-    let shots = pbp.shots();
+    // Test shot attempt helper (includes goals, shots on goal, missed shots, blocked shots)
+    let shots = pbp.shot_attempts(None);
     for shot in &shots {
         assert!(shot.type_desc_key.is_scoring_chance());
     }
 
-    // Test plays_in_period
-    let period_1_plays = pbp.plays_in_period(1);
+    // Blocked shots count for the shooter's team, not the blocker's
+    let away_attempts = pbp.shot_attempts(Some(pbp.away_team.id));
+    let home_attempts = pbp.shot_attempts(Some(pbp.home_team.id));
+    assert_eq!(away_attempts.len() + home_attempts.len(), shots.len());
+    for blocked in shots
+        .iter()
+        .filter(|p| p.type_desc_key == PlayEventType::BlockedShot)
+    {
+        let details = blocked.details.as_ref().unwrap();
+        let blocker = pbp.get_player(details.blocking_player_id.unwrap()).unwrap();
+        assert_eq!(details.event_owner_team_id, Some(blocker.team_id));
+        assert_ne!(pbp.shooting_team_id(blocked), Some(blocker.team_id));
+    }
+
+    // Test events_in_period
+    let period_1_plays = pbp.events_in_period(1);
     assert!(!period_1_plays.is_empty());
     for play in &period_1_plays {
         assert_eq!(play.period_descriptor.number, 1);
@@ -86,3 +118,109 @@ async fn test_play_by_play_helper_methods() {
     let situation = pbp.current_situation();
     assert!(situation.is_some());
 }
+
+#[tokio::test]
+async fn test_play_by_play_team_stats_match_boxscore() {
+    let client = Client::new().unwrap();
+    let pbp = client.play_by_play(2024020444).await.unwrap();
+    let boxscore = client.boxscore(2024020444).await.unwrap();
+
+    for (side, players) in [
+        (HomeRoad::Road, &boxscore.player_by_game_stats.away_team),
+        (HomeRoad::Home, &boxscore.player_by_game_stats.home_team),
+    ] {
+        let from_events = pbp.team_stats(side);
+        let official = TeamGameStats::from_team_player_stats(players);
+        assert_eq!(
+            from_events.shots_on_goal, official.shots_on_goal,
+            "{:?} SOG",
+            side
+        );
+        assert_eq!(from_events.hits, official.hits, "{:?} hits", side);
+        assert_eq!(
+            from_events.blocked_shots, official.blocked_shots,
+            "{:?} blocks",
+            side
+        );
+        assert_eq!(
+            from_events.penalty_minutes, official.penalty_minutes,
+            "{:?} PIM",
+            side
+        );
+    }
+
+    let counts = pbp.player_event_counts();
+    for skater in boxscore
+        .player_by_game_stats
+        .away_team
+        .forwards
+        .iter()
+        .chain(&boxscore.player_by_game_stats.home_team.forwards)
+    {
+        let from_events = counts.get(&skater.player_id).copied().unwrap_or_default();
+        assert_eq!(
+            from_events.goals, skater.goals,
+            "{} goals",
+            skater.name.default
+        );
+        assert_eq!(
+            from_events.assists, skater.assists,
+            "{} assists",
+            skater.name.default
+        );
+        assert_eq!(
+            from_events.shots, skater.sog,
+            "{} shots",
+            skater.name.default
+        );
+    }
+}
+
+/// Faceoff and power-play counts from the events against the NHL's own
+/// right-rail team comparison for the same game.
+#[tokio::test]
+async fn test_play_by_play_faceoffs_and_power_plays_match_right_rail() {
+    let client = Client::new().unwrap();
+    let pbp = client.play_by_play(2024020444).await.unwrap();
+    let right_rail = client.season_series(2024020444).await.unwrap();
+
+    let rail = |category: &str, side: HomeRoad| {
+        right_rail
+            .team_stat(category)
+            .unwrap_or_else(|| panic!("right rail has no {}", category))
+            .value(side)
+            .clone()
+    };
+    let away = pbp.team_stats(HomeRoad::Road);
+    let home = pbp.team_stats(HomeRoad::Home);
+    assert_eq!(away.faceoff_total, home.faceoff_total);
+    assert_eq!(
+        away.faceoff_wins.zip(home.faceoff_wins).map(|(a, h)| a + h),
+        home.faceoff_total
+    );
+
+    for (side, stats) in [(HomeRoad::Road, away), (HomeRoad::Home, home)] {
+        let (goals, opportunities) = rail("powerPlay", side).as_fraction().unwrap();
+        assert_eq!(stats.power_play_goals, goals, "{:?} PP goals", side);
+        assert_eq!(
+            stats.power_play_opportunities,
+            Some(opportunities),
+            "{:?} PP opportunities",
+            side
+        );
+
+        let nhl_pctg = match rail("faceoffWinningPctg", side) {
+            TeamStatValue::Number(number) => number.as_f64().unwrap(),
+            TeamStatValue::Text(text) => text.parse().unwrap(),
+        };
+        let counted = stats.faceoff_percentage().unwrap() / 100.0;
+        // The rail rounds to three decimals.
+        assert!(
+            (counted - nhl_pctg).abs() < 0.0006,
+            "{:?} faceoffs: counted {}, NHL {}",
+            side,
+            counted,
+            nhl_pctg
+        );
+    }
+}